@@ -0,0 +1,63 @@
+use std::cmp::Ordering;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use simple_poker::domain::model::card::{Card, Suit};
+use simple_poker::domain::service::hand_evaluation::{HandEvaluationService, HandRankingRules, HandStrength};
+use simple_poker::domain::service::lookup_evaluator::LookupEvaluator;
+
+// 同じシードで作った手札の組（毎回同じ入力で比べる）
+fn sample_hands(size: usize, count: usize) -> Vec<Vec<Card>> {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut deck: Vec<Card> = Suit::all().iter()
+        .flat_map(|&suit| (1..=13).map(move |rank| Card::new(suit, rank).unwrap()))
+        .collect();
+    (0..count)
+        .map(|_| deck.partial_shuffle(&mut rng, size).0.to_vec())
+        .collect()
+}
+
+// 既存の役判定で5枚の組み合わせをすべて試す
+fn slow_best(cards: &[Card]) -> HandStrength {
+    let n = cards.len();
+    let mut best = HandEvaluationService::evaluate_hand(&cards[..5]);
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        let strength = HandEvaluationService::evaluate_hand(&[cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                        if HandRankingRules::STANDARD.compare(&strength, &best) == Ordering::Greater {
+                            best = strength;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+fn hand_evaluation(c: &mut Criterion) {
+    // 表は最初の呼び出しで作られるので、計測前に作っておく
+    LookupEvaluator::evaluate(&sample_hands(5, 1)[0]);
+
+    for size in [5, 7] {
+        let hands = sample_hands(size, 1_000);
+        let mut group = c.benchmark_group(format!("{}枚", size));
+        group.bench_function("既存の役判定", |b| {
+            b.iter(|| hands.iter().map(|hand| slow_best(black_box(hand))).max_by(|a, b| HandRankingRules::STANDARD.compare(a, b)))
+        });
+        group.bench_function("ルックアップ表", |b| {
+            b.iter(|| hands.iter().map(|hand| LookupEvaluator::evaluate(black_box(hand))).max())
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, hand_evaluation);
+criterion_main!(benches);
@@ -1,24 +1,28 @@
+use crate::domain::model::event::EventPublisher;
 use crate::domain::model::game::GameId;
-use crate::domain::model::player::{Player, PlayerId};
+use crate::domain::model::player::{BotKind, Player, PlayerId};
 use crate::domain::repository::game_repository::GameRepository;
 use crate::domain::repository::player_repository::PlayerRepository;
 
-pub struct AddPlayerUseCase<G: GameRepository, P: PlayerRepository> {
+pub struct AddPlayerUseCase<G: GameRepository, P: PlayerRepository, E: EventPublisher> {
     game_repository: G,
     player_repository: P,
+    event_publisher: E,
 }
 
 pub struct AddPlayerParams {
     pub game_id: GameId,
     pub player_name: String,
     pub initial_chips: u32,
+    pub bot: Option<BotKind>, // コンピュータが操作する場合はその種類
 }
 
-impl<G: GameRepository, P: PlayerRepository> AddPlayerUseCase<G, P> {
-    pub fn new(game_repository: G, player_repository: P) -> Self {
+impl<G: GameRepository, P: PlayerRepository, E: EventPublisher> AddPlayerUseCase<G, P, E> {
+    pub fn new(game_repository: G, player_repository: P, event_publisher: E) -> Self {
         Self {
             game_repository,
             player_repository,
+            event_publisher,
         }
     }
     
@@ -29,7 +33,10 @@ impl<G: GameRepository, P: PlayerRepository> AddPlayerUseCase<G, P> {
             .ok_or_else(|| format!("ゲーム {} が見つかりません", params.game_id.value()))?;
         
         // プレイヤーを作成
-        let player = Player::new(params.player_name, params.initial_chips);
+        let player = match params.bot {
+            Some(kind) => Player::new_bot(params.player_name, params.initial_chips, kind),
+            None => Player::new(params.player_name, params.initial_chips),
+        };
         let player_id = player.id().clone();
         
         // ゲームにプレイヤーを追加
@@ -40,8 +47,13 @@ impl<G: GameRepository, P: PlayerRepository> AddPlayerUseCase<G, P> {
         self.player_repository.save(&player)?;
         
         // 更新されたゲームを保存
+        let events = game.take_events();
         self.game_repository.save(&game)?;
         
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+        
         Ok(player_id)
     }
 }
@@ -52,6 +64,7 @@ mod tests {
     use crate::domain::model::game::{Game, GameVariant};
     use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
     use crate::infrastructure::repository::inmemory::player_repository_inmemory::InMemoryPlayerRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
     
     #[test]
     fn プレイヤー追加_正常系() {
@@ -67,12 +80,13 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = AddPlayerUseCase::new(game_repo.clone(), player_repo.clone());
+        let mut usecase = AddPlayerUseCase::new(game_repo.clone(), player_repo.clone(), InMemoryEventPublisher::new());
         
         let params = AddPlayerParams {
             game_id: game_id.clone(),
             player_name: "テストプレイヤー".to_string(),
             initial_chips: 1000,
+            bot: None,
         };
         
         let result = usecase.execute(params);
@@ -99,12 +113,13 @@ mod tests {
         let game_repo = InMemoryGameRepository::new();
         let player_repo = InMemoryPlayerRepository::new();
         
-        let mut usecase = AddPlayerUseCase::new(game_repo, player_repo);
+        let mut usecase = AddPlayerUseCase::new(game_repo, player_repo, InMemoryEventPublisher::new());
         
         let params = AddPlayerParams {
             game_id: GameId::new(), // 存在しないゲームID
             player_name: "テストプレイヤー".to_string(),
             initial_chips: 1000,
+            bot: None,
         };
         
         let result = usecase.execute(params);
@@ -126,13 +141,14 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = AddPlayerUseCase::new(game_repo.clone(), player_repo.clone());
+        let mut usecase = AddPlayerUseCase::new(game_repo.clone(), player_repo.clone(), InMemoryEventPublisher::new());
         
         // 1人目のプレイヤーを追加
         let params1 = AddPlayerParams {
             game_id: game_id.clone(),
             player_name: "プレイヤー1".to_string(),
             initial_chips: 500,
+            bot: None,
         };
         let result1 = usecase.execute(params1);
         assert!(result1.is_ok());
@@ -142,6 +158,7 @@ mod tests {
             game_id: game_id.clone(),
             player_name: "プレイヤー2".to_string(),
             initial_chips: 1000,
+            bot: None,
         };
         let result2 = usecase.execute(params2);
         assert!(result2.is_ok());
@@ -176,16 +193,37 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = AddPlayerUseCase::new(game_repo, player_repo);
+        let mut usecase = AddPlayerUseCase::new(game_repo, player_repo, InMemoryEventPublisher::new());
         
         let params = AddPlayerParams {
             game_id,
             player_name: "新規プレイヤー".to_string(),
             initial_chips: 1000,
+            bot: None,
         };
         
         let result = usecase.execute(params);
         assert!(result.is_err());
         assert!(result.err().unwrap().contains("開始"));
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn コンピュータのプレイヤーを追加() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+        
+        let mut usecase = AddPlayerUseCase::new(game_repo.clone(), InMemoryPlayerRepository::new(), InMemoryEventPublisher::new());
+        let params = AddPlayerParams {
+            game_id: game_id.clone(),
+            player_name: "コンピュータ".to_string(),
+            initial_chips: 1000,
+            bot: Some(BotKind::TightPassive),
+        };
+        usecase.execute(params).unwrap();
+        
+        let updated_game = game_repo.find_by_id(&game_id).unwrap();
+        assert_eq!(updated_game.players()[0].bot(), Some(BotKind::TightPassive), "コンピュータの席になっていません");
+    }
+}
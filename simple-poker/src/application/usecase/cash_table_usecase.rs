@@ -0,0 +1,211 @@
+use crate::domain::model::cash_table::CashTable;
+use crate::domain::model::event::EventPublisher;
+use crate::domain::model::game::GameId;
+use crate::domain::model::player::PlayerId;
+use crate::domain::repository::game_repository::GameRepository;
+use crate::domain::repository::player_repository::PlayerRepository;
+
+/// キャッシュゲームの席の出入りとチップの買い足し（チップはプレイヤーのバンクロールとやり取りする）
+pub struct CashTableUseCase<G: GameRepository, P: PlayerRepository, E: EventPublisher> {
+    game_repository: G,
+    player_repository: P,
+    event_publisher: E,
+    table: CashTable,
+}
+
+/// 席での操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CashTableCommand {
+    SitDown { buy_in: u32 }, // 持ち込むチップをバンクロールから引き出して席に着く
+    Rebuy { amount: u32 },   // 買い足すチップをバンクロールから引き出す（再購入・追加購入）
+    StandUp,                 // 席を立ち、残っているチップをバンクロールに戻す
+}
+
+pub struct CashTableParams {
+    pub game_id: GameId,
+    pub player_id: PlayerId,
+    pub command: CashTableCommand,
+}
+
+impl<G: GameRepository, P: PlayerRepository, E: EventPublisher> CashTableUseCase<G, P, E> {
+    pub fn new(game_repository: G, player_repository: P, event_publisher: E, table: CashTable) -> Self {
+        Self {
+            game_repository,
+            player_repository,
+            event_publisher,
+            table,
+        }
+    }
+
+    /// 操作を行い、バンクロールとやり取りしたチップの額を返す
+    ///
+    /// 席に着く・買い足す場合は引き出した額、席を立つ場合は戻した額になる。
+    pub fn execute(&mut self, params: CashTableParams) -> Result<u32, String> {
+        // ゲームとプレイヤーを取得
+        let mut game = self.game_repository
+            .find_by_id(&params.game_id)
+            .ok_or_else(|| format!("ゲーム {} が見つかりません", params.game_id.value()))?;
+        let mut account = self.player_repository
+            .find_by_id(&params.player_id)
+            .ok_or_else(|| format!("プレイヤー {} が見つかりません", params.player_id.value()))?;
+
+        let moved = match params.command {
+            CashTableCommand::SitDown { buy_in } => {
+                let seated = account.buy_in(buy_in).map_err(|e| e.to_string())?;
+                self.table.sit_down(&mut game, seated).map_err(|e| e.to_string())?;
+                buy_in
+            },
+            CashTableCommand::Rebuy { amount } => {
+                account.withdraw_chips(amount).map_err(|e| e.to_string())?;
+                self.table.rebuy(&mut game, &params.player_id, amount).map_err(|e| e.to_string())?;
+                amount
+            },
+            CashTableCommand::StandUp => {
+                let seated = self.table.stand_up(&mut game, &params.player_id).map_err(|e| e.to_string())?;
+                account.add_chips(seated.chips());
+                seated.chips()
+            },
+        };
+
+        // 更新されたプレイヤーとゲームを保存
+        self.player_repository.save(&account)?;
+        let events = game.take_events();
+        self.game_repository.save(&game)?;
+
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::game::{Game, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
+    use crate::infrastructure::repository::inmemory::player_repository_inmemory::InMemoryPlayerRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
+
+    // テスト用のゲーム作成（指定したチップで他のプレイヤーが座っている）
+    fn create_test_game(others: &[u32]) -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 1, 2).unwrap();
+        for (i, &chips) in others.iter().enumerate() {
+            game.add_player(Player::new(format!("他のプレイヤー{}", i + 1), chips)).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn バンクロールから持ち込んで席に着く() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let mut player_repo = InMemoryPlayerRepository::new();
+        let game = create_test_game(&[]);
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+        let account = Player::new("テストプレイヤー".to_string(), 1000);
+        let player_id = account.id().clone();
+        player_repo.save(&account).unwrap();
+
+        let table = CashTable::new(6, 100, 200).unwrap();
+        let mut usecase = CashTableUseCase::new(game_repo.clone(), player_repo.clone(), InMemoryEventPublisher::new(), table);
+        let params = CashTableParams {
+            game_id: game_id.clone(),
+            player_id: player_id.clone(),
+            command: CashTableCommand::SitDown { buy_in: 200 },
+        };
+        assert_eq!(usecase.execute(params), Ok(200), "席に着けません");
+
+        let game = game_repo.find_by_id(&game_id).unwrap();
+        assert_eq!(game.players().len(), 1, "席に着いていません");
+        assert_eq!(game.players()[0].id(), &player_id, "席のプレイヤーIDが一致しません");
+        assert_eq!(game.players()[0].chips(), 200, "持ち込んだチップが一致しません");
+        assert_eq!(player_repo.find_by_id(&player_id).unwrap().chips(), 800, "バンクロールから引き出されていません");
+    }
+
+    #[test]
+    fn 範囲外の持ち込みやバンクロール不足では席に着けない() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let mut player_repo = InMemoryPlayerRepository::new();
+        let game = create_test_game(&[]);
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+        let account = Player::new("テストプレイヤー".to_string(), 150);
+        let player_id = account.id().clone();
+        player_repo.save(&account).unwrap();
+
+        let table = CashTable::new(6, 100, 200).unwrap();
+        let mut usecase = CashTableUseCase::new(game_repo.clone(), player_repo.clone(), InMemoryEventPublisher::new(), table);
+        let too_small = usecase.execute(CashTableParams {
+            game_id: game_id.clone(),
+            player_id: player_id.clone(),
+            command: CashTableCommand::SitDown { buy_in: 50 },
+        });
+        assert!(too_small.is_err(), "最小額未満で席に着けてしまいます");
+        let too_large = usecase.execute(CashTableParams {
+            game_id: game_id.clone(),
+            player_id: player_id.clone(),
+            command: CashTableCommand::SitDown { buy_in: 180 },
+        });
+        assert!(too_large.is_err(), "バンクロールを超えて持ち込めてしまいます");
+
+        assert!(game_repo.find_by_id(&game_id).unwrap().players().is_empty(), "席に着いてしまっています");
+        assert_eq!(player_repo.find_by_id(&player_id).unwrap().chips(), 150, "バンクロールが変わっています");
+    }
+
+    #[test]
+    fn 最大額までチップを買い足す() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let mut player_repo = InMemoryPlayerRepository::new();
+        let game = create_test_game(&[]);
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+        let account = Player::new("テストプレイヤー".to_string(), 1000);
+        let player_id = account.id().clone();
+        player_repo.save(&account).unwrap();
+
+        let table = CashTable::new(6, 100, 200).unwrap();
+        let mut usecase = CashTableUseCase::new(game_repo.clone(), player_repo.clone(), InMemoryEventPublisher::new(), table);
+        let params = |command| CashTableParams { game_id: game_id.clone(), player_id: player_id.clone(), command };
+        usecase.execute(params(CashTableCommand::SitDown { buy_in: 120 })).unwrap();
+
+        let over = usecase.execute(params(CashTableCommand::Rebuy { amount: 100 }));
+        assert!(over.is_err(), "最大額を超えて買い足せてしまいます");
+        assert_eq!(player_repo.find_by_id(&player_id).unwrap().chips(), 880, "失敗した買い足しでバンクロールが減っています");
+
+        usecase.execute(params(CashTableCommand::Rebuy { amount: 80 })).unwrap();
+        assert_eq!(game_repo.find_by_id(&game_id).unwrap().players()[0].chips(), 200, "席のチップが一致しません");
+        assert_eq!(player_repo.find_by_id(&player_id).unwrap().chips(), 800, "バンクロールから引き出されていません");
+    }
+
+    #[test]
+    fn 席を立つと残りのチップがバンクロールに戻る() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let mut player_repo = InMemoryPlayerRepository::new();
+        let game = create_test_game(&[200]);
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+        let account = Player::new("テストプレイヤー".to_string(), 1000);
+        let player_id = account.id().clone();
+        player_repo.save(&account).unwrap();
+
+        let table = CashTable::new(6, 100, 200).unwrap();
+        let mut usecase = CashTableUseCase::new(game_repo.clone(), player_repo.clone(), InMemoryEventPublisher::new(), table);
+        let params = |command| CashTableParams { game_id: game_id.clone(), player_id: player_id.clone(), command };
+
+        // バンクロール1000から200を持ち込み、その後チップが350になった
+        usecase.execute(params(CashTableCommand::SitDown { buy_in: 200 })).unwrap();
+        let mut game = game_repo.find_by_id(&game_id).unwrap();
+        game.add_chips(&player_id, 150).unwrap();
+        game_repo.save(&game).unwrap();
+
+        let cash_out = usecase.execute(params(CashTableCommand::StandUp)).unwrap();
+
+        assert_eq!(cash_out, 350, "持ち帰ったチップが一致しません");
+        assert_eq!(player_repo.find_by_id(&player_id).unwrap().chips(), 1150, "バンクロールに戻っていません");
+        assert_eq!(game_repo.find_by_id(&game_id).unwrap().players().len(), 1, "席から外れていません");
+        assert!(usecase.execute(params(CashTableCommand::StandUp)).is_err(), "2回席を立てます");
+    }
+}
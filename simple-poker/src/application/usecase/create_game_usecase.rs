@@ -1,7 +1,7 @@
-use chrono::Utc;
-use crate::domain::model::game::{Game, GameId, GameVariant};
+use crate::domain::model::bet::BettingStructure;
+use crate::domain::model::game::{Game, GameId, GameVariant, TableRules};
 use crate::domain::model::error::DomainError;
-use crate::domain::model::event::{GameEvent, EventPublisher};
+use crate::domain::model::event::EventPublisher;
 use crate::domain::repository::game_repository::GameRepository;
 
 pub struct CreateGameUseCase<R: GameRepository, E: EventPublisher> {
@@ -13,6 +13,9 @@ pub struct CreateGameParams {
     pub variant: GameVariant,
     pub small_blind: u32,
     pub big_blind: u32,
+    pub betting_structure: BettingStructure,
+    pub rules: TableRules, // ボタンの動かし方や役の表など卓のルール
+    pub seed: Option<u64>, // 指定するとカードの配られ方が再現可能になる
 }
 
 impl<R: GameRepository, E: EventPublisher> CreateGameUseCase<R, E> {
@@ -21,21 +24,19 @@ impl<R: GameRepository, E: EventPublisher> CreateGameUseCase<R, E> {
     }
     
     pub fn execute(&mut self, params: CreateGameParams) -> Result<GameId, DomainError> {
-        let game = Game::new(params.variant, params.small_blind, params.big_blind)
+        let mut game = Game::new_with_structure(params.variant, params.small_blind, params.big_blind, params.betting_structure, params.seed)
             .map_err(|e| DomainError::InvalidGameOperation(e.to_string()))?;
+        if params.rules != game.rules() {
+            game.set_rules(params.rules)?;
+        }
         
         let game_id = game.id().clone();
+        let events = game.take_events();
         self.game_repository.save(&game)?;
         
-        let event = GameEvent::GameCreated {
-            game_id: game_id.clone(),
-            variant: params.variant,
-            small_blind: params.small_blind,
-            big_blind: params.big_blind,
-            time: Utc::now(),
-        };
-        
-        self.event_publisher.publish(event)?;
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
         
         Ok(game_id)
     }
@@ -44,6 +45,7 @@ impl<R: GameRepository, E: EventPublisher> CreateGameUseCase<R, E> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::model::game::{ButtonRule, OddChipRule};
     use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
     use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
     use crate::domain::model::event::EventSubscriber;
@@ -68,6 +70,9 @@ mod tests {
             variant: GameVariant::FiveCardDraw,
             small_blind: 5,
             big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            rules: TableRules::default(),
+            seed: None,
         };
         
         let result = usecase.execute(params);
@@ -91,6 +96,9 @@ mod tests {
             variant: GameVariant::FiveCardDraw,
             small_blind: 20,
             big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            rules: TableRules::default(),
+            seed: None,
         };
         
         let result = usecase.execute(params);
@@ -102,4 +110,44 @@ mod tests {
             _ => panic!("期待したエラー型ではありません"),
         }
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn シード指定でゲーム作成() {
+        let game_repo = InMemoryGameRepository::new();
+        let event_publisher = InMemoryEventPublisher::new();
+        let mut usecase = CreateGameUseCase::new(game_repo.clone(), event_publisher);
+        
+        let params = CreateGameParams {
+            variant: GameVariant::TexasHoldem,
+            small_blind: 5,
+            big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            rules: TableRules::default(),
+            seed: Some(12345),
+        };
+        
+        let game_id = usecase.execute(params).unwrap();
+        let saved_game = game_repo.find_by_id(&game_id).unwrap();
+        assert_eq!(saved_game.seed(), Some(12345), "シードがゲームに保存されていません");
+    }
+    
+    #[test]
+    fn ボタンの動かし方を指定してゲーム作成() {
+        let game_repo = InMemoryGameRepository::new();
+        let mut usecase = CreateGameUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
+        
+        let params = CreateGameParams {
+            variant: GameVariant::TexasHoldem,
+            small_blind: 5,
+            big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            rules: TableRules { button_rule: ButtonRule::MovingButton, odd_chip_rule: OddChipRule::HighestCard, ..TableRules::default() },
+            seed: None,
+        };
+        
+        let game_id = usecase.execute(params).unwrap();
+        let saved_game = game_repo.find_by_id(&game_id).unwrap();
+        assert_eq!(saved_game.rules().button_rule, ButtonRule::MovingButton, "ボタンの動かし方がゲームに保存されていません");
+        assert_eq!(saved_game.rules().odd_chip_rule, OddChipRule::HighestCard, "端数チップの渡し方がゲームに保存されていません");
+    }
+}
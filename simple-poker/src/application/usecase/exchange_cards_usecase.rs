@@ -1,9 +1,11 @@
+use crate::domain::model::event::EventPublisher;
 use crate::domain::model::game::GameId;
 use crate::domain::model::player::PlayerId;
 use crate::domain::repository::game_repository::GameRepository;
 
-pub struct ExchangeCardsUseCase<R: GameRepository> {
+pub struct ExchangeCardsUseCase<R: GameRepository, E: EventPublisher> {
     game_repository: R,
+    event_publisher: E,
 }
 
 pub struct ExchangeCardsParams {
@@ -12,9 +14,9 @@ pub struct ExchangeCardsParams {
     pub card_indices: Vec<usize>,
 }
 
-impl<R: GameRepository> ExchangeCardsUseCase<R> {
-    pub fn new(game_repository: R) -> Self {
-        Self { game_repository }
+impl<R: GameRepository, E: EventPublisher> ExchangeCardsUseCase<R, E> {
+    pub fn new(game_repository: R, event_publisher: E) -> Self {
+        Self { game_repository, event_publisher }
     }
     
     pub fn execute(&mut self, params: ExchangeCardsParams) -> Result<(), String> {
@@ -33,8 +35,13 @@ impl<R: GameRepository> ExchangeCardsUseCase<R> {
             .map_err(|e| e.to_string())?;
         
         // 更新されたゲームを保存
+        let events = game.take_events();
         self.game_repository.save(&game)?;
         
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+        
         Ok(())
     }
 }
@@ -43,8 +50,10 @@ impl<R: GameRepository> ExchangeCardsUseCase<R> {
 mod tests {
     use super::*;
     use crate::domain::model::game::{Game, GameVariant, GamePhase, GameSerializedData};
+    use crate::domain::model::bet::BettingStructure;
     use crate::domain::model::player::Player;
     use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
     
     // テスト用のゲーム作成
     fn create_test_game() -> Game {
@@ -77,14 +86,24 @@ mod tests {
                 variant: game.variant(),
                 players: game.players().to_vec(),
                 community_cards: game.community_cards().to_vec(),
-                pot_total: game.pot().total(),
+                deck: Some(game.deck().cards_in_draw_order()),
+                main_pot: game.pot().total(),
+                side_pots: Vec::new(),
                 current_phase: GamePhase::Drawing,
                 current_round: game.current_round(),
                 current_player_index: game.current_player_index(),
                 dealer_index: game.dealer_index(),
+                rules: game.rules(),
+                positions: game.positions(),
                 small_blind: game.small_blind(),
                 big_blind: game.big_blind(),
-                current_bet: game.current_bet()
+                ante: 0,
+                betting_structure: BettingStructure::NoLimit,
+                current_bet: game.current_bet(),
+                acted_players: Vec::new(),
+                seed: game.seed(),
+                hand_number: game.hand_number(),
+                hand_history: None,
             }
         ).unwrap();
         
@@ -95,7 +114,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = ExchangeCardsUseCase::new(game_repo.clone());
+        let mut usecase = ExchangeCardsUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
         
         let params = ExchangeCardsParams {
             game_id: game_id.clone(),
@@ -117,7 +136,7 @@ mod tests {
     #[test]
     fn 存在しないゲームでのカード交換() {
         let game_repo = InMemoryGameRepository::new();
-        let mut usecase = ExchangeCardsUseCase::new(game_repo);
+        let mut usecase = ExchangeCardsUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = ExchangeCardsParams {
             game_id: GameId::new(), // 存在しないゲームID
@@ -139,7 +158,7 @@ mod tests {
         // ゲームをリポジトリに保存
         game_repo.save(&game).unwrap();
         
-        let mut usecase = ExchangeCardsUseCase::new(game_repo);
+        let mut usecase = ExchangeCardsUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = ExchangeCardsParams {
             game_id,
@@ -166,21 +185,31 @@ mod tests {
                 variant: game.variant(),
                 players: game.players().to_vec(),
                 community_cards: game.community_cards().to_vec(),
-                pot_total: game.pot().total(),
+                deck: Some(game.deck().cards_in_draw_order()),
+                main_pot: game.pot().total(),
+                side_pots: Vec::new(),
                 current_phase: GamePhase::Drawing,
                 current_round: game.current_round(),
                 current_player_index: game.current_player_index(),
                 dealer_index: game.dealer_index(),
+                rules: game.rules(),
+                positions: game.positions(),
                 small_blind: game.small_blind(),
                 big_blind: game.big_blind(),
-                current_bet: game.current_bet()
+                ante: 0,
+                betting_structure: BettingStructure::NoLimit,
+                current_bet: game.current_bet(),
+                acted_players: Vec::new(),
+                seed: game.seed(),
+                hand_number: game.hand_number(),
+                hand_history: None,
             }
         ).unwrap();
         
         // ゲームをリポジトリに保存
         game_repo.save(&game).unwrap();
         
-        let mut usecase = ExchangeCardsUseCase::new(game_repo);
+        let mut usecase = ExchangeCardsUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = ExchangeCardsParams {
             game_id,
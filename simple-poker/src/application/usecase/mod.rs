@@ -3,4 +3,7 @@ pub mod add_player_usecase;
 pub mod start_game_usecase;
 pub mod place_bet_usecase;
 pub mod exchange_cards_usecase;
+pub mod cash_table_usecase;
+pub mod sit_out_usecase;
 
+pub mod showdown_usecase;
@@ -1,11 +1,13 @@
 use crate::domain::model::bet::BetAction;
+use crate::domain::model::event::EventPublisher;
 use crate::domain::model::game::GameId;
 use crate::domain::model::player::PlayerId;
 use crate::domain::repository::game_repository::GameRepository;
 use crate::domain::service::game_rule::GameRuleService;
 
-pub struct PlaceBetUseCase<R: GameRepository> {
+pub struct PlaceBetUseCase<R: GameRepository, E: EventPublisher> {
     game_repository: R,
+    event_publisher: E,
 }
 
 pub struct PlaceBetParams {
@@ -15,9 +17,9 @@ pub struct PlaceBetParams {
     pub bet_amount: Option<u32>,
 }
 
-impl<R: GameRepository> PlaceBetUseCase<R> {
-    pub fn new(game_repository: R) -> Self {
-        Self { game_repository }
+impl<R: GameRepository, E: EventPublisher> PlaceBetUseCase<R, E> {
+    pub fn new(game_repository: R, event_publisher: E) -> Self {
+        Self { game_repository, event_publisher }
     }
     
     pub fn execute(&mut self, params: PlaceBetParams) -> Result<(), String> {
@@ -40,8 +42,13 @@ impl<R: GameRepository> PlaceBetUseCase<R> {
         ).map_err(|e| e.to_string())?;
         
         // 更新されたゲームを保存
+        let events = game.take_events();
         self.game_repository.save(&game)?;
         
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+        
         Ok(())
     }
 }
@@ -49,9 +56,11 @@ impl<R: GameRepository> PlaceBetUseCase<R> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::model::game::{Game, GameVariant, GamePhase, GameSerializedData};
+    use crate::domain::model::game::{Game, GameVariant, GamePhase, GameSerializedData, TableRules};
+    use crate::domain::model::bet::BettingStructure;
     use crate::domain::model::player::Player;
     use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
     
     // テスト用のゲーム作成
     fn create_test_game() -> Game {
@@ -83,7 +92,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = PlaceBetUseCase::new(game_repo.clone());
+        let mut usecase = PlaceBetUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
         
         let params = PlaceBetParams {
             game_id: game_id.clone(),
@@ -115,7 +124,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = PlaceBetUseCase::new(game_repo.clone());
+        let mut usecase = PlaceBetUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
         
         let params = PlaceBetParams {
             game_id: game_id.clone(),
@@ -149,14 +158,24 @@ mod tests {
                 variant: game.variant(),
                 players: game.players().to_vec(),
                 community_cards: game.community_cards().to_vec(),
-                pot_total: 0, // ポット額を0に初期化
+                deck: Some(game.deck().cards_in_draw_order()),
+                main_pot: 0, // ポット額を0に初期化
+                side_pots: Vec::new(),
                 current_phase: GamePhase::Betting,
                 current_round: game.current_round(),
                 current_player_index: 0, // カレントプレイヤーを0に設定
                 dealer_index: game.dealer_index(),
+                rules: game.rules(),
+                positions: game.positions(),
                 small_blind: game.small_blind(),
                 big_blind: game.big_blind(),
-                current_bet: 10 // 現在のベット額を10に設定
+                ante: 0,
+                betting_structure: BettingStructure::NoLimit,
+                current_bet: 10, // 現在のベット額を10に設定
+                acted_players: Vec::new(),
+                seed: game.seed(),
+                hand_number: game.hand_number(),
+                hand_history: None,
             }
         ).unwrap();
         
@@ -164,7 +183,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = PlaceBetUseCase::new(game_repo.clone());
+        let mut usecase = PlaceBetUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
         
         let params = PlaceBetParams {
             game_id: game_id.clone(),
@@ -206,7 +225,7 @@ mod tests {
     #[test]
     fn 存在しないゲームへのアクション() {
         let game_repo = InMemoryGameRepository::new();
-        let mut usecase = PlaceBetUseCase::new(game_repo);
+        let mut usecase = PlaceBetUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = PlaceBetParams {
             game_id: GameId::new(),
@@ -229,7 +248,7 @@ mod tests {
         // ゲームをリポジトリに保存
         game_repo.save(&game).unwrap();
         
-        let mut usecase = PlaceBetUseCase::new(game_repo);
+        let mut usecase = PlaceBetUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = PlaceBetParams {
             game_id,
@@ -242,4 +261,50 @@ mod tests {
         assert!(result.is_err());
         assert!(result.err().unwrap().contains("参加していません"));
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn ユースケースのイベントからゲームを復元できる() {
+        use crate::application::usecase::add_player_usecase::{AddPlayerParams, AddPlayerUseCase};
+        use crate::application::usecase::create_game_usecase::{CreateGameParams, CreateGameUseCase};
+        use crate::application::usecase::start_game_usecase::{StartGameParams, StartGameUseCase};
+        use crate::domain::repository::event_store::EventStore;
+        use crate::domain::service::game_projector::GameProjector;
+        use crate::infrastructure::repository::inmemory::event_store_inmemory::InMemoryEventStore;
+        use crate::infrastructure::repository::inmemory::player_repository_inmemory::InMemoryPlayerRepository;
+        use crate::infrastructure::service::event::storing_event_publisher::StoringEventPublisher;
+        
+        let game_repo = InMemoryGameRepository::new();
+        let store = InMemoryEventStore::new();
+        let publisher = StoringEventPublisher::new(store.clone(), InMemoryEventPublisher::new());
+        
+        let game_id = CreateGameUseCase::new(game_repo.clone(), publisher.clone())
+            .execute(CreateGameParams { variant: GameVariant::TexasHoldem, small_blind: 5, big_blind: 10, betting_structure: BettingStructure::NoLimit, rules: TableRules::default(), seed: None })
+            .unwrap();
+        let mut add_player = AddPlayerUseCase::new(game_repo.clone(), InMemoryPlayerRepository::new(), publisher.clone());
+        for name in ["プレイヤー1", "プレイヤー2", "プレイヤー3"] {
+            add_player.execute(AddPlayerParams {
+                game_id: game_id.clone(),
+                player_name: name.to_string(),
+                initial_chips: 1000,
+                bot: None,
+            }).unwrap();
+        }
+        StartGameUseCase::new(game_repo.clone(), publisher.clone())
+            .execute(StartGameParams { game_id: game_id.clone() })
+            .unwrap();
+        
+        let game = game_repo.find_by_id(&game_id).unwrap();
+        let player_id = game.players()[game.current_player_index()].id().clone();
+        PlaceBetUseCase::new(game_repo.clone(), publisher)
+            .execute(PlaceBetParams { game_id: game_id.clone(), player_id, action: BetAction::Raise, bet_amount: Some(40) })
+            .unwrap();
+        
+        let saved = game_repo.find_by_id(&game_id).unwrap();
+        let projected = GameProjector::project(&store.load(&game_id).unwrap()).expect("復元に失敗");
+        let chips = |game: &Game| game.players().iter().map(|p| p.chips()).collect::<Vec<_>>();
+        assert_eq!(chips(&projected), chips(&saved), "チップが一致しません");
+        assert_eq!(projected.current_bet(), saved.current_bet(), "現在のベット額が一致しません");
+        assert_eq!(projected.current_player_index(), saved.current_player_index(), "手番が一致しません");
+        assert_eq!(projected.hand_history().unwrap().actions, saved.hand_history().unwrap().actions, "アクションの記録が一致しません");
+    }
+}
@@ -0,0 +1,159 @@
+use chrono::Utc;
+
+use crate::domain::model::event::EventPublisher;
+use crate::domain::model::game::{Game, GameId};
+use crate::domain::repository::game_history_repository::{GameHistoryEntry, GameHistoryRepository};
+use crate::domain::repository::game_repository::GameRepository;
+use crate::domain::service::game_rule::{GameRuleService, PotDistribution};
+
+pub struct ShowdownUseCase<R: GameRepository, H: GameHistoryRepository, E: EventPublisher> {
+    game_repository: R,
+    history_repository: H,
+    event_publisher: E,
+}
+
+pub struct ShowdownParams {
+    pub game_id: GameId,
+}
+
+pub struct ShowdownResult {
+    pub game: Game,
+    pub distributions: Vec<PotDistribution>,
+}
+
+impl<R: GameRepository, H: GameHistoryRepository, E: EventPublisher> ShowdownUseCase<R, H, E> {
+    pub fn new(game_repository: R, history_repository: H, event_publisher: E) -> Self {
+        Self { game_repository, history_repository, event_publisher }
+    }
+    
+    /// ポットを分配し、終了したハンドの記録を履歴に保存する
+    pub fn execute(&mut self, params: ShowdownParams) -> Result<ShowdownResult, String> {
+        // ゲームを取得
+        let mut game = self.game_repository
+            .find_by_id(&params.game_id)
+            .ok_or_else(|| format!("ゲーム {} が見つかりません", params.game_id.value()))?;
+        
+        // ポットを分配
+        let distributions = GameRuleService::distribute_pot(&mut game).map_err(|e| e.to_string())?;
+        
+        // 更新されたゲームを保存
+        let events = game.take_events();
+        self.game_repository.save(&game)?;
+        
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+        
+        // ハンドの記録を履歴に保存
+        if let Some(hand_history) = game.hand_history() {
+            let entry = GameHistoryEntry::from_hand_history(hand_history.clone(), Utc::now());
+            self.history_repository.save(&entry)?;
+        }
+        
+        Ok(ShowdownResult { game, distributions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::{BettingRound, GameVariant};
+    use crate::domain::model::hand_history::BlindKind;
+    use crate::domain::model::player::Player;
+    use crate::domain::service::hand_evaluation::HandRank;
+    use crate::infrastructure::repository::inmemory::game_history_repository_inmemory::InMemoryGameHistoryRepository;
+    use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
+    
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+    
+    // 3人のホールデムをショーダウンまで進める（プレイヤー1がAのペアで勝つ並び）
+    fn play_holdem_to_showdown() -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー3".to_string(), 1000)).unwrap();
+        game.preset_deck(vec![
+            card(Suit::Spade, 1), card(Suit::Heart, 1),
+            card(Suit::Spade, 2), card(Suit::Heart, 7),
+            card(Suit::Club, 3), card(Suit::Diamond, 8),
+            card(Suit::Club, 9), card(Suit::Diamond, 10), card(Suit::Spade, 12),
+            card(Suit::Heart, 4),
+            card(Suit::Club, 13),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        
+        // プリフロップ：プレイヤー1がコール、プレイヤー2がフォールド
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Fold, None).unwrap();
+        
+        // 残りのラウンドは進めるだけ
+        while game.current_phase() != crate::domain::model::game::GamePhase::Showdown {
+            game.end_betting_round().unwrap();
+        }
+        game
+    }
+    
+    #[test]
+    fn ショーダウンでハンドの記録が履歴に保存される() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let history_repo = InMemoryGameHistoryRepository::new();
+        let game = play_holdem_to_showdown();
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+        
+        let mut usecase = ShowdownUseCase::new(game_repo.clone(), history_repo.clone(), InMemoryEventPublisher::new());
+        let result = usecase.execute(ShowdownParams { game_id: game_id.clone() }).expect("ショーダウンに失敗");
+        assert!(!result.distributions.is_empty(), "ポットが分配されていません");
+        
+        let entry = history_repo.find_by_game_id(&game_id).expect("履歴が保存されていません");
+        let history = entry.hand_history.as_ref().expect("ハンドの記録がありません");
+        let players = game.players();
+        
+        assert_eq!(history.seats.len(), 3, "座席数が一致しません");
+        assert!(history.seats.iter().all(|seat| seat.starting_stack == 1000), "開始時のスタックが一致しません");
+        assert_eq!(history.seats[0].hole_cards, vec![card(Suit::Spade, 1), card(Suit::Heart, 1)], "手札が記録されていません");
+        assert_eq!(history.blinds.len(), 2, "ブラインドが記録されていません");
+        assert_eq!(history.blinds[0].kind, BlindKind::Small, "スモールブラインドが一致しません");
+        assert_eq!(history.blinds[1].amount, 10, "ビッグブラインドの額が一致しません");
+        
+        let preflop = history.actions_in(BettingRound::PreFlop);
+        assert_eq!(preflop.len(), 2, "プリフロップのアクション数が一致しません");
+        assert_eq!(preflop[0].action, BetAction::Call, "アクションが一致しません");
+        assert_eq!(preflop[0].amount, 10, "コール額が一致しません");
+        assert_eq!(preflop[1].action, BetAction::Fold, "アクションが一致しません");
+        
+        assert_eq!(history.board.len(), 3, "フロップ・ターン・リバーが記録されていません");
+        assert_eq!(history.community_cards(), game.community_cards(), "コミュニティカードが一致しません");
+        
+        assert_eq!(history.showdown.len(), 2, "ショーダウンした手の数が一致しません");
+        let winner_hand = history.showdown.iter().find(|s| &s.player_id == players[0].id()).unwrap();
+        assert_eq!(winner_hand.strength.rank(), HandRank::OnePair, "役が一致しません");
+        
+        assert_eq!(history.payouts.len(), 1, "配当の数が一致しません");
+        assert_eq!(history.payouts[0].winners, vec![(players[0].id().clone(), 25)], "配当が一致しません");
+        assert_eq!(entry.winner_ids, vec![players[0].id().clone()], "勝者が一致しません");
+        assert_eq!(entry.pot_amount, 25, "ポット額が一致しません");
+    }
+    
+    #[test]
+    fn 負けたプレイヤーでも参加したハンドを検索できる() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let history_repo = InMemoryGameHistoryRepository::new();
+        let game = play_holdem_to_showdown();
+        game_repo.save(&game).unwrap();
+        
+        let mut usecase = ShowdownUseCase::new(game_repo, history_repo.clone(), InMemoryEventPublisher::new());
+        usecase.execute(ShowdownParams { game_id: game.id().clone() }).unwrap();
+        
+        // フォールドしたプレイヤー2も参加者として検索できる
+        let folded_player = game.players()[1].id();
+        assert_eq!(history_repo.find_by_player_id(folded_player).len(), 1, "参加者で検索できません");
+    }
+}
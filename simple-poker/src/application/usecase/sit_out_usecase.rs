@@ -0,0 +1,76 @@
+use crate::domain::model::event::EventPublisher;
+use crate::domain::model::game::GameId;
+use crate::domain::model::player::PlayerId;
+use crate::domain::repository::game_repository::GameRepository;
+
+/// 席を確保したまま離席する、または離席から戻る
+pub struct SitOutUseCase<R: GameRepository, E: EventPublisher> {
+    game_repository: R,
+    event_publisher: E,
+}
+
+pub struct SitOutParams {
+    pub game_id: GameId,
+    pub player_id: PlayerId,
+    pub sitting_out: bool, // true なら離席、false なら復帰
+}
+
+impl<R: GameRepository, E: EventPublisher> SitOutUseCase<R, E> {
+    pub fn new(game_repository: R, event_publisher: E) -> Self {
+        Self { game_repository, event_publisher }
+    }
+
+    pub fn execute(&mut self, params: SitOutParams) -> Result<(), String> {
+        // ゲームを取得
+        let mut game = self.game_repository
+            .find_by_id(&params.game_id)
+            .ok_or_else(|| format!("ゲーム {} が見つかりません", params.game_id.value()))?;
+
+        // 次のハンドから反映される
+        if params.sitting_out {
+            game.sit_out(&params.player_id).map_err(|e| e.to_string())?;
+        } else {
+            game.sit_in(&params.player_id).map_err(|e| e.to_string())?;
+        }
+
+        // 更新されたゲームを保存
+        let events = game.take_events();
+        self.game_repository.save(&game)?;
+
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::model::game::{Game, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
+
+    #[test]
+    fn 離席と復帰() {
+        let mut game_repo = InMemoryGameRepository::new();
+        let mut game = Game::new(GameVariant::TexasHoldem, 1, 2).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 200)).unwrap();
+        let player_id = game.players()[0].id().clone();
+        let game_id = game.id().clone();
+        game_repo.save(&game).unwrap();
+
+        let mut usecase = SitOutUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
+        usecase.execute(SitOutParams { game_id: game_id.clone(), player_id: player_id.clone(), sitting_out: true }).unwrap();
+        assert!(game_repo.find_by_id(&game_id).unwrap().players()[0].is_sitting_out(), "離席していません");
+        assert!(
+            usecase.execute(SitOutParams { game_id: game_id.clone(), player_id: player_id.clone(), sitting_out: true }).is_err(),
+            "2回離席できてしまいます"
+        );
+
+        usecase.execute(SitOutParams { game_id: game_id.clone(), player_id, sitting_out: false }).unwrap();
+        assert!(!game_repo.find_by_id(&game_id).unwrap().players()[0].is_sitting_out(), "復帰していません");
+    }
+}
@@ -1,17 +1,19 @@
+use crate::domain::model::event::EventPublisher;
 use crate::domain::model::game::GameId;
 use crate::domain::repository::game_repository::GameRepository;
 
-pub struct StartGameUseCase<R: GameRepository> {
+pub struct StartGameUseCase<R: GameRepository, E: EventPublisher> {
     game_repository: R,
+    event_publisher: E,
 }
 
 pub struct StartGameParams {
     pub game_id: GameId,
 }
 
-impl<R: GameRepository> StartGameUseCase<R> {
-    pub fn new(game_repository: R) -> Self {
-        Self { game_repository }
+impl<R: GameRepository, E: EventPublisher> StartGameUseCase<R, E> {
+    pub fn new(game_repository: R, event_publisher: E) -> Self {
+        Self { game_repository, event_publisher }
     }
     
     pub fn execute(&mut self, params: StartGameParams) -> Result<(), String> {
@@ -26,16 +28,31 @@ impl<R: GameRepository> StartGameUseCase<R> {
         // カードを配る
         game.deal_cards().map_err(|e| e.to_string())?;
         
+        // アンティがあればブラインドより先に投入
+        if game.ante() > 0 {
+            game.post_antes().map_err(|e| e.to_string())?;
+        }
+        
         // ホールデムとオマハの場合はブラインドを投入
-        if matches!(game.variant(), crate::domain::model::game::GameVariant::TexasHoldem | crate::domain::model::game::GameVariant::Omaha) {
+        if game.variant().has_board() {
             if let Some(crate::domain::model::game::BettingRound::PreFlop) = game.current_round() {
                 game.post_blinds().map_err(|e| e.to_string())?;
             }
         }
         
+        // スタッドの場合は表向きのカードが最も弱いプレイヤーがブリングインを投入
+        if game.variant().is_stud() {
+            game.post_bring_in().map_err(|e| e.to_string())?;
+        }
+        
         // 更新されたゲームを保存
+        let events = game.take_events();
         self.game_repository.save(&game)?;
         
+        for event in events {
+            self.event_publisher.publish(event)?;
+        }
+        
         Ok(())
     }
 }
@@ -46,6 +63,7 @@ mod tests {
     use crate::domain::model::game::{Game, GameVariant, GamePhase};
     use crate::domain::model::player::Player;
     use crate::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
+    use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
     
     #[test]
     fn ゲーム開始_正常系() {
@@ -65,7 +83,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = StartGameUseCase::new(game_repo.clone());
+        let mut usecase = StartGameUseCase::new(game_repo.clone(), InMemoryEventPublisher::new());
         
         let params = StartGameParams {
             game_id: game_id.clone(),
@@ -90,7 +108,7 @@ mod tests {
     #[test]
     fn 存在しないゲームの開始() {
         let game_repo = InMemoryGameRepository::new();
-        let mut usecase = StartGameUseCase::new(game_repo);
+        let mut usecase = StartGameUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = StartGameParams {
             game_id: GameId::new(), // 存在しないゲームID
@@ -118,7 +136,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = StartGameUseCase::new(game_repo);
+        let mut usecase = StartGameUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = StartGameParams {
             game_id,
@@ -146,7 +164,7 @@ mod tests {
         game_repo.save(&game).unwrap();
         
         // ユースケース実行
-        let mut usecase = StartGameUseCase::new(game_repo);
+        let mut usecase = StartGameUseCase::new(game_repo, InMemoryEventPublisher::new());
         
         let params = StartGameParams {
             game_id,
@@ -0,0 +1,20 @@
+use simple_poker::presentation::cli::equity_command::{EquityCommand, USAGE};
+use std::env;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+    
+    match EquityCommand::run(&args) {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("[エラー] {}\n", e);
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        }
+    }
+}
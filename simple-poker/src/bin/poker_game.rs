@@ -37,7 +37,7 @@ struct Card {
 
 impl Card {
     fn new(suit: Suit, rank: u8) -> Result<Self, &'static str> {
-        if rank < 1 || rank > 13 {
+        if !(1..=13).contains(&rank) {
             return Err("無効なカードランクです");
         }
         Ok(Self { suit, rank })
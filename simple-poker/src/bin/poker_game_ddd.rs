@@ -1,12 +1,23 @@
 use std::path::Path;
 use simple_poker::application::usecase::create_game_usecase::{CreateGameParams, CreateGameUseCase};
-use simple_poker::domain::model::game::GameVariant;
+use simple_poker::domain::model::bet::BettingStructure;
+use simple_poker::domain::model::game::{GameVariant, TableRules};
 use simple_poker::domain::model::error::DomainError;
 use simple_poker::domain::repository::game_repository::GameRepository;
+use simple_poker::infrastructure::repository::file::event_store_file::FileEventStore;
+use simple_poker::infrastructure::repository::file::file_store::CorruptFileReport;
+use simple_poker::infrastructure::repository::file::game_history_repository_file::FileGameHistoryRepository;
 use simple_poker::infrastructure::repository::file::game_repository_file::FileGameRepository;
+use simple_poker::infrastructure::repository::inmemory::event_store_inmemory::InMemoryEventStore;
+use simple_poker::infrastructure::repository::inmemory::game_history_repository_inmemory::InMemoryGameHistoryRepository;
 use simple_poker::infrastructure::repository::inmemory::game_repository_inmemory::InMemoryGameRepository;
 use simple_poker::infrastructure::repository::inmemory::player_repository_inmemory::InMemoryPlayerRepository;
+use simple_poker::infrastructure::repository::sqlite::database::SqliteDatabase;
+use simple_poker::infrastructure::repository::sqlite::game_history_repository_sqlite::SqliteGameHistoryRepository;
+use simple_poker::infrastructure::repository::sqlite::game_repository_sqlite::SqliteGameRepository;
+use simple_poker::infrastructure::repository::sqlite::player_repository_sqlite::SqlitePlayerRepository;
 use simple_poker::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
+use simple_poker::infrastructure::service::event::storing_event_publisher::StoringEventPublisher;
 use simple_poker::presentation::cli::menu::MenuController;
 use std::env;
 
@@ -17,10 +28,12 @@ fn main() {
     
     // コマンドライン引数でストレージタイプを指定
     let args: Vec<String> = env::args().collect();
-    let storage_type = if args.len() > 1 && args[1] == "file" {
-        StorageType::File
-    } else {
-        StorageType::InMemory
+    let storage_type = match args.get(1).map(String::as_str) {
+        Some("file") => StorageType::File,
+        Some("sqlite") => StorageType::Sqlite(
+            args.get(2).cloned().unwrap_or_else(|| "data/poker.db".to_string())
+        ),
+        _ => StorageType::InMemory,
     };
     
     // イベントパブリッシャーの初期化
@@ -32,6 +45,7 @@ fn main() {
             println!("インメモリストレージを使用します。");
             let game_repo = InMemoryGameRepository::new();
             let player_repo = InMemoryPlayerRepository::new();
+            let event_publisher = StoringEventPublisher::new(InMemoryEventStore::new(), event_publisher);
             
             // デモゲームを作成（オプション）
             if let Err(e) = create_demo_game(&mut game_repo.clone(), event_publisher.clone()) {
@@ -39,14 +53,36 @@ fn main() {
             }
             
             // メニューコントローラの作成と実行
-            let mut menu = MenuController::new(game_repo, player_repo, event_publisher);
+            let mut menu = MenuController::new(game_repo, player_repo, event_publisher, InMemoryGameHistoryRepository::new());
             menu.run();
         },
         StorageType::File => {
             println!("ファイルストレージを使用します。");
             match FileGameRepository::new(Path::new("data/games")) {
                 Ok(game_repo) => {
+                    // 書き込み途中のファイルや破損ファイルを整理
+                    match game_repo.recover() {
+                        Ok(reports) => report_quarantined(&reports),
+                        Err(e) => eprintln!("保存データの点検に失敗しました: {}", e),
+                    }
+                    
                     let player_repo = InMemoryPlayerRepository::new();
+                    let history_repo = match FileGameHistoryRepository::new(Path::new("data/history")) {
+                        Ok(repo) => repo,
+                        Err(e) => {
+                            eprintln!("ハンド履歴の保存先を初期化できませんでした: {}", e);
+                            return;
+                        }
+                    };
+                    report_quarantined(&history_repo.corrupt_file_reports());
+                    let event_store = match FileEventStore::new(Path::new("data/events")) {
+                        Ok(store) => store,
+                        Err(e) => {
+                            eprintln!("イベントの保存先を初期化できませんでした: {}", e);
+                            return;
+                        }
+                    };
+                    let event_publisher = StoringEventPublisher::new(event_store, event_publisher);
                     
                     // デモゲームを作成（オプション）
                     if let Err(e) = create_demo_game(&mut game_repo.clone(), event_publisher.clone()) {
@@ -54,7 +90,7 @@ fn main() {
                     }
                     
                     // メニューコントローラの作成と実行
-                    let mut menu = MenuController::new(game_repo, player_repo, event_publisher);
+                    let mut menu = MenuController::new(game_repo, player_repo, event_publisher, history_repo);
                     menu.run();
                 },
                 Err(e) => {
@@ -65,7 +101,38 @@ fn main() {
                     let player_repo = InMemoryPlayerRepository::new();
                     
                     // メニューコントローラの作成と実行
-                    let mut menu = MenuController::new(game_repo, player_repo, event_publisher.clone());
+                    let mut menu = MenuController::new(game_repo, player_repo, event_publisher.clone(), InMemoryGameHistoryRepository::new());
+                    menu.run();
+                }
+            }
+        }
+        StorageType::Sqlite(path) => {
+            println!("SQLiteストレージを使用します: {}", path);
+            match SqliteDatabase::open(Path::new(&path)) {
+                Ok(database) => {
+                    let game_repo = SqliteGameRepository::new(database.clone());
+                    let player_repo = SqlitePlayerRepository::new(database.clone());
+                    let history_repo = SqliteGameHistoryRepository::new(database);
+                    let event_publisher = StoringEventPublisher::new(InMemoryEventStore::new(), event_publisher);
+                    
+                    // デモゲームを作成（オプション）
+                    if let Err(e) = create_demo_game(&mut game_repo.clone(), event_publisher.clone()) {
+                        eprintln!("デモゲーム作成エラー: {}", e);
+                    }
+                    
+                    // メニューコントローラの作成と実行
+                    let mut menu = MenuController::new(game_repo, player_repo, event_publisher, history_repo);
+                    menu.run();
+                },
+                Err(e) => {
+                    eprintln!("SQLiteストレージの初期化に失敗しました: {}", e);
+                    eprintln!("インメモリストレージを代わりに使用します。");
+                    
+                    let game_repo = InMemoryGameRepository::new();
+                    let player_repo = InMemoryPlayerRepository::new();
+                    
+                    // メニューコントローラの作成と実行
+                    let mut menu = MenuController::new(game_repo, player_repo, event_publisher.clone(), InMemoryGameHistoryRepository::new());
                     menu.run();
                 }
             }
@@ -77,18 +144,33 @@ fn main() {
 enum StorageType {
     InMemory,
     File,
+    Sqlite(String), // データベースファイルのパス
+}
+
+// 隔離した破損ファイルを警告として表示する関数
+fn report_quarantined(reports: &[CorruptFileReport]) {
+    for report in reports {
+        eprintln!(
+            "警告: 読み込めないファイル {} を {} に隔離しました（{}）",
+            report.original_path.display(),
+            report.quarantined_path.display(),
+            report.reason
+        );
+    }
 }
 
 // デモゲームを作成する関数
-fn create_demo_game<R: GameRepository + Clone, E: Clone>(
+fn create_demo_game<R: GameRepository + Clone, E: Clone + simple_poker::domain::model::event::EventPublisher>(
     repo: &mut R, 
     event_publisher: E
-) -> Result<(), DomainError> 
-where E: simple_poker::domain::model::event::EventPublisher {
+) -> Result<(), DomainError> {
     let params = CreateGameParams {
         variant: GameVariant::FiveCardDraw,
         small_blind: 5,
         big_blind: 10,
+        betting_structure: BettingStructure::NoLimit,
+        rules: TableRules::default(),
+        seed: None,
     };
     
     let mut usecase = CreateGameUseCase::new(repo.clone(), event_publisher);
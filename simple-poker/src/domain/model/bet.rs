@@ -24,6 +24,52 @@ impl fmt::Display for BetAction {
     }
 }
 
+/// ベット・レイズできる額の決まり
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BettingStructure {
+    /// 手持ちのチップすべてまでベットできる
+    #[default]
+    NoLimit,
+    /// コールした後のポットの額までレイズできる
+    PotLimit,
+    /// ベット・レイズの額が決まっていて（前半のラウンドは small_bet、後半は big_bet）、
+    /// 1ラウンドのベット・レイズの回数は raise_cap まで
+    FixedLimit { small_bet: u32, big_bet: u32, raise_cap: u32 },
+}
+
+impl BettingStructure {
+    pub const DEFAULT_RAISE_CAP: u32 = 4;
+    
+    /// ビッグブラインドを基準にした標準的なフィックスドリミット（ビッグベットはスモールベットの2倍）
+    pub fn fixed_limit(big_blind: u32) -> Self {
+        BettingStructure::FixedLimit {
+            small_bet: big_blind,
+            big_bet: big_blind * 2,
+            raise_cap: Self::DEFAULT_RAISE_CAP,
+        }
+    }
+    
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if let BettingStructure::FixedLimit { small_bet, big_bet, raise_cap } = *self {
+            if small_bet == 0 || big_bet < small_bet {
+                return Err(DomainError::InvalidBet(format!("ベットの額が不正です（{}/{}）", small_bet, big_bet)));
+            }
+            if raise_cap == 0 {
+                return Err(DomainError::InvalidBet("レイズの回数の上限は1以上である必要があります".into()));
+            }
+        }
+        Ok(())
+    }
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            BettingStructure::NoLimit => "ノーリミット",
+            BettingStructure::PotLimit => "ポットリミット",
+            BettingStructure::FixedLimit { .. } => "フィックスドリミット",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct BetAmount(u32);
 
@@ -111,6 +157,14 @@ impl fmt::Display for Chips {
     }
 }
 
+/// ポット計算に使うプレイヤーごとの拠出情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotContribution {
+    pub player_id: String,
+    pub amount: u32,      // ハンド全体での拠出額
+    pub is_folded: bool,  // フォールド済みのプレイヤーは拠出するが獲得資格はない
+}
+
 #[derive(Debug, Clone)]
 pub struct Pot {
     chips: Chips,
@@ -153,4 +207,63 @@ impl Pot {
         self.chips = Chips::new(0);
         self.side_pots.clear();
     }
+    
+    /// 拠出額からメインポットとサイドポットを構築する
+    ///
+    /// 戻り値の先頭がメインポットで、以降がサイドポット。各要素は
+    /// (チップ額, 獲得資格のあるプレイヤーID)。拠出額で説明できない
+    /// チップ（直接追加された分）はメインポットに含める。
+    pub fn build_pots(&self, contributions: &[PotContribution]) -> Vec<(Chips, Vec<String>)> {
+        let mut levels: Vec<u32> = contributions.iter()
+            .filter(|c| !c.is_folded && c.amount > 0)
+            .map(|c| c.amount)
+            .collect();
+        levels.sort();
+        levels.dedup();
+        
+        let mut pots: Vec<(Chips, Vec<String>)> = Vec::new();
+        let mut previous_level = 0;
+        
+        for (i, &level) in levels.iter().enumerate() {
+            // 最後の階層はフォールドしたプレイヤーの超過分もすべて吸収する
+            let upper = if i + 1 == levels.len() { u32::MAX } else { level };
+            let amount: u32 = contributions.iter()
+                .map(|c| c.amount.min(upper).saturating_sub(previous_level))
+                .sum();
+            let eligible: Vec<String> = contributions.iter()
+                .filter(|c| !c.is_folded && c.amount >= level)
+                .map(|c| c.player_id.clone())
+                .collect();
+            
+            // 獲得資格者が同じ階層は1つのポットにまとめる
+            match pots.last_mut() {
+                Some((chips, last_eligible)) if *last_eligible == eligible => chips.add(amount),
+                _ => pots.push((Chips::new(amount), eligible)),
+            }
+            previous_level = level;
+        }
+        
+        let accounted: u32 = contributions.iter().map(|c| c.amount).sum();
+        let surplus = self.total().saturating_sub(accounted);
+        if surplus > 0 {
+            match pots.first_mut() {
+                Some((chips, _)) => chips.add(surplus),
+                None => {
+                    let eligible = contributions.iter()
+                        .filter(|c| !c.is_folded)
+                        .map(|c| c.player_id.clone())
+                        .collect();
+                    pots.push((Chips::new(surplus), eligible));
+                }
+            }
+        }
+        
+        pots
+    }
+}
+
+impl Default for Pot {
+    fn default() -> Self {
+        Self::new()
+    }
 } 
\ No newline at end of file
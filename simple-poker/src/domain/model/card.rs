@@ -27,6 +27,9 @@ impl fmt::Display for Suit {
     }
 }
 
+/// ジョーカーのランク（通常のカードは1〜13）
+pub const JOKER_RANK: u8 = 0;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Card {
     suit: Suit,
@@ -35,12 +38,21 @@ pub struct Card {
 
 impl Card {
     pub fn new(suit: Suit, rank: u8) -> Result<Self, &'static str> {
-        if rank < 1 || rank > 13 {
+        if !(1..=13).contains(&rank) {
             return Err("ランクは1から13の間でなければなりません");
         }
         Ok(Self { suit, rank })
     }
 
+    /// ジョーカー（スートは2枚のジョーカーを区別するためだけに使う）
+    pub fn joker(suit: Suit) -> Self {
+        Self { suit, rank: JOKER_RANK }
+    }
+
+    pub fn is_joker(&self) -> bool {
+        self.rank == JOKER_RANK
+    }
+
     pub fn suit(&self) -> Suit {
         self.suit
     }
@@ -60,13 +72,116 @@ impl Card {
 
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let rank_str = match self.rank {
-            1 => "A".to_string(),
-            11 => "J".to_string(),
-            12 => "Q".to_string(),
-            13 => "K".to_string(),
-            n => n.to_string(),
-        };
-        write!(f, "{}{}", self.suit, rank_str)
+        if self.is_joker() {
+            return write!(f, "JK");
+        }
+        write!(f, "{}{}", self.suit, rank_label(self.rank))
+    }
+} 
+
+/// ジョーカーの使い方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum JokerRule {
+    /// どのカードの代わりにもなる
+    #[default]
+    Wild,
+    /// Aの代わりか、ストレート・フラッシュを完成させるときだけ使える（バグ）
+    Bug,
+}
+
+/// ワイルドカードの決まり（デッキに入れるジョーカーと、ワイルドにするランク）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct WildCards {
+    jokers: u8,
+    joker_rule: JokerRule,
+    wild_ranks: u16,      // ワイルドにするランクのビット（1 << ランク）
+    five_of_a_kind: bool, // ワイルドでファイブカードを作れる
+}
+
+impl WildCards {
+    /// ワイルドカードなし
+    pub const NONE: Self = Self { jokers: 0, joker_rule: JokerRule::Wild, wild_ranks: 0, five_of_a_kind: false };
+    /// デッキに入れられるジョーカーの最大枚数
+    pub const MAX_JOKERS: u8 = 2;
+
+    /// ジョーカーの枚数と使い方、ワイルドにするランクを指定する（ファイブカードは作れる）
+    pub fn new(jokers: u8, joker_rule: JokerRule, wild_ranks: &[u8]) -> Result<Self, &'static str> {
+        if jokers > Self::MAX_JOKERS {
+            return Err("ジョーカーは2枚までです");
+        }
+        if wild_ranks.iter().any(|rank| !(1..=13).contains(rank)) {
+            return Err("ワイルドにするランクは1から13の間でなければなりません");
+        }
+        let wild_ranks = wild_ranks.iter().fold(0, |bits, &rank| bits | 1 << rank);
+        Ok(Self { jokers, joker_rule, wild_ranks, five_of_a_kind: true })
+    }
+
+    /// ワイルドでファイブカードを作れないようにする（4枚そろった残りはキッカーになる）
+    pub fn without_five_of_a_kind(self) -> Self {
+        Self { five_of_a_kind: false, ..self }
+    }
+
+    pub fn jokers(&self) -> u8 {
+        self.jokers
+    }
+
+    pub fn joker_rule(&self) -> JokerRule {
+        self.joker_rule
+    }
+
+    pub fn wild_ranks(&self) -> Vec<u8> {
+        (1..=13).filter(|rank| self.wild_ranks & 1 << rank != 0).collect()
     }
-} 
\ No newline at end of file
+
+    pub fn five_of_a_kind(&self) -> bool {
+        self.five_of_a_kind
+    }
+
+    /// ワイルドカードを使わないか
+    pub fn is_empty(&self) -> bool {
+        self.jokers == 0 && self.wild_ranks == 0
+    }
+
+    /// ほかのカードの代わりになるカードか（バグのジョーカーも含む）
+    pub fn is_wild(&self, card: &Card) -> bool {
+        if card.is_joker() {
+            return self.jokers > 0;
+        }
+        self.wild_ranks & 1 << card.rank() != 0
+    }
+
+    /// 使い道が限られたジョーカー（バグ）か
+    pub fn is_bug(&self, card: &Card) -> bool {
+        card.is_joker() && self.jokers > 0 && self.joker_rule == JokerRule::Bug
+    }
+
+    /// 決まりの説明（例: "ジョーカー1枚（バグ）・2がワイルド"）
+    pub fn name(&self) -> String {
+        let mut parts = Vec::new();
+        if self.jokers > 0 {
+            let rule = match self.joker_rule {
+                JokerRule::Wild => "ワイルド",
+                JokerRule::Bug => "バグ",
+            };
+            parts.push(format!("ジョーカー{}枚（{}）", self.jokers, rule));
+        }
+        let ranks: Vec<String> = self.wild_ranks().iter().map(|&rank| rank_label(rank)).collect();
+        if !ranks.is_empty() {
+            parts.push(format!("{}がワイルド", ranks.join("と")));
+        }
+        if parts.is_empty() {
+            return "なし".to_string();
+        }
+        parts.join("・")
+    }
+}
+
+fn rank_label(rank: u8) -> String {
+    match rank {
+        1 => "A".to_string(),
+        11 => "J".to_string(),
+        12 => "Q".to_string(),
+        13 => "K".to_string(),
+        n => n.to_string(),
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::card::{Card, JokerRule, Suit, WildCards};
 
     #[test]
     fn カード作成_有効なランク() {
@@ -78,4 +78,32 @@ mod tests {
         assert_eq!(format!("{}", Suit::Heart), "♥", "ハートの表示が正しくありません");
         assert_eq!(format!("{}", Suit::Spade), "♠", "スペードの表示が正しくありません");
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn ジョーカーの作成と表示() {
+        let joker = Card::joker(Suit::Spade);
+        assert!(joker.is_joker(), "ジョーカーと判定されません");
+        assert!(!joker.is_ace(), "ジョーカーがエースと判定されています");
+        assert_eq!(format!("{}", joker), "JK", "ジョーカーの表示が正しくありません");
+        assert_ne!(joker, Card::joker(Suit::Heart), "2枚のジョーカーが等価と判定されています");
+        assert!(!Card::new(Suit::Spade, 1).unwrap().is_joker(), "Aがジョーカーと判定されています");
+    }
+    
+    #[test]
+    fn ワイルドカードの決まり() {
+        let deuces = WildCards::new(0, JokerRule::Wild, &[2]).unwrap();
+        assert!(deuces.is_wild(&Card::new(Suit::Club, 2).unwrap()), "2がワイルドになっていません");
+        assert!(!deuces.is_wild(&Card::new(Suit::Club, 3).unwrap()), "3がワイルドになっています");
+        assert_eq!(deuces.name(), "2がワイルド", "決まりの説明が正しくありません");
+        
+        let bug = WildCards::new(1, JokerRule::Bug, &[]).unwrap();
+        let joker = Card::joker(Suit::Spade);
+        assert!(bug.is_wild(&joker) && bug.is_bug(&joker), "ジョーカーがバグになっていません");
+        assert_eq!(bug.name(), "ジョーカー1枚（バグ）", "決まりの説明が正しくありません");
+        
+        assert!(WildCards::NONE.is_empty(), "ワイルドカードなしが空になっていません");
+        assert_eq!(WildCards::NONE.name(), "なし", "決まりの説明が正しくありません");
+        assert!(WildCards::new(3, JokerRule::Wild, &[]).is_err(), "ジョーカー3枚でエラーになりません");
+        assert!(WildCards::new(0, JokerRule::Wild, &[14]).is_err(), "ランク14をワイルドにできてしまいます");
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Serialize, Deserialize};
+
+use super::error::DomainError;
+use super::game::Game;
+use super::player::{Player, PlayerId};
+
+/// キャッシュゲームのテーブルの決まり（席数と持ち込めるチップの範囲）
+///
+/// ゲームの外側で席の出入りを管理する。席に着く・立つ・チップを買い足すのはハンドの間にのみ行える。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CashTable {
+    max_seats: usize,
+    min_buy_in: u32,
+    max_buy_in: u32,
+}
+
+impl CashTable {
+    pub fn new(max_seats: usize, min_buy_in: u32, max_buy_in: u32) -> Result<Self, DomainError> {
+        if !(2..=10).contains(&max_seats) {
+            return Err(DomainError::InvalidGameOperation(format!("1テーブルの席数は2〜10です（{}が指定されています）", max_seats)));
+        }
+        if min_buy_in == 0 || min_buy_in > max_buy_in {
+            return Err(DomainError::InvalidGameOperation(format!(
+                "持ち込めるチップの範囲が不正です（{}〜{}）", min_buy_in, max_buy_in
+            )));
+        }
+        Ok(Self { max_seats, min_buy_in, max_buy_in })
+    }
+
+    pub fn max_seats(&self) -> usize {
+        self.max_seats
+    }
+
+    pub fn min_buy_in(&self) -> u32 {
+        self.min_buy_in
+    }
+
+    pub fn max_buy_in(&self) -> u32 {
+        self.max_buy_in
+    }
+
+    /// 空いている席の数
+    pub fn open_seats(&self, game: &Game) -> usize {
+        self.max_seats.saturating_sub(game.players().len())
+    }
+
+    /// 空いている席に座らせる（持ち込むチップは最小〜最大の範囲内）
+    pub fn sit_down(&self, game: &mut Game, player: Player) -> Result<(), DomainError> {
+        if self.open_seats(game) == 0 {
+            return Err(DomainError::InvalidGameOperation("空いている席がありません".into()));
+        }
+        self.validate_stack(player.chips())?;
+        game.add_player(player)
+    }
+
+    /// チップを買い足す（再購入・追加購入）
+    ///
+    /// 買い足した後のスタックが最小〜最大の範囲内である必要がある。
+    pub fn rebuy(&self, game: &mut Game, player_id: &PlayerId, amount: u32) -> Result<(), DomainError> {
+        let stack = game.players().iter()
+            .find(|player| player.id() == player_id)
+            .map(|player| player.chips())
+            .ok_or_else(|| DomainError::ResourceNotFound(format!("プレイヤー {} がゲームに参加していません", player_id)))?;
+        self.validate_stack(stack + amount)?;
+        game.add_chips(player_id, amount)
+    }
+
+    /// 席を立つ（残っているチップごとプレイヤーを返す）
+    pub fn stand_up(&self, game: &mut Game, player_id: &PlayerId) -> Result<Player, DomainError> {
+        game.remove_player(player_id)
+    }
+
+    fn validate_stack(&self, stack: u32) -> Result<(), DomainError> {
+        if stack < self.min_buy_in || stack > self.max_buy_in {
+            return Err(DomainError::InvalidGameOperation(format!(
+                "持ち込めるチップは{}〜{}です（{}になります）", self.min_buy_in, self.max_buy_in, stack
+            )));
+        }
+        Ok(())
+    }
+}
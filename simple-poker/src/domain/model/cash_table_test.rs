@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::cash_table::CashTable;
+    use crate::domain::model::game::{Game, GamePhase, GameVariant};
+    use crate::domain::model::hand_history::BlindKind;
+    use crate::domain::model::player::Player;
+    use crate::domain::service::game_projector::GameProjector;
+
+    fn cash_game(players: usize) -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 1, 2).unwrap();
+        for i in 1..=players {
+            game.add_player(Player::new(format!("プレイヤー{}", i), 200)).unwrap();
+        }
+        game
+    }
+
+    fn blinds(game: &Game) -> Vec<(String, BlindKind, u32)> {
+        game.hand_history().unwrap().blinds.iter()
+            .map(|blind| {
+                let name = game.players().iter().find(|player| player.id() == &blind.player_id).unwrap().name();
+                (name.to_string(), blind.kind, blind.amount)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn 席数と持ち込めるチップの範囲を守る() {
+        assert!(CashTable::new(1, 100, 200).is_err(), "1席のテーブルを作成できてしまいます");
+        assert!(CashTable::new(6, 0, 200).is_err(), "最小額0のテーブルを作成できてしまいます");
+        assert!(CashTable::new(6, 300, 200).is_err(), "最小額が最大額を超えるテーブルを作成できてしまいます");
+
+        let table = CashTable::new(2, 100, 200).unwrap();
+        let mut game = Game::new(GameVariant::TexasHoldem, 1, 2).unwrap();
+        assert!(table.sit_down(&mut game, Player::new("少なすぎる".to_string(), 50)).is_err(), "最小額未満で座れてしまいます");
+        assert!(table.sit_down(&mut game, Player::new("多すぎる".to_string(), 250)).is_err(), "最大額を超えて座れてしまいます");
+        table.sit_down(&mut game, Player::new("プレイヤー1".to_string(), 100)).unwrap();
+        table.sit_down(&mut game, Player::new("プレイヤー2".to_string(), 200)).unwrap();
+        assert_eq!(table.open_seats(&game), 0, "空いている席の数が一致しません");
+        assert!(table.sit_down(&mut game, Player::new("プレイヤー3".to_string(), 100)).is_err(), "満席なのに座れてしまいます");
+    }
+
+    #[test]
+    fn 買い足しは範囲内でハンドの間にのみできる() {
+        let table = CashTable::new(6, 100, 200).unwrap();
+        let mut game = cash_game(2);
+        let id = game.players()[0].id().clone();
+        assert!(table.rebuy(&mut game, &id, 1).is_err(), "最大額を超えて買い足せてしまいます");
+
+        let short = Player::new("ショートスタック".to_string(), 100);
+        let short_id = short.id().clone();
+        table.sit_down(&mut game, short).unwrap();
+        game.start_game().unwrap();
+        assert!(table.rebuy(&mut game, &short_id, 1).is_err(), "ハンドの途中で買い足せてしまいます");
+        game.reset_for_new_hand().unwrap();
+
+        table.rebuy(&mut game, &short_id, 60).unwrap();
+        assert_eq!(game.players()[2].chips(), 160, "買い足したチップが一致しません");
+    }
+
+    #[test]
+    fn 席を立つのはハンドの間にのみできる() {
+        let table = CashTable::new(6, 100, 200).unwrap();
+        let mut game = cash_game(3);
+        let id = game.players()[0].id().clone();
+
+        game.start_game().unwrap();
+        assert!(table.stand_up(&mut game, &id).is_err(), "ハンドの途中で席を立てます");
+        game.reset_for_new_hand().unwrap();
+
+        let player = table.stand_up(&mut game, &id).unwrap();
+        assert_eq!(player.chips(), 200, "持ち帰るチップが一致しません");
+        assert_eq!(game.players().len(), 2, "席から外れていません");
+    }
+
+    #[test]
+    fn 離席中のプレイヤーには配らずブラインドを払わなかった印を付ける() {
+        let mut game = cash_game(4);
+        let away = game.players()[1].id().clone();
+        game.sit_out(&away).unwrap();
+
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        let history = game.hand_history().unwrap();
+        assert_eq!(history.seats.len(), 3, "離席中のプレイヤーが記録されています");
+        assert!(game.players()[1].hand().cards().is_empty(), "離席中のプレイヤーに配られています");
+        assert!(game.players()[1].is_folded(), "離席中のプレイヤーがハンドに参加しています");
+
+        // ブラインドは離席中のプレイヤーを飛ばして回る
+        game.post_blinds().unwrap();
+        assert_eq!(blinds(&game), vec![
+            ("プレイヤー3".to_string(), BlindKind::Small, 1),
+            ("プレイヤー4".to_string(), BlindKind::Big, 2),
+        ], "ブラインドが一致しません");
+        assert!(game.players()[1].has_missed_blind(), "払わなかったブラインドが記録されていません");
+        assert_eq!(game.current_player_index(), 0, "最初に行動するプレイヤーが一致しません");
+
+        // イベントから同じ状態を復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert!(restored.players()[1].is_sitting_out(), "復元したプレイヤーが離席していません");
+        assert!(restored.players()[1].has_missed_blind(), "復元したプレイヤーに印がありません");
+    }
+
+    #[test]
+    fn 復帰したプレイヤーは払わなかったビッグブラインドを払う() {
+        let mut game = cash_game(4);
+        let returning = game.players()[3].id().clone();
+        game.sit_out(&returning).unwrap();
+        game.players_mut()[3].set_missed_blind(true);
+        assert!(game.sit_in(&game.players()[0].id().clone()).is_err(), "離席していないプレイヤーが復帰できてしまいます");
+        game.sit_in(&returning).unwrap();
+
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        assert_eq!(blinds(&game), vec![
+            ("プレイヤー2".to_string(), BlindKind::Small, 1),
+            ("プレイヤー3".to_string(), BlindKind::Big, 2),
+            ("プレイヤー4".to_string(), BlindKind::Big, 2),
+        ], "ブラインドが一致しません");
+        assert_eq!(game.players()[3].current_bet(), 2, "払ったブラインドがベット額に含まれていません");
+        assert!(!game.players()[3].has_missed_blind(), "払った後も印が残っています");
+        assert_eq!(game.pot().total(), 5, "ポットの額が一致しません");
+        assert_eq!(game.current_phase(), GamePhase::Betting, "ベッティングフェーズではありません");
+    }
+
+    #[test]
+    fn 参加できるプレイヤーが2人未満では始められない() {
+        let mut game = cash_game(3);
+        let first = game.players()[0].id().clone();
+        let second = game.players()[1].id().clone();
+        game.sit_out(&first).unwrap();
+        game.sit_out(&second).unwrap();
+        assert!(game.start_game().is_err(), "1人で始められてしまいます");
+
+        game.sit_in(&second).unwrap();
+        assert!(game.start_game().is_ok(), "2人で始められません");
+    }
+}
@@ -1,7 +1,14 @@
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
 use super::card::{Card, Suit};
 
+/// 52枚のデッキのランク（Aは1）
+pub const STANDARD_RANKS: [u8; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+/// 2〜5を抜いた36枚のデッキ（ショートデッキ）のランク
+pub const SHORT_DECK_RANKS: [u8; 9] = [1, 6, 7, 8, 9, 10, 11, 12, 13];
+
 #[derive(Debug, Clone)]
 pub struct Deck {
     cards: Vec<Card>,
@@ -9,10 +16,23 @@ pub struct Deck {
 
 impl Deck {
     pub fn new() -> Result<Self, &'static str> {
-        let mut cards = Vec::with_capacity(52);
+        Self::with_ranks(&STANDARD_RANKS)
+    }
+    
+    /// 指定したランクだけを各スートにそろえたデッキを作成する
+    pub fn with_ranks(ranks: &[u8]) -> Result<Self, &'static str> {
+        Self::with_jokers(ranks, 0)
+    }
+    
+    /// 指定したランクのカードに、ジョーカーを指定した枚数（2枚まで）加えたデッキを作成する
+    pub fn with_jokers(ranks: &[u8], jokers: u8) -> Result<Self, &'static str> {
+        if jokers > 2 {
+            return Err("ジョーカーは2枚までです");
+        }
+        let mut cards = Vec::with_capacity(ranks.len() * 4 + jokers as usize);
         
         for &suit in Suit::all().iter() {
-            for rank in 1..=13 {
+            for &rank in ranks {
                 if let Ok(card) = Card::new(suit, rank) {
                     cards.push(card);
                 } else {
@@ -20,14 +40,66 @@ impl Deck {
                 }
             }
         }
+        // 2枚のジョーカーはスートで区別する
+        for &suit in [Suit::Spade, Suit::Heart].iter().take(jokers as usize) {
+            cards.push(Card::joker(suit));
+        }
         
         Ok(Self { cards })
     }
     
+    /// 指定した順番のカードでデッキを作成する（先頭のカードから順に引かれる）
+    pub fn from_cards(cards: Vec<Card>) -> Result<Self, &'static str> {
+        for (i, card) in cards.iter().enumerate() {
+            if cards[..i].contains(card) {
+                return Err("デッキに重複したカードが含まれています");
+            }
+        }
+        
+        // drawは末尾から引くため、逆順に格納する
+        let mut cards = cards;
+        cards.reverse();
+        Ok(Self { cards })
+    }
+    
+    /// 指定したカードを上から順に積み、残りのカードをその下に並べたデッキを作成する
+    pub fn stacked(top_cards: Vec<Card>) -> Result<Self, &'static str> {
+        Self::stacked_with_ranks(top_cards, &STANDARD_RANKS)
+    }
+    
+    /// 指定したランクのデッキで、指定したカードを上から順に積む
+    pub fn stacked_with_ranks(top_cards: Vec<Card>, ranks: &[u8]) -> Result<Self, &'static str> {
+        Self::stacked_on(top_cards, Self::with_ranks(ranks)?)
+    }
+    
+    /// 指定したカードを上から順に積み、base の残りのカードをその下に並べる
+    pub fn stacked_on(top_cards: Vec<Card>, base: Deck) -> Result<Self, &'static str> {
+        if top_cards.iter().any(|card| !base.cards.contains(card)) {
+            return Err("デッキに含まれないカードが指定されています");
+        }
+        let mut cards = top_cards;
+        for card in base.cards.into_iter() {
+            if !cards.contains(&card) {
+                cards.push(card);
+            }
+        }
+        Self::from_cards(cards)
+    }
+    
     pub fn shuffle(&mut self) {
         self.cards.shuffle(&mut rand::thread_rng());
     }
     
+    /// シード値から決定的にシャッフルする（同じシードなら同じ並びになる）
+    pub fn shuffle_with_seed(&mut self, seed: u64) {
+        self.shuffle_with(&mut StdRng::seed_from_u64(seed));
+    }
+    
+    /// 任意の乱数生成器でシャッフルする
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+    
     pub fn draw(&mut self) -> Option<Card> {
         self.cards.pop()
     }
@@ -55,4 +127,14 @@ impl Deck {
     pub fn add_card(&mut self, card: Card) {
         self.cards.push(card);
     }
+    
+    /// 最後に引かれる位置にカードを戻す（捨て札が残りのカードより先に配られないように）
+    pub fn add_card_to_bottom(&mut self, card: Card) {
+        self.cards.insert(0, card);
+    }
+    
+    /// 残りのカードを引かれる順に返す
+    pub fn cards_in_draw_order(&self) -> Vec<Card> {
+        self.cards.iter().rev().copied().collect()
+    }
 } 
\ No newline at end of file
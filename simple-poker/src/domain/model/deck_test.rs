@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::domain::model::deck::Deck;
+    use crate::domain::model::deck::{Deck, SHORT_DECK_RANKS, STANDARD_RANKS};
     use crate::domain::model::card::{Card, Suit};
     use std::collections::HashSet;
 
@@ -94,4 +94,86 @@ mod tests {
         assert_eq!(deck.remaining(), 0, "全て引いた後もデッキが空ではありません");
         assert!(deck.is_empty(), "全て引いた後にデッキが空でないと判定されています");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn シード指定シャッフル_同じシードなら同じ並び() {
+        let mut deck1 = Deck::new().unwrap();
+        let mut deck2 = Deck::new().unwrap();
+        deck1.shuffle_with_seed(42);
+        deck2.shuffle_with_seed(42);
+        assert_eq!(deck1.cards_in_draw_order(), deck2.cards_in_draw_order(), "同じシードで並びが一致しません");
+        
+        let mut deck3 = Deck::new().unwrap();
+        deck3.shuffle_with_seed(43);
+        assert_ne!(deck1.cards_in_draw_order(), deck3.cards_in_draw_order(), "異なるシードで並びが一致しています");
+        assert_eq!(deck3.remaining(), 52, "シャッフル後の枚数が52ではありません");
+    }
+
+    #[test]
+    fn カード指定でデッキ作成() {
+        let cards = vec![
+            Card::new(Suit::Spade, 1).unwrap(),
+            Card::new(Suit::Heart, 13).unwrap(),
+            Card::new(Suit::Club, 2).unwrap(),
+        ];
+        let mut deck = Deck::from_cards(cards.clone()).unwrap();
+        assert_eq!(deck.remaining(), 3, "デッキ枚数が一致しません");
+        assert_eq!(deck.draw_multiple(3), cards, "指定した順番で引けません");
+        
+        // 重複したカードはエラー
+        let duplicated = vec![cards[0], cards[0]];
+        assert!(Deck::from_cards(duplicated).is_err(), "重複したカードでもデッキが作成できてしまいます");
+    }
+
+    #[test]
+    fn 上積みデッキ作成() {
+        let top = vec![
+            Card::new(Suit::Diamond, 7).unwrap(),
+            Card::new(Suit::Spade, 1).unwrap(),
+        ];
+        let mut deck = Deck::stacked(top.clone()).unwrap();
+        assert_eq!(deck.remaining(), 52, "上積みデッキの枚数が52ではありません");
+        assert_eq!(deck.draw_multiple(2), top, "上積みしたカードが先に引けません");
+        
+        let mut rest = HashSet::new();
+        while let Some(card) = deck.draw() {
+            assert!(rest.insert(card), "カードが重複しています");
+            assert!(!top.contains(&card), "上積みしたカードが再度含まれています");
+        }
+        assert_eq!(rest.len(), 50, "残りのカード枚数が一致しません");
+    }
+
+    #[test]
+    fn ショートデッキは2から5を抜いた36枚() {
+        let mut deck = Deck::with_ranks(&SHORT_DECK_RANKS).unwrap();
+        assert_eq!(deck.remaining(), 36, "ショートデッキの枚数が36ではありません");
+        while let Some(card) = deck.draw() {
+            assert!(!(2..=5).contains(&card.rank()), "2〜5のカードが含まれています: {}", card);
+        }
+        
+        // デッキにないランクは上積みできない
+        let top = vec![Card::new(Suit::Heart, 2).unwrap()];
+        assert!(Deck::stacked_with_ranks(top, &SHORT_DECK_RANKS).is_err(), "ショートデッキに2を積めてしまいます");
+    }
+
+    #[test]
+    fn ジョーカー入りのデッキ() {
+        let mut deck = Deck::with_jokers(&STANDARD_RANKS, 2).unwrap();
+        assert_eq!(deck.remaining(), 54, "ジョーカー2枚入りのデッキが54枚ではありません");
+        let mut jokers = HashSet::new();
+        while let Some(card) = deck.draw() {
+            if card.is_joker() {
+                jokers.insert(card);
+            }
+        }
+        assert_eq!(jokers.len(), 2, "ジョーカーが2枚入っていません");
+        assert!(Deck::with_jokers(&STANDARD_RANKS, 3).is_err(), "ジョーカー3枚でエラーになりません");
+        
+        // ジョーカーはジョーカー入りのデッキにだけ上積みできる
+        let top = vec![Card::joker(Suit::Spade)];
+        let deck = Deck::stacked_on(top.clone(), Deck::with_jokers(&STANDARD_RANKS, 1).unwrap()).unwrap();
+        assert_eq!(deck.cards_in_draw_order()[0], Card::joker(Suit::Spade), "ジョーカーが一番上にありません");
+        assert_eq!(deck.remaining(), 53, "デッキの枚数が一致しません");
+        assert!(Deck::stacked(top).is_err(), "ジョーカーなしのデッキにジョーカーを積めてしまいます");
+    }
+}
@@ -2,9 +2,10 @@ use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 
 use super::card::Card;
-use super::game::{GameId, GameVariant, BettingRound};
-use super::player::PlayerId;
-use super::bet::BetAction;
+use super::game::{GameId, GameVariant, BettingRound, TableRules};
+use super::hand_history::BlindKind;
+use super::player::{BotKind, PlayerId};
+use super::bet::{BetAction, BettingStructure};
 
 /// ドメインイベントを表す基本インターフェース
 pub trait DomainEvent: std::fmt::Debug {
@@ -14,19 +15,33 @@ pub trait DomainEvent: std::fmt::Debug {
 }
 
 /// ゲーム関連のドメインイベント
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Game` の状態を変える操作はすべてイベントを残す。操作そのものを表すイベント
+/// （`GameCreated` や `PlayerAction` など）に続いて、その操作の結果として起きたイベント
+/// （`BettingRoundStarted` や `CommunityCardsDealt` など）が並ぶ。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GameEvent {
     GameCreated {
         game_id: GameId,
         variant: GameVariant,
         small_blind: u32,
         big_blind: u32,
+        #[serde(default)]
+        betting_structure: BettingStructure,
+        #[serde(default)]
+        seed: Option<u64>,
         time: DateTime<Utc>,
     },
     
     GameStarted {
         game_id: GameId,
         player_count: usize,
+        #[serde(default)]
+        hand_number: u32,
+        #[serde(default)]
+        dealer_index: usize,
+        #[serde(default)]
+        deck: Vec<Card>, // シャッフル後のデッキ（引かれる順）
         time: DateTime<Utc>,
     },
     
@@ -35,12 +50,65 @@ pub enum GameEvent {
         player_id: PlayerId,
         player_name: String,
         initial_chips: u32,
+        #[serde(default)]
+        bot: Option<BotKind>, // コンピュータが操作する席
+        #[serde(default)]
+        seat: Option<usize>,  // 席の番号を指定して座らせた場合の番号（Noneなら空いている席）
+        time: DateTime<Utc>,
+    },
+    
+    PlayerRemoved {
+        game_id: GameId,
+        player_id: PlayerId,
+        time: DateTime<Utc>,
+    },
+    
+    BlindsChanged {
+        game_id: GameId,
+        small_blind: u32,
+        big_blind: u32,
+        ante: u32,
+        time: DateTime<Utc>,
+    },
+    
+    TableRulesChanged {
+        game_id: GameId,
+        rules: TableRules,
+        time: DateTime<Utc>,
+    },
+    
+    ChipsAdded {
+        game_id: GameId,
+        player_id: PlayerId,
+        amount: u32,
+        time: DateTime<Utc>,
+    },
+    
+    PlayerSatOut {
+        game_id: GameId,
+        player_id: PlayerId,
+        time: DateTime<Utc>,
+    },
+    
+    PlayerSatIn {
+        game_id: GameId,
+        player_id: PlayerId,
         time: DateTime<Utc>,
     },
     
     CardsDealt {
         game_id: GameId,
         player_id: PlayerId,
+        #[serde(default)]
+        cards: Vec<Card>,
+        time: DateTime<Utc>,
+    },
+    
+    BlindPosted {
+        game_id: GameId,
+        player_id: PlayerId,
+        kind: BlindKind,
+        amount: u32,
         time: DateTime<Utc>,
     },
     
@@ -54,7 +122,13 @@ pub enum GameEvent {
         game_id: GameId,
         player_id: PlayerId,
         action: BetAction,
-        amount: Option<u32>,
+        amount: Option<u32>, // 指定されたベット額
+        time: DateTime<Utc>,
+    },
+    
+    BettingRoundEnded {
+        game_id: GameId,
+        round: BettingRound,
         time: DateTime<Utc>,
     },
     
@@ -62,6 +136,12 @@ pub enum GameEvent {
         game_id: GameId,
         player_id: PlayerId,
         count: usize,
+        #[serde(default)]
+        card_indices: Vec<usize>,
+        #[serde(default)]
+        discarded: Vec<Card>,
+        #[serde(default)]
+        drawn: Vec<Card>,
         time: DateTime<Utc>,
     },
     
@@ -77,6 +157,44 @@ pub enum GameEvent {
         pot_amount: u32,
         time: DateTime<Utc>,
     },
+    
+    HandReset {
+        game_id: GameId,
+        hand_number: u32, // 次のハンドの番号
+        time: DateTime<Utc>,
+    },
+}
+
+impl GameEvent {
+    /// 発生時刻を除いて同じイベントか
+    pub fn same_as(&self, other: &GameEvent) -> bool {
+        let mut normalized = other.clone();
+        *normalized.time_mut() = self.occurred_at();
+        self == &normalized
+    }
+    
+    fn time_mut(&mut self) -> &mut DateTime<Utc> {
+        match self {
+            GameEvent::GameCreated { time, .. } => time,
+            GameEvent::GameStarted { time, .. } => time,
+            GameEvent::PlayerAdded { time, .. } => time,
+            GameEvent::PlayerRemoved { time, .. } => time,
+            GameEvent::BlindsChanged { time, .. } => time,
+            GameEvent::TableRulesChanged { time, .. } => time,
+            GameEvent::ChipsAdded { time, .. } => time,
+            GameEvent::PlayerSatOut { time, .. } => time,
+            GameEvent::PlayerSatIn { time, .. } => time,
+            GameEvent::CardsDealt { time, .. } => time,
+            GameEvent::BlindPosted { time, .. } => time,
+            GameEvent::BettingRoundStarted { time, .. } => time,
+            GameEvent::PlayerAction { time, .. } => time,
+            GameEvent::BettingRoundEnded { time, .. } => time,
+            GameEvent::CardsExchanged { time, .. } => time,
+            GameEvent::CommunityCardsDealt { time, .. } => time,
+            GameEvent::GameEnded { time, .. } => time,
+            GameEvent::HandReset { time, .. } => time,
+        }
+    }
 }
 
 impl DomainEvent for GameEvent {
@@ -85,12 +203,21 @@ impl DomainEvent for GameEvent {
             GameEvent::GameCreated { .. } => "GameCreated",
             GameEvent::GameStarted { .. } => "GameStarted",
             GameEvent::PlayerAdded { .. } => "PlayerAdded",
+            GameEvent::PlayerRemoved { .. } => "PlayerRemoved",
+            GameEvent::BlindsChanged { .. } => "BlindsChanged",
+            GameEvent::TableRulesChanged { .. } => "TableRulesChanged",
+            GameEvent::ChipsAdded { .. } => "ChipsAdded",
+            GameEvent::PlayerSatOut { .. } => "PlayerSatOut",
+            GameEvent::PlayerSatIn { .. } => "PlayerSatIn",
             GameEvent::CardsDealt { .. } => "CardsDealt",
+            GameEvent::BlindPosted { .. } => "BlindPosted",
             GameEvent::BettingRoundStarted { .. } => "BettingRoundStarted",
             GameEvent::PlayerAction { .. } => "PlayerAction",
+            GameEvent::BettingRoundEnded { .. } => "BettingRoundEnded",
             GameEvent::CardsExchanged { .. } => "CardsExchanged",
             GameEvent::CommunityCardsDealt { .. } => "CommunityCardsDealt",
             GameEvent::GameEnded { .. } => "GameEnded",
+            GameEvent::HandReset { .. } => "HandReset",
         }
     }
     
@@ -99,12 +226,21 @@ impl DomainEvent for GameEvent {
             GameEvent::GameCreated { time, .. } => *time,
             GameEvent::GameStarted { time, .. } => *time,
             GameEvent::PlayerAdded { time, .. } => *time,
+            GameEvent::PlayerRemoved { time, .. } => *time,
+            GameEvent::BlindsChanged { time, .. } => *time,
+            GameEvent::TableRulesChanged { time, .. } => *time,
+            GameEvent::ChipsAdded { time, .. } => *time,
+            GameEvent::PlayerSatOut { time, .. } => *time,
+            GameEvent::PlayerSatIn { time, .. } => *time,
             GameEvent::CardsDealt { time, .. } => *time,
+            GameEvent::BlindPosted { time, .. } => *time,
             GameEvent::BettingRoundStarted { time, .. } => *time,
             GameEvent::PlayerAction { time, .. } => *time,
+            GameEvent::BettingRoundEnded { time, .. } => *time,
             GameEvent::CardsExchanged { time, .. } => *time,
             GameEvent::CommunityCardsDealt { time, .. } => *time,
             GameEvent::GameEnded { time, .. } => *time,
+            GameEvent::HandReset { time, .. } => *time,
         }
     }
     
@@ -113,12 +249,21 @@ impl DomainEvent for GameEvent {
             GameEvent::GameCreated { game_id, .. } => game_id.value().to_string(),
             GameEvent::GameStarted { game_id, .. } => game_id.value().to_string(),
             GameEvent::PlayerAdded { game_id, .. } => game_id.value().to_string(),
+            GameEvent::PlayerRemoved { game_id, .. } => game_id.value().to_string(),
+            GameEvent::BlindsChanged { game_id, .. } => game_id.value().to_string(),
+            GameEvent::TableRulesChanged { game_id, .. } => game_id.value().to_string(),
+            GameEvent::ChipsAdded { game_id, .. } => game_id.value().to_string(),
+            GameEvent::PlayerSatOut { game_id, .. } => game_id.value().to_string(),
+            GameEvent::PlayerSatIn { game_id, .. } => game_id.value().to_string(),
             GameEvent::CardsDealt { game_id, .. } => game_id.value().to_string(),
+            GameEvent::BlindPosted { game_id, .. } => game_id.value().to_string(),
             GameEvent::BettingRoundStarted { game_id, .. } => game_id.value().to_string(),
             GameEvent::PlayerAction { game_id, .. } => game_id.value().to_string(),
+            GameEvent::BettingRoundEnded { game_id, .. } => game_id.value().to_string(),
             GameEvent::CardsExchanged { game_id, .. } => game_id.value().to_string(),
             GameEvent::CommunityCardsDealt { game_id, .. } => game_id.value().to_string(),
             GameEvent::GameEnded { game_id, .. } => game_id.value().to_string(),
+            GameEvent::HandReset { game_id, .. } => game_id.value().to_string(),
         }
     }
 }
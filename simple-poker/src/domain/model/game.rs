@@ -1,25 +1,98 @@
+use chrono::Utc;
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
 
-use super::bet::Pot;
-use super::card::Card;
-use super::deck::Deck;
-use super::player::Player;
+use super::bet::{BetAction, BettingStructure, Pot};
+use super::card::{Card, Suit, WildCards};
+use super::deck::{Deck, SHORT_DECK_RANKS, STANDARD_RANKS};
+use super::player::{Player, PlayerId};
 use super::error::{DomainError, DeckError};
+use super::event::GameEvent;
+use super::hand_history::{
+    ActionRecord, BlindKind, BlindRecord, BoardRecord, DrawRecord, HandHistory, PayoutRecord, SeatRecord, ShowdownRecord,
+};
+use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRankingRules};
+use crate::domain::service::hand_evaluation_specification::HandRankingTable;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameVariant {
     FiveCardDraw,
     TexasHoldem,
     Omaha,
+    SevenCardStud,
+    Razz, // エース・トゥ・ファイブのローだけで勝負するスタッド
+    DeuceToSevenTripleDraw, // デュース・トゥ・セブンのローで勝負し、3回交換するドロー
+    OmahaHiLo,              // ハイとエイト・オア・ベターのローでポットを分けるオマハ
+    ShortDeckHoldem,        // 2〜5を抜いた36枚のデッキで遊ぶホールデム
 }
 
 impl GameVariant {
+    /// 1人に配られる手札の最大枚数
     pub fn hand_size(&self) -> usize {
         match self {
-            GameVariant::FiveCardDraw => 5,
-            GameVariant::TexasHoldem => 2,
-            GameVariant::Omaha => 4,
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw => 5,
+            GameVariant::TexasHoldem | GameVariant::ShortDeckHoldem => 2,
+            GameVariant::Omaha | GameVariant::OmahaHiLo => 4,
+            GameVariant::SevenCardStud | GameVariant::Razz => 7,
+        }
+    }
+    
+    /// ハンドの始めに配る手札の枚数（スタッドは伏せた2枚と表向きの1枚）
+    pub fn starting_hand_size(&self) -> usize {
+        match self {
+            GameVariant::SevenCardStud | GameVariant::Razz => 3,
+            _ => self.hand_size(),
+        }
+    }
+    
+    /// ボードの最大枚数（スタッドはカードが足りない場合の共通のカード1枚）
+    pub fn max_community_cards(&self) -> usize {
+        match self {
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw => 0,
+            GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem => 5,
+            GameVariant::SevenCardStud | GameVariant::Razz => 1,
+        }
+    }
+    
+    /// 手札を各ストリートで1枚ずつ配り足すスタッド系か
+    pub fn is_stud(&self) -> bool {
+        matches!(self, GameVariant::SevenCardStud | GameVariant::Razz)
+    }
+    
+    /// 手札を交換するドロー系か
+    pub fn is_draw(&self) -> bool {
+        matches!(self, GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw)
+    }
+    
+    /// ボードのカードを使うホールデム・オマハ系か
+    pub fn has_board(&self) -> bool {
+        matches!(self, GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem)
+    }
+    
+    /// ローの手だけで勝負するか
+    pub fn is_low_only(&self) -> bool {
+        matches!(self, GameVariant::Razz | GameVariant::DeuceToSevenTripleDraw)
+    }
+    
+    /// ポットをハイとローに分けるか
+    pub fn is_hi_lo(&self) -> bool {
+        matches!(self, GameVariant::OmahaHiLo)
+    }
+    
+    /// 配られた順番で何枚目の手札が表向き（他のプレイヤーに見える）か
+    ///
+    /// スタッドは3〜6枚目が表向きで、最初の2枚と7枚目は伏せて配る。
+    pub fn is_face_up(&self, card_index: usize) -> bool {
+        self.is_stud() && (2..6).contains(&card_index)
+    }
+    
+    /// ハンドの最初のベッティングラウンド
+    pub fn first_round(&self) -> BettingRound {
+        match self {
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw => BettingRound::PreDraw,
+            GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo
+                | GameVariant::ShortDeckHoldem => BettingRound::PreFlop,
+            GameVariant::SevenCardStud | GameVariant::Razz => BettingRound::ThirdStreet,
         }
     }
     
@@ -28,6 +101,27 @@ impl GameVariant {
             GameVariant::FiveCardDraw => "ファイブカードドロー",
             GameVariant::TexasHoldem => "テキサスホールデム",
             GameVariant::Omaha => "オマハ",
+            GameVariant::SevenCardStud => "セブンカードスタッド",
+            GameVariant::Razz => "ラズ",
+            GameVariant::DeuceToSevenTripleDraw => "2-7 トリプルドロー",
+            GameVariant::OmahaHiLo => "オマハ・ハイロー",
+            GameVariant::ShortDeckHoldem => "ショートデッキ・ホールデム",
+        }
+    }
+    
+    /// デッキに入れるランク
+    pub fn deck_ranks(&self) -> &'static [u8] {
+        match self {
+            GameVariant::ShortDeckHoldem => &SHORT_DECK_RANKS,
+            _ => &STANDARD_RANKS,
+        }
+    }
+    
+    /// 役の決まり（ショートデッキは A-6-7-8-9 をストレートとし、フラッシュをフルハウスより強くする）
+    pub fn hand_ranking_rules(&self) -> HandRankingRules {
+        match self {
+            GameVariant::ShortDeckHoldem => HandRankingRules::SHORT_DECK,
+            _ => HandRankingRules::STANDARD,
         }
     }
 }
@@ -35,11 +129,18 @@ impl GameVariant {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BettingRound {
     PreDraw,   // ファイブカードドローでの最初のベッティングラウンド
-    PostDraw,  // ファイブカードドローでの2回目のベッティングラウンド
+    PostDraw,  // ドローでの最初の交換後のベッティングラウンド
     PreFlop,   // テキサスホールデム・オマハでの最初のベッティングラウンド
     Flop,      // テキサスホールデム・オマハでのフロップ後のベッティングラウンド
     Turn,      // テキサスホールデム・オマハでのターン後のベッティングラウンド
     River,     // テキサスホールデム・オマハでのリバー後のベッティングラウンド
+    ThirdStreet,   // スタッドで3枚配られた後の最初のベッティングラウンド
+    FourthStreet,  // スタッドで4枚目（表向き）が配られた後のベッティングラウンド
+    FifthStreet,   // スタッドで5枚目（表向き）が配られた後のベッティングラウンド
+    SixthStreet,   // スタッドで6枚目（表向き）が配られた後のベッティングラウンド
+    SeventhStreet, // スタッドで7枚目（伏せて）が配られた後の最後のベッティングラウンド
+    PostSecondDraw, // トリプルドローでの2回目の交換後のベッティングラウンド
+    PostThirdDraw,  // トリプルドローでの3回目の交換後の最後のベッティングラウンド
 }
 
 impl BettingRound {
@@ -47,10 +148,19 @@ impl BettingRound {
         match (game_variant, self) {
             (GameVariant::FiveCardDraw, BettingRound::PreDraw) => Some(BettingRound::PostDraw),
             (GameVariant::FiveCardDraw, BettingRound::PostDraw) => None,
-            (GameVariant::TexasHoldem | GameVariant::Omaha, BettingRound::PreFlop) => Some(BettingRound::Flop),
-            (GameVariant::TexasHoldem | GameVariant::Omaha, BettingRound::Flop) => Some(BettingRound::Turn),
-            (GameVariant::TexasHoldem | GameVariant::Omaha, BettingRound::Turn) => Some(BettingRound::River),
-            (GameVariant::TexasHoldem | GameVariant::Omaha, BettingRound::River) => None,
+            (GameVariant::DeuceToSevenTripleDraw, BettingRound::PreDraw) => Some(BettingRound::PostDraw),
+            (GameVariant::DeuceToSevenTripleDraw, BettingRound::PostDraw) => Some(BettingRound::PostSecondDraw),
+            (GameVariant::DeuceToSevenTripleDraw, BettingRound::PostSecondDraw) => Some(BettingRound::PostThirdDraw),
+            (GameVariant::DeuceToSevenTripleDraw, BettingRound::PostThirdDraw) => None,
+            (GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem, BettingRound::PreFlop) => Some(BettingRound::Flop),
+            (GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem, BettingRound::Flop) => Some(BettingRound::Turn),
+            (GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem, BettingRound::Turn) => Some(BettingRound::River),
+            (GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem, BettingRound::River) => None,
+            (GameVariant::SevenCardStud | GameVariant::Razz, BettingRound::ThirdStreet) => Some(BettingRound::FourthStreet),
+            (GameVariant::SevenCardStud | GameVariant::Razz, BettingRound::FourthStreet) => Some(BettingRound::FifthStreet),
+            (GameVariant::SevenCardStud | GameVariant::Razz, BettingRound::FifthStreet) => Some(BettingRound::SixthStreet),
+            (GameVariant::SevenCardStud | GameVariant::Razz, BettingRound::SixthStreet) => Some(BettingRound::SeventhStreet),
+            (GameVariant::SevenCardStud | GameVariant::Razz, BettingRound::SeventhStreet) => None,
             _ => None,
         }
     }
@@ -63,6 +173,31 @@ impl BettingRound {
             BettingRound::Flop => "フロップ",
             BettingRound::Turn => "ターン",
             BettingRound::River => "リバー",
+            BettingRound::ThirdStreet => "3rdストリート",
+            BettingRound::FourthStreet => "4thストリート",
+            BettingRound::FifthStreet => "5thストリート",
+            BettingRound::SixthStreet => "6thストリート",
+            BettingRound::SeventhStreet => "7thストリート",
+            BettingRound::PostSecondDraw => "2回目のドロー後",
+            BettingRound::PostThirdDraw => "3回目のドロー後",
+        }
+    }
+    
+    /// 始まるときに手札を交換するラウンドか
+    pub fn starts_with_draw(&self) -> bool {
+        matches!(self, BettingRound::PostDraw | BettingRound::PostSecondDraw | BettingRound::PostThirdDraw)
+    }
+    
+    /// フィックスドリミットでビッグベットの額を使う後半のラウンドか
+    ///
+    /// トリプルドローは最初の交換後まではスモールベット、2回目の交換後からビッグベット。
+    pub fn uses_big_bet(&self, game_variant: GameVariant) -> bool {
+        match (game_variant, self) {
+            (GameVariant::DeuceToSevenTripleDraw, BettingRound::PostDraw) => false,
+            (_, BettingRound::PostDraw | BettingRound::PostSecondDraw | BettingRound::PostThirdDraw) => true,
+            (_, BettingRound::Turn | BettingRound::River) => true,
+            (_, BettingRound::FifthStreet | BettingRound::SixthStreet | BettingRound::SeventhStreet) => true,
+            _ => false,
         }
     }
 }
@@ -80,6 +215,12 @@ pub enum GamePhase {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameId(String);
 
+impl Default for GameId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GameId {
     pub fn new() -> Self {
         Self(Uuid::new_v4().to_string())
@@ -94,6 +235,98 @@ impl GameId {
     }
 }
 
+/// 1テーブルの席の数（席の番号は0から始まる）
+pub const MAX_SEATS: usize = 10;
+
+/// スタッドで1ハンドに参加できる人数（8人を超えると7枚ずつ配るカードが足りない）
+pub const MAX_STUD_PLAYERS: usize = 8;
+
+/// プレイヤーが抜けたり加わったりしたときのディーラーボタンの動かし方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ButtonRule {
+    /// ビッグブラインドが必ず次の席へ進み、ボタンとスモールブラインドはそれに合わせて置く
+    /// （ボタンが空席に残ったり、スモールブラインドが払われなかったりする）
+    #[default]
+    DeadButton,
+    /// ボタンが常に次のプレイヤーへ進む（ブラインドを払わずに済むプレイヤーが出ることがある）
+    MovingButton,
+}
+
+impl ButtonRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ButtonRule::DeadButton => "デッドボタン",
+            ButtonRule::MovingButton => "ムービングボタン",
+        }
+    }
+}
+
+/// スプリットポットで割り切れない端数チップの渡し方
+///
+/// ハイとローに分ける場合、ポットを半分にしたときの端数はハイの側に付ける。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OddChipRule {
+    /// ディーラーボタンの左隣から近い勝者に1枚ずつ渡す
+    #[default]
+    LeftOfButton,
+    /// 最も高いカード（同じランクならスペード、ハート、ダイヤ、クラブの順）を持つ勝者から1枚ずつ渡す
+    HighestCard,
+}
+
+impl OddChipRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OddChipRule::LeftOfButton => "ボタンの左隣から",
+            OddChipRule::HighestCard => "最も高いカードから",
+        }
+    }
+}
+
+/// ショートデッキでスリーカードとストレートのどちらを強くするか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ShortDeckTripsRule {
+    /// スリーカードをストレートより強くする（ストレートの方ができやすいため）
+    #[default]
+    TripsBeatStraight,
+    /// 通常のポーカーと同じくストレートをスリーカードより強くする
+    StraightBeatsTrips,
+}
+
+impl ShortDeckTripsRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShortDeckTripsRule::TripsBeatStraight => "スリーカードが上",
+            ShortDeckTripsRule::StraightBeatsTrips => "ストレートが上",
+        }
+    }
+}
+
+/// ゲームごとに決める卓のルール（ハンドの間にまとめて変更する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableRules {
+    pub button_rule: ButtonRule,
+    pub odd_chip_rule: OddChipRule, // スプリットポットの端数チップの渡し方
+    pub short_deck_trips_rule: ShortDeckTripsRule, // ショートデッキでスリーカードとストレートのどちらを強くするか
+    pub wild_cards: WildCards, // ジョーカーとワイルドにするランク
+    pub hand_ranking_table: Option<HandRankingTable>, // ゲームで決めた役の表（なければゲームの種類の表）
+}
+
+/// ハンドでのディーラーボタンとブラインドの席の番号
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TablePositions {
+    pub button: usize,      // 空席のこともある
+    pub small_blind: usize, // 空席や配られないプレイヤーの席ならスモールブラインドは払われない
+    pub big_blind: usize,
+}
+
+// ラウンド内のレイズの状況
+struct RoundRaises {
+    full_raises: u32,
+    raise_size: u32,                  // 最後の完全なレイズの上乗せ額
+    last_full_raise_at: Option<usize>, // 最後の完全なレイズのアクションの位置
+}
+
 #[derive(Debug, Clone)]
 pub struct Game {
     id: GameId,
@@ -105,10 +338,20 @@ pub struct Game {
     current_round: Option<BettingRound>,
     current_phase: GamePhase,
     current_player_index: usize,
-    dealer_index: usize,
+    dealer_index: usize, // ボタンの席に座っているプレイヤー（空席ならその手前のプレイヤー）
+    rules: TableRules,
+    positions: Option<TablePositions>, // 進行中または直前のハンドのボタンとブラインドの席
     small_blind: u32,
     big_blind: u32,
+    ante: u32,         // ハンドごとに全員が投入するアンティ（0ならなし）
+    betting_structure: BettingStructure,
     current_bet: u32,  // 現在のラウンドでの最大ベット額
+    acted_players: Vec<PlayerId>, // このラウンドで最後にベット額が上がった後に行動したプレイヤー
+    seed: Option<u64>, // デッキシャッフル用のシード（Noneならランダム）
+    hand_number: u32,  // このゲームで何ハンド目か（0始まり）
+    deck_preset: bool, // 次のハンドでデッキの並びが指定済みか
+    hand_history: Option<HandHistory>, // 進行中のハンドの記録
+    events: Vec<GameEvent>, // まだ取り出されていないイベント
 }
 
 // デシリアライズのためのデータ構造体
@@ -117,27 +360,66 @@ pub struct GameSerializedData {
     pub variant: GameVariant,
     pub players: Vec<Player>,
     pub community_cards: Vec<Card>,
-    pub pot_total: u32,
+    pub deck: Option<Vec<Card>>,           // 引かれる順の残りのデッキ（Noneなら未使用のカードから再構築）
+    pub main_pot: u32,
+    pub side_pots: Vec<(u32, Vec<String>)>, // (チップ額, 対象プレイヤーID)
     pub current_phase: GamePhase,
     pub current_round: Option<BettingRound>,
     pub current_player_index: usize,
     pub dealer_index: usize,
+    pub rules: TableRules,
+    pub positions: Option<TablePositions>,
     pub small_blind: u32,
     pub big_blind: u32,
+    pub ante: u32,
+    pub betting_structure: BettingStructure,
     pub current_bet: u32,
+    pub acted_players: Vec<PlayerId>,
+    pub seed: Option<u64>,
+    pub hand_number: u32,
+    pub hand_history: Option<HandHistory>,
 }
 
 impl Game {
     pub fn new(variant: GameVariant, small_blind: u32, big_blind: u32) -> Result<Self, DomainError> {
+        Self::new_with_seed(variant, small_blind, big_blind, None)
+    }
+    
+    /// シードを指定してゲームを作成する（同じシードなら同じ順番でカードが配られる）
+    pub fn new_with_seed(variant: GameVariant, small_blind: u32, big_blind: u32, seed: Option<u64>) -> Result<Self, DomainError> {
+        Self::new_with_structure(variant, small_blind, big_blind, BettingStructure::NoLimit, seed)
+    }
+    
+    /// ベット・レイズできる額の決まりを指定してゲームを作成する
+    pub fn new_with_structure(
+        variant: GameVariant,
+        small_blind: u32,
+        big_blind: u32,
+        betting_structure: BettingStructure,
+        seed: Option<u64>,
+    ) -> Result<Self, DomainError> {
+        Self::new_with_id(GameId::new(), variant, small_blind, big_blind, betting_structure, seed)
+    }
+    
+    // IDを指定してゲームを作成する（イベントからの復元用）
+    pub(crate) fn new_with_id(
+        id: GameId,
+        variant: GameVariant,
+        small_blind: u32,
+        big_blind: u32,
+        betting_structure: BettingStructure,
+        seed: Option<u64>,
+    ) -> Result<Self, DomainError> {
         // スモールブラインドがビッグブラインドより大きいとエラー
         if small_blind > big_blind {
             return Err(DomainError::InvalidGameOperation("スモールブラインドはビッグブラインド以下である必要があります".into()));
         }
+        betting_structure.validate()?;
         
-        let deck = Deck::new().map_err(|e| DomainError::InvalidState(e.to_string()))?;
+        let deck = Self::new_deck(variant, WildCards::NONE)?;
         
-        Ok(Self {
-            id: GameId::new(),
+        let mut game = Self {
+            id,
             variant,
             players: Vec::new(),
             deck,
@@ -147,10 +429,30 @@ impl Game {
             current_phase: GamePhase::NotStarted,
             current_player_index: 0,
             dealer_index: 0,
+            rules: TableRules::default(),
+            positions: None,
             small_blind,
             big_blind,
+            ante: 0,
+            betting_structure,
             current_bet: 0,
-        })
+            acted_players: Vec::new(),
+            seed,
+            hand_number: 0,
+            deck_preset: false,
+            hand_history: None,
+            events: Vec::new(),
+        };
+        game.emit(GameEvent::GameCreated {
+            game_id: game.id.clone(),
+            variant,
+            small_blind,
+            big_blind,
+            betting_structure,
+            seed,
+            time: Utc::now(),
+        });
+        Ok(game)
     }
     
     pub fn id(&self) -> &GameId {
@@ -161,12 +463,58 @@ impl Game {
         self.variant
     }
     
-    pub fn add_player(&mut self, player: Player) -> Result<(), DomainError> {
+    /// 空いている席にプレイヤーを座らせる（最後に座ったプレイヤーの次の席から順に探す）
+    pub fn add_player(&mut self, mut player: Player) -> Result<(), DomainError> {
+        self.validate_new_player(&player)?;
+        let start = self.players.last().map_or(0, |last| last.seat() + 1);
+        let seat = (0..MAX_SEATS)
+            .map(|offset| (start + offset) % MAX_SEATS)
+            .find(|&seat| self.player_index_at_seat(seat).is_none())
+            .ok_or_else(|| DomainError::InvalidGameOperation("空いている席がありません".into()))?;
+        
+        self.emit_player_added(&player, None);
+        player.set_seat(seat);
+        self.insert_player(player);
+        Ok(())
+    }
+    
+    /// 指定した番号の席にプレイヤーを座らせる（ハンドの間にのみ可能）
+    ///
+    /// 手番は席の番号順に回る。ディーラーボタンは同じ席に残る。
+    pub fn seat_player(&mut self, mut player: Player, seat: usize) -> Result<(), DomainError> {
+        self.validate_new_player(&player)?;
+        if seat >= MAX_SEATS {
+            return Err(DomainError::InvalidGameOperation(format!("無効な席の番号です: {}", seat + 1)));
+        }
+        if self.player_index_at_seat(seat).is_some() {
+            return Err(DomainError::InvalidGameOperation(format!("{}番の席には既にプレイヤーが座っています", seat + 1)));
+        }
+        
+        self.emit_player_added(&player, Some(seat));
+        player.set_seat(seat);
+        self.insert_player(player);
+        Ok(())
+    }
+    
+    // 席の番号順になる位置にプレイヤーを加える
+    fn insert_player(&mut self, mut player: Player) {
+        player.set_dealer(false);
+        let index = self.players.iter()
+            .position(|other| other.seat() > player.seat())
+            .unwrap_or(self.players.len());
+        self.players.insert(index, player);
+        if self.players.len() > 1 && index <= self.dealer_index {
+            self.dealer_index += 1;
+        }
+        self.place_button();
+    }
+    
+    fn validate_new_player(&self, player: &Player) -> Result<(), DomainError> {
         if self.current_phase != GamePhase::NotStarted {
             return Err(DomainError::InvalidGameOperation("ゲームが既に開始されています".into()));
         }
         
-        if self.players.len() >= 10 {
+        if self.players.len() >= MAX_SEATS {
             return Err(DomainError::InvalidGameOperation("プレイヤー数の上限に達しています".into()));
         }
         
@@ -174,11 +522,116 @@ impl Game {
         if self.players.iter().any(|p| p.id() == player.id()) {
             return Err(DomainError::InvalidGameOperation("このプレイヤーIDは既に使用されています".into()));
         }
+        Ok(())
+    }
+    
+    fn emit_player_added(&mut self, player: &Player, seat: Option<usize>) {
+        self.emit(GameEvent::PlayerAdded {
+            game_id: self.id.clone(),
+            player_id: player.id().clone(),
+            player_name: player.name().to_string(),
+            initial_chips: player.chips(),
+            bot: player.bot(),
+            seat,
+            time: Utc::now(),
+        });
+    }
+    
+    /// プレイヤーを席から外す（ハンドの間にのみ可能）
+    ///
+    /// 次のハンドのボタンとブラインドの席はボタンの動かし方に従って決め直す。
+    pub fn remove_player(&mut self, player_id: &PlayerId) -> Result<Player, DomainError> {
+        if self.current_phase != GamePhase::NotStarted {
+            return Err(DomainError::InvalidGameOperation("プレイヤーはハンドの間にのみ外せます".into()));
+        }
+        let index = self.player_index_of(player_id)?;
         
-        self.players.push(player);
+        self.emit(GameEvent::PlayerRemoved {
+            game_id: self.id.clone(),
+            player_id: player_id.clone(),
+            time: Utc::now(),
+        });
+        let player = self.players.remove(index);
+        
+        if index < self.dealer_index {
+            self.dealer_index -= 1;
+        }
+        if self.dealer_index >= self.players.len() {
+            self.dealer_index = 0;
+        }
+        let dealer_index = self.dealer_index;
+        for (i, player) in self.players.iter_mut().enumerate() {
+            player.set_dealer(i == dealer_index);
+        }
+        self.place_button();
+        Ok(player)
+    }
+    
+    /// 席に着いているプレイヤーにチップを追加する（ハンドの間にのみ可能）
+    pub fn add_chips(&mut self, player_id: &PlayerId, amount: u32) -> Result<(), DomainError> {
+        if self.current_phase != GamePhase::NotStarted {
+            return Err(DomainError::InvalidGameOperation("チップはハンドの間にのみ追加できます".into()));
+        }
+        if amount == 0 {
+            return Err(DomainError::InvalidGameOperation("追加するチップは1以上である必要があります".into()));
+        }
+        let index = self.player_index_of(player_id)?;
+        
+        self.emit(GameEvent::ChipsAdded {
+            game_id: self.id.clone(),
+            player_id: player_id.clone(),
+            amount,
+            time: Utc::now(),
+        });
+        self.players[index].add_chips(amount);
         Ok(())
     }
     
+    /// 席を確保したまま離席する（次のハンドからカードを配られない）
+    pub fn sit_out(&mut self, player_id: &PlayerId) -> Result<(), DomainError> {
+        let index = self.player_index_of(player_id)?;
+        if self.players[index].is_sitting_out() {
+            return Err(DomainError::InvalidPlayerOperation("既に離席しています".into()));
+        }
+        
+        self.emit(GameEvent::PlayerSatOut {
+            game_id: self.id.clone(),
+            player_id: player_id.clone(),
+            time: Utc::now(),
+        });
+        self.players[index].set_sitting_out(true);
+        Ok(())
+    }
+    
+    /// 離席から戻る（次のハンドから参加する）
+    ///
+    /// 離席中にブラインドを払わずに済ませていた場合は、次のハンドでビッグブラインドを払う。
+    pub fn sit_in(&mut self, player_id: &PlayerId) -> Result<(), DomainError> {
+        let index = self.player_index_of(player_id)?;
+        if !self.players[index].is_sitting_out() {
+            return Err(DomainError::InvalidPlayerOperation("離席していません".into()));
+        }
+        
+        self.emit(GameEvent::PlayerSatIn {
+            game_id: self.id.clone(),
+            player_id: player_id.clone(),
+            time: Utc::now(),
+        });
+        self.players[index].set_sitting_out(false);
+        Ok(())
+    }
+    
+    fn player_index_of(&self, player_id: &PlayerId) -> Result<usize, DomainError> {
+        self.players.iter()
+            .position(|player| player.id() == player_id)
+            .ok_or_else(|| DomainError::ResourceNotFound(format!("プレイヤー {} がゲームに参加していません", player_id)))
+    }
+    
+    // ハンドでカードを配られるか（離席中とチップのないプレイヤーは配られない）
+    fn is_dealt_in(player: &Player) -> bool {
+        !player.is_sitting_out() && player.chips() > 0
+    }
+    
     pub fn players(&self) -> &[Player] {
         &self.players
     }
@@ -195,10 +648,24 @@ impl Game {
         &mut self.pot
     }
     
+    /// プレイヤーの手札のうち表向きに配られたカード（スタッド以外では空）
+    pub fn face_up_cards(&self, player_index: usize) -> Vec<Card> {
+        self.players.get(player_index)
+            .map(|player| player.hand().cards().iter().enumerate()
+                .filter(|(i, _)| self.variant.is_face_up(*i))
+                .map(|(_, card)| *card)
+                .collect())
+            .unwrap_or_default()
+    }
+    
     pub fn community_cards(&self) -> &[Card] {
         &self.community_cards
     }
     
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+    
     pub fn current_round(&self) -> Option<BettingRound> {
         self.current_round
     }
@@ -227,6 +694,266 @@ impl Game {
         self.big_blind
     }
     
+    pub fn ante(&self) -> u32 {
+        self.ante
+    }
+    
+    pub fn betting_structure(&self) -> BettingStructure {
+        self.betting_structure
+    }
+    
+    /// 2人だけで行うハンドか（進行中のハンドは配られた人数、ハンドの間は次のハンドに参加できる人数で判定する）
+    pub fn is_heads_up(&self) -> bool {
+        match self.hand_history.as_ref() {
+            Some(history) if self.current_phase != GamePhase::NotStarted => history.seats.len() == 2,
+            _ => self.players.iter().filter(|player| Self::is_dealt_in(player)).count() == 2,
+        }
+    }
+    
+    pub fn rules(&self) -> TableRules {
+        self.rules
+    }
+
+    /// 卓のルールを変更する（ハンドの間にのみ可能、ジョーカーの枚数が変わればデッキも作り直す）
+    pub fn set_rules(&mut self, rules: TableRules) -> Result<(), DomainError> {
+        if self.current_phase != GamePhase::NotStarted {
+            return Err(DomainError::InvalidGameOperation("卓のルールはハンドの間にのみ変更できます".into()));
+        }
+        if !rules.wild_cards.is_empty() && (self.variant.is_low_only() || self.variant.is_hi_lo()) {
+            return Err(DomainError::InvalidGameOperation(format!("{}ではワイルドカードを使えません", self.variant.name())));
+        }
+        if rules.hand_ranking_table.is_some() && self.variant.is_low_only() {
+            return Err(DomainError::InvalidGameOperation(format!("{}では役の表を使いません", self.variant.name())));
+        }
+
+        self.emit(GameEvent::TableRulesChanged {
+            game_id: self.id.clone(),
+            rules,
+            time: Utc::now(),
+        });
+        let previous = self.rules;
+        self.rules = rules;
+        if rules.wild_cards != previous.wild_cards {
+            self.deck = Self::new_deck(self.variant, rules.wild_cards)?;
+            self.deck_preset = false;
+        }
+        if rules.button_rule != previous.button_rule {
+            self.place_button();
+        }
+        Ok(())
+    }
+
+    // ゲームの種類とジョーカーの枚数に合わせたデッキ
+    fn new_deck(variant: GameVariant, wild_cards: WildCards) -> Result<Deck, DomainError> {
+        Deck::with_jokers(variant.deck_ranks(), wild_cards.jokers()).map_err(|e| DomainError::InvalidState(e.to_string()))
+    }
+
+    /// このゲームで使う役の決まり（ショートデッキならスリーカードとストレートの設定、ワイルドカードと役の表を反映する）
+    pub fn hand_ranking_rules(&self) -> HandRankingRules {
+        let rules = self.variant.hand_ranking_rules().with_wild_cards(self.rules.wild_cards);
+        if let Some(table) = self.rules.hand_ranking_table {
+            return rules.with_table(table);
+        }
+        match self.variant {
+            GameVariant::ShortDeckHoldem => rules.with_trips_beat_straight(
+                self.rules.short_deck_trips_rule == ShortDeckTripsRule::TripsBeatStraight
+            ),
+            _ => rules,
+        }
+    }
+
+    /// 進行中または直前のハンドのボタンとブラインドの席
+    pub fn positions(&self) -> Option<TablePositions> {
+        self.positions
+    }
+
+    /// 指定した番号の席に座っているプレイヤーのインデックス
+    pub fn player_index_at_seat(&self, seat: usize) -> Option<usize> {
+        self.players.iter().position(|player| player.seat() == seat)
+    }
+
+    /// 次のハンドのボタンとブラインドの席（カードを配られるプレイヤーが2人未満ならNone）
+    ///
+    /// 最初のハンドはディーラーのプレイヤーの席をボタンにする。2人のときはボタンがスモールブラインドを払い、
+    /// 直前にビッグブラインドを払ったプレイヤーがボタンになる。3人以上のときはボタンの動かし方に従う。
+    pub fn next_positions(&self) -> Option<TablePositions> {
+        let seats: Vec<usize> = self.players.iter()
+            .filter(|player| Self::is_dealt_in(player))
+            .map(|player| player.seat())
+            .collect();
+        if seats.len() < 2 {
+            return None;
+        }
+        // 指定した席より後ろで最初にカードを配られる席
+        let next_after = |seat: usize| seats.iter().copied().find(|&s| s > seat).unwrap_or(seats[0]);
+        let heads_up = seats.len() == 2;
+
+        let positions = match self.positions {
+            None => {
+                let mut button = self.players.get(self.dealer_index).map_or(seats[0], |player| player.seat());
+                if heads_up && !seats.contains(&button) {
+                    button = next_after(button);
+                }
+                let small_blind = if heads_up { button } else { next_after(button) };
+                TablePositions { button, small_blind, big_blind: next_after(small_blind) }
+            },
+            Some(last) if heads_up => {
+                let button = if seats.contains(&last.big_blind) { last.big_blind } else { next_after(last.button) };
+                TablePositions { button, small_blind: button, big_blind: next_after(button) }
+            },
+            Some(last) => match self.rules.button_rule {
+                ButtonRule::DeadButton => TablePositions {
+                    button: last.small_blind,
+                    small_blind: last.big_blind,
+                    big_blind: next_after(last.big_blind),
+                },
+                ButtonRule::MovingButton => {
+                    let button = next_after(last.button);
+                    let small_blind = next_after(button);
+                    TablePositions { button, small_blind, big_blind: next_after(small_blind) }
+                },
+            },
+        };
+        Some(positions)
+    }
+
+    // ハンドの間に、次のハンドのボタンの席に合わせてディーラーを置き直す
+    fn place_button(&mut self) {
+        if self.current_phase != GamePhase::NotStarted || self.positions.is_none() {
+            return;
+        }
+        if let Some(next) = self.next_positions() {
+            self.set_button(next.button);
+        }
+    }
+
+    // ボタンの席に座っているプレイヤー（空席ならその手前のプレイヤー）をディーラーにする
+    fn set_button(&mut self, button: usize) {
+        self.dealer_index = self.players.iter()
+            .rposition(|player| player.seat() <= button)
+            .unwrap_or(self.players.len().saturating_sub(1));
+        for player in self.players.iter_mut() {
+            player.set_dealer(player.seat() == button);
+        }
+    }
+
+    /// このラウンドで最後にベット額が上がった後に行動したか
+    pub fn has_acted_since_raise(&self, player_id: &PlayerId) -> bool {
+        self.acted_players.contains(player_id)
+    }
+    
+    /// このラウンドで最後にベット額が上がった後に行動したプレイヤー
+    pub fn acted_players(&self) -> &[PlayerId] {
+        &self.acted_players
+    }
+    
+    // 行動したプレイヤーを記録する（ベット額を上げた場合はそれまでの記録を消す）
+    pub(crate) fn mark_acted(&mut self, player_index: usize, raised: bool) {
+        let Some(player_id) = self.players.get(player_index).map(|player| player.id().clone()) else {
+            return;
+        };
+        if raised {
+            self.acted_players.clear();
+        }
+        if !self.acted_players.contains(&player_id) {
+            self.acted_players.push(player_id);
+        }
+    }
+    
+    pub(crate) fn set_current_player_index(&mut self, player_index: usize) {
+        self.current_player_index = player_index;
+    }
+    
+    /// 現在のラウンドで行われたベット・レイズの回数（ブラインドと、レイズに満たないオールインは含めない）
+    pub fn raises_in_round(&self) -> u32 {
+        self.round_raises().full_raises
+    }
+    
+    /// 現在のラウンドで最後に行われたベット・レイズの上乗せ額（まだなければビッグブラインド）
+    ///
+    /// 次のレイズはこの額以上を上乗せしなければならない。
+    pub fn last_raise_size(&self) -> u32 {
+        self.round_raises().raise_size
+    }
+    
+    /// プレイヤーがこのラウンドでまだレイズできるか
+    ///
+    /// 既に行動したプレイヤーは、その後に完全なレイズがあった場合にだけ再びレイズできる
+    /// （レイズに満たないオールインではベッティングは再開されない）。
+    pub fn is_betting_reopened_for(&self, player_id: &PlayerId) -> bool {
+        let (Some(history), Some(round)) = (self.hand_history.as_ref(), self.current_round) else {
+            return true;
+        };
+        let last_acted = history.actions.iter()
+            .rposition(|action| action.round == round && &action.player_id == player_id);
+        match last_acted {
+            Some(acted_at) => self.round_raises().last_full_raise_at.is_some_and(|raised_at| raised_at > acted_at),
+            None => true,
+        }
+    }
+    
+    // ハンドの記録から現在のラウンドのレイズの状況を求める
+    fn round_raises(&self) -> RoundRaises {
+        let mut raises = RoundRaises {
+            full_raises: 0,
+            raise_size: self.big_blind,
+            last_full_raise_at: None,
+        };
+        let (Some(history), Some(round)) = (self.hand_history.as_ref(), self.current_round) else {
+            return raises;
+        };
+        let mut bet_to = if round != self.variant.first_round() {
+            0
+        } else {
+            history.blinds.iter()
+                .filter(|blind| blind.kind != BlindKind::Ante)
+                .map(|blind| blind.amount)
+                .max()
+                .unwrap_or(0)
+        };
+        for (index, action) in history.actions.iter().enumerate().filter(|(_, action)| action.round == round) {
+            if action.bet_to <= bet_to {
+                continue;
+            }
+            // 上乗せ額が直前のレイズ以上なら完全なレイズ、満たなければベット額だけが上がる
+            // （スタッドでブリングインから額を上げるコンプリートは、ベットと同じく0からの額で数える）
+            let increment = if self.variant.is_stud() && bet_to < self.big_blind {
+                action.bet_to
+            } else {
+                action.bet_to - bet_to
+            };
+            if increment >= raises.raise_size {
+                raises.full_raises += 1;
+                raises.raise_size = increment;
+                raises.last_full_raise_at = Some(index);
+            }
+            bet_to = action.bet_to;
+        }
+        raises
+    }
+    
+    /// 次のハンドからのブラインドとアンティを変更する（ハンドの間にのみ可能）
+    pub fn set_blinds(&mut self, small_blind: u32, big_blind: u32, ante: u32) -> Result<(), DomainError> {
+        if self.current_phase != GamePhase::NotStarted {
+            return Err(DomainError::InvalidGameOperation("ブラインドはハンドの間にのみ変更できます".into()));
+        }
+        if small_blind > big_blind {
+            return Err(DomainError::InvalidGameOperation("スモールブラインドはビッグブラインド以下である必要があります".into()));
+        }
+        
+        self.small_blind = small_blind;
+        self.big_blind = big_blind;
+        self.ante = ante;
+        self.emit(GameEvent::BlindsChanged {
+            game_id: self.id.clone(),
+            small_blind,
+            big_blind,
+            ante,
+            time: Utc::now(),
+        });
+        Ok(())
+    }
+    
     pub fn current_bet(&self) -> u32 {
         self.current_bet
     }
@@ -235,44 +962,145 @@ impl Game {
         self.current_bet = amount;
     }
     
+    pub fn set_current_phase(&mut self, phase: GamePhase) {
+        self.current_phase = phase;
+    }
+    
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    
+    pub fn hand_number(&self) -> u32 {
+        self.hand_number
+    }
+    
+    /// 現在のハンドのシャッフルに使うシード（ゲームのシードとハンド番号から導出）
+    pub fn hand_seed(&self) -> Option<u64> {
+        self.seed.map(|seed| seed.wrapping_add(self.hand_number as u64))
+    }
+    
+    /// 発生したイベントを取り出す（取り出したイベントはゲームから消える）
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+    
+    fn emit(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+    
+    /// 進行中（または直前に終了した）ハンドの記録
+    pub fn hand_history(&self) -> Option<&HandHistory> {
+        self.hand_history.as_ref()
+    }
+    
+    /// 次のハンドのデッキの並びを指定する（シナリオテスト用）
+    ///
+    /// 指定したカードが上から順に配られ、残りのカードはその下に積まれる。
+    pub fn preset_deck(&mut self, top_cards: Vec<Card>) -> Result<(), DomainError> {
+        if self.current_phase != GamePhase::NotStarted {
+            return Err(DomainError::InvalidGameOperation("デッキの並びはゲーム開始前にのみ指定できます".into()));
+        }
+        
+        self.deck = Deck::stacked_on(top_cards, Self::new_deck(self.variant, self.rules.wild_cards)?).map_err(|e| DomainError::InvalidCard(e.to_string()))?;
+        self.deck_preset = true;
+        Ok(())
+    }
+    
     pub fn start_game(&mut self) -> Result<(), DomainError> {
-        if self.players.len() < 2 {
+        let dealt_in = self.players.iter().filter(|player| Self::is_dealt_in(player)).count();
+        if dealt_in < 2 {
             return Err(DomainError::InvalidGameOperation("ゲームを開始するには最低2人のプレイヤーが必要です".into()));
         }
+        if self.variant.is_stud() && dealt_in > MAX_STUD_PLAYERS {
+            return Err(DomainError::InvalidGameOperation(format!("スタッドは最大{}人までしか参加できません", MAX_STUD_PLAYERS)));
+        }
         
         if self.current_phase != GamePhase::NotStarted {
             return Err(DomainError::InvalidGameOperation("ゲームは既に開始されています".into()));
         }
         
-        // デッキをシャッフル
-        self.deck.shuffle();
+        // デッキをシャッフル（並びが指定済みならそのまま使う）
+        self.shuffle_deck();
+        let deck = self.deck.cards_in_draw_order();
         
-        // ディーラーを設定（ランダムに）
-        self.dealer_index = 0;
-        self.players[self.dealer_index].set_dealer(true);
+        // ボタンとブラインドの席を決める（最初のハンドはディーラーのプレイヤーの席がボタン）
+        if self.dealer_index >= self.players.len() {
+            self.dealer_index = 0;
+        }
+        let positions = self.next_positions()
+            .ok_or_else(|| DomainError::InvalidState("ボタンとブラインドの席を決められません".into()))?;
+        self.positions = Some(positions);
+        self.set_button(positions.button);
         
         // ゲームバリアントに応じて最初のラウンドを設定
-        self.current_round = Some(match self.variant {
-            GameVariant::FiveCardDraw => BettingRound::PreDraw,
-            GameVariant::TexasHoldem | GameVariant::Omaha => BettingRound::PreFlop,
-        });
+        self.current_round = Some(self.variant.first_round());
         
         self.current_phase = GamePhase::Dealing;
         self.current_player_index = self.next_active_player_index(self.dealer_index);
+        self.acted_players.clear();
+        
+        // ハンドの記録を開始（開始時のスタックを残す）
+        let seats = self.players.iter()
+            .filter(|player| Self::is_dealt_in(player))
+            .map(|player| SeatRecord {
+                seat: player.seat(),
+                player_id: player.id().clone(),
+                name: player.name().to_string(),
+                starting_stack: player.chips(),
+                hole_cards: Vec::new(),
+            })
+            .collect();
+        let mut history = HandHistory::new(
+            self.id.clone(),
+            self.hand_number,
+            self.variant,
+            self.small_blind,
+            self.big_blind,
+            positions.button,
+            seats,
+        );
+        history.betting_structure = self.betting_structure;
+        self.hand_history = Some(history);
+        
+        self.emit(GameEvent::GameStarted {
+            game_id: self.id.clone(),
+            player_count: self.players.len(),
+            hand_number: self.hand_number,
+            dealer_index: self.dealer_index,
+            deck,
+            time: Utc::now(),
+        });
         
         Ok(())
     }
     
+    // シードがあれば決定的に、なければランダムにシャッフル
+    fn shuffle_deck(&mut self) {
+        if self.deck_preset {
+            self.deck_preset = false;
+            return;
+        }
+        
+        match self.hand_seed() {
+            Some(seed) => self.deck.shuffle_with_seed(seed),
+            None => self.deck.shuffle(),
+        }
+    }
+    
     pub fn deal_cards(&mut self) -> Result<(), DomainError> {
         if self.current_phase != GamePhase::Dealing {
             return Err(DomainError::InvalidGameOperation("カードを配るのはDealingフェーズでのみ可能です".into()));
         }
         
-        let hand_size = self.variant.hand_size();
+        let hand_size = self.variant.starting_hand_size();
         
-        // 各プレイヤーに手札を配る
+        // 各プレイヤーに手札を配る（配られないプレイヤーはフォールドとして扱う）
         for player in &mut self.players {
             player.reset_for_new_round();
+            if !Self::is_dealt_in(player) {
+                player.fold();
+                continue;
+            }
             let cards = self.deck.draw_multiple(hand_size);
             
             for card in cards {
@@ -281,12 +1109,68 @@ impl Game {
         }
         
         // バリアントに応じてコミュニティカードを配る（ホールデムとオマハの場合）
-        if matches!(self.variant, GameVariant::TexasHoldem | GameVariant::Omaha) {
+        if self.variant.has_board() {
             self.community_cards.clear();
         }
         
+        if let Some(history) = self.hand_history.as_mut() {
+            for seat in history.seats.iter_mut() {
+                if let Some(player) = self.players.iter().find(|player| player.id() == &seat.player_id) {
+                    seat.hole_cards = player.hand().cards().to_vec();
+                }
+            }
+        }
+        let dealt: Vec<GameEvent> = self.players.iter()
+            .filter(|player| !player.is_folded())
+            .map(|player| GameEvent::CardsDealt {
+                game_id: self.id.clone(),
+                player_id: player.id().clone(),
+                cards: player.hand().cards().to_vec(),
+                time: Utc::now(),
+            })
+            .collect();
+        self.events.extend(dealt);
+        
         // フェーズを更新
         self.current_phase = GamePhase::Betting;
+        self.current_player_index = self.next_active_player_index(self.dealer_index);
+        
+        Ok(())
+    }
+    
+    /// チップが残っている全員からアンティを集める（ブラインドより先に投入する）
+    ///
+    /// アンティはラウンドのベット額には含めず、ポットへの拠出としてだけ扱う。
+    pub fn post_antes(&mut self) -> Result<(), DomainError> {
+        if self.current_phase != GamePhase::Betting || self.current_round != Some(self.variant.first_round()) {
+            return Err(DomainError::InvalidGameOperation("アンティは最初のベッティングラウンドでのみ投入可能です".into()));
+        }
+        if self.ante == 0 {
+            return Err(DomainError::InvalidGameOperation("アンティが設定されていません".into()));
+        }
+        if self.players.iter().any(|player| player.total_bet() > 0) {
+            return Err(DomainError::InvalidGameOperation("アンティはベットやブラインドより先に投入する必要があります".into()));
+        }
+        
+        for index in 0..self.players.len() {
+            if self.players[index].is_folded() || self.players[index].chips() == 0 {
+                continue;
+            }
+            let amount = self.players[index].post_ante(self.ante)?;
+            self.pot.add(amount);
+            
+            let player_id = self.players[index].id().clone();
+            if let Some(history) = self.hand_history.as_mut() {
+                history.blinds.push(BlindRecord { player_id: player_id.clone(), kind: BlindKind::Ante, amount });
+            }
+            self.emit(GameEvent::BlindPosted {
+                game_id: self.id.clone(),
+                player_id,
+                kind: BlindKind::Ante,
+                amount,
+                time: Utc::now(),
+            });
+        }
         
         Ok(())
     }
@@ -300,29 +1184,123 @@ impl Game {
             return Err(DomainError::InvalidGameOperation("ブラインドを投入するには最低2人のプレイヤーが必要です".into()));
         }
         
-        // スモールブラインドのプレイヤーを特定
-        let small_blind_index = self.next_active_player_index(self.dealer_index);
-        
-        // スモールブラインドを投入
-        let small_blind_amount = self.players[small_blind_index].place_bet(self.small_blind)?;
-        self.pot.add(small_blind_amount);
+        // ボタンとブラインドの席はハンドの開始時に決まっている（空席などならスモールブラインドは払われない）
+        let positions = self.positions.or_else(|| self.next_positions())
+            .ok_or_else(|| DomainError::InvalidState("ブラインドの席が決まっていません".into()))?;
+        let in_hand = |seat: usize| self.player_index_at_seat(seat).filter(|&index| !self.players[index].is_folded());
+        let small_blind_index = in_hand(positions.small_blind);
+        let big_blind_index = in_hand(positions.big_blind)
+            .ok_or_else(|| DomainError::InvalidState("ビッグブラインドの席にプレイヤーがいません".into()))?;
         
-        // ビッグブラインドのプレイヤーを特定
-        let big_blind_index = self.next_active_player_index(small_blind_index);
-        
-        // ビッグブラインドを投入
+        let mut blinds = Vec::new();
+        if let Some(index) = small_blind_index {
+            let amount = self.players[index].place_bet(self.small_blind)?;
+            self.pot.add(amount);
+            blinds.push((index, BlindKind::Small, amount));
+        }
         let big_blind_amount = self.players[big_blind_index].place_bet(self.big_blind)?;
         self.pot.add(big_blind_amount);
+        blinds.push((big_blind_index, BlindKind::Big, big_blind_amount));
+        blinds.extend(self.settle_missed_blinds(small_blind_index, big_blind_index)?);
+        for (index, kind, amount) in blinds {
+            let player_id = self.players[index].id().clone();
+            if let Some(history) = self.hand_history.as_mut() {
+                history.blinds.push(BlindRecord { player_id: player_id.clone(), kind, amount });
+            }
+            self.emit(GameEvent::BlindPosted {
+                game_id: self.id.clone(),
+                player_id,
+                kind,
+                amount,
+                time: Utc::now(),
+            });
+        }
         
         // 現在のベット額をビッグブラインドに設定
         self.current_bet = self.big_blind;
         
-        // アクションを始めるプレイヤーを設定（ビッグブラインドの次のプレイヤー）
+        // アクションを始めるプレイヤーを設定（ビッグブラインドの次のプレイヤー、2人のときはボタン）
         self.current_player_index = self.next_active_player_index(big_blind_index);
         
         Ok(())
     }
     
+    /// スタッドの3rdストリートで、表向きのカードが最も弱いプレイヤーにブリングイン（スモールブラインドの額）を払わせる
+    ///
+    /// 最も弱いカードはセブンカードスタッドでは最も低いカード（Aは高い）、ラズでは最も高いカード（Aは低い）。
+    /// 同じランクならスート（♣ < ♦ < ♥ < ♠）で比べる。アクションはその次のプレイヤーから始まる。
+    pub fn post_bring_in(&mut self) -> Result<(), DomainError> {
+        if self.current_phase != GamePhase::Betting || self.current_round != Some(BettingRound::ThirdStreet) {
+            return Err(DomainError::InvalidGameOperation("ブリングインはスタッドの3rdストリートでのみ投入可能です".into()));
+        }
+        if self.players.iter().any(|player| player.current_bet() > 0) {
+            return Err(DomainError::InvalidGameOperation("ブリングインは既に投入されています".into()));
+        }
+        
+        let suit_order = |card: &Card| Suit::all().iter().position(|suit| *suit == card.suit());
+        let up_cards = (0..self.players.len())
+            .filter(|&index| !self.players[index].is_folded())
+            .filter_map(|index| self.face_up_cards(index).first().map(|card| (index, *card)));
+        let bring_in_index = match self.variant {
+            GameVariant::Razz => up_cards
+                .max_by_key(|(_, card)| (card.rank(), suit_order(card)))
+                .map(|(index, _)| index),
+            _ => up_cards
+                .min_by_key(|(_, card)| (if card.is_ace() { 14 } else { card.rank() }, suit_order(card)))
+                .map(|(index, _)| index),
+        }.ok_or_else(|| DomainError::InvalidState("表向きのカードが配られていません".into()))?;
+        
+        let amount = self.players[bring_in_index].place_bet(self.small_blind)?;
+        self.pot.add(amount);
+        let player_id = self.players[bring_in_index].id().clone();
+        if let Some(history) = self.hand_history.as_mut() {
+            history.blinds.push(BlindRecord { player_id: player_id.clone(), kind: BlindKind::BringIn, amount });
+        }
+        self.emit(GameEvent::BlindPosted {
+            game_id: self.id.clone(),
+            player_id,
+            kind: BlindKind::BringIn,
+            amount,
+            time: Utc::now(),
+        });
+        
+        // ブリングインは強制ベットなので、ほかのプレイヤーが行動すればラウンドを終えられる
+        self.current_bet = amount;
+        self.mark_acted(bring_in_index, false);
+        self.current_player_index = self.next_active_player_index(bring_in_index);
+        
+        Ok(())
+    }
+    
+    // 離席中にブラインドの順番が回ってきたプレイヤーに印を付け、
+    // 離席から戻ったプレイヤーにはビッグブラインドを払わせる（ブラインドの位置にいれば払ったものとする）
+    fn settle_missed_blinds(&mut self, small_blind_index: Option<usize>, big_blind_index: usize) -> Result<Vec<(usize, BlindKind, u32)>, DomainError> {
+        let count = self.players.len();
+        let mut index = (self.dealer_index + 1) % count;
+        while index != (big_blind_index + 1) % count {
+            if self.players[index].is_sitting_out() {
+                self.players[index].set_missed_blind(true);
+            }
+            index = (index + 1) % count;
+        }
+        
+        let mut posted = Vec::new();
+        for offset in 1..=count {
+            let index = (big_blind_index + offset) % count;
+            let player = &mut self.players[index];
+            if player.is_folded() || !player.has_missed_blind() {
+                continue;
+            }
+            player.set_missed_blind(false);
+            if Some(index) != small_blind_index && index != big_blind_index {
+                let amount = player.place_bet(self.big_blind)?;
+                self.pot.add(amount);
+                posted.push((index, BlindKind::Big, amount));
+            }
+        }
+        Ok(posted)
+    }
+    
     // 次の有効なプレイヤーのインデックスを取得
     fn next_active_player_index(&self, from_index: usize) -> usize {
         let mut index = (from_index + 1) % self.players.len();
@@ -354,8 +1332,15 @@ impl Game {
         let current_round = self.current_round.ok_or_else(|| 
             DomainError::InvalidState("現在のラウンドが設定されていません".into()))?;
         
-        // プレイヤーのベット額をリセット
+        // プレイヤーのラウンド内のベット額と行動の記録をリセット
         self.reset_all_player_bets();
+        self.acted_players.clear();
+        
+        self.emit(GameEvent::BettingRoundEnded {
+            game_id: self.id.clone(),
+            round: current_round,
+            time: Utc::now(),
+        });
         
         // 次のラウンドへの移行を処理
         self.transition_to_next_round(current_round)?;
@@ -363,6 +1348,23 @@ impl Game {
         Ok(())
     }
     
+    /// 1人を残して全員がフォールドしたら、残りのラウンドを行わずにショーダウンへ進む
+    pub(crate) fn end_uncontested_hand(&mut self) -> Result<(), DomainError> {
+        let current_round = self.current_round.ok_or_else(|| 
+            DomainError::InvalidState("現在のラウンドが設定されていません".into()))?;
+        
+        self.reset_all_player_bets();
+        self.acted_players.clear();
+        self.emit(GameEvent::BettingRoundEnded {
+            game_id: self.id.clone(),
+            round: current_round,
+            time: Utc::now(),
+        });
+        self.current_phase = GamePhase::Showdown;
+        
+        Ok(())
+    }
+    
     // プレイヤーのベット額をリセット
     fn reset_all_player_bets(&mut self) {
         for player in &mut self.players {
@@ -376,9 +1378,18 @@ impl Game {
             Some(next_round) => {
                 self.current_round = Some(next_round);
                 self.current_bet = 0;
+                self.emit(GameEvent::BettingRoundStarted {
+                    game_id: self.id.clone(),
+                    round: next_round,
+                    time: Utc::now(),
+                });
                 
                 self.handle_community_cards(next_round)?;
                 self.setup_next_round_player();
+                // 交換できるプレイヤーがいなければそのままベッティングへ
+                if self.current_phase == GamePhase::Drawing {
+                    self.check_drawing_phase_completion();
+                }
             },
             None => {
                 // 最後のラウンドが終了した場合、ショーダウンに移行
@@ -391,20 +1402,37 @@ impl Game {
     
     // コミュニティカードの処理
     fn handle_community_cards(&mut self, round: BettingRound) -> Result<(), DomainError> {
+        let dealt_before = self.community_cards.len();
+        
         match (self.variant, round) {
-            (GameVariant::TexasHoldem | GameVariant::Omaha, BettingRound::Flop) => {
+            (variant, BettingRound::Flop) if variant.has_board() => {
                 self.deal_flop()?;
             },
-            (GameVariant::TexasHoldem | GameVariant::Omaha, BettingRound::Turn | BettingRound::River) => {
+            (variant, BettingRound::Turn | BettingRound::River) if variant.has_board() => {
                 self.deal_single_community_card()?;
             },
-            (GameVariant::FiveCardDraw, BettingRound::PostDraw) => {
-                // ファイブカードドローの場合、カード交換フェーズに移行
+            (GameVariant::SevenCardStud | GameVariant::Razz, _) => {
+                self.deal_street()?;
+            },
+            (variant, round) if variant.is_draw() && round.starts_with_draw() => {
+                // ドローの場合、カード交換フェーズに移行
                 self.current_phase = GamePhase::Drawing;
             },
             _ => {},
         }
         
+        if self.community_cards.len() > dealt_before {
+            let cards = self.community_cards[dealt_before..].to_vec();
+            if let Some(history) = self.hand_history.as_mut() {
+                history.board.push(BoardRecord { round, cards: cards.clone() });
+            }
+            self.emit(GameEvent::CommunityCardsDealt {
+                game_id: self.id.clone(),
+                cards,
+                time: Utc::now(),
+            });
+        }
+        
         Ok(())
     }
     
@@ -432,12 +1460,66 @@ impl Game {
         }
     }
     
+    // スタッドで残っているプレイヤーに1枚ずつ配る（7thストリートだけ伏せて配る）
+    // カードが足りない場合は全員が使う共通のカードを1枚開く
+    fn deal_street(&mut self) -> Result<(), DomainError> {
+        let receivers: Vec<usize> = (0..self.players.len())
+            .filter(|&index| !self.players[index].is_folded())
+            .collect();
+        if self.deck.remaining() < receivers.len() {
+            return self.deal_single_community_card();
+        }
+        
+        for index in receivers {
+            let card = self.deck.draw().ok_or(DomainError::DeckError(DeckError::EmptyDeck))?;
+            self.players[index].add_card_to_hand(card)?;
+            let player_id = self.players[index].id().clone();
+            if let Some(seat) = self.hand_history.as_mut()
+                .and_then(|history| history.seats.iter_mut().find(|seat| seat.player_id == player_id))
+            {
+                seat.hole_cards.push(card);
+            }
+            self.emit(GameEvent::CardsDealt {
+                game_id: self.id.clone(),
+                player_id,
+                cards: vec![card],
+                time: Utc::now(),
+            });
+        }
+        Ok(())
+    }
+    
     // 次のラウンドのプレイヤーを設定
     fn setup_next_round_player(&mut self) {
+        if self.variant.is_stud() {
+            self.current_player_index = self.best_showing_player_index();
+            return;
+        }
         // 次のラウンドのアクションを始めるプレイヤーを設定（ディーラーの次のプレイヤー）
         self.current_player_index = self.next_active_player_index(self.dealer_index);
     }
     
+    // スタッドの4thストリート以降で最初に行動するプレイヤー（表向きのカードが最も強いプレイヤー）
+    // 同じ強さならディーラーの次から時計回りで先のプレイヤー。そのプレイヤーがオールインなら次のプレイヤーから始める
+    fn best_showing_player_index(&self) -> usize {
+        let count = self.players.len();
+        let contenders = (1..=count)
+            .map(|offset| (self.dealer_index + offset) % count)
+            .filter(|&index| !self.players[index].is_folded());
+        let best = match self.variant {
+            GameVariant::Razz => contenders.min_by_key(|&index| HandEvaluationService::evaluate_low(&self.face_up_cards(index))),
+            _ => contenders
+                .map(|index| (index, HandEvaluationService::evaluate_showing(&self.face_up_cards(index))))
+                .min_by(|(_, a), (_, b)| HandRankingRules::SHOWING.compare(b, a))
+                .map(|(index, _)| index),
+        };
+        match best {
+            Some(index) if self.players[index].is_all_in() => self.next_active_player_index(index),
+            Some(index) => index,
+            None => self.next_active_player_index(self.dealer_index),
+        }
+    }
+    
     // ファイブカードドローのカード交換処理
     pub fn exchange_cards(&mut self, player_index: usize, card_indices: &[usize]) -> Result<(), DomainError> {
         self.validate_card_exchange_phase()?;
@@ -477,6 +1559,8 @@ impl Game {
     // 実際のカード交換処理
     fn perform_card_exchange(&mut self, player_index: usize, card_indices: &[usize]) -> Result<(), DomainError> {
         let player = &mut self.players[player_index];
+        let mut discarded = Vec::with_capacity(card_indices.len());
+        let mut drawn = Vec::with_capacity(card_indices.len());
         
         // 指定されたカードを交換
         for &index in card_indices {
@@ -486,29 +1570,107 @@ impl Game {
             
             if let Some(new_card) = self.deck.draw() {
                 if let Ok(old_card) = player.hand_mut().replace_card(index, new_card) {
-                    // 古いカードをデッキに戻す
-                    self.deck.add_card(old_card);
+                    // 古いカードはデッキの一番下に戻す（後の交換で他のプレイヤーに配られないように）
+                    self.deck.add_card_to_bottom(old_card);
+                    discarded.push(old_card);
+                    drawn.push(new_card);
                 }
             } else {
                 return Err(DomainError::DeckError(DeckError::EmptyDeck));
             }
         }
         
+        let player_id = player.id().clone();
+        self.emit(GameEvent::CardsExchanged {
+            game_id: self.id.clone(),
+            player_id: player_id.clone(),
+            count: drawn.len(),
+            card_indices: card_indices.to_vec(),
+            discarded: discarded.clone(),
+            drawn: drawn.clone(),
+            time: Utc::now(),
+        });
+        if let (Some(history), Some(round)) = (self.hand_history.as_mut(), self.current_round) {
+            history.draws.push(DrawRecord { round, player_id, discarded, drawn });
+        }
+        
         Ok(())
     }
     
     // ドローフェーズの完了チェック
     fn check_drawing_phase_completion(&mut self) {
-        if self.current_player_index == 0 {
+        // 行動できるプレイヤー全員がこのラウンドの交換を終えたか（記録がなければ手番が最初の席に戻ったか）
+        let completed = match (self.hand_history.as_ref(), self.current_round) {
+            (Some(history), Some(round)) => self.players.iter()
+                .filter(|player| !player.is_folded() && !player.is_all_in())
+                .all(|player| history.draws.iter().any(|draw| draw.round == round && &draw.player_id == player.id())),
+            _ => self.current_player_index == 0,
+        };
+        if completed {
             // すべてのプレイヤーがカード交換を完了したら、次のベッティングラウンドへ
             self.current_phase = GamePhase::Betting;
+            self.setup_next_round_player();
+        }
+    }
+    
+    // ベッティングアクションを記録する（requested は指定されたベット額）
+    pub(crate) fn record_action(
+        &mut self,
+        player_index: usize,
+        action: BetAction,
+        requested: Option<u32>,
+        amount: u32,
+        bet_to: u32,
+    ) {
+        let Some(player_id) = self.players.get(player_index).map(|player| player.id().clone()) else {
+            return;
+        };
+        if let (Some(history), Some(round)) = (self.hand_history.as_mut(), self.current_round) {
+            history.actions.push(ActionRecord {
+                round,
+                player_id: player_id.clone(),
+                action,
+                amount,
+                bet_to,
+            });
+        }
+        self.emit(GameEvent::PlayerAction {
+            game_id: self.id.clone(),
+            player_id,
+            action,
+            amount: requested,
+            time: Utc::now(),
+        });
+    }
+    
+    // ショーダウンの手とポットの配当を記録する
+    pub(crate) fn record_showdown(&mut self, showdown: Vec<ShowdownRecord>, payouts: Vec<PayoutRecord>) {
+        let mut winner_ids = Vec::new();
+        for (player_id, _) in payouts.iter().flat_map(|payout| payout.winners.iter()) {
+            if !winner_ids.contains(player_id) {
+                winner_ids.push(player_id.clone());
+            }
+        }
+        self.emit(GameEvent::GameEnded {
+            game_id: self.id.clone(),
+            winner_ids,
+            pot_amount: payouts.iter().map(|payout| payout.amount).sum(),
+            time: Utc::now(),
+        });
+        
+        if let Some(history) = self.hand_history.as_mut() {
+            history.showdown = showdown;
+            history.payouts = payouts;
         }
     }
     
     // ゲームをリセットして新しいハンドを開始する準備
     pub fn reset_for_new_hand(&mut self) -> Result<(), DomainError> {
         // デッキをリセット
-        self.deck = Deck::new().map_err(|e| DomainError::InvalidState(e.to_string()))?;
+        self.deck = Self::new_deck(self.variant, self.rules.wild_cards)?;
+        self.deck_preset = false;
+        self.hand_number += 1;
+        self.hand_history = None;
         
         // ポットをクリア
         self.pot.clear();
@@ -521,51 +1683,151 @@ impl Game {
             player.reset_for_new_game();
         }
         
-        // ディーラーボタンを次のプレイヤーに移動
-        self.players[self.dealer_index].set_dealer(false);
-        self.dealer_index = (self.dealer_index + 1) % self.players.len();
-        self.players[self.dealer_index].set_dealer(true);
-        
         // ゲームの状態をリセット
         self.current_round = None;
         self.current_phase = GamePhase::NotStarted;
         self.current_bet = 0;
+        self.acted_players.clear();
         self.current_player_index = 0;
         
+        // ディーラーボタンを次のハンドの席に移動（席の記録がない古いデータでは次のプレイヤーへ）
+        if self.positions.is_none() && !self.players.is_empty() {
+            self.dealer_index = (self.dealer_index + 1) % self.players.len();
+        }
+        self.place_button();
+        
+        self.emit(GameEvent::HandReset {
+            game_id: self.id.clone(),
+            hand_number: self.hand_number,
+            time: Utc::now(),
+        });
+        
         Ok(())
     }
     
     // デシリアライズのためのファクトリメソッド
     pub fn from_serialized(data: GameSerializedData) -> Result<Self, DomainError> {
-        let mut game = Self::new(data.variant, data.small_blind, data.big_blind)?;
+        let mut game = Self::new_with_structure(data.variant, data.small_blind, data.big_blind, data.betting_structure, data.seed)?;
         
         // IDの設定
         game.id = data.id;
+        game.hand_number = data.hand_number;
         
-        // プレイヤーの追加
+        // プレイヤーの追加（席の番号が並び順になっていなければ並び順で振り直す）
         game.players = data.players;
+        if game.players.windows(2).any(|pair| pair[0].seat() >= pair[1].seat()) {
+            for (seat, player) in game.players.iter_mut().enumerate() {
+                player.set_seat(seat);
+            }
+        }
         
         // コミュニティカードのセット
         game.community_cards = data.community_cards;
         
+        // 配られたカードとデッキの整合性を検証し、デッキを復元（ジョーカーの枚数はワイルドカードの設定で決まる）
+        game.rules = data.rules;
+        game.deck = game.restore_deck(data.deck)?;
+        
         // ポットの設定
         game.pot = Pot::new();
-        game.pot_mut().add(data.pot_total);
+        game.pot_mut().add(data.main_pot);
+        for (amount, player_ids) in data.side_pots {
+            game.pot_mut().create_side_pot(amount, player_ids);
+        }
         
         // 各種ステータスの設定
         game.current_phase = data.current_phase;
         game.current_round = data.current_round;
         game.current_player_index = data.current_player_index;
         game.dealer_index = data.dealer_index;
+        game.positions = data.positions;
+        game.ante = data.ante;
         game.current_bet = data.current_bet;
+        game.acted_players = data.acted_players;
+        game.hand_history = data.hand_history;
+        // スナップショットからの復元ではイベントを発生させない
+        game.events.clear();
         
         // ディーラーフラグを設定
-        if game.players.len() > data.dealer_index {
-            for (i, player) in game.players.iter_mut().enumerate() {
-                player.set_dealer(i == data.dealer_index);
-            }
+        match game.positions {
+            Some(positions) if game.current_phase != GamePhase::NotStarted => game.set_button(positions.button),
+            Some(_) => game.place_button(),
+            None if game.players.len() > data.dealer_index => {
+                for (i, player) in game.players.iter_mut().enumerate() {
+                    player.set_dealer(i == data.dealer_index);
+                }
+            },
+            None => {},
         }
         
         Ok(game)
     }
+    
+    // 手札・コミュニティカード・デッキに重複や存在しないカードがないか検証してデッキを復元
+    fn restore_deck(&self, deck: Option<Vec<Card>>) -> Result<Deck, DomainError> {
+        let hand_size = self.variant.hand_size();
+        for player in &self.players {
+            if player.hand().size() > hand_size {
+                return Err(DomainError::InvalidCard(format!(
+                    "{}の手札が{}枚あります（{}では最大{}枚）",
+                    player.name(), player.hand().size(), self.variant.name(), hand_size
+                )));
+            }
+        }
+        
+        let max_community_cards = self.variant.max_community_cards();
+        if self.community_cards.len() > max_community_cards {
+            return Err(DomainError::InvalidCard(format!(
+                "コミュニティカードが{}枚あります（{}では最大{}枚）",
+                self.community_cards.len(), self.variant.name(), max_community_cards
+            )));
+        }
+        
+        let full_deck = Self::new_deck(self.variant, self.rules.wild_cards)?.cards_in_draw_order();
+        let mut dealt: Vec<Card> = Vec::new();
+        for card in self.players.iter()
+            .flat_map(|p| p.hand().cards().iter())
+            .chain(self.community_cards.iter())
+        {
+            if !full_deck.contains(card) {
+                return Err(DomainError::InvalidCard(format!("存在しないカードです: {}", card)));
+            }
+            if dealt.contains(card) {
+                return Err(DomainError::InvalidCard(format!("カードが重複しています: {}", card)));
+            }
+            dealt.push(*card);
+        }
+        
+        match deck {
+            Some(cards) => {
+                for card in &cards {
+                    if !full_deck.contains(card) {
+                        return Err(DomainError::InvalidCard(format!("デッキに存在しないカードがあります: {}", card)));
+                    }
+                    if dealt.contains(card) {
+                        return Err(DomainError::InvalidCard(format!("配られたカードがデッキにも残っています: {}", card)));
+                    }
+                }
+                if dealt.len() + cards.len() != full_deck.len() {
+                    return Err(DomainError::InvalidCard(format!(
+                        "カードの枚数が一致しません（配られたカード{}枚 + デッキ{}枚）",
+                        dealt.len(), cards.len()
+                    )));
+                }
+                Deck::from_cards(cards).map_err(|e| DomainError::InvalidCard(e.to_string()))
+            },
+            None => {
+                // デッキが保存されていない場合は、まだ配られていないカードから再構築する
+                let remaining: Vec<Card> = full_deck.into_iter()
+                    .filter(|card| !dealt.contains(card))
+                    .collect();
+                let mut deck = Deck::from_cards(remaining).map_err(|e| DomainError::InvalidCard(e.to_string()))?;
+                match self.hand_seed() {
+                    Some(seed) => deck.shuffle_with_seed(seed),
+                    None => deck.shuffle(),
+                }
+                Ok(deck)
+            },
+        }
+    }
 } 
\ No newline at end of file
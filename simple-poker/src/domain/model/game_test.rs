@@ -1,7 +1,11 @@
 #[cfg(test)]
 mod tests {
-    use crate::domain::model::game::{Game, GameVariant, GamePhase};
+    use crate::domain::model::game::{ButtonRule, Game, GameVariant, GamePhase, OddChipRule, TablePositions, TableRules, MAX_SEATS};
     use crate::domain::model::player::Player;
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::hand_history::BlindKind;
+    use crate::domain::service::game_projector::GameProjector;
+    use crate::domain::model::event::GameEvent;
 
     #[test]
     fn ゲーム作成_有効なパラメータ() {
@@ -145,4 +149,343 @@ mod tests {
         assert_eq!(game.pot().total(), 0, "リセット後ポットが0ではありません");
         assert_eq!(game.current_bet(), 0, "リセット後ベット額が0ではありません");
     }
-} 
\ No newline at end of file
+    
+    // 2人のプレイヤーでシード付きのゲームを作成して配る
+    fn deal_seeded_game(seed: u64) -> Game {
+        let mut game = Game::new_with_seed(GameVariant::TexasHoldem, 5, 10, Some(seed)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game
+    }
+    
+    #[test]
+    fn シード指定_同じシードなら同じ手札() {
+        let game1 = deal_seeded_game(2024);
+        let game2 = deal_seeded_game(2024);
+        
+        for i in 0..2 {
+            assert_eq!(
+                game1.players()[i].hand().cards(),
+                game2.players()[i].hand().cards(),
+                "同じシードで{}人目の手札が一致しません", i + 1
+            );
+        }
+    }
+    
+    #[test]
+    fn シード指定_ハンドごとに異なる並びで再現可能() {
+        let mut game1 = deal_seeded_game(7);
+        let mut game2 = deal_seeded_game(7);
+        let first_hand = game1.players()[0].hand().cards().to_vec();
+        
+        // 次のハンドへ
+        for game in [&mut game1, &mut game2] {
+            game.reset_for_new_hand().unwrap();
+            game.start_game().unwrap();
+            game.deal_cards().unwrap();
+        }
+        
+        assert_eq!(game1.hand_number(), 1, "ハンド番号が進んでいません");
+        assert_ne!(game1.players()[0].hand().cards(), first_hand.as_slice(), "次のハンドでも同じ手札が配られています");
+        assert_eq!(game1.players()[0].hand().cards(), game2.players()[0].hand().cards(), "2ハンド目の手札が再現されません");
+    }
+    
+    #[test]
+    fn デッキの並びを指定して配る() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        
+        let top = vec![
+            Card::new(Suit::Spade, 1).unwrap(),
+            Card::new(Suit::Heart, 1).unwrap(),
+            Card::new(Suit::Spade, 13).unwrap(),
+            Card::new(Suit::Heart, 13).unwrap(),
+        ];
+        game.preset_deck(top.clone()).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        
+        // 指定した順番で各プレイヤーに配られる（シャッフルされない）
+        assert_eq!(game.players()[0].hand().cards(), &top[0..2], "1人目の手札が指定した並びではありません");
+        assert_eq!(game.players()[1].hand().cards(), &top[2..4], "2人目の手札が指定した並びではありません");
+        
+        // 開始後はデッキを指定できない
+        assert!(game.preset_deck(top).is_err(), "ゲーム開始後にデッキの並びを指定できてしまいます");
+    }
+
+    #[test]
+    fn ハンドの記録_座席と交換したカード() {
+        let mut game = Game::new(GameVariant::FiveCardDraw, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 800)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        
+        let dealt = game.players()[0].hand().cards().to_vec();
+        game.end_betting_round().unwrap();
+        assert_eq!(game.current_phase(), GamePhase::Drawing, "ドローフェーズに移行していません");
+        game.exchange_cards(0, &[0, 1]).unwrap();
+        
+        let history = game.hand_history().expect("ハンドの記録が開始されていません");
+        assert_eq!(history.seats[1].starting_stack, 800, "開始時のスタックが一致しません");
+        assert_eq!(history.seats[0].hole_cards, dealt, "配られた手札が記録されていません");
+        assert_eq!(history.draws.len(), 1, "カード交換が記録されていません");
+        assert_eq!(history.draws[0].discarded, dealt[..2].to_vec(), "捨てたカードが一致しません");
+        assert_eq!(history.draws[0].drawn, game.players()[0].hand().cards()[..2].to_vec(), "引いたカードが一致しません");
+        
+        // 次のハンドでは記録がリセットされる
+        game.reset_for_new_hand().unwrap();
+        assert!(game.hand_history().is_none(), "前のハンドの記録が残っています");
+    }
+    
+    #[test]
+    fn アンティはラウンドのベット額に含めずポットに入る() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー3".to_string(), 3)).unwrap();
+        game.set_blinds(5, 10, 5).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        
+        // ブラインドより先に投入する
+        game.post_antes().unwrap();
+        assert_eq!(game.pot().total(), 13, "アンティがポットに入っていません");
+        assert_eq!(game.players()[0].current_bet(), 0, "アンティがベット額に含まれています");
+        assert_eq!(game.players()[0].total_bet(), 5, "アンティが拠出額に含まれていません");
+        assert!(game.players()[2].is_all_in(), "アンティを払いきれないプレイヤーがオールインになっていません");
+        assert!(game.post_antes().is_err(), "アンティを2回投入できてしまいます");
+        
+        game.post_blinds().unwrap();
+        assert_eq!(game.current_bet(), 10, "ベット額がビッグブラインドではありません");
+        let history = game.hand_history().unwrap();
+        for player in game.players() {
+            assert_eq!(history.total_contributed(player.id()), player.total_bet(), "記録された拠出額が一致しません");
+        }
+    }
+    
+    #[test]
+    fn 卓のルールはまとめて変更しイベントから復元できる() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        let top = vec![Card::new(Suit::Spade, 1).unwrap()];
+        game.preset_deck(top.clone()).unwrap();
+
+        let rules = TableRules { button_rule: ButtonRule::MovingButton, odd_chip_rule: OddChipRule::HighestCard, ..game.rules() };
+        game.set_rules(rules).unwrap();
+        assert_eq!(game.rules(), rules, "卓のルールが変更されていません");
+
+        // ジョーカーの枚数が変わらなければ指定したデッキの並びは残る
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        assert_eq!(game.players()[0].hand().cards()[0], top[0], "指定したデッキの並びが消えています");
+        assert!(game.set_rules(TableRules::default()).is_err(), "ハンドの途中で卓のルールを変更できてしまいます");
+
+        let events = game.take_events();
+        let changes = events.iter().filter(|event| matches!(event, GameEvent::TableRulesChanged { .. })).count();
+        assert_eq!(changes, 1, "ルールの変更が1つのイベントになっていません");
+        let restored = GameProjector::project(&events).expect("復元に失敗");
+        assert_eq!(restored.rules(), rules, "復元した卓のルールが一致しません");
+    }
+
+    #[test]
+    fn ブラインドとプレイヤーはハンドの間にのみ変更できる() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        for i in 1..=3 {
+            game.add_player(Player::new(format!("プレイヤー{}", i), 1000)).unwrap();
+        }
+        assert!(game.set_blinds(20, 10, 0).is_err(), "逆転したブラインドを設定できてしまいます");
+        game.start_game().unwrap();
+        assert!(game.set_blinds(10, 20, 0).is_err(), "ハンドの途中でブラインドを変更できてしまいます");
+        let id = game.players()[1].id().clone();
+        assert!(game.remove_player(&id).is_err(), "ハンドの途中でプレイヤーを外せてしまいます");
+        
+        // ボタンが回った先のプレイヤーを外すと、ボタンは次の席に移る
+        game.reset_for_new_hand().unwrap();
+        assert_eq!(game.dealer_index(), 1, "ボタンが回っていません");
+        let removed = game.remove_player(&id).unwrap();
+        assert_eq!(removed.name(), "プレイヤー2", "外したプレイヤーが一致しません");
+        assert_eq!(game.players()[game.dealer_index()].name(), "プレイヤー3", "ボタンが次の席に移っていません");
+        assert!(game.players()[game.dealer_index()].is_dealer(), "ディーラーの印が付いていません");
+    }
+    
+    #[test]
+    fn 席を指定して座らせてもボタンは同じプレイヤーに残る() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        for (i, seat) in [2, 4, 6].into_iter().enumerate() {
+            game.seat_player(Player::new(format!("プレイヤー{}", i + 1), 1000), seat).unwrap();
+        }
+        game.start_game().unwrap();
+        game.reset_for_new_hand().unwrap();
+        assert_eq!(game.dealer_index(), 1, "ボタンが回っていません");
+        
+        // ボタンより前の席に座らせる
+        game.seat_player(Player::new("プレイヤー4".to_string(), 1000), 0).unwrap();
+        assert_eq!(game.players()[0].name(), "プレイヤー4", "指定した席に座っていません");
+        assert_eq!(game.players()[0].seat(), 0, "席の番号が一致しません");
+        assert_eq!(game.players()[game.dealer_index()].name(), "プレイヤー2", "ボタンが別のプレイヤーに移っています");
+        assert!(!game.players()[0].is_dealer(), "座らせたプレイヤーにディーラーの印が付いています");
+        assert!(game.seat_player(Player::new("プレイヤー5".to_string(), 1000), 4).is_err(), "埋まっている席に座らせられてしまいます");
+        assert!(game.seat_player(Player::new("プレイヤー5".to_string(), 1000), MAX_SEATS).is_err(), "存在しない席に座らせられてしまいます");
+        
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.players()[0].name(), "プレイヤー4", "復元した席順が一致しません");
+        assert_eq!(restored.dealer_index(), game.dealer_index(), "復元したボタンの位置が一致しません");
+    }
+
+    // ハンドの記録からブラインドを払ったプレイヤーの名前を取り出す
+    fn blind_names(game: &Game) -> Vec<(String, BlindKind)> {
+        game.hand_history().unwrap().blinds.iter()
+            .map(|blind| {
+                let name = game.players().iter().find(|player| player.id() == &blind.player_id).unwrap().name();
+                (name.to_string(), blind.kind)
+            })
+            .collect()
+    }
+
+    fn start_hand(game: &mut Game) {
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+    }
+
+    #[test]
+    fn 二人のときはボタンがスモールブラインドを払い交互に入れ替わる() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        assert!(game.is_heads_up(), "2人のゲームがヘッズアップと判定されません");
+
+        start_hand(&mut game);
+        assert_eq!(blind_names(&game), vec![
+            ("プレイヤー1".to_string(), BlindKind::Small),
+            ("プレイヤー2".to_string(), BlindKind::Big),
+        ], "ブラインドが一致しません");
+        assert_eq!(game.current_player_index(), 0, "プリフロップはボタンから行動しません");
+
+        // 次のハンドではボタンとブラインドが入れ替わる
+        game.reset_for_new_hand().unwrap();
+        start_hand(&mut game);
+        assert_eq!(game.dealer_index(), 1, "ボタンが移動していません");
+        assert!(game.players()[1].is_dealer(), "ボタンの印が移動していません");
+        assert_eq!(blind_names(&game), vec![
+            ("プレイヤー2".to_string(), BlindKind::Small),
+            ("プレイヤー1".to_string(), BlindKind::Big),
+        ], "ブラインドが一致しません");
+        assert_eq!(game.current_player_index(), 1, "プリフロップはボタンから行動しません");
+    }
+
+    #[test]
+    fn 三人から二人になっても同じプレイヤーが続けてビッグブラインドを払わない() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        for name in ["A", "B", "C"] {
+            game.add_player(Player::new(name.to_string(), 1000)).unwrap();
+        }
+        start_hand(&mut game);
+        assert_eq!(blind_names(&game), vec![
+            ("B".to_string(), BlindKind::Small),
+            ("C".to_string(), BlindKind::Big),
+        ], "ブラインドが一致しません");
+
+        // ボタンの A がチップを失って抜ける
+        game.set_current_phase(GamePhase::Showdown);
+        let chips = game.players()[0].chips();
+        game.players_mut()[0].withdraw_chips(chips).unwrap();
+        game.reset_for_new_hand().unwrap();
+        let busted = game.players()[0].id().clone();
+        game.remove_player(&busted).unwrap();
+
+        // 直前にビッグブラインドを払った C がボタンになり、B がビッグブラインドを払う
+        start_hand(&mut game);
+        assert_eq!(game.players()[game.dealer_index()].name(), "C", "ボタンの位置が一致しません");
+        assert_eq!(blind_names(&game), vec![
+            ("C".to_string(), BlindKind::Small),
+            ("B".to_string(), BlindKind::Big),
+        ], "ブラインドが一致しません");
+    }
+
+    // 4人で1ハンド目を終え、指定したプレイヤーを外して次のハンドを始める
+    fn play_after_leaving(button_rule: ButtonRule, leaving: &str) -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.set_rules(TableRules { button_rule, ..game.rules() }).unwrap();
+        for name in ["A", "B", "C", "D"] {
+            game.add_player(Player::new(name.to_string(), 1000)).unwrap();
+        }
+        start_hand(&mut game);
+        assert_eq!(game.positions(), Some(TablePositions { button: 0, small_blind: 1, big_blind: 2 }), "1ハンド目の席が一致しません");
+        assert!(game.set_rules(TableRules { button_rule: ButtonRule::MovingButton, ..game.rules() }).is_err(), "ハンドの途中でボタンの動かし方を変更できてしまいます");
+
+        game.reset_for_new_hand().unwrap();
+        let id = game.players().iter().find(|player| player.name() == leaving).unwrap().id().clone();
+        game.remove_player(&id).unwrap();
+        start_hand(&mut game);
+        game
+    }
+
+    #[test]
+    fn デッドボタン_スモールブラインドが抜けるとボタンが空席に残る() {
+        let mut game = play_after_leaving(ButtonRule::DeadButton, "B");
+
+        // ビッグブラインドは1席進み、ボタンは抜けた B の席に残る
+        assert_eq!(game.positions(), Some(TablePositions { button: 1, small_blind: 2, big_blind: 3 }), "席が一致しません");
+        assert_eq!(blind_names(&game), vec![
+            ("C".to_string(), BlindKind::Small),
+            ("D".to_string(), BlindKind::Big),
+        ], "ブラインドが一致しません");
+        assert!(game.players().iter().all(|player| !player.is_dealer()), "空席のボタンなのにディーラーの印が付いています");
+        assert_eq!(game.hand_history().unwrap().dealer_seat, 1, "記録のボタンの席が一致しません");
+        assert_eq!(game.players()[game.current_player_index()].name(), "A", "ビッグブラインドの次から行動しません");
+
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.positions(), game.positions(), "復元した席が一致しません");
+
+        // 次のハンドでは通常どおり1席ずつ進む
+        game.reset_for_new_hand().unwrap();
+        start_hand(&mut game);
+        assert_eq!(game.positions(), Some(TablePositions { button: 2, small_blind: 3, big_blind: 0 }), "席が一致しません");
+        assert!(game.players()[game.dealer_index()].is_dealer(), "ボタンの印が付いていません");
+    }
+
+    #[test]
+    fn デッドボタン_ビッグブラインドが抜けるとスモールブラインドは払われない() {
+        let game = play_after_leaving(ButtonRule::DeadButton, "C");
+
+        // 1ハンド目でビッグブラインドを払った C の席がスモールブラインドになる
+        assert_eq!(game.positions(), Some(TablePositions { button: 1, small_blind: 2, big_blind: 3 }), "席が一致しません");
+        assert_eq!(blind_names(&game), vec![("D".to_string(), BlindKind::Big)], "ブラインドが一致しません");
+        assert_eq!(game.players()[game.dealer_index()].name(), "B", "ボタンの位置が一致しません");
+        assert_eq!(game.pot().total(), 10, "ポットが一致しません");
+    }
+
+    #[test]
+    fn ムービングボタンではボタンが次のプレイヤーへ進む() {
+        let game = play_after_leaving(ButtonRule::MovingButton, "B");
+
+        // ボタンは空席を飛ばして C に進み、A は続けてブラインドを払わずに済む
+        assert_eq!(game.rules().button_rule, ButtonRule::MovingButton);
+        assert_eq!(game.positions(), Some(TablePositions { button: 2, small_blind: 3, big_blind: 0 }), "席が一致しません");
+        assert_eq!(blind_names(&game), vec![
+            ("D".to_string(), BlindKind::Small),
+            ("A".to_string(), BlindKind::Big),
+        ], "ブラインドが一致しません");
+        assert_eq!(game.players()[game.dealer_index()].name(), "C", "ボタンの位置が一致しません");
+    }
+
+    #[test]
+    fn 空いている席に番号順に座る() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.seat_player(Player::new("A".to_string(), 1000), 3).unwrap();
+        game.seat_player(Player::new("B".to_string(), 1000), 1).unwrap();
+        game.add_player(Player::new("C".to_string(), 1000)).unwrap();
+        let seats: Vec<(&str, usize)> = game.players().iter().map(|player| (player.name(), player.seat())).collect();
+        assert_eq!(seats, vec![("B", 1), ("A", 3), ("C", 4)], "席の番号と並び順が一致しません");
+
+        start_hand(&mut game);
+        let recorded: Vec<usize> = game.hand_history().unwrap().seats.iter().map(|seat| seat.seat).collect();
+        assert_eq!(recorded, vec![1, 3, 4], "記録の席の番号が一致しません");
+    }
+}
@@ -0,0 +1,199 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+
+use super::bet::{BetAction, BettingStructure};
+use super::card::Card;
+use super::game::{BettingRound, GameId, GameVariant};
+use super::player::PlayerId;
+use crate::domain::service::hand_evaluation::{HandStrength, LowHand};
+
+/// ハンド開始時の座席ごとの記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatRecord {
+    pub seat: usize,
+    pub player_id: PlayerId,
+    pub name: String,
+    pub starting_stack: u32,
+    pub hole_cards: Vec<Card>, // 配られた手札（スタッドではストリートごとに配り足したカードも含む）
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlindKind {
+    Small,
+    Big,
+    Ante,
+    BringIn, // スタッドで表向きのカードが最も弱いプレイヤーが払う強制ベット
+}
+
+/// 投入されたブラインドとアンティ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindRecord {
+    pub player_id: PlayerId,
+    pub kind: BlindKind,
+    pub amount: u32,
+}
+
+/// ベッティングラウンド中のアクション
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionRecord {
+    pub round: BettingRound,
+    pub player_id: PlayerId,
+    pub action: BetAction,
+    pub amount: u32, // このアクションで出したチップ
+    pub bet_to: u32, // アクション後のラウンド内でのベット額
+}
+
+/// 誰にもコールされずに返されたベット
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReturnRecord {
+    pub round: BettingRound,
+    pub player_id: PlayerId,
+    pub amount: u32,
+}
+
+/// ドローでのカード交換
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DrawRecord {
+    pub round: BettingRound,
+    pub player_id: PlayerId,
+    pub discarded: Vec<Card>,
+    pub drawn: Vec<Card>,
+}
+
+/// ラウンドの開始時に開かれたコミュニティカード
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardRecord {
+    pub round: BettingRound,
+    pub cards: Vec<Card>,
+}
+
+/// ショーダウンで公開された手
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShowdownRecord {
+    pub player_id: PlayerId,
+    pub cards: Vec<Card>,
+    pub strength: HandStrength,
+    #[serde(default)]
+    pub low: Option<LowHand>, // ローで勝負するバリアントでの手
+}
+
+/// ポットごとの配当
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayoutRecord {
+    pub amount: u32,
+    pub eligible_player_ids: Vec<PlayerId>,
+    pub winners: Vec<(PlayerId, u32)>, // (プレイヤーID, 獲得額)
+}
+
+/// 1ハンド分の完全な記録（監査・振り返り用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandHistory {
+    pub game_id: GameId,
+    pub hand_number: u32,
+    pub variant: GameVariant,
+    pub small_blind: u32,
+    pub big_blind: u32,
+    #[serde(default)]
+    pub betting_structure: BettingStructure,
+    pub dealer_seat: usize,
+    pub started_at: DateTime<Utc>,
+    pub seats: Vec<SeatRecord>,
+    pub blinds: Vec<BlindRecord>,
+    pub actions: Vec<ActionRecord>,
+    #[serde(default)]
+    pub returned: Vec<ReturnRecord>, // 読み込んだハンドで返されたベット
+    pub draws: Vec<DrawRecord>,
+    pub board: Vec<BoardRecord>,
+    pub showdown: Vec<ShowdownRecord>,
+    pub payouts: Vec<PayoutRecord>,
+}
+
+impl HandHistory {
+    pub fn new(
+        game_id: GameId,
+        hand_number: u32,
+        variant: GameVariant,
+        small_blind: u32,
+        big_blind: u32,
+        dealer_seat: usize,
+        seats: Vec<SeatRecord>,
+    ) -> Self {
+        Self {
+            game_id,
+            hand_number,
+            variant,
+            small_blind,
+            big_blind,
+            betting_structure: BettingStructure::NoLimit,
+            dealer_seat,
+            started_at: Utc::now(),
+            seats,
+            blinds: Vec::new(),
+            actions: Vec::new(),
+            returned: Vec::new(),
+            draws: Vec::new(),
+            board: Vec::new(),
+            showdown: Vec::new(),
+            payouts: Vec::new(),
+        }
+    }
+    
+    /// ハンドに参加した全プレイヤーのID（座席順）
+    pub fn participant_ids(&self) -> Vec<PlayerId> {
+        self.seats.iter().map(|seat| seat.player_id.clone()).collect()
+    }
+    
+    /// いずれかのポットを獲得したプレイヤーのID（重複なし）
+    pub fn winner_ids(&self) -> Vec<PlayerId> {
+        let mut winners: Vec<PlayerId> = Vec::new();
+        for (player_id, _) in self.payouts.iter().flat_map(|payout| payout.winners.iter()) {
+            if !winners.contains(player_id) {
+                winners.push(player_id.clone());
+            }
+        }
+        winners
+    }
+    
+    /// 配当されたチップの合計
+    pub fn total_pot(&self) -> u32 {
+        self.payouts.iter().map(|payout| payout.amount).sum()
+    }
+    
+    /// 指定したラウンドのアクション
+    pub fn actions_in(&self, round: BettingRound) -> Vec<&ActionRecord> {
+        self.actions.iter().filter(|action| action.round == round).collect()
+    }
+    
+    /// ブラインドとアクションでプレイヤーがハンド全体で出したチップ（返されたベットを除く）
+    pub fn total_contributed(&self, player_id: &PlayerId) -> u32 {
+        let blinds: u32 = self.blinds.iter()
+            .filter(|blind| &blind.player_id == player_id)
+            .map(|blind| blind.amount)
+            .sum();
+        let actions: u32 = self.actions.iter()
+            .filter(|action| &action.player_id == player_id)
+            .map(|action| action.amount)
+            .sum();
+        let returned: u32 = self.returned.iter()
+            .filter(|returned| &returned.player_id == player_id)
+            .map(|returned| returned.amount)
+            .sum();
+        (blinds + actions).saturating_sub(returned)
+    }
+    
+    /// プレイヤーがフォールドしたラウンド
+    pub fn folded_in(&self, player_id: &PlayerId) -> Option<BettingRound> {
+        self.actions.iter()
+            .find(|action| &action.player_id == player_id && action.action == BetAction::Fold)
+            .map(|action| action.round)
+    }
+    
+    pub fn seat_of(&self, player_id: &PlayerId) -> Option<&SeatRecord> {
+        self.seats.iter().find(|seat| &seat.player_id == player_id)
+    }
+    
+    /// ハンド終了までに開かれたコミュニティカード
+    pub fn community_cards(&self) -> Vec<Card> {
+        self.board.iter().flat_map(|record| record.cards.iter().copied()).collect()
+    }
+}
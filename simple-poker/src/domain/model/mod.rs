@@ -6,6 +6,9 @@ pub mod player;
 pub mod game;
 pub mod error;
 pub mod event;
+pub mod hand_history;
+pub mod tournament;
+pub mod cash_table;
 
 #[cfg(test)]
 mod card_test;
@@ -21,3 +24,9 @@ mod player_test;
 
 #[cfg(test)]
 mod game_test;
+
+#[cfg(test)]
+mod tournament_test;
+
+#[cfg(test)]
+mod cash_table_test;
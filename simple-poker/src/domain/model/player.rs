@@ -7,9 +7,15 @@ use super::hand::Hand;
 use super::card::Card;
 use super::error::DomainError;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PlayerId(String);
 
+impl Default for PlayerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PlayerId {
     pub fn new() -> Self {
         Self(Uuid::new_v4().to_string())
@@ -30,6 +36,34 @@ impl fmt::Display for PlayerId {
     }
 }
 
+/// 席を操作するコンピュータの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BotKind {
+    /// 合法なアクションからランダムに選ぶ
+    Random,
+    /// 強い手だけで参加し、自分からはベットしない
+    TightPassive,
+    /// 勝率とポットオッズを比べて判断する
+    PotOdds,
+}
+
+impl BotKind {
+    pub fn all() -> [BotKind; 3] {
+        [BotKind::Random, BotKind::TightPassive, BotKind::PotOdds]
+    }
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            BotKind::Random => "ランダム",
+            BotKind::TightPassive => "タイト・パッシブ",
+            BotKind::PotOdds => "ポットオッズ",
+        }
+    }
+}
+
+/// 手札の最大枚数（スタッドでは7枚になる）
+pub const MAX_HAND_SIZE: usize = 7;
+
 #[derive(Debug, Clone)]
 pub struct Player {
     id: PlayerId,
@@ -37,9 +71,14 @@ pub struct Player {
     hand: Hand,
     chips: Chips,
     current_bet: u32,
+    total_bet: u32,  // 現在のハンド全体での拠出額（サイドポット計算用）
     is_folded: bool,
     is_all_in: bool,
     is_dealer: bool,
+    bot: Option<BotKind>,  // コンピュータが操作する席
+    sitting_out: bool,     // 席を確保したままハンドに参加しない
+    missed_blind: bool,    // 離席中にブラインドを払わなかった（復帰時にビッグブラインドを払う）
+    seat: usize,           // 座っている席の番号（0始まり、ゲームに座ったときに決まる）
 }
 
 /// デシリアライズのためのデータ構造体
@@ -49,9 +88,14 @@ pub struct PlayerSerializedData {
     pub chips_amount: u32,
     pub cards: Vec<Card>,
     pub current_bet: u32,
+    pub total_bet: u32,
     pub is_folded: bool,
     pub is_all_in: bool,
     pub is_dealer: bool,
+    pub bot: Option<BotKind>,
+    pub sitting_out: bool,
+    pub missed_blind: bool,
+    pub seat: usize,
 }
 
 impl Player {
@@ -59,18 +103,31 @@ impl Player {
         Self {
             id: PlayerId::new(),
             name,
-            hand: Hand::new(5), // デフォルトの手札サイズは5
+            hand: Hand::new(MAX_HAND_SIZE),
             chips: Chips::new(initial_chips),
             current_bet: 0,
+            total_bet: 0,
             is_folded: false,
             is_all_in: false,
             is_dealer: false,
+            bot: None,
+            sitting_out: false,
+            missed_blind: false,
+            seat: 0,
+        }
+    }
+    
+    /// コンピュータが操作するプレイヤー
+    pub fn new_bot(name: String, initial_chips: u32, kind: BotKind) -> Self {
+        Self {
+            bot: Some(kind),
+            ..Self::new(name, initial_chips)
         }
     }
     
     /// デシリアライズ用のファクトリメソッド
     pub fn from_serialized(data: PlayerSerializedData) -> Result<Self, DomainError> {
-        let mut hand = Hand::new(5);
+        let mut hand = Hand::new(MAX_HAND_SIZE);
         for card in data.cards {
             hand.add_card(card).map_err(|e| DomainError::InvalidCard(e.to_string()))?;
         }
@@ -81,9 +138,14 @@ impl Player {
             hand,
             chips: Chips::new(data.chips_amount),
             current_bet: data.current_bet,
+            total_bet: data.total_bet,
             is_folded: data.is_folded,
             is_all_in: data.is_all_in,
             is_dealer: data.is_dealer,
+            bot: data.bot,
+            sitting_out: data.sitting_out,
+            missed_blind: data.missed_blind,
+            seat: data.seat,
         })
     }
     
@@ -111,10 +173,31 @@ impl Player {
         self.chips.add(amount);
     }
     
+    /// 手持ちのチップから amount を引き出す
+    pub fn withdraw_chips(&mut self, amount: u32) -> Result<(), DomainError> {
+        self.chips.subtract(amount)
+    }
+    
+    /// 手持ちのチップから amount を持ち込み、同じIDで席に着くプレイヤーを作る
+    pub fn buy_in(&mut self, amount: u32) -> Result<Player, DomainError> {
+        self.withdraw_chips(amount)?;
+        Ok(Self {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            bot: self.bot,
+            ..Self::new(String::new(), amount)
+        })
+    }
+    
     pub fn current_bet(&self) -> u32 {
         self.current_bet
     }
     
+    /// 現在のハンドでポットに拠出した総額
+    pub fn total_bet(&self) -> u32 {
+        self.total_bet
+    }
+    
     pub fn is_folded(&self) -> bool {
         self.is_folded
     }
@@ -127,6 +210,42 @@ impl Player {
         self.is_dealer
     }
     
+    /// コンピュータが操作する場合はその種類
+    pub fn bot(&self) -> Option<BotKind> {
+        self.bot
+    }
+    
+    pub fn is_bot(&self) -> bool {
+        self.bot.is_some()
+    }
+    
+    /// 離席中か（席は確保したままカードを配られない）
+    pub fn is_sitting_out(&self) -> bool {
+        self.sitting_out
+    }
+    
+    pub fn set_sitting_out(&mut self, sitting_out: bool) {
+        self.sitting_out = sitting_out;
+    }
+    
+    /// 離席中にブラインドを払わずに済ませたか
+    pub fn has_missed_blind(&self) -> bool {
+        self.missed_blind
+    }
+    
+    pub fn set_missed_blind(&mut self, missed_blind: bool) {
+        self.missed_blind = missed_blind;
+    }
+    
+    /// 座っている席の番号（0始まり）
+    pub fn seat(&self) -> usize {
+        self.seat
+    }
+    
+    pub(crate) fn set_seat(&mut self, seat: usize) {
+        self.seat = seat;
+    }
+    
     pub fn set_dealer(&mut self, is_dealer: bool) {
         self.is_dealer = is_dealer;
     }
@@ -147,7 +266,7 @@ impl Player {
             self.is_all_in = true;
             available
         } else {
-            self.chips.subtract(amount).map_err(|e| DomainError::from(e))?;
+            self.chips.subtract(amount)?;
             if self.chips.is_zero() {
                 self.is_all_in = true;
             }
@@ -155,9 +274,21 @@ impl Player {
         };
         
         self.current_bet += bet_amount;
+        self.total_bet += bet_amount;
         Ok(bet_amount)
     }
     
+    /// アンティを支払う（ラウンドのベット額には含めず、ハンド全体の拠出額にだけ加える）
+    pub fn post_ante(&mut self, amount: u32) -> Result<u32, DomainError> {
+        let paid = amount.min(self.chips.amount());
+        self.chips.subtract(paid)?;
+        if paid > 0 && self.chips.is_zero() {
+            self.is_all_in = true;
+        }
+        self.total_bet += paid;
+        Ok(paid)
+    }
+    
     pub fn reset_bet(&mut self) {
         self.current_bet = 0;
     }
@@ -166,6 +297,7 @@ impl Player {
         self.hand.clear();
         self.is_folded = false;
         self.current_bet = 0;
+        self.total_bet = 0;
     }
     
     pub fn reset_for_new_game(&mut self) {
@@ -194,11 +326,12 @@ impl fmt::Display for Player {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{} ({}チップ){}{}",
+            "{} ({}チップ){}{}{}",
             self.name,
             self.chips.amount(),
             if self.is_dealer { " [ディーラー]" } else { "" },
-            if self.is_all_in { " [オールイン]" } else { "" }
+            if self.is_all_in { " [オールイン]" } else { "" },
+            if self.sitting_out { " [離席中]" } else { "" }
         )
     }
 } 
\ No newline at end of file
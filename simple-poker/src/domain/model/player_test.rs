@@ -69,6 +69,7 @@ mod tests {
         player.reset_bet();
         assert_eq!(player.current_bet(), 0, "ベットリセット後のベット額が0ではありません");
         assert_eq!(player.chips(), 500, "ベットリセット後のチップ数が一致しません");
+        assert_eq!(player.total_bet(), 500, "ベットリセットでハンド全体の拠出額が消えています");
     }
 
     #[test]
@@ -83,6 +84,7 @@ mod tests {
         assert_eq!(player.hand().size(), 1, "手札サイズが一致しません");
         player.reset_for_new_round();
         assert_eq!(player.current_bet(), 0, "新ラウンドリセット後のベット額が0ではありません");
+        assert_eq!(player.total_bet(), 0, "新ラウンドリセット後の拠出額が0ではありません");
         assert!(!player.is_folded(), "新ラウンドリセット後もフォールド状態です");
         assert!(player.hand().is_empty(), "新ラウンドリセット後の手札が空ではありません");
         assert_eq!(player.chips(), 500, "新ラウンドリセット後のチップ数が一致しません");
@@ -113,6 +115,7 @@ mod tests {
         let card2 = Card::new(Suit::Heart, 10).unwrap();
         let cards = vec![card1, card2];
         let current_bet = 500;
+        let total_bet = 700;
         let is_folded = false;
         let is_all_in = false;
         let is_dealer = true;
@@ -124,9 +127,14 @@ mod tests {
                 chips_amount: chips,
                 cards: cards.clone(),
                 current_bet,
+                total_bet,
                 is_folded,
                 is_all_in,
-                is_dealer
+                is_dealer,
+                bot: None,
+                sitting_out: false,
+                missed_blind: false,
+                seat: 0,
             }
         );
         
@@ -136,6 +144,7 @@ mod tests {
         assert_eq!(player.name(), &name, "名前が一致しません");
         assert_eq!(player.chips(), chips, "チップ数が一致しません");
         assert_eq!(player.current_bet(), current_bet, "ベット額が一致しません");
+        assert_eq!(player.total_bet(), total_bet, "ハンド全体の拠出額が一致しません");
         assert_eq!(player.is_folded(), is_folded, "フォールド状態が一致しません");
         assert_eq!(player.is_all_in(), is_all_in, "オールイン状態が一致しません");
         assert_eq!(player.is_dealer(), is_dealer, "ディーラー状態が一致しません");
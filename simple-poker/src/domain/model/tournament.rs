@@ -0,0 +1,330 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Serialize, Deserialize};
+
+use super::error::DomainError;
+use super::game::{Game, GamePhase};
+use super::player::PlayerId;
+
+/// 1つのレベルのブラインドとアンティ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindLevel {
+    pub small_blind: u32,
+    pub big_blind: u32,
+    pub ante: u32,
+}
+
+impl BlindLevel {
+    pub fn new(small_blind: u32, big_blind: u32, ante: u32) -> Self {
+        Self { small_blind, big_blind, ante }
+    }
+}
+
+/// レベルが上がるまでの長さ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LevelDuration {
+    Hands(u32),   // プレイしたハンド数
+    Minutes(u32), // トーナメント開始からの経過時間
+}
+
+/// ブラインドとアンティが上がっていくスケジュール
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindSchedule {
+    levels: Vec<BlindLevel>,
+    duration: LevelDuration,
+}
+
+impl BlindSchedule {
+    pub fn new(levels: Vec<BlindLevel>, duration: LevelDuration) -> Result<Self, DomainError> {
+        if levels.is_empty() {
+            return Err(DomainError::InvalidGameOperation("ブラインドのレベルが1つもありません".into()));
+        }
+        if let Some(level) = levels.iter().find(|level| level.small_blind > level.big_blind) {
+            return Err(DomainError::InvalidGameOperation(format!(
+                "スモールブラインドはビッグブラインド以下である必要があります（{}/{}）", level.small_blind, level.big_blind
+            )));
+        }
+        if matches!(duration, LevelDuration::Hands(0) | LevelDuration::Minutes(0)) {
+            return Err(DomainError::InvalidGameOperation("レベルの長さは1以上である必要があります".into()));
+        }
+        Ok(Self { levels, duration })
+    }
+
+    pub fn levels(&self) -> &[BlindLevel] {
+        &self.levels
+    }
+
+    pub fn duration(&self) -> LevelDuration {
+        self.duration
+    }
+
+    /// プレイしたハンド数と経過時間でのレベル（0始まり、最後のレベルより先には進まない）
+    pub fn level_index(&self, hands_played: u32, elapsed: Duration) -> usize {
+        let index = match self.duration {
+            LevelDuration::Hands(hands) => (hands_played / hands) as usize,
+            LevelDuration::Minutes(minutes) => (elapsed.num_minutes().max(0) / minutes as i64) as usize,
+        };
+        index.min(self.levels.len() - 1)
+    }
+
+    pub fn level(&self, index: usize) -> BlindLevel {
+        self.levels[index.min(self.levels.len() - 1)]
+    }
+}
+
+/// 順位ごとの賞金の配分（パーセント）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrizeStructure {
+    percentages: Vec<u32>, // 1位から順
+}
+
+impl PrizeStructure {
+    pub fn new(percentages: Vec<u32>) -> Result<Self, DomainError> {
+        if percentages.iter().sum::<u32>() != 100 {
+            return Err(DomainError::InvalidGameOperation("賞金の配分は合計100%である必要があります".into()));
+        }
+        if percentages.windows(2).any(|pair| pair[0] < pair[1]) {
+            return Err(DomainError::InvalidGameOperation("下の順位の配分が上の順位を超えています".into()));
+        }
+        Ok(Self { percentages })
+    }
+
+    /// 1位がすべて受け取る配分
+    pub fn winner_takes_all() -> Self {
+        Self { percentages: vec![100] }
+    }
+
+    pub fn percentages(&self) -> &[u32] {
+        &self.percentages
+    }
+
+    /// 賞金総額を順位ごとに分ける（端数は1位に加える）
+    pub fn payouts(&self, prize_pool: u32) -> Vec<u32> {
+        let mut payouts: Vec<u32> = self.percentages.iter()
+            .map(|&percent| (prize_pool as u64 * percent as u64 / 100) as u32)
+            .collect();
+        let remainder = prize_pool - payouts.iter().sum::<u32>();
+        if let Some(first) = payouts.first_mut() {
+            *first += remainder;
+        }
+        payouts
+    }
+}
+
+/// チップがなくなって脱落したプレイヤー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Elimination {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub place: usize,
+    pub hand_number: u32, // 脱落したハンド
+}
+
+impl Elimination {
+    /// ポットの分配が終わったハンドでチップがなくなったプレイヤー（順位の低い順）
+    ///
+    /// このハンドに配られていなかったプレイヤーは、チップがなくても含めない。
+    /// 同じハンドで複数人が脱落した場合は、ハンド開始時のスタックが多かったプレイヤーを上の順位にする。
+    /// スタックが同じプレイヤーは同着として、占める順位のうち最も上の順位を共有する。
+    /// remaining はこのハンドの前に残っていた人数で、最も下の順位になる。
+    pub(crate) fn from_finished_hand(game: &Game, remaining: usize) -> Result<Vec<Elimination>, DomainError> {
+        if !matches!(game.current_phase(), GamePhase::Showdown | GamePhase::Complete) || game.pot().total() > 0 {
+            return Err(DomainError::InvalidGameOperation("ポットの分配が終わっていません".into()));
+        }
+
+        // このハンドに配られていたプレイヤーだけが脱落の対象になる
+        let history = game.hand_history()
+            .ok_or_else(|| DomainError::InvalidState("ハンドの記録がありません".to_string()))?;
+        let mut busted: Vec<(u32, usize)> = game.players().iter()
+            .enumerate()
+            .filter(|(_, player)| player.chips() == 0)
+            .filter_map(|(index, player)| history.seat_of(player.id()).map(|seat| (seat.starting_stack, index)))
+            .collect();
+        busted.sort();
+
+        Ok(busted.iter()
+            .map(|&(stack, index)| {
+                let player = &game.players()[index];
+                // 自分以下のスタックで脱落した人数（自分を含む）だけ下の順位が埋まる
+                let at_or_below = busted.iter().filter(|&&(other, _)| other <= stack).count();
+                Elimination {
+                    player_id: player.id().clone(),
+                    name: player.name().to_string(),
+                    place: remaining + 1 - at_or_below,
+                    hand_number: game.hand_number(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// 順位表の1行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    pub place: usize,
+    pub player_id: PlayerId,
+    pub name: String,
+    pub chips: u32, // 残っているチップ（脱落したプレイヤーは0）
+    pub prize: u32,
+}
+
+/// シットアンドゴー形式のトーナメント
+///
+/// ゲームの外側で進行を管理する。ハンドが終わるたびに `finish_hand` を呼ぶと、
+/// 脱落者を席から外し、ディーラーボタンを回して、スケジュールに従ってブラインドを上げる。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tournament {
+    schedule: BlindSchedule,
+    prizes: PrizeStructure,
+    buy_in: u32,
+    entrants: usize,
+    started_at: Option<DateTime<Utc>>,
+    hands_played: u32,
+    level_index: usize,
+    eliminations: Vec<Elimination>, // 脱落した順
+}
+
+impl Tournament {
+    pub fn new(schedule: BlindSchedule, prizes: PrizeStructure, buy_in: u32) -> Self {
+        Self {
+            schedule,
+            prizes,
+            buy_in,
+            entrants: 0,
+            started_at: None,
+            hands_played: 0,
+            level_index: 0,
+            eliminations: Vec::new(),
+        }
+    }
+
+    /// 参加者がそろったゲームで開始し、最初のレベルのブラインドを設定する
+    pub fn start(&mut self, game: &mut Game, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if self.started_at.is_some() {
+            return Err(DomainError::InvalidGameOperation("トーナメントは既に開始されています".into()));
+        }
+        if game.current_phase() != GamePhase::NotStarted {
+            return Err(DomainError::InvalidGameOperation("トーナメントはハンドの開始前に始める必要があります".into()));
+        }
+        if game.players().len() < 2 {
+            return Err(DomainError::InvalidGameOperation("トーナメントには最低2人のプレイヤーが必要です".into()));
+        }
+
+        let level = self.schedule.level(0);
+        game.set_blinds(level.small_blind, level.big_blind, level.ante)?;
+        self.entrants = game.players().len();
+        self.started_at = Some(now);
+        Ok(())
+    }
+
+    pub fn schedule(&self) -> &BlindSchedule {
+        &self.schedule
+    }
+
+    pub fn prizes(&self) -> &PrizeStructure {
+        &self.prizes
+    }
+
+    pub fn buy_in(&self) -> u32 {
+        self.buy_in
+    }
+
+    pub fn entrants(&self) -> usize {
+        self.entrants
+    }
+
+    pub fn prize_pool(&self) -> u32 {
+        self.buy_in * self.entrants as u32
+    }
+
+    pub fn hands_played(&self) -> u32 {
+        self.hands_played
+    }
+
+    /// 現在のレベル（0始まり）
+    pub fn level_index(&self) -> usize {
+        self.level_index
+    }
+
+    pub fn current_level(&self) -> BlindLevel {
+        self.schedule.level(self.level_index)
+    }
+
+    pub fn eliminations(&self) -> &[Elimination] {
+        &self.eliminations
+    }
+
+    /// 残りが1人になったか
+    pub fn is_finished(&self) -> bool {
+        self.started_at.is_some() && self.entrants - self.eliminations.len() <= 1
+    }
+
+    /// ポットの分配が終わったハンドを締めくくり、次のハンドの準備をする
+    ///
+    /// チップがなくなったプレイヤーを脱落させて席から外す。
+    /// トーナメントが続く場合は、ハンド数や経過時間に応じてブラインドとアンティを上げる。
+    pub fn finish_hand(&mut self, game: &mut Game, now: DateTime<Utc>) -> Result<Vec<Elimination>, DomainError> {
+        let Some(started_at) = self.started_at else {
+            return Err(DomainError::InvalidGameOperation("トーナメントが開始されていません".into()));
+        };
+        if self.is_finished() {
+            return Err(DomainError::InvalidGameOperation("トーナメントは既に終了しています".into()));
+        }
+        let eliminated = Elimination::from_finished_hand(game, game.players().len())?;
+
+        // ボタンを回してから脱落者を外す（ボタンが脱落者に回った場合は次の席に移る）
+        self.hands_played += 1;
+        game.reset_for_new_hand()?;
+        for elimination in &eliminated {
+            game.remove_player(&elimination.player_id)?;
+        }
+        self.eliminations.extend(eliminated.iter().cloned());
+
+        if !self.is_finished() {
+            let level_index = self.schedule.level_index(self.hands_played, now - started_at);
+            if level_index != self.level_index {
+                self.level_index = level_index;
+                let level = self.schedule.level(level_index);
+                game.set_blinds(level.small_blind, level.big_blind, level.ante)?;
+            }
+        }
+        Ok(eliminated)
+    }
+
+    /// 現時点の順位表（残っているプレイヤーはチップの多い順、脱落したプレイヤーは脱落の遅い順）
+    ///
+    /// 賞金はトーナメントが終わった時点の順位で確定する。
+    /// 同着のプレイヤーは占める順位の賞金の合計を等分する（端数は先に並ぶプレイヤーから1ずつ加える）。
+    pub fn standings(&self, game: &Game) -> Vec<Standing> {
+        let payouts = self.prizes.payouts(self.prize_pool());
+        let prize = |place: usize| payouts.get(place - 1).copied().unwrap_or(0);
+
+        let mut remaining: Vec<_> = game.players().iter().collect();
+        remaining.sort_by_key(|player| std::cmp::Reverse(player.chips()));
+        let mut standings: Vec<Standing> = remaining.into_iter()
+            .enumerate()
+            .map(|(i, player)| Standing {
+                place: i + 1,
+                player_id: player.id().clone(),
+                name: player.name().to_string(),
+                chips: player.chips(),
+                prize: prize(i + 1),
+            })
+            .collect();
+
+        let mut eliminations: Vec<&Elimination> = self.eliminations.iter().collect();
+        eliminations.sort_by_key(|elimination| elimination.place);
+        for tied in eliminations.chunk_by(|a, b| a.place == b.place) {
+            let place = tied[0].place;
+            let total: u32 = (place..place + tied.len()).map(prize).sum();
+            let remainder = total as usize % tied.len();
+            standings.extend(tied.iter().enumerate().map(|(i, elimination)| Standing {
+                place,
+                player_id: elimination.player_id.clone(),
+                name: elimination.name.clone(),
+                chips: 0,
+                prize: total / tied.len() as u32 + u32::from(i < remainder),
+            }));
+        }
+        standings
+    }
+}
@@ -0,0 +1,229 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::{Game, GamePhase, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::domain::model::tournament::{BlindLevel, BlindSchedule, Elimination, LevelDuration, PrizeStructure, Tournament};
+    use crate::domain::service::game_projector::GameProjector;
+    use crate::domain::service::game_rule::GameRuleService;
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    fn levels() -> Vec<BlindLevel> {
+        vec![BlindLevel::new(5, 10, 5), BlindLevel::new(10, 20, 10), BlindLevel::new(25, 50, 25)]
+    }
+
+    fn sit_and_go(stacks: &[u32]) -> (Game, Tournament) {
+        let mut game = Game::new(GameVariant::TexasHoldem, 1, 2).unwrap();
+        for (i, &stack) in stacks.iter().enumerate() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), stack)).unwrap();
+        }
+        let schedule = BlindSchedule::new(levels(), LevelDuration::Hands(1)).unwrap();
+        let prizes = PrizeStructure::new(vec![70, 30]).unwrap();
+        let mut tournament = Tournament::new(schedule, prizes, 100);
+        tournament.start(&mut game, Utc::now()).unwrap();
+        (game, tournament)
+    }
+
+    // プレイヤー1がAのペア、他の2人は役なしになる並びで配り、アンティとブラインドまで投入する
+    fn deal_hand(game: &mut Game) {
+        game.preset_deck(vec![
+            card(Suit::Spade, 1), card(Suit::Heart, 1),
+            card(Suit::Spade, 2), card(Suit::Heart, 7),
+            card(Suit::Club, 3), card(Suit::Diamond, 8),
+            card(Suit::Club, 9), card(Suit::Diamond, 10), card(Suit::Spade, 12),
+            card(Suit::Heart, 4),
+            card(Suit::Club, 13),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_antes().unwrap();
+        game.post_blinds().unwrap();
+    }
+
+    // 残りのラウンドを進めてポットを分配する
+    fn finish_showdown(game: &mut Game) {
+        while game.current_phase() != GamePhase::Showdown {
+            game.end_betting_round().unwrap();
+        }
+        GameRuleService::distribute_pot(game).unwrap();
+    }
+
+    #[test]
+    fn ハンド数でブラインドのレベルが上がる() {
+        let schedule = BlindSchedule::new(levels(), LevelDuration::Hands(10)).unwrap();
+        assert_eq!(schedule.level_index(0, Duration::zero()), 0, "最初のレベルではありません");
+        assert_eq!(schedule.level_index(9, Duration::hours(5)), 0, "時間でレベルが上がっています");
+        assert_eq!(schedule.level_index(10, Duration::zero()), 1, "10ハンドでレベルが上がっていません");
+        assert_eq!(schedule.level_index(500, Duration::zero()), 2, "最後のレベルを超えています");
+    }
+
+    #[test]
+    fn 経過時間でブラインドのレベルが上がる() {
+        let schedule = BlindSchedule::new(levels(), LevelDuration::Minutes(15)).unwrap();
+        assert_eq!(schedule.level_index(100, Duration::minutes(14)), 0, "ハンド数でレベルが上がっています");
+        assert_eq!(schedule.level_index(0, Duration::minutes(15)), 1, "15分でレベルが上がっていません");
+        assert_eq!(schedule.level(1), BlindLevel::new(10, 20, 10), "レベルの内容が一致しません");
+    }
+
+    #[test]
+    fn 不正なスケジュールと賞金の配分はエラーになる() {
+        assert!(BlindSchedule::new(Vec::new(), LevelDuration::Hands(10)).is_err(), "レベルなしで作成できてしまいます");
+        assert!(BlindSchedule::new(vec![BlindLevel::new(20, 10, 0)], LevelDuration::Hands(10)).is_err(), "逆転したブラインドで作成できてしまいます");
+        assert!(BlindSchedule::new(levels(), LevelDuration::Minutes(0)).is_err(), "長さ0のレベルで作成できてしまいます");
+        assert!(PrizeStructure::new(vec![60, 30]).is_err(), "合計100%でない配分で作成できてしまいます");
+        assert!(PrizeStructure::new(vec![30, 70]).is_err(), "下位の方が多い配分で作成できてしまいます");
+    }
+
+    #[test]
+    fn 賞金の端数は1位に加える() {
+        let prizes = PrizeStructure::new(vec![50, 30, 20]).unwrap();
+        assert_eq!(prizes.payouts(1001), vec![501, 300, 200], "配当が一致しません");
+        assert_eq!(PrizeStructure::winner_takes_all().payouts(300), vec![300], "1位が総取りしていません");
+    }
+
+    #[test]
+    fn 開始時に最初のレベルのブラインドを設定する() {
+        let (game, tournament) = sit_and_go(&[1000, 1000, 1000]);
+        assert_eq!((game.small_blind(), game.big_blind(), game.ante()), (5, 10, 5), "ブラインドが設定されていません");
+        assert_eq!(tournament.entrants(), 3, "参加人数が一致しません");
+        assert_eq!(tournament.prize_pool(), 300, "賞金総額が一致しません");
+        assert!(!tournament.is_finished(), "開始直後に終了しています");
+    }
+
+    #[test]
+    fn 脱落者を外してボタンを回しブラインドを上げる() {
+        let (mut game, mut tournament) = sit_and_go(&[1000, 1000, 100]);
+        deal_hand(&mut game);
+        assert_eq!(game.pot().total(), 30, "アンティとブラインドがポットに入っていません");
+
+        // プレイヤー3がオールインし、プレイヤー1だけがコールする
+        GameRuleService::process_action(&mut game, 2, BetAction::AllIn, None).unwrap();
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Fold, None).unwrap();
+        finish_showdown(&mut game);
+
+        let eliminated = tournament.finish_hand(&mut game, Utc::now()).unwrap();
+        assert_eq!(eliminated.len(), 1, "脱落者の数が一致しません");
+        assert_eq!((eliminated[0].name.as_str(), eliminated[0].place), ("プレイヤー3", 3), "脱落者の順位が一致しません");
+        assert_eq!(game.players().len(), 2, "脱落者が席から外れていません");
+        assert_eq!(game.players()[game.dealer_index()].name(), "プレイヤー2", "ボタンが次の席に回っていません");
+        assert_eq!(tournament.level_index(), 1, "レベルが上がっていません");
+        assert_eq!((game.small_blind(), game.big_blind(), game.ante()), (10, 20, 10), "ブラインドが上がっていません");
+        assert!(!tournament.is_finished(), "2人残っているのに終了しています");
+
+        // イベントから同じ状態を復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.players().len(), 2, "復元したゲームの人数が一致しません");
+        assert_eq!(restored.dealer_index(), game.dealer_index(), "復元したボタンの位置が一致しません");
+        assert_eq!(restored.ante(), 10, "復元したアンティが一致しません");
+    }
+
+    #[test]
+    fn 最後の1人が残ると順位と賞金が確定する() {
+        let (mut game, mut tournament) = sit_and_go(&[1000, 100, 200]);
+        deal_hand(&mut game);
+
+        GameRuleService::process_action(&mut game, 0, BetAction::AllIn, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::AllIn, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::AllIn, None).unwrap();
+        finish_showdown(&mut game);
+
+        let eliminated = tournament.finish_hand(&mut game, Utc::now()).unwrap();
+        assert_eq!(eliminated.len(), 2, "脱落者の数が一致しません");
+        assert!(tournament.is_finished(), "トーナメントが終了していません");
+        assert!(tournament.finish_hand(&mut game, Utc::now()).is_err(), "終了後もハンドを続けられます");
+
+        // 同じハンドで脱落した場合は開始時のスタックが多い方が上位
+        let standings = tournament.standings(&game);
+        let summary: Vec<(usize, &str, u32, u32)> = standings.iter()
+            .map(|standing| (standing.place, standing.name.as_str(), standing.chips, standing.prize))
+            .collect();
+        assert_eq!(summary, vec![
+            (1, "プレイヤー1", 1300, 210),
+            (2, "プレイヤー3", 0, 90),
+            (3, "プレイヤー2", 0, 0),
+        ], "順位表が一致しません");
+    }
+
+    #[test]
+    fn 同じスタックで同時に脱落すると同着で賞金を等分する() {
+        let (mut game, mut tournament) = sit_and_go(&[1000, 200, 200]);
+        deal_hand(&mut game);
+
+        GameRuleService::process_action(&mut game, 0, BetAction::AllIn, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::AllIn, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::AllIn, None).unwrap();
+        finish_showdown(&mut game);
+
+        let eliminated = tournament.finish_hand(&mut game, Utc::now()).unwrap();
+        let places: Vec<usize> = eliminated.iter().map(|elimination| elimination.place).collect();
+        assert_eq!(places, vec![2, 2], "同着の順位になっていません");
+        assert!(tournament.is_finished(), "トーナメントが終了していません");
+
+        // 2位と3位の賞金（90 + 0）を2人で分ける
+        let standings = tournament.standings(&game);
+        let summary: Vec<(usize, &str, u32)> = standings.iter()
+            .map(|standing| (standing.place, standing.name.as_str(), standing.prize))
+            .collect();
+        assert_eq!(summary, vec![
+            (1, "プレイヤー1", 210),
+            (2, "プレイヤー2", 45),
+            (2, "プレイヤー3", 45),
+        ], "順位表が一致しません");
+    }
+
+    #[test]
+    fn ハンドに配られていないチップのないプレイヤーは脱落者に含めない() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 1, 2).unwrap();
+        for (name, chips) in [("プレイヤー1", 1000), ("プレイヤー2", 200), ("チップなし", 0)] {
+            game.add_player(Player::new(name.to_string(), chips)).unwrap();
+        }
+        game.set_blinds(1, 2, 1).unwrap();
+        deal_hand(&mut game);
+        assert_eq!(game.hand_history().unwrap().seats.len(), 2, "チップのないプレイヤーに配られています");
+
+        for _ in 0..2 {
+            let current = game.current_player_index();
+            GameRuleService::process_action(&mut game, current, BetAction::AllIn, None).unwrap();
+        }
+        finish_showdown(&mut game);
+
+        let eliminated = Elimination::from_finished_hand(&game, 2).unwrap();
+        let summary: Vec<(&str, usize)> = eliminated.iter().map(|elimination| (elimination.name.as_str(), elimination.place)).collect();
+        assert_eq!(summary, vec![("プレイヤー2", 2)], "脱落者が一致しません");
+    }
+
+    #[test]
+    fn 時間経過でブラインドが上がる() {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー3".to_string(), 1000)).unwrap();
+        let schedule = BlindSchedule::new(levels(), LevelDuration::Minutes(10)).unwrap();
+        let mut tournament = Tournament::new(schedule, PrizeStructure::winner_takes_all(), 50);
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 19, 0, 0).unwrap();
+        tournament.start(&mut game, start).unwrap();
+
+        deal_hand(&mut game);
+        GameRuleService::process_action(&mut game, 1, BetAction::Fold, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Fold, None).unwrap();
+        finish_showdown(&mut game);
+
+        tournament.finish_hand(&mut game, start + Duration::minutes(21)).unwrap();
+        assert_eq!(tournament.level_index(), 2, "経過時間に応じたレベルになっていません");
+        assert_eq!(game.big_blind(), 50, "ビッグブラインドが上がっていません");
+    }
+
+    #[test]
+    fn 分配前のハンドは締めくくれない() {
+        let (mut game, mut tournament) = sit_and_go(&[1000, 1000]);
+        deal_hand(&mut game);
+        assert!(tournament.finish_hand(&mut game, Utc::now()).is_err(), "ハンドの途中で締めくくれてしまいます");
+    }
+}
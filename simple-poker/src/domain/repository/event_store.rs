@@ -0,0 +1,21 @@
+use crate::domain::model::error::DomainError;
+use crate::domain::model::event::{DomainEvent, GameEvent};
+use crate::domain::model::game::GameId;
+
+/// ゲームごとのイベントを追記のみで保存するストア
+///
+/// 一度保存したイベントは書き換えも削除もしない。
+/// イベントパブリッシャーから共有して使えるように、実装は複製しても同じ保存先を指す。
+pub trait EventStore {
+    /// イベントを末尾に追記し、そのゲームでのイベントの番号（0始まり）を返す
+    fn append(&self, event: &GameEvent) -> Result<usize, DomainError>;
+    /// ゲームのイベントを発生順に取得する
+    fn load(&self, game_id: &GameId) -> Result<Vec<GameEvent>, DomainError>;
+    /// イベントが保存されているゲームのID
+    fn game_ids(&self) -> Result<Vec<GameId>, DomainError>;
+}
+
+/// イベントが属するゲームのID
+pub fn event_game_id(event: &GameEvent) -> GameId {
+    GameId::from_string(event.aggregate_id())
+}
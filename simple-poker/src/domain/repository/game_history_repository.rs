@@ -1,4 +1,5 @@
 use crate::domain::model::game::GameId;
+use crate::domain::model::hand_history::HandHistory;
 use crate::domain::model::player::PlayerId;
 use crate::domain::model::error::DomainError;
 
@@ -10,11 +11,45 @@ pub struct GameHistoryEntry {
     pub pot_amount: u32,
     pub variant: String,
     pub player_count: usize,
+    pub hand_history: Option<HandHistory>, // 古い記録にはない
+}
+
+impl GameHistoryEntry {
+    /// 終了したハンドの記録から履歴エントリを作成する
+    pub fn from_hand_history(hand_history: HandHistory, timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        Self {
+            game_id: hand_history.game_id.clone(),
+            timestamp,
+            winner_ids: hand_history.winner_ids(),
+            pot_amount: hand_history.total_pot(),
+            variant: format!("{:?}", hand_history.variant),
+            player_count: hand_history.seats.len(),
+            hand_history: Some(hand_history),
+        }
+    }
+    
+    /// ハンドに参加したプレイヤーのID（記録がない場合は勝者のみ）
+    pub fn participant_ids(&self) -> Vec<PlayerId> {
+        let mut participants = self.hand_history.as_ref()
+            .map(|history| history.participant_ids())
+            .unwrap_or_default();
+        for winner_id in &self.winner_ids {
+            if !participants.contains(winner_id) {
+                participants.push(winner_id.clone());
+            }
+        }
+        participants
+    }
+    
+    pub fn involves(&self, player_id: &PlayerId) -> bool {
+        self.participant_ids().iter().any(|id| id.value() == player_id.value())
+    }
 }
 
 pub trait GameHistoryRepository {
     fn save(&mut self, entry: &GameHistoryEntry) -> Result<(), DomainError>;
     fn find_by_game_id(&self, game_id: &GameId) -> Option<GameHistoryEntry>;
+    /// 指定したプレイヤーが参加したエントリ（勝者に限らない）
     fn find_by_player_id(&self, player_id: &PlayerId) -> Vec<GameHistoryEntry>;
     fn find_all(&self) -> Vec<GameHistoryEntry>;
-} 
\ No newline at end of file
+}
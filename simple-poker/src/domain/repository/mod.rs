@@ -1,3 +1,4 @@
 pub mod game_repository;
 pub mod player_repository;
 pub mod game_history_repository;
+pub mod event_store;
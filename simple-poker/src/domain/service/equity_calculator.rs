@@ -0,0 +1,328 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::domain::model::card::Card;
+use crate::domain::model::deck::Deck;
+use crate::domain::model::error::DomainError;
+use crate::domain::model::game::GameVariant;
+use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules, HandStrength, LowHand};
+use crate::domain::service::lookup_evaluator::{HandValue, LookupEvaluator};
+
+/// 勝率計算の条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EquityParams {
+    pub variant: GameVariant,
+    pub hands: Vec<Vec<Card>>,  // プレイヤーごとの分かっている手札（足りない分はランダムに配る）
+    pub board: Vec<Card>,       // 開いているコミュニティカード
+    pub dead_cards: Vec<Card>,  // 配られないことが分かっているカード
+    pub samples: usize,         // 全列挙しない場合の試行回数
+    pub seed: Option<u64>,      // 指定すると試行の結果が再現できる
+    pub rules: HandRankingRules, // 役の決まり（ショートデッキでスリーカードとストレートの順番を変える場合など）
+}
+
+impl EquityParams {
+    pub fn new(variant: GameVariant, hands: Vec<Vec<Card>>) -> Self {
+        Self {
+            variant,
+            hands,
+            board: Vec::new(),
+            dead_cards: Vec::new(),
+            samples: EquityCalculator::DEFAULT_SAMPLES,
+            seed: None,
+            rules: variant.hand_ranking_rules(),
+        }
+    }
+}
+
+/// 1人のプレイヤーの勝率
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerEquity {
+    pub wins: u64,   // 単独で勝った回数
+    pub ties: u64,   // 引き分けた回数
+    pub losses: u64,
+    pub equity: f64, // 引き分けを等分したポットの取り分（0.0〜1.0）
+    pub rank_counts: BTreeMap<HandRank, u64>, // 完成した役ごとの回数
+}
+
+impl PlayerEquity {
+    fn new() -> Self {
+        Self { wins: 0, ties: 0, losses: 0, equity: 0.0, rank_counts: BTreeMap::new() }
+    }
+
+    fn trials(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    fn percent(&self, count: u64) -> f64 {
+        match self.trials() {
+            0 => 0.0,
+            trials => count as f64 * 100.0 / trials as f64,
+        }
+    }
+
+    pub fn win_percent(&self) -> f64 {
+        self.percent(self.wins)
+    }
+
+    pub fn tie_percent(&self) -> f64 {
+        self.percent(self.ties)
+    }
+
+    pub fn loss_percent(&self) -> f64 {
+        self.percent(self.losses)
+    }
+
+    /// その役で終わる割合
+    pub fn rank_percent(&self, rank: HandRank) -> f64 {
+        self.percent(self.rank_counts.get(&rank).copied().unwrap_or(0))
+    }
+}
+
+/// 勝率計算の結果
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityResult {
+    pub trials: u64,
+    pub exact: bool, // すべての組み合わせを列挙した場合は true
+    pub players: Vec<PlayerEquity>,
+}
+
+/// 分かっているカードから各プレイヤーの勝率を求めるサービス
+///
+/// 手札がすべて分かっていて、残りのボードの組み合わせが少なければ全列挙し、
+/// それ以外はランダムに配って見積もる。
+pub struct EquityCalculator;
+
+impl EquityCalculator {
+    pub const DEFAULT_SAMPLES: usize = 10_000;
+    /// 全列挙する組み合わせ数の上限
+    pub const EXACT_LIMIT: u64 = 50_000;
+
+    pub fn calculate(params: &EquityParams) -> Result<EquityResult, DomainError> {
+        let hand_size = params.variant.hand_size();
+        let board_size = match params.variant {
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw
+                | GameVariant::SevenCardStud | GameVariant::Razz => 0,
+            GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem => 5,
+        };
+        Self::validate(params, hand_size, board_size)?;
+
+        let known: Vec<Card> = params.hands.iter().flatten()
+            .chain(&params.board)
+            .chain(&params.dead_cards)
+            .copied()
+            .collect();
+        let unseen: Vec<Card> = Self::full_deck(params)?.into_iter()
+            .filter(|card| !known.contains(card))
+            .collect();
+
+        let missing: Vec<usize> = params.hands.iter().map(|hand| hand_size - hand.len()).collect();
+        let board_needed = board_size - params.board.len();
+        let needed = missing.iter().sum::<usize>() + board_needed;
+        if needed > unseen.len() {
+            return Err(DomainError::InvalidGameOperation("配るカードが足りません".into()));
+        }
+
+        let mut players = vec![PlayerEquity::new(); params.hands.len()];
+        let combinations = Self::combination_count(unseen.len(), board_needed);
+        let exact = needed == board_needed && combinations <= Self::EXACT_LIMIT;
+        let trials = if exact {
+            Self::for_each_combination(&unseen, board_needed, |board_rest| {
+                let board: Vec<Card> = params.board.iter().chain(board_rest).copied().collect();
+                Self::settle(params, &params.hands, &board, &mut players);
+            });
+            combinations
+        } else {
+            let mut rng = match params.seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            };
+            let mut deck = unseen;
+            let samples = params.samples.max(1);
+            for _ in 0..samples {
+                let (dealt, _) = deck.partial_shuffle(&mut rng, needed);
+                let mut dealt = dealt.iter().copied();
+                let hands: Vec<Vec<Card>> = params.hands.iter()
+                    .zip(&missing)
+                    .map(|(hand, &count)| hand.iter().copied().chain(dealt.by_ref().take(count)).collect())
+                    .collect();
+                let board: Vec<Card> = params.board.iter().copied().chain(dealt).collect();
+                Self::settle(params, &hands, &board, &mut players);
+            }
+            samples as u64
+        };
+
+        for player in &mut players {
+            player.equity /= trials as f64;
+        }
+        Ok(EquityResult { trials, exact, players })
+    }
+
+    fn validate(params: &EquityParams, hand_size: usize, board_size: usize) -> Result<(), DomainError> {
+        if params.hands.len() < 2 {
+            return Err(DomainError::InvalidGameOperation("勝率の計算には2人以上のプレイヤーが必要です".into()));
+        }
+        if let Some(hand) = params.hands.iter().find(|hand| hand.len() > hand_size) {
+            return Err(DomainError::InvalidCard(format!(
+                "{}の手札は{}枚までです（{}枚指定されています）", params.variant.name(), hand_size, hand.len()
+            )));
+        }
+        if params.board.len() > board_size {
+            return Err(DomainError::InvalidCard(format!(
+                "{}のボードは{}枚までです（{}枚指定されています）", params.variant.name(), board_size, params.board.len()
+            )));
+        }
+
+        let full_deck = Self::full_deck(params)?;
+        let mut seen = Vec::new();
+        for card in params.hands.iter().flatten().chain(&params.board).chain(&params.dead_cards) {
+            if !full_deck.contains(card) {
+                return Err(DomainError::InvalidCard(format!("カード {} は{}のデッキにありません", card, params.variant.name())));
+            }
+            if seen.contains(card) {
+                return Err(DomainError::InvalidCard(format!("カード {} が重複しています", card)));
+            }
+            seen.push(*card);
+        }
+        Ok(())
+    }
+
+    // ゲームの種類とジョーカーの枚数に合わせたデッキのすべてのカード
+    fn full_deck(params: &EquityParams) -> Result<Vec<Card>, DomainError> {
+        Deck::with_jokers(params.variant.deck_ranks(), params.rules.wild_cards().jokers())
+            .map(|deck| deck.cards_in_draw_order())
+            .map_err(|e| DomainError::InvalidCard(e.to_string()))
+    }
+
+    // 1回分の結果を集計する
+    fn settle(params: &EquityParams, hands: &[Vec<Card>], board: &[Card], players: &mut [PlayerEquity]) {
+        let variant = params.variant;
+        // ローだけで勝負するゲームはローの小さい手が勝つ
+        match variant {
+            GameVariant::Razz | GameVariant::DeuceToSevenTripleDraw => {
+                let lows: Vec<Reverse<LowHand>> = hands.iter()
+                    .map(|hand| Reverse(match variant {
+                        GameVariant::Razz => HandEvaluationService::find_best_low_hand(hand, board),
+                        _ => HandEvaluationService::evaluate_deuce_to_seven(hand),
+                    }))
+                    .collect();
+                Self::tally(&lows, Ord::cmp, |low| low.0.rank(), players);
+                return;
+            },
+            GameVariant::OmahaHiLo => {
+                Self::settle_hi_lo(hands, board, &params.rules, players);
+                return;
+            },
+            _ => {},
+        }
+        // 通常の決まりで表で引ける場合は数値の比較だけで済ませる
+        let values: Option<Vec<HandValue>> = (params.rules == HandRankingRules::STANDARD)
+            .then(|| hands.iter().map(|hand| LookupEvaluator::find_best(hand, board, variant)).collect())
+            .flatten();
+        match values {
+            Some(values) => Self::tally(&values, Ord::cmp, |value| value.rank(), players),
+            None => {
+                // 役の表の順位で比べる（ショーダウンと同じ比べ方）
+                let strengths: Vec<HandStrength> = hands.iter()
+                    .map(|hand| HandEvaluationService::find_best_hand_with_rules(hand, board, variant, &params.rules))
+                    .collect();
+                Self::tally(&strengths, |a, b| params.rules.compare(a, b), HandStrength::rank, players);
+            },
+        }
+    }
+
+    // ハイとローでポットを半分ずつ分ける（ローがなければハイの総取り）
+    //
+    // 1人で全体を取った場合だけ勝ちとし、一部だけ取った場合は引き分けに数える。
+    fn settle_hi_lo(hands: &[Vec<Card>], board: &[Card], rules: &HandRankingRules, players: &mut [PlayerEquity]) {
+        let highs: Vec<HandStrength> = hands.iter()
+            .map(|hand| HandEvaluationService::find_best_hand_with_rules(hand, board, GameVariant::OmahaHiLo, rules))
+            .collect();
+        let lows: Vec<Option<LowHand>> = hands.iter()
+            .map(|hand| HandEvaluationService::find_best_omaha_low(hand, board))
+            .collect();
+        let (Some(best_high), best_low) = (highs.iter().max_by(|a, b| rules.compare(a, b)), lows.iter().flatten().min()) else {
+            return;
+        };
+        let is_best_high = |high: &HandStrength| rules.compare(high, best_high) == Ordering::Equal;
+        let high_winners = highs.iter().filter(|high| is_best_high(high)).count();
+        let low_winners = lows.iter().filter(|low| low.is_some() && low.as_ref() == best_low).count();
+        let high_share = if best_low.is_some() { 0.5 } else { 1.0 };
+
+        for ((player, high), low) in players.iter_mut().zip(&highs).zip(&lows) {
+            *player.rank_counts.entry(high.rank()).or_insert(0) += 1;
+            let mut share = 0.0;
+            if is_best_high(high) {
+                share += high_share / high_winners as f64;
+            }
+            if low.is_some() && low.as_ref() == best_low {
+                share += 0.5 / low_winners as f64;
+            }
+            if share == 0.0 {
+                player.losses += 1;
+            } else if share == 1.0 {
+                player.wins += 1;
+            } else {
+                player.ties += 1;
+            }
+            player.equity += share;
+        }
+    }
+
+    fn tally<T>(
+        strengths: &[T],
+        compare: impl Fn(&T, &T) -> Ordering,
+        rank: impl Fn(&T) -> HandRank,
+        players: &mut [PlayerEquity],
+    ) {
+        let Some(best) = strengths.iter().max_by(|a, b| compare(a, b)) else {
+            return;
+        };
+        let is_best = |strength: &T| compare(strength, best) == Ordering::Equal;
+        let winners = strengths.iter().filter(|strength| is_best(strength)).count();
+
+        for (player, strength) in players.iter_mut().zip(strengths) {
+            *player.rank_counts.entry(rank(strength)).or_insert(0) += 1;
+            if !is_best(strength) {
+                player.losses += 1;
+            } else if winners == 1 {
+                player.wins += 1;
+                player.equity += 1.0;
+            } else {
+                player.ties += 1;
+                player.equity += 1.0 / winners as f64;
+            }
+        }
+    }
+
+    fn combination_count(n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+        (0..k as u64).fold(1, |count, i| count * (n as u64 - i) / (i + 1))
+    }
+
+    // cards から k 枚を選ぶすべての組み合わせについて f を呼ぶ
+    fn for_each_combination(cards: &[Card], k: usize, mut f: impl FnMut(&[Card])) {
+        let n = cards.len();
+        let mut indices: Vec<usize> = (0..k).collect();
+        let mut chosen = Vec::with_capacity(k);
+        loop {
+            chosen.clear();
+            chosen.extend(indices.iter().map(|&i| cards[i]));
+            f(&chosen);
+
+            // 右から増やせる位置を探して次の組み合わせに進む
+            let Some(i) = (0..k).rev().find(|&i| indices[i] < n - k + i) else {
+                return;
+            };
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::GameVariant;
+    use crate::domain::service::equity_calculator::{EquityCalculator, EquityParams};
+    use crate::domain::service::hand_evaluation::{HandRank, HandRankingRules};
+    use crate::domain::service::hand_evaluation_specification::HandRankingTable;
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    fn kings() -> Vec<Card> {
+        vec![card(Suit::Spade, 13), card(Suit::Club, 13)]
+    }
+
+    fn queens() -> Vec<Card> {
+        vec![card(Suit::Heart, 12), card(Suit::Diamond, 12)]
+    }
+
+    // ストレートもフラッシュもできないターンまでのボード
+    fn turn_board() -> Vec<Card> {
+        vec![card(Suit::Club, 2), card(Suit::Diamond, 7), card(Suit::Spade, 9), card(Suit::Heart, 3)]
+    }
+
+    #[test]
+    fn リバーまで開いていれば1通りで決まる() {
+        let mut board = turn_board();
+        board.push(card(Suit::Heart, 5));
+        let params = EquityParams { board, ..EquityParams::new(GameVariant::TexasHoldem, vec![kings(), queens()]) };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert!(result.exact, "全列挙されていません");
+        assert_eq!(result.trials, 1, "試行回数が一致しません");
+        assert_eq!(result.players[0].win_percent(), 100.0, "勝率が一致しません");
+        assert_eq!(result.players[1].loss_percent(), 100.0, "負ける割合が一致しません");
+    }
+
+    #[test]
+    fn ターンから全列挙する() {
+        let params = EquityParams { board: turn_board(), ..EquityParams::new(GameVariant::TexasHoldem, vec![kings(), queens()]) };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert!(result.exact, "全列挙されていません");
+        assert_eq!(result.trials, 44, "残りのカードの枚数と一致しません");
+
+        // クイーンが落ちた場合だけ逆転される
+        let kings = &result.players[0];
+        assert_eq!((kings.wins, kings.ties, kings.losses), (42, 0, 2), "勝敗の回数が一致しません");
+        assert!((kings.equity - 42.0 / 44.0).abs() < 1e-9, "取り分が一致しません");
+        assert!((result.players[1].win_percent() - 200.0 / 44.0).abs() < 1e-9, "逆転の割合が一致しません");
+
+        // ボードとペアになればツーペア、キングが落ちればスリーカード
+        assert_eq!(kings.rank_counts.get(&HandRank::TwoPair), Some(&12), "ツーペアの回数が一致しません");
+        assert_eq!(kings.rank_counts.get(&HandRank::ThreeOfAKind), Some(&2), "スリーカードの回数が一致しません");
+        assert_eq!(kings.rank_counts.get(&HandRank::OnePair), Some(&30), "ワンペアの回数が一致しません");
+    }
+
+    #[test]
+    fn 見えているカードは配られない() {
+        let params = EquityParams {
+            board: turn_board(),
+            dead_cards: vec![card(Suit::Spade, 12), card(Suit::Club, 12)],
+            ..EquityParams::new(GameVariant::TexasHoldem, vec![kings(), queens()])
+        };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert_eq!(result.trials, 42, "捨て札が除かれていません");
+        assert_eq!(result.players[0].win_percent(), 100.0, "逆転できないはずです");
+    }
+
+    #[test]
+    fn 引き分けはポットを等分する() {
+        // ボードのストレートを2人とも使う
+        let board = vec![card(Suit::Club, 9), card(Suit::Diamond, 10), card(Suit::Spade, 11), card(Suit::Heart, 12), card(Suit::Club, 13)];
+        let hands = vec![
+            vec![card(Suit::Heart, 2), card(Suit::Diamond, 3)],
+            vec![card(Suit::Spade, 4), card(Suit::Club, 5)],
+        ];
+        let params = EquityParams { board, ..EquityParams::new(GameVariant::TexasHoldem, hands) };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        for player in &result.players {
+            assert_eq!(player.tie_percent(), 100.0, "引き分けになっていません");
+            assert_eq!(player.equity, 0.5, "取り分が等分されていません");
+            assert_eq!(player.rank_percent(HandRank::Straight), 100.0, "役の内訳が一致しません");
+        }
+    }
+
+    #[test]
+    fn 組み合わせが多い場合はシード付きで見積もる() {
+        let seven_deuce = vec![card(Suit::Heart, 7), card(Suit::Club, 2)];
+        let params = EquityParams {
+            samples: 2_000,
+            seed: Some(42),
+            ..EquityParams::new(GameVariant::TexasHoldem, vec![kings(), seven_deuce])
+        };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert!(!result.exact, "プリフロップは全列挙しないはずです");
+        assert_eq!(result.trials, 2_000, "試行回数が一致しません");
+        let equity = result.players[0].equity;
+        assert!((0.8..0.95).contains(&equity), "キングのペアの勝率が想定外です: {}", equity);
+
+        // 同じシードなら同じ結果になる
+        assert_eq!(EquityCalculator::calculate(&params).unwrap(), result, "結果が再現できません");
+    }
+
+    #[test]
+    fn 手札が分からないプレイヤーはランダムに配る() {
+        let quads = vec![card(Suit::Spade, 8), card(Suit::Heart, 8), card(Suit::Diamond, 8), card(Suit::Club, 8), card(Suit::Spade, 2)];
+        let params = EquityParams {
+            samples: 500,
+            seed: Some(1),
+            ..EquityParams::new(GameVariant::FiveCardDraw, vec![quads, Vec::new(), Vec::new()])
+        };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert!(!result.exact, "全列挙されています");
+        assert!(result.players[0].win_percent() > 95.0, "フォーカードの勝率が低すぎます");
+        assert_eq!(result.players[0].rank_percent(HandRank::FourOfAKind), 100.0, "役の内訳が一致しません");
+    }
+
+    #[test]
+    fn オマハはホールカード2枚とボード3枚で評価する() {
+        // ボードのフラッシュはホールカードにハートが2枚ないと使えない
+        let board = vec![card(Suit::Heart, 2), card(Suit::Heart, 6), card(Suit::Heart, 9), card(Suit::Heart, 11)];
+        let hands = vec![
+            vec![card(Suit::Heart, 13), card(Suit::Heart, 4), card(Suit::Club, 5), card(Suit::Diamond, 5)],
+            vec![card(Suit::Spade, 12), card(Suit::Club, 12), card(Suit::Diamond, 12), card(Suit::Heart, 3)],
+        ];
+        let params = EquityParams { board, ..EquityParams::new(GameVariant::Omaha, hands) };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert!(result.exact, "全列挙されていません");
+        assert_eq!(result.trials, 40, "残りのカードの枚数と一致しません");
+        assert_eq!(result.players[0].rank_percent(HandRank::Flush), 100.0, "フラッシュが完成していません");
+        assert_eq!(result.players[1].rank_percent(HandRank::Flush), 0.0, "ハート1枚でフラッシュになっています");
+    }
+
+    #[test]
+    fn オマハ・ハイローはハイとローで半分ずつ分ける() {
+        let board = vec![card(Suit::Club, 2), card(Suit::Diamond, 4), card(Suit::Heart, 7), card(Suit::Spade, 13), card(Suit::Diamond, 12)];
+        let hands = vec![
+            vec![card(Suit::Heart, 13), card(Suit::Club, 13), card(Suit::Spade, 12), card(Suit::Spade, 11)],
+            vec![card(Suit::Heart, 1), card(Suit::Spade, 3), card(Suit::Club, 9), card(Suit::Club, 10)],
+        ];
+        let params = EquityParams { board, ..EquityParams::new(GameVariant::OmahaHiLo, hands) };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert_eq!(result.players[0].equity, 0.5, "ハイの取り分が一致しません");
+        assert_eq!(result.players[1].equity, 0.5, "ローの取り分が一致しません");
+        assert_eq!(result.players[0].tie_percent(), 100.0, "ポットの一部だけ取った場合は引き分けに数えていません");
+    }
+
+    #[test]
+    fn オマハ・ハイローのハイも役の表の順番で比べる() {
+        // ボードに8以下のカードがないのでローはなく、ハイの総取りになる
+        let board = vec![card(Suit::Heart, 13), card(Suit::Diamond, 13), card(Suit::Heart, 9), card(Suit::Heart, 10), card(Suit::Club, 12)];
+        let hands = vec![
+            vec![card(Suit::Club, 13), card(Suit::Diamond, 12), card(Suit::Spade, 3), card(Suit::Spade, 4)],
+            vec![card(Suit::Heart, 1), card(Suit::Heart, 2), card(Suit::Club, 5), card(Suit::Club, 6)],
+        ];
+        let standard = EquityParams { board: board.clone(), ..EquityParams::new(GameVariant::OmahaHiLo, hands.clone()) };
+        let result = EquityCalculator::calculate(&standard).unwrap();
+        assert_eq!(result.players[0].win_percent(), 100.0, "通常の表でフルハウスが勝っていません");
+
+        let rules = HandRankingRules::STANDARD.with_table(HandRankingTable::STANDARD.with_swapped(HandRank::Flush, HandRank::FullHouse));
+        let swapped = EquityParams { board, rules, ..EquityParams::new(GameVariant::OmahaHiLo, hands) };
+        let result = EquityCalculator::calculate(&swapped).unwrap();
+        assert_eq!(result.players[1].win_percent(), 100.0, "フラッシュを強くした表でフラッシュが勝っていません");
+        assert_eq!(result.players[0].loss_percent(), 100.0, "フラッシュを強くした表でフルハウスが負けていません");
+    }
+
+    #[test]
+    fn ショートデッキは36枚から配りフラッシュがフルハウスより強い() {
+        // リバーで 8♠ が出たときだけ 8 のフォーカードが勝つ
+        let board = vec![card(Suit::Heart, 13), card(Suit::Diamond, 13), card(Suit::Heart, 8), card(Suit::Heart, 7)];
+        let hands = vec![
+            vec![card(Suit::Heart, 1), card(Suit::Heart, 9)],
+            vec![card(Suit::Club, 8), card(Suit::Diamond, 8)],
+        ];
+        let params = EquityParams { board, ..EquityParams::new(GameVariant::ShortDeckHoldem, hands) };
+
+        let result = EquityCalculator::calculate(&params).unwrap();
+        assert!(result.exact, "全列挙されていません");
+        assert_eq!(result.trials, 28, "残りのカードが36枚のデッキから数えられていません");
+        assert_eq!(result.players[0].wins, 27, "フラッシュがフルハウスに勝っていません");
+        assert_eq!(result.players[1].wins, 1, "フォーカードの回数が一致しません");
+
+        let two = EquityParams::new(GameVariant::ShortDeckHoldem, vec![vec![card(Suit::Club, 2)], Vec::new()]);
+        assert!(EquityCalculator::calculate(&two).is_err(), "ショートデッキにない2で計算できてしまいます");
+    }
+
+    #[test]
+    fn 不正な条件はエラーになる() {
+        let single = EquityParams::new(GameVariant::TexasHoldem, vec![kings()]);
+        assert!(EquityCalculator::calculate(&single).is_err(), "1人で計算できてしまいます");
+
+        let duplicated = EquityParams::new(GameVariant::TexasHoldem, vec![kings(), kings()]);
+        assert!(EquityCalculator::calculate(&duplicated).is_err(), "重複したカードで計算できてしまいます");
+
+        let mut board = turn_board();
+        board.extend([card(Suit::Heart, 5), card(Suit::Heart, 6)]);
+        let long_board = EquityParams { board, ..EquityParams::new(GameVariant::TexasHoldem, vec![kings(), queens()]) };
+        assert!(EquityCalculator::calculate(&long_board).is_err(), "6枚のボードで計算できてしまいます");
+
+        let long_hand = EquityParams::new(GameVariant::TexasHoldem, vec![[kings(), queens()].concat(), Vec::new()]);
+        assert!(EquityCalculator::calculate(&long_hand).is_err(), "ホールカードが多すぎても計算できてしまいます");
+    }
+}
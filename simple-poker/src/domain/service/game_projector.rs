@@ -0,0 +1,153 @@
+use crate::domain::model::error::DomainError;
+use crate::domain::model::event::{DomainEvent, GameEvent};
+use crate::domain::model::game::Game;
+use crate::domain::model::hand_history::BlindKind;
+use crate::domain::model::player::{Player, PlayerId, PlayerSerializedData};
+use crate::domain::service::game_rule::GameRuleService;
+
+/// イベントの列からゲームを復元するプロジェクター
+///
+/// 操作を表すイベント（`GameCreated`・`PlayerAction` など）ごとに同じ操作をゲームに対して再実行し、
+/// その操作で発生したイベントが記録と一致するかを確かめながら進める。
+/// 配られたカードはシャッフル後のデッキ（`GameStarted`）から再現される。
+pub struct GameProjector;
+
+impl GameProjector {
+    /// すべてのイベントを再生したゲーム
+    pub fn project(events: &[GameEvent]) -> Result<Game, DomainError> {
+        Self::project_until(events, events.len())
+    }
+
+    /// 先頭から count 件のイベントまでを再生したゲーム
+    ///
+    /// 1つの操作で発生したイベントはまとめて再生するため、
+    /// 途中で区切った場合はその操作のイベントの最後まで適用される。
+    pub fn project_until(events: &[GameEvent], count: usize) -> Result<Game, DomainError> {
+        let limit = count.min(events.len());
+        let mut game = None;
+        let mut index = 0;
+        while index < limit {
+            index += Self::apply_next(&mut game, events, index)?;
+        }
+        game.ok_or_else(|| DomainError::InvalidState("GameCreated イベントがありません".into()))
+    }
+
+    /// 操作ごとのゲームの状態（適用したイベント数とその時点のゲーム）
+    pub fn replay(events: &[GameEvent]) -> Result<Vec<(usize, Game)>, DomainError> {
+        let mut game = None;
+        let mut index = 0;
+        let mut states = Vec::new();
+        while index < events.len() {
+            index += Self::apply_next(&mut game, events, index)?;
+            if let Some(game) = &game {
+                states.push((index, game.clone()));
+            }
+        }
+        Ok(states)
+    }
+
+    // index のイベントが表す操作を再実行し、その操作で消費したイベント数を返す
+    fn apply_next(game: &mut Option<Game>, events: &[GameEvent], index: usize) -> Result<usize, DomainError> {
+        let head = &events[index];
+        let produced = Self::apply(game, head).map_err(|e| {
+            DomainError::InvalidState(format!("{}番目のイベント({})を再生できません: {}", index, head.event_type(), e))
+        })?;
+        if produced.is_empty() {
+            return Err(DomainError::InvalidState(format!(
+                "{}番目のイベント({})の操作でイベントが発生しませんでした", index, head.event_type()
+            )));
+        }
+
+        for (offset, event) in produced.iter().enumerate() {
+            let position = index + offset;
+            let matches = events.get(position).is_some_and(|recorded| recorded.same_as(event));
+            if !matches {
+                return Err(DomainError::InvalidState(format!(
+                    "{}番目のイベントが再生結果({})と一致しません", position, event.event_type()
+                )));
+            }
+        }
+        Ok(produced.len())
+    }
+
+    // 操作を表すイベントを再実行し、発生したイベントを返す
+    fn apply(game: &mut Option<Game>, event: &GameEvent) -> Result<Vec<GameEvent>, DomainError> {
+        if let GameEvent::GameCreated { game_id, variant, small_blind, big_blind, betting_structure, seed, .. } = event {
+            if game.is_some() {
+                return Err(DomainError::InvalidState("ゲームは既に作成されています".into()));
+            }
+            let created = game.insert(Game::new_with_id(game_id.clone(), *variant, *small_blind, *big_blind, *betting_structure, *seed)?);
+            return Ok(created.take_events());
+        }
+
+        let game = game.as_mut()
+            .ok_or_else(|| DomainError::InvalidState("ゲームが作成されていません".into()))?;
+        match event {
+            GameEvent::PlayerAdded { player_id, player_name, initial_chips, bot, seat, .. } => {
+                let player = Player::from_serialized(PlayerSerializedData {
+                    id: player_id.clone(),
+                    name: player_name.clone(),
+                    chips_amount: *initial_chips,
+                    cards: Vec::new(),
+                    current_bet: 0,
+                    total_bet: 0,
+                    is_folded: false,
+                    is_all_in: false,
+                    is_dealer: false,
+                    bot: *bot,
+                    sitting_out: false,
+                    missed_blind: false,
+                    seat: 0,
+                })?;
+                match seat {
+                    Some(seat) => game.seat_player(player, *seat)?,
+                    None => game.add_player(player)?,
+                }
+            },
+            GameEvent::PlayerRemoved { player_id, .. } => {
+                game.remove_player(player_id)?;
+            },
+            GameEvent::BlindsChanged { small_blind, big_blind, ante, .. } => {
+                game.set_blinds(*small_blind, *big_blind, *ante)?;
+            },
+            GameEvent::TableRulesChanged { rules, .. } => game.set_rules(*rules)?,
+            GameEvent::ChipsAdded { player_id, amount, .. } => game.add_chips(player_id, *amount)?,
+            GameEvent::PlayerSatOut { player_id, .. } => game.sit_out(player_id)?,
+            GameEvent::PlayerSatIn { player_id, .. } => game.sit_in(player_id)?,
+            GameEvent::GameStarted { deck, .. } => {
+                if !deck.is_empty() {
+                    game.preset_deck(deck.clone())?;
+                }
+                game.start_game()?;
+            },
+            GameEvent::CardsDealt { .. } => game.deal_cards()?,
+            GameEvent::BlindPosted { kind: BlindKind::Ante, .. } => game.post_antes()?,
+            GameEvent::BlindPosted { kind: BlindKind::BringIn, .. } => game.post_bring_in()?,
+            GameEvent::BlindPosted { .. } => game.post_blinds()?,
+            GameEvent::PlayerAction { player_id, action, amount, .. } => {
+                let player_index = Self::player_index(game, player_id)?;
+                GameRuleService::process_action(game, player_index, *action, *amount)?;
+            },
+            GameEvent::BettingRoundEnded { .. } => game.end_betting_round()?,
+            GameEvent::CardsExchanged { player_id, card_indices, .. } => {
+                let player_index = Self::player_index(game, player_id)?;
+                game.exchange_cards(player_index, card_indices)?;
+            },
+            GameEvent::GameEnded { .. } => {
+                GameRuleService::distribute_pot(game)?;
+            },
+            GameEvent::HandReset { .. } => game.reset_for_new_hand()?,
+            GameEvent::GameCreated { .. } => unreachable!("GameCreated は先に処理済み"),
+            GameEvent::BettingRoundStarted { .. } | GameEvent::CommunityCardsDealt { .. } => {
+                return Err(DomainError::InvalidState("他の操作の結果として発生するイベントです".into()));
+            },
+        }
+        Ok(game.take_events())
+    }
+
+    fn player_index(game: &Game, player_id: &PlayerId) -> Result<usize, DomainError> {
+        game.players().iter()
+            .position(|player| player.id() == player_id)
+            .ok_or_else(|| DomainError::ResourceNotFound(format!("プレイヤー {} がゲームに参加していません", player_id)))
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::card::Card;
+    use crate::domain::model::event::{DomainEvent, GameEvent};
+    use crate::domain::model::game::{BettingRound, Game, GamePhase, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::domain::service::game_projector::GameProjector;
+    use crate::domain::service::game_rule::GameRuleService;
+
+    // シャッフルはランダムのまま3人のホールデムを1ハンド進め、発生したイベントを返す
+    fn play_holdem_hand() -> (Game, Vec<GameEvent>) {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー3".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(30)).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Fold, None).unwrap();
+        while game.current_phase() != GamePhase::Showdown {
+            game.end_betting_round().unwrap();
+        }
+        GameRuleService::distribute_pot(&mut game).unwrap();
+
+        let events = game.take_events();
+        (game, events)
+    }
+
+    fn chips(game: &Game) -> Vec<u32> {
+        game.players().iter().map(|player| player.chips()).collect()
+    }
+
+    fn hands(game: &Game) -> Vec<Vec<Card>> {
+        game.players().iter().map(|player| player.hand().cards().to_vec()).collect()
+    }
+
+    #[test]
+    fn すべての操作でイベントが発生する() {
+        let (_, events) = play_holdem_hand();
+        let types: Vec<&str> = events.iter().map(|event| event.event_type()).collect();
+
+        assert_eq!(types[..5], ["GameCreated", "PlayerAdded", "PlayerAdded", "PlayerAdded", "GameStarted"], "準備のイベントが一致しません");
+        assert_eq!(types.iter().filter(|t| **t == "CardsDealt").count(), 3, "配札のイベントが一致しません");
+        assert_eq!(types.iter().filter(|t| **t == "BlindPosted").count(), 2, "ブラインドのイベントが一致しません");
+        assert_eq!(types.iter().filter(|t| **t == "CommunityCardsDealt").count(), 3, "ボードのイベントが一致しません");
+        assert_eq!(types.last(), Some(&"GameEnded"), "最後のイベントが一致しません");
+
+        let GameEvent::CardsDealt { cards, .. } = events.iter().find(|e| e.event_type() == "CardsDealt").unwrap() else {
+            unreachable!();
+        };
+        assert_eq!(cards.len(), 2, "配られたカードがイベントに含まれていません");
+    }
+
+    #[test]
+    fn イベントからゲームを復元する() {
+        let (game, events) = play_holdem_hand();
+        let projected = GameProjector::project(&events).expect("復元に失敗");
+
+        assert_eq!(projected.id(), game.id(), "ゲームIDが一致しません");
+        assert_eq!(projected.current_phase(), game.current_phase(), "フェーズが一致しません");
+        assert_eq!(chips(&projected), chips(&game), "チップが一致しません");
+        assert_eq!(hands(&projected), hands(&game), "手札が一致しません");
+        assert_eq!(projected.community_cards(), game.community_cards(), "コミュニティカードが一致しません");
+        assert_eq!(projected.deck().cards_in_draw_order(), game.deck().cards_in_draw_order(), "デッキが一致しません");
+
+        let history = projected.hand_history().unwrap();
+        let original = game.hand_history().unwrap();
+        assert_eq!(history.actions, original.actions, "アクションの記録が一致しません");
+        assert_eq!(history.payouts, original.payouts, "配当の記録が一致しません");
+    }
+
+    #[test]
+    fn 途中のイベントまでの状態に戻せる() {
+        let (game, events) = play_holdem_hand();
+        let flop = events.iter()
+            .position(|event| matches!(event, GameEvent::BettingRoundStarted { round: BettingRound::Flop, .. }))
+            .unwrap();
+
+        // フロップが始まるまでのイベントでは、まだボードが開いていない
+        let before_flop = GameProjector::project_until(&events, flop - 1).unwrap();
+        assert_eq!(before_flop.current_round(), Some(BettingRound::PreFlop), "ラウンドが一致しません");
+        assert!(before_flop.community_cards().is_empty(), "ボードが開いています");
+
+        // 操作の途中で区切っても、その操作の結果まで適用される
+        let at_flop = GameProjector::project_until(&events, flop + 1).unwrap();
+        assert_eq!(at_flop.current_round(), Some(BettingRound::Flop), "ラウンドが一致しません");
+        assert_eq!(at_flop.community_cards(), &game.community_cards()[..3], "フロップが一致しません");
+
+        let states = GameProjector::replay(&events).unwrap();
+        assert_eq!(states.last().map(|(count, _)| *count), Some(events.len()), "すべてのイベントが再生されていません");
+        assert!(states.iter().any(|(_, state)| state.current_round() == Some(BettingRound::Turn)), "ターンの状態がありません");
+    }
+
+    #[test]
+    fn カード交換と次のハンドを復元する() {
+        let mut game = Game::new(GameVariant::FiveCardDraw, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.end_betting_round().unwrap();
+        game.exchange_cards(0, &[0, 2]).unwrap();
+        game.exchange_cards(1, &[]).unwrap();
+        game.end_betting_round().unwrap();
+        GameRuleService::distribute_pot(&mut game).unwrap();
+        game.reset_for_new_hand().unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+
+        let events = game.take_events();
+        let exchanged = events.iter()
+            .find_map(|event| match event {
+                GameEvent::CardsExchanged { card_indices, drawn, .. } if !drawn.is_empty() => Some(card_indices.clone()),
+                _ => None,
+            })
+            .expect("カード交換のイベントがありません");
+        assert_eq!(exchanged, vec![0, 2], "交換したカードの位置が一致しません");
+
+        let projected = GameProjector::project(&events).expect("復元に失敗");
+        assert_eq!(projected.hand_number(), 1, "ハンド番号が一致しません");
+        assert_eq!(projected.dealer_index(), game.dealer_index(), "ディーラーが一致しません");
+        assert_eq!(hands(&projected), hands(&game), "2ハンド目の手札が一致しません");
+        assert_eq!(chips(&projected), chips(&game), "チップが一致しません");
+    }
+
+    #[test]
+    fn 記録と異なるイベントは再生できない() {
+        let (_, mut events) = play_holdem_hand();
+        let dealt = events.iter().position(|event| event.event_type() == "CardsDealt").unwrap();
+        if let GameEvent::CardsDealt { cards, .. } = &mut events[dealt] {
+            cards.reverse();
+        }
+
+        let result = GameProjector::project(&events);
+        assert!(result.is_err(), "書き換えられたイベントを再生できてしまいます");
+        assert!(GameProjector::project_until(&events, dealt).is_ok(), "書き換えより前のイベントは再生できるはずです");
+
+        // 他の操作の結果として発生するイベントから始めることはできない
+        let (_, events) = play_holdem_hand();
+        let board = events.iter().position(|event| event.event_type() == "CommunityCardsDealt").unwrap();
+        assert!(GameProjector::project(&events[board..]).is_err(), "途中のイベントから再生できてしまいます");
+    }
+}
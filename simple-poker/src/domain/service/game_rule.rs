@@ -1,8 +1,18 @@
-use crate::domain::model::bet::BetAction;
-use crate::domain::model::game::{Game, GamePhase, GameVariant};
+use crate::domain::model::bet::{BetAction, BettingStructure, PotContribution};
+use crate::domain::model::card::{Card, Suit};
+use crate::domain::model::game::{Game, GamePhase, GameVariant, OddChipRule};
+use crate::domain::model::hand_history::{PayoutRecord, ShowdownRecord};
 use crate::domain::model::player::Player;
 use crate::domain::model::error::DomainError;
-use crate::domain::service::hand_evaluation::HandEvaluationService;
+use crate::domain::service::hand_evaluation::{HandEvaluationService, HandStrength, LowHand};
+
+/// 1つのポット（メインまたはサイド）の分配結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotDistribution {
+    pub amount: u32,
+    pub eligible_player_indices: Vec<usize>,
+    pub winners: Vec<(usize, u32)>, // (プレイヤーインデックス, 獲得額)
+}
 
 pub struct GameRuleService;
 
@@ -40,22 +50,71 @@ impl GameRuleService {
             actions.push(BetAction::Call);
         }
         
-        // レイズが可能か
-        if player.chips() > call_amount {
-            let min_raise_amount = call_amount + game.big_blind(); // 最小レイズ額
-            if player.chips() >= min_raise_amount {
-                actions.push(BetAction::Raise);
-            }
+        // レイズが可能か（決まり上の最小額に手持ちのチップで届くか）
+        if Self::raise_range(game, player_index).is_some() {
+            actions.push(BetAction::Raise);
         }
         
-        // オールインは常に可能（ただしチップがある場合）
-        if player.chips() > 0 {
+        // オールインはチップがあり、決まり上の最大額を超えない場合に可能（コールに満たないオールインは常に可能）
+        let all_in_to = player.current_bet() + player.chips();
+        let within_limit = all_in_to <= game.current_bet()
+            || Self::raise_limits(game, player).is_some_and(|(_, max_raise_to)| all_in_to <= max_raise_to);
+        if player.chips() > 0 && within_limit {
             actions.push(BetAction::AllIn);
         }
         
         actions
     }
     
+    /// レイズ後のベット額として指定できる範囲（最小, 最大）
+    ///
+    /// 手持ちのチップで最小額に届かない場合、レイズの回数が上限に達している場合、
+    /// 行動済みでその後に完全なレイズがない場合は None。
+    pub fn raise_range(game: &Game, player_index: usize) -> Option<(u32, u32)> {
+        let player = game.players().get(player_index)?;
+        let (min_raise_to, max_raise_to) = Self::raise_limits(game, player)?;
+        let stack_limit = player.current_bet() + player.chips();
+        (stack_limit >= min_raise_to).then(|| (min_raise_to, max_raise_to.min(stack_limit)))
+    }
+    
+    // ベットの決まりによるレイズ後のベット額の範囲（手持ちのチップは考えない）
+    fn raise_limits(game: &Game, player: &Player) -> Option<(u32, u32)> {
+        // 行動済みのプレイヤーは、その後に完全なレイズがなければレイズできない
+        if !game.is_betting_reopened_for(player.id()) {
+            return None;
+        }
+        let current_bet = game.current_bet();
+        // 最小レイズは直前のレイズと同じ額の上乗せ（スタッドでブリングインだけなら、ビッグブラインドの額まで上げるコンプリート）
+        let min_raise_to = if game.variant().is_stud() && current_bet < game.big_blind() {
+            game.big_blind()
+        } else {
+            current_bet + game.last_raise_size()
+        };
+        match game.betting_structure() {
+            BettingStructure::NoLimit => Some((min_raise_to, u32::MAX)),
+            BettingStructure::PotLimit => {
+                // コールした後のポットの額だけ上乗せできる
+                let call_amount = current_bet.saturating_sub(player.current_bet());
+                let pot_raise_to = current_bet + game.pot().total() + call_amount;
+                Some((min_raise_to, pot_raise_to.max(min_raise_to)))
+            },
+            BettingStructure::FixedLimit { small_bet, big_bet, raise_cap } => {
+                if game.raises_in_round() >= raise_cap {
+                    return None;
+                }
+                let bet_size = match game.current_round() {
+                    Some(round) if round.uses_big_bet(game.variant()) => big_bet,
+                    _ => small_bet,
+                };
+                // ブリングインに対してはスモールベットの額までのコンプリート
+                if current_bet < bet_size && game.variant().is_stud() {
+                    return Some((bet_size, bet_size));
+                }
+                Some((current_bet + bet_size, current_bet + bet_size))
+            },
+        }
+    }
+    
     // プレイヤーのアクションを処理
     pub fn process_action(
         game: &mut Game,
@@ -76,6 +135,9 @@ impl GameRuleService {
             return Err(DomainError::InvalidGameOperation("そのアクションは現在実行できません".into()));
         }
         
+        let chips_before = game.players()[player_index].chips();
+        let bet_before = game.current_bet();
+        
         match action {
             BetAction::Fold => {
                 game.players_mut()[player_index].fold();
@@ -98,13 +160,16 @@ impl GameRuleService {
             },
             BetAction::Raise => {
                 if let Some(raise_to) = bet_amount {
-                    let current_bet = game.current_bet();
                     let player_bet = game.players()[player_index].current_bet();
-                    let min_raise = current_bet + game.big_blind();
+                    let (min_raise, max_raise) = Self::raise_limits(game, &game.players()[player_index])
+                        .ok_or_else(|| DomainError::InvalidBet("このラウンドではこれ以上レイズできません".into()))?;
                     
-                    // レイズは最低でも現在のベット+最小ベット額以上でなければならない
+                    // レイズは最低でも現在のベット+直前のレイズの上乗せ額以上でなければならない
                     if raise_to < min_raise {
-                        return Err(DomainError::InvalidBet(format!("レイズは現在のベット額+最小ベット額以上でなければなりません（最小: {}）", min_raise)));
+                        return Err(DomainError::InvalidBet(format!("レイズは現在のベット額+直前のレイズ額以上でなければなりません（最小: {}）", min_raise)));
+                    }
+                    if raise_to > max_raise {
+                        return Err(DomainError::InvalidBet(format!("{}ではそのレイズはできません（最大: {}）", game.betting_structure().name(), max_raise)));
                     }
                     
                     // プレイヤーがベットする額を計算（既にベットしている額を差し引く）
@@ -141,87 +206,83 @@ impl GameRuleService {
             },
         }
         
+        // ハンドの記録に残す
+        let player = &game.players()[player_index];
+        let amount = chips_before - player.chips();
+        let bet_to = player.current_bet();
+        game.record_action(player_index, action, bet_amount, amount, bet_to);
+        game.mark_acted(player_index, game.current_bet() > bet_before);
+        
         // ラウンドが終了したかチェック
-        Self::check_round_completion(game)?;
+        Self::check_round_completion(game, player_index)?;
         
         Ok(())
     }
     
-    // ラウンドが終了したかチェック
-    fn check_round_completion(game: &mut Game) -> Result<(), DomainError> {
+    // ラウンドが終了したかチェックし、終わっていなければ次に行動するプレイヤーに手番を移す
+    fn check_round_completion(game: &mut Game, player_index: usize) -> Result<(), DomainError> {
         if game.current_phase() != GamePhase::Betting {
             return Ok(());
         }
         
-        // 次のプレイヤーに移動
-        let next_player_index = Self::find_next_active_player(game);
-        let current_player_index = game.current_player_index();
+        // 1人を残して全員がフォールドした
+        if Self::contender_count(game) <= 1 {
+            return game.end_uncontested_hand();
+        }
         
-        // 参照の競合を解消するために、ここで別々のスコープで処理
-        {
-            let players = game.players_mut();
-            if current_player_index < players.len() {
-                players[current_player_index].reset_bet();
-            }
+        if let Some(next_index) = Self::next_player_to_act(game, player_index) {
+            game.set_current_player_index(next_index);
+            return Ok(());
         }
         
-        // 次のプレイヤーが見つからない場合、またはラウンドを一周した場合
-        let dealer_index = game.dealer_index();
-        if next_player_index == dealer_index || next_player_index == current_player_index || Self::is_betting_round_complete(game) {
-            game.end_betting_round().map_err(|e| DomainError::from(e))?;
-        } else {
-            let player_count = game.players().len();
-            let next_index = (current_player_index + 1) % player_count;
-            
-            // 参照の競合を解消するために、ここで別々のスコープで処理
-            {
-                let players = game.players_mut();
-                if next_index < players.len() {
-                    players[next_index].reset_bet();
-                }
-            }
+        // 全員が行動して額がそろった。行動できるプレイヤーがいなければ残りのラウンドも続けて進める
+        game.end_betting_round()?;
+        while game.current_phase() == GamePhase::Betting && Self::is_betting_round_complete(game) {
+            game.end_betting_round()?;
         }
         
         Ok(())
     }
     
-    // 次のアクティブなプレイヤーを探す
-    fn find_next_active_player(game: &Game) -> usize {
+    // 指定した席の次から順に、まだ行動する必要があるプレイヤーを探す
+    fn next_player_to_act(game: &Game, from_index: usize) -> Option<usize> {
         let player_count = game.players().len();
-        let mut index = (game.current_player_index() + 1) % player_count;
-        let start_index = game.current_player_index();
-        
-        while index != start_index {
-            let player = &game.players()[index];
-            if !player.is_folded() && !player.is_all_in() {
-                return index;
-            }
-            index = (index + 1) % player_count;
-        }
-        
-        // 全員がフォールドかオールインの場合、または一周した場合
-        start_index
+        (1..=player_count)
+            .map(|offset| (from_index + offset) % player_count)
+            .find(|&index| Self::needs_to_act(game, index))
     }
     
-    // ベッティングラウンドが完了したかどうかを判定
-    fn is_betting_round_complete(game: &Game) -> bool {
-        // アクティブなプレイヤーの数を取得
+    // このラウンドでまだ行動する必要があるか
+    // （ベット額がそろっていない、またはベット額が上がった後にまだ行動していない）
+    fn needs_to_act(game: &Game, player_index: usize) -> bool {
+        let player = &game.players()[player_index];
+        if player.is_folded() || player.is_all_in() {
+            return false;
+        }
+        if player.current_bet() < game.current_bet() {
+            return true;
+        }
+        // 他に行動できるプレイヤーがいなければ、額がそろっている限り行動は要らない
         let active_players = game.players().iter()
             .filter(|p| !p.is_folded() && !p.is_all_in())
             .count();
-        
-        // アクティブなプレイヤーが0または1の場合、ラウンド終了
-        if active_players <= 1 {
+        active_players > 1 && !game.has_acted_since_raise(player.id())
+    }
+    
+    // フォールドしていないプレイヤーの数
+    fn contender_count(game: &Game) -> usize {
+        game.players().iter().filter(|p| !p.is_folded()).count()
+    }
+    
+    // ベッティングラウンドが完了したかどうかを判定
+    pub(crate) fn is_betting_round_complete(game: &Game) -> bool {
+        // フォールドしていないプレイヤーが1人以下ならラウンド終了
+        if Self::contender_count(game) <= 1 {
             return true;
         }
         
-        // 全員のベット額が現在のベット額と一致しているかチェック
-        let current_bet = game.current_bet();
-        let all_matched = game.players().iter()
-            .filter(|p| !p.is_folded() && !p.is_all_in())
-            .all(|p| p.current_bet() == current_bet);
-        
-        all_matched
+        // 行動が必要なプレイヤーが残っていなければラウンド終了
+        (0..game.players().len()).all(|index| !Self::needs_to_act(game, index))
     }
     
     // ゲームの勝者を決定
@@ -231,77 +292,238 @@ impl GameRuleService {
         }
         
         // フォールドしていないプレイヤーだけを対象にする
-        let active_players: Vec<(usize, &Player)> = game.players().iter()
+        let contenders: Vec<usize> = game.players().iter()
             .enumerate()
             .filter(|(_, p)| !p.is_folded())
+            .map(|(idx, _)| idx)
             .collect();
         
-        if active_players.len() == 1 {
+        // ハイローはハイとローのそれぞれの勝者（両方で勝ったプレイヤーは1回だけ）
+        let mut winners = Self::best_hands_among(game, &contenders);
+        if game.variant().is_hi_lo() {
+            for idx in Self::best_lows_among(game, &contenders) {
+                if !winners.contains(&idx) {
+                    winners.push(idx);
+                }
+            }
+        }
+        winners.into_iter()
+            .map(|idx| (idx, game.players()[idx].name().to_string()))
+            .collect()
+    }
+    
+    // 指定したプレイヤーの中で最強の手を持つプレイヤーのインデックスを返す
+    fn best_hands_among(game: &Game, contenders: &[usize]) -> Vec<usize> {
+        if contenders.len() <= 1 {
             // 1人だけ残っている場合は自動的に勝者
-            return vec![(active_players[0].0, active_players[0].1.name().to_string())];
+            return contenders.to_vec();
         }
         
-        // 各プレイヤーの手の強さを評価
-        let mut player_strengths = Vec::new();
-        
-        for (idx, player) in active_players {
-            let hand_strength = match game.variant() {
-                GameVariant::FiveCardDraw => {
-                    HandEvaluationService::evaluate_hand(player.hand().cards())
-                },
-                _ => {
-                    HandEvaluationService::find_best_hand(
-                        player.hand().cards(),
-                        game.community_cards(),
-                        game.variant()
-                    )
-                },
-            };
-            
-            player_strengths.push((idx, player.name().to_string(), hand_strength));
+        // ローだけで勝負するバリアントはローの最も小さいプレイヤーが勝つ
+        if game.variant().is_low_only() {
+            return Self::best_lows_among(game, contenders);
         }
         
-        // 最強の手を持つプレイヤーを見つける
-        player_strengths.sort_by(|(_, _, a), (_, _, b)| b.cmp(a));
-        
-        let best_strength = &player_strengths[0].2;
-        let winners: Vec<(usize, String)> = player_strengths.iter()
-            .filter(|(_, _, strength)| strength == best_strength)
-            .map(|&(idx, ref name, _)| (idx, name.clone()))
+        // 各プレイヤーの手の強さを評価
+        let player_strengths: Vec<(usize, HandStrength)> = contenders.iter()
+            .map(|&idx| (idx, Self::evaluate_player_hand(game, &game.players()[idx])))
             .collect();
         
-        winners
+        // 最強の手を持つプレイヤーを見つける（役の強さの順番はゲームの決まりに従う）
+        let rules = game.hand_ranking_rules();
+        let best_strength = match player_strengths.iter().map(|(_, strength)| strength).max_by(|a, b| rules.compare(a, b)) {
+            Some(strength) => strength.clone(),
+            None => return Vec::new(),
+        };
+        
+        player_strengths.into_iter()
+            .filter(|(_, strength)| *strength == best_strength)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+    
+    // プレイヤーの手の強さをバリアントに応じて評価
+    fn evaluate_player_hand(game: &Game, player: &Player) -> HandStrength {
+        match game.variant() {
+            GameVariant::FiveCardDraw => {
+                HandEvaluationService::evaluate_hand_with_rules(player.hand().cards(), &game.hand_ranking_rules())
+            },
+            _ => {
+                HandEvaluationService::find_best_hand_with_rules(
+                    player.hand().cards(),
+                    game.community_cards(),
+                    game.variant(),
+                    &game.hand_ranking_rules(),
+                )
+            },
+        }
+    }
+    
+    // 指定したプレイヤーの中でローの最も小さいプレイヤーのインデックス（ローの条件を満たす人がいなければ空）
+    fn best_lows_among(game: &Game, contenders: &[usize]) -> Vec<usize> {
+        let player_lows: Vec<(usize, LowHand)> = contenders.iter()
+            .filter_map(|&idx| Self::low_hand(game, &game.players()[idx]).map(|low| (idx, low)))
+            .collect();
+        let Some(best_low) = player_lows.iter().map(|(_, low)| low).min().cloned() else {
+            return Vec::new();
+        };
+        player_lows.into_iter()
+            .filter(|(_, low)| *low == best_low)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+    
+    // ローで勝負するバリアントでのプレイヤーの手（それ以外や、ハイローでローの条件を満たさない場合は None）
+    fn low_hand(game: &Game, player: &Player) -> Option<LowHand> {
+        let cards = player.hand().cards();
+        match game.variant() {
+            GameVariant::Razz => Some(HandEvaluationService::find_best_low_hand(cards, game.community_cards())),
+            GameVariant::DeuceToSevenTripleDraw => Some(HandEvaluationService::evaluate_deuce_to_seven(cards)),
+            GameVariant::OmahaHiLo => HandEvaluationService::find_best_omaha_low(cards, game.community_cards()),
+            _ => None,
+        }
     }
     
     // ポットを分配
-    pub fn distribute_pot(game: &mut Game) -> Result<Vec<(usize, u32)>, DomainError> {
+    //
+    // 各プレイヤーのハンド全体での拠出額からメインポットとサイドポットを構築し、
+    // ポットごとに獲得資格のあるプレイヤーの中で最強の手に分配する。
+    // ハイローではポットごとにハイとローで半分ずつ分け（ローがいなければハイの総取り）、
+    // 割り切れない端数チップはゲームの端数チップの渡し方に従って渡す。
+    pub fn distribute_pot(game: &mut Game) -> Result<Vec<PotDistribution>, DomainError> {
         if game.current_phase() != GamePhase::Showdown {
             return Err(DomainError::InvalidGameOperation("ショーダウンフェーズでのみポットを分配できます".into()));
         }
         
-        let winners = Self::determine_winners(game);
-        if winners.is_empty() {
-            return Err(DomainError::InvalidGameOperation("勝者が決定できません".into()));
-        }
-        
-        let pot_amount = game.pot().total();
-        let winner_count = winners.len();
-        
-        // 単純に等分（サイドポットは省略）
-        let amount_per_winner = pot_amount / winner_count as u32;
-        let mut distribution = Vec::new();
+        let contributions: Vec<PotContribution> = game.players().iter()
+            .map(|p| PotContribution {
+                player_id: p.id().value().to_string(),
+                amount: p.total_bet(),
+                is_folded: p.is_folded(),
+            })
+            .collect();
+        let pots = game.pot().build_pots(&contributions);
         
-        for (idx, _) in &winners {
-            game.players_mut()[*idx].add_chips(amount_per_winner);
-            distribution.push((*idx, amount_per_winner));
+        let mut distributions = Vec::with_capacity(pots.len());
+        for (chips, eligible_ids) in pots {
+            let mut eligible: Vec<usize> = eligible_ids.iter()
+                .filter_map(|id| game.players().iter().position(|p| p.id().value() == id))
+                .collect();
+            
+            // 獲得資格者がいない場合はフォールドしていない全員を対象にする
+            if eligible.is_empty() {
+                eligible = game.players().iter()
+                    .enumerate()
+                    .filter(|(_, p)| !p.is_folded())
+                    .map(|(idx, _)| idx)
+                    .collect();
+            }
+            
+            let winners = Self::best_hands_among(game, &eligible);
+            if winners.is_empty() {
+                return Err(DomainError::InvalidGameOperation("勝者が決定できません".into()));
+            }
+            
+            let amount = chips.amount();
+            let low_winners = if game.variant().is_hi_lo() {
+                Self::best_lows_among(game, &eligible)
+            } else {
+                Vec::new()
+            };
+            // ハイの側が端数を含む半分を取る
+            let low_amount = if low_winners.is_empty() { 0 } else { amount / 2 };
+            let mut winner_shares = Self::split_among(game, winners, amount - low_amount);
+            for (idx, won) in Self::split_among(game, low_winners, low_amount) {
+                match winner_shares.iter_mut().find(|(winner, _)| *winner == idx) {
+                    Some((_, total)) => *total += won,
+                    None => winner_shares.push((idx, won)),
+                }
+            }
+            
+            for &(idx, won) in &winner_shares {
+                game.players_mut()[idx].add_chips(won);
+            }
+            
+            distributions.push(PotDistribution {
+                amount,
+                eligible_player_indices: eligible,
+                winners: winner_shares,
+            });
         }
         
+        // ショーダウンした手と配当をハンドの記録に残す
+        let showdown = Self::showdown_records(game);
+        let payouts = distributions.iter()
+            .map(|distribution| PayoutRecord {
+                amount: distribution.amount,
+                eligible_player_ids: distribution.eligible_player_indices.iter()
+                    .map(|&idx| game.players()[idx].id().clone())
+                    .collect(),
+                winners: distribution.winners.iter()
+                    .map(|&(idx, won)| (game.players()[idx].id().clone(), won))
+                    .collect(),
+            })
+            .collect();
+        game.record_showdown(showdown, payouts);
+        
         // ポットをクリア
         game.pot_mut().clear();
         
-        // ゲームフェーズを完了に設定
-        // この部分は実際には別のサービスメソッドで行うべきかもしれません
+        Ok(distributions)
+    }
+    
+    // 2人以上が残った場合に公開された手（全員がフォールドした場合は公開しない）
+    fn showdown_records(game: &Game) -> Vec<ShowdownRecord> {
+        let contenders: Vec<&Player> = game.players().iter().filter(|p| !p.is_folded()).collect();
+        if contenders.len() < 2 {
+            return Vec::new();
+        }
         
-        Ok(distribution)
+        contenders.into_iter()
+            .map(|player| ShowdownRecord {
+                player_id: player.id().clone(),
+                cards: player.hand().cards().to_vec(),
+                strength: Self::evaluate_player_hand(game, player),
+                low: Self::low_hand(game, player),
+            })
+            .collect()
+    }
+    
+    // 勝者で等分し、割り切れない端数チップは端数チップの渡し方の順に1枚ずつ渡す
+    fn split_among(game: &Game, winners: Vec<usize>, amount: u32) -> Vec<(usize, u32)> {
+        if winners.is_empty() {
+            return Vec::new();
+        }
+        let winners = match game.rules().odd_chip_rule {
+            OddChipRule::LeftOfButton => Self::order_from_dealer(game, winners),
+            OddChipRule::HighestCard => Self::order_by_highest_card(game, winners),
+        };
+        let share = amount / winners.len() as u32;
+        let odd_chips = amount % winners.len() as u32;
+        winners.iter()
+            .enumerate()
+            .map(|(i, &idx)| (idx, share + if (i as u32) < odd_chips { 1 } else { 0 }))
+            .collect()
+    }
+    
+    // 手札の最も高いカード（Aが最も高く、同じランクならスペード、ハート、ダイヤ、クラブの順）の強い順に並べ替える
+    fn order_by_highest_card(game: &Game, mut indices: Vec<usize>) -> Vec<usize> {
+        let card_order = |card: &Card| {
+            let rank = if card.is_ace() { 14 } else { card.rank() };
+            let suit = Suit::all().iter().position(|&suit| suit == card.suit()).unwrap_or(0);
+            (rank, suit)
+        };
+        indices.sort_by_key(|&idx| {
+            std::cmp::Reverse(game.players()[idx].hand().cards().iter().map(card_order).max())
+        });
+        indices
+    }
+    
+    // ディーラーの左隣から時計回りの順に並べ替える
+    fn order_from_dealer(game: &Game, mut indices: Vec<usize>) -> Vec<usize> {
+        let player_count = game.players().len().max(1);
+        let dealer_index = game.dealer_index();
+        indices.sort_by_key(|&idx| (idx + player_count - dealer_index - 1) % player_count);
+        indices
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::service::game_rule::GameRuleService;
+    use crate::domain::model::bet::{BetAction, BettingStructure};
+    use crate::domain::model::card::{Card, JokerRule, Suit, WildCards};
     use crate::domain::model::player::Player;
-    use crate::domain::model::game::{Game, GameVariant};
+    use crate::domain::model::game::{BettingRound, Game, GameVariant, GamePhase, OddChipRule, ShortDeckTripsRule, TableRules};
+    use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules};
+    use crate::domain::service::hand_evaluation_specification::HandRankingTable;
+    use crate::domain::service::game_projector::GameProjector;
 
     // テスト用のゲーム作成
     fn create_test_game() -> Game {
@@ -128,7 +132,8 @@ mod tests {
         let mut game = create_test_game();
         
         // プレイヤー0のチップを減らす（テスト用）
-        game.players_mut()[0].place_bet(900).unwrap(); // 残り100チップ
+        let bet = game.players_mut()[0].place_bet(900).unwrap(); // 残り100チップ
+        game.pot_mut().add(bet);
         
         // プレイヤー0がオールイン
         GameRuleService::process_action(&mut game, 0, BetAction::AllIn, None).unwrap();
@@ -147,13 +152,15 @@ mod tests {
         // ベット設定
         game.set_current_bet(50);
         
-        // 全プレイヤーが同額をベット
-        for i in 0..3 {
-            game.players_mut()[i].place_bet(50).unwrap();
-        }
+        // 全プレイヤーが同額をコール
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+        assert!(!GameRuleService::is_betting_round_complete(&game));
+        GameRuleService::process_action(&mut game, 2, BetAction::Call, None).unwrap();
         
-        // これでラウンド終了と判定される
-        assert!(GameRuleService::is_betting_round_complete(&game));
+        // 全員が行動して額がそろったのでラウンドが終わり、カード交換に進む
+        assert_eq!(game.current_round(), Some(BettingRound::PostDraw));
+        assert_eq!(game.current_phase(), GamePhase::Drawing);
     }
 
     #[test]
@@ -193,6 +200,7 @@ mod tests {
         game.players_mut()[2].fold();
         
         // 勝者の決定
+        game.set_current_phase(GamePhase::Showdown);
         let winners = GameRuleService::determine_winners(&game);
         
         // プレイヤー0だけが勝者
@@ -204,6 +212,9 @@ mod tests {
     fn ポット分配() {
         let mut game = create_test_game();
         
+        // ショーダウンフェーズに設定（テスト用）
+        game.set_current_phase(GamePhase::Showdown);
+        
         // ポットに追加
         game.pot_mut().add(300);
         
@@ -230,30 +241,19 @@ mod tests {
         // ポットに追加
         game.pot_mut().add(300);
         
-        // プレイヤー0と1に同じ手札を持たせる（テスト用にランクを同じにする）
-        let cards = vec![
-            Card::new(Suit::Heart, 10).unwrap(),
-            Card::new(Suit::Diamond, 10).unwrap(),
-            Card::new(Suit::Club, 10).unwrap(),
-            Card::new(Suit::Spade, 10).unwrap(),
-            Card::new(Suit::Heart, 13).unwrap(),
-        ];
-        
-        for card in &cards {
-            game.players_mut()[0].hand_mut().add_card(*card).unwrap();
-            game.players_mut()[1].hand_mut().add_card(*card).unwrap();
-        }
-        
-        // 勝者決定（実装依存のため、テスト可能かは状況による）
-        let winners = vec![(0, "プレイヤー1".to_string()), (1, "プレイヤー2".to_string())];
+        // プレイヤー0と1に同じ役を持たせる
+        set_hand(&mut game, 0, &[(Suit::Heart, 10), (Suit::Diamond, 10), (Suit::Club, 10), (Suit::Spade, 10), (Suit::Heart, 13)]);
+        set_hand(&mut game, 1, &[(Suit::Heart, 10), (Suit::Diamond, 10), (Suit::Club, 10), (Suit::Spade, 10), (Suit::Heart, 13)]);
+        game.players_mut()[2].fold();
         
         // ポット分配
         let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
         
         // ポットは等分される
-        assert_eq!(distribution.len(), 2);
-        assert_eq!(distribution[0].1, 150); // 300/2 = 150
-        assert_eq!(distribution[1].1, 150);
+        assert_eq!(distribution.len(), 1);
+        assert_eq!(distribution[0].winners.len(), 2);
+        assert_eq!(distribution[0].winners[0].1, 150); // 300/2 = 150
+        assert_eq!(distribution[0].winners[1].1, 150);
         
         // プレイヤーのチップが増える
         assert_eq!(game.players()[0].chips(), 1150); // 元の1000 + 150
@@ -262,4 +262,768 @@ mod tests {
         // ポットは空になる
         assert_eq!(game.pot().total(), 0);
     }
-} 
\ No newline at end of file
+
+    // 手札を差し替える（テスト用）
+    fn set_hand(game: &mut Game, player_index: usize, cards: &[(Suit, u8)]) {
+        let hand = game.players_mut()[player_index].hand_mut();
+        hand.clear();
+        for &(suit, rank) in cards {
+            hand.add_card(Card::new(suit, rank).unwrap()).unwrap();
+        }
+    }
+
+    // 各プレイヤーの拠出額をポットに投入する（テスト用）
+    fn contribute(game: &mut Game, amounts: &[u32]) {
+        for (i, &amount) in amounts.iter().enumerate() {
+            let bet = game.players_mut()[i].place_bet(amount).unwrap();
+            game.pot_mut().add(bet);
+        }
+    }
+
+    fn create_short_stack_game(stacks: &[u32]) -> Game {
+        let mut game = Game::new(GameVariant::FiveCardDraw, 5, 10).unwrap();
+        for (i, &chips) in stacks.iter().enumerate() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), chips)).unwrap();
+        }
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.set_current_phase(GamePhase::Showdown);
+        game
+    }
+
+    #[test]
+    fn サイドポット_ショートスタックが最強の場合() {
+        // プレイヤー0は100チップでオールイン、1と2は300ずつ
+        let mut game = create_short_stack_game(&[100, 1000, 1000]);
+        contribute(&mut game, &[100, 300, 300]);
+        
+        set_hand(&mut game, 0, &[(Suit::Heart, 9), (Suit::Diamond, 9), (Suit::Club, 9), (Suit::Spade, 9), (Suit::Heart, 2)]);
+        set_hand(&mut game, 1, &[(Suit::Heart, 13), (Suit::Diamond, 13), (Suit::Club, 13), (Suit::Spade, 4), (Suit::Heart, 3)]);
+        set_hand(&mut game, 2, &[(Suit::Heart, 12), (Suit::Diamond, 12), (Suit::Club, 5), (Suit::Spade, 6), (Suit::Heart, 7)]);
+        
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        
+        // メインポット 100×3 = 300 はプレイヤー0、サイドポット 200×2 = 400 はプレイヤー1
+        assert_eq!(distribution.len(), 2);
+        assert_eq!(distribution[0].amount, 300);
+        assert_eq!(distribution[0].eligible_player_indices, vec![0, 1, 2]);
+        assert_eq!(distribution[0].winners, vec![(0, 300)]);
+        assert_eq!(distribution[1].amount, 400);
+        assert_eq!(distribution[1].eligible_player_indices, vec![1, 2]);
+        assert_eq!(distribution[1].winners, vec![(1, 400)]);
+        
+        assert_eq!(game.players()[0].chips(), 300);
+        assert_eq!(game.players()[1].chips(), 700 + 400);
+        assert_eq!(game.players()[2].chips(), 700);
+        assert_eq!(game.pot().total(), 0);
+    }
+
+    #[test]
+    fn サイドポット_複数のオールインとフォールド() {
+        // プレイヤー0: 50オールイン、1: 150オールイン、2: 400、3: 200拠出後フォールド
+        let mut game = create_short_stack_game(&[50, 150, 1000, 1000]);
+        contribute(&mut game, &[50, 150, 400, 200]);
+        game.players_mut()[3].fold();
+        
+        set_hand(&mut game, 0, &[(Suit::Heart, 1), (Suit::Diamond, 1), (Suit::Club, 1), (Suit::Spade, 4), (Suit::Heart, 3)]);
+        set_hand(&mut game, 1, &[(Suit::Heart, 13), (Suit::Diamond, 13), (Suit::Club, 8), (Suit::Spade, 4), (Suit::Diamond, 3)]);
+        set_hand(&mut game, 2, &[(Suit::Heart, 12), (Suit::Diamond, 12), (Suit::Club, 5), (Suit::Spade, 6), (Suit::Heart, 7)]);
+        set_hand(&mut game, 3, &[(Suit::Spade, 1), (Suit::Club, 13), (Suit::Spade, 13), (Suit::Club, 12), (Suit::Spade, 12)]);
+        
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        
+        // メイン: 50×4 = 200、サイド1: 100×3 = 300、サイド2: 250 + 50（フォールド分） = 300
+        assert_eq!(distribution.len(), 3);
+        assert_eq!(distribution[0].amount, 200);
+        assert_eq!(distribution[0].winners, vec![(0, 200)]);
+        assert_eq!(distribution[1].amount, 300);
+        assert_eq!(distribution[1].eligible_player_indices, vec![1, 2]);
+        assert_eq!(distribution[1].winners, vec![(1, 300)]);
+        assert_eq!(distribution[2].amount, 300);
+        assert_eq!(distribution[2].eligible_player_indices, vec![2]);
+        assert_eq!(distribution[2].winners, vec![(2, 300)]);
+        
+        // 配られたチップの合計は拠出額の合計と一致する
+        let total: u32 = distribution.iter().map(|d| d.amount).sum();
+        assert_eq!(total, 50 + 150 + 400 + 200);
+        assert_eq!(game.players()[3].chips(), 800);
+    }
+
+    #[test]
+    fn サイドポット_端数チップはディーラーの左隣から配る() {
+        let mut game = create_short_stack_game(&[1000, 1000, 1000]);
+        contribute(&mut game, &[101, 101, 101]);
+        game.players_mut()[0].fold();
+        
+        set_hand(&mut game, 1, &[(Suit::Heart, 10), (Suit::Diamond, 10), (Suit::Club, 4), (Suit::Spade, 5), (Suit::Heart, 7)]);
+        set_hand(&mut game, 2, &[(Suit::Spade, 10), (Suit::Club, 10), (Suit::Diamond, 4), (Suit::Heart, 5), (Suit::Diamond, 7)]);
+        
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        
+        // 303チップを2人で分けると端数1チップ。ディーラー（0）の左隣のプレイヤー1が受け取る
+        assert_eq!(distribution.len(), 1);
+        assert_eq!(distribution[0].winners, vec![(1, 152), (2, 151)]);
+    }
+
+    // ブラインドを払った直後のホールデム（ディーラー0、SB1、BB2、最初に行動するのは0）
+    fn create_structured_game(betting_structure: BettingStructure) -> Game {
+        let mut game = Game::new_with_structure(GameVariant::TexasHoldem, 5, 10, betting_structure, None).unwrap();
+        for i in 1..=3 {
+            game.add_player(Player::new(format!("プレイヤー{}", i), 1000)).unwrap();
+        }
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        game
+    }
+
+    #[test]
+    fn ノーリミットでは手持ちのチップまでレイズできる() {
+        let game = create_structured_game(BettingStructure::NoLimit);
+        assert_eq!(GameRuleService::raise_range(&game, 0), Some((20, 1000)), "レイズできる範囲が一致しません");
+    }
+
+    #[test]
+    fn ポットリミットの上限はコールした後のポットの額() {
+        let mut game = create_structured_game(BettingStructure::PotLimit);
+
+        // ポット15、コールに10必要なので 10 + 15 + 10 = 35 まで
+        assert_eq!(GameRuleService::raise_range(&game, 0), Some((20, 35)), "レイズできる範囲が一致しません");
+        assert!(GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(40)).is_err(), "ポットを超えてレイズできてしまいます");
+        assert!(!GameRuleService::available_actions(&game, 0).contains(&BetAction::AllIn), "ポットを超えてオールインできてしまいます");
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(35)).unwrap();
+        assert_eq!(game.current_bet(), 35, "ポットサイズのレイズができません");
+    }
+
+    #[test]
+    fn フィックスドリミットは決まった額だけレイズでき上限回数で止まる() {
+        let mut game = create_structured_game(BettingStructure::fixed_limit(10));
+
+        // プリフロップはスモールベット単位
+        assert_eq!(GameRuleService::raise_range(&game, 0), Some((20, 20)), "レイズできる額が一致しません");
+        assert!(GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(30)).is_err(), "決まった額以外でレイズできてしまいます");
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(20)).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Raise, Some(30)).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Raise, Some(40)).unwrap();
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(50)).unwrap();
+        assert_eq!(game.raises_in_round(), 4, "レイズの回数が一致しません");
+
+        // 4回目のレイズで上限に達する
+        let actions = GameRuleService::available_actions(&game, 1);
+        assert!(!actions.contains(&BetAction::Raise), "上限に達した後もレイズできます");
+        assert!(!actions.contains(&BetAction::AllIn), "上限に達した後もオールインできます");
+        assert!(actions.contains(&BetAction::Call), "コールできません");
+        assert!(GameRuleService::process_action(&mut game, 1, BetAction::Raise, Some(60)).is_err(), "上限に達した後もレイズできてしまいます");
+
+        // ターンからはビッグベット単位
+        game.end_betting_round().unwrap();
+        assert_eq!(GameRuleService::raise_range(&game, 1), Some((10, 10)), "フロップのベット額が一致しません");
+        game.end_betting_round().unwrap();
+        assert_eq!(GameRuleService::raise_range(&game, 1), Some((20, 20)), "ターンのベット額が一致しません");
+    }
+
+    #[test]
+    fn ベットの決まりをイベントから復元できる() {
+        let mut game = create_structured_game(BettingStructure::PotLimit);
+        assert_eq!(game.hand_history().unwrap().betting_structure, BettingStructure::PotLimit, "ハンド履歴に記録されていません");
+
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.betting_structure(), BettingStructure::PotLimit, "復元したベットの決まりが一致しません");
+        assert!(
+            Game::new_with_structure(GameVariant::TexasHoldem, 5, 10, BettingStructure::FixedLimit { small_bet: 0, big_bet: 10, raise_cap: 4 }, None).is_err(),
+            "0チップ単位のフィックスドリミットを作成できてしまいます"
+        );
+    }
+
+    // ブラインドを払った直後のノーリミットホールデム（スタックを指定する）
+    fn create_no_limit_game(stacks: &[u32]) -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        for (i, &stack) in stacks.iter().enumerate() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), stack)).unwrap();
+        }
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        game
+    }
+
+    #[test]
+    fn 最小レイズは直前のレイズの上乗せ額() {
+        let mut game = create_no_limit_game(&[1000, 1000, 1000]);
+        assert_eq!(game.last_raise_size(), 10, "最初の上乗せ額はビッグブラインドではありません");
+
+        // 10から40へのレイズで上乗せ額は30になる
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(40)).unwrap();
+        assert_eq!(game.last_raise_size(), 30, "上乗せ額が一致しません");
+        assert_eq!(GameRuleService::raise_range(&game, 1).map(|(min, _)| min), Some(70), "最小レイズ額が一致しません");
+        assert!(GameRuleService::process_action(&mut game, 1, BetAction::Raise, Some(60)).is_err(), "上乗せ額が足りないレイズができてしまいます");
+
+        // さらに大きなレイズで最小額も上がる
+        GameRuleService::process_action(&mut game, 1, BetAction::Raise, Some(100)).unwrap();
+        assert_eq!(game.last_raise_size(), 60, "上乗せ額が一致しません");
+        assert_eq!(GameRuleService::raise_range(&game, 2).map(|(min, _)| min), Some(160), "最小レイズ額が一致しません");
+    }
+
+    #[test]
+    fn レイズに満たないオールインでは行動済みのプレイヤーはレイズできない() {
+        // ビッグブラインドのプレイヤー3は残り50しかない
+        let mut game = create_no_limit_game(&[1000, 1000, 60]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(40)).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+
+        // 40から60へのオールインは上乗せ20で、完全なレイズ（30）に満たない
+        GameRuleService::process_action(&mut game, 2, BetAction::AllIn, None).unwrap();
+        assert_eq!(game.current_bet(), 60, "オールインの額までベット額が上がっていません");
+        assert_eq!(game.last_raise_size(), 30, "レイズに満たないオールインで上乗せ額が変わっています");
+        assert_eq!(game.raises_in_round(), 1, "レイズに満たないオールインがレイズに数えられています");
+
+        for seat in [0, 1] {
+            let actions = GameRuleService::available_actions(&game, seat);
+            assert!(actions.contains(&BetAction::Call), "コールできません");
+            assert!(!actions.contains(&BetAction::Raise), "ベッティングが再開されています");
+            assert!(!actions.contains(&BetAction::AllIn), "オールインでレイズできてしまいます");
+            assert_eq!(GameRuleService::raise_range(&game, seat), None, "レイズできる範囲があります");
+        }
+        assert!(GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(90)).is_err(), "ベッティングが再開されています");
+    }
+
+    #[test]
+    fn 完全なレイズになるオールインは行動済みのプレイヤーにも再びレイズを認める() {
+        let mut game = create_no_limit_game(&[1000, 1000, 80]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(40)).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+
+        // 40から80へのオールインは上乗せ40で完全なレイズ
+        GameRuleService::process_action(&mut game, 2, BetAction::AllIn, None).unwrap();
+        assert_eq!(game.last_raise_size(), 40, "上乗せ額が一致しません");
+        assert!(game.is_betting_reopened_for(game.players()[0].id()), "ベッティングが再開されていません");
+        assert_eq!(GameRuleService::raise_range(&game, 0).map(|(min, _)| min), Some(120), "最小レイズ額が一致しません");
+    }
+
+    #[test]
+    fn ビッグブラインドは全員がコールしても行動できる() {
+        let mut game = create_no_limit_game(&[1000, 1000, 1000]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+
+        // 額はそろっているが、ビッグブラインドはまだ行動していない
+        assert_eq!(game.current_round(), Some(BettingRound::PreFlop), "ビッグブラインドの行動前にラウンドが終わっています");
+        assert_eq!(game.current_player_index(), 2, "ビッグブラインドの手番になっていません");
+        let actions = GameRuleService::available_actions(&game, 2);
+        assert!(actions.contains(&BetAction::Check) && actions.contains(&BetAction::Raise), "ビッグブラインドがチェックかレイズを選べません");
+
+        GameRuleService::process_action(&mut game, 2, BetAction::Check, None).unwrap();
+        assert_eq!(game.current_round(), Some(BettingRound::Flop), "フロップに進んでいません");
+        assert_eq!(game.community_cards().len(), 3, "フロップが配られていません");
+        assert_eq!(game.current_player_index(), 1, "フロップの最初の手番が一致しません");
+    }
+
+    #[test]
+    fn 全員がチェックするまでラウンドは終わらない() {
+        let mut game = create_no_limit_game(&[1000, 1000, 1000]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Check, None).unwrap();
+
+        GameRuleService::process_action(&mut game, 1, BetAction::Check, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Check, None).unwrap();
+        assert_eq!(game.current_round(), Some(BettingRound::Flop), "全員がチェックする前にラウンドが終わっています");
+        assert_eq!(game.current_player_index(), 0, "次の手番が一致しません");
+
+        GameRuleService::process_action(&mut game, 0, BetAction::Check, None).unwrap();
+        assert_eq!(game.current_round(), Some(BettingRound::Turn), "ターンに進んでいません");
+    }
+
+    #[test]
+    fn 複数回のレイズでも全員がそろうまで続きラウンドのベット額とハンドの拠出額を分けて持つ() {
+        let mut game = create_no_limit_game(&[1000, 1000, 1000]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Raise, Some(30)).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Raise, Some(60)).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Call, None).unwrap();
+
+        // 最初にレイズしたプレイヤーはまだ再レイズにコールしていない
+        assert_eq!(game.current_round(), Some(BettingRound::PreFlop), "全員がそろう前にラウンドが終わっています");
+        assert_eq!(game.current_player_index(), 0, "次の手番が一致しません");
+        assert_eq!(game.players()[1].current_bet(), 60, "ラウンド内のベット額が消えています");
+        assert!(game.has_acted_since_raise(game.players()[1].id()), "再レイズしたプレイヤーが行動済みになっていません");
+        assert!(!game.has_acted_since_raise(game.players()[0].id()), "再レイズの前に行動したプレイヤーが行動済みのままです");
+
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        assert_eq!(game.current_round(), Some(BettingRound::Flop), "フロップに進んでいません");
+        assert_eq!(game.pot().total(), 180, "ポットの額が一致しません");
+        for player in game.players() {
+            assert_eq!(player.current_bet(), 0, "ラウンド内のベット額がリセットされていません");
+            assert_eq!(player.total_bet(), 60, "ハンド全体の拠出額が一致しません");
+        }
+    }
+
+    #[test]
+    fn 全員がフォールドしたら残りのラウンドを行わずにショーダウンへ進む() {
+        let mut game = create_no_limit_game(&[1000, 1000, 1000]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Fold, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Fold, None).unwrap();
+
+        assert_eq!(game.current_phase(), GamePhase::Showdown, "ショーダウンに進んでいません");
+        assert!(game.community_cards().is_empty(), "コミュニティカードが配られています");
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        assert_eq!(distribution[0].winners, vec![(2, 15)], "残ったプレイヤーがポットを獲得していません");
+    }
+
+    #[test]
+    fn 全員がオールインしたら残りのカードを配ってショーダウンへ進む() {
+        let mut game = create_no_limit_game(&[1000, 1000, 1000]);
+        GameRuleService::process_action(&mut game, 0, BetAction::AllIn, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Fold, None).unwrap();
+
+        assert_eq!(game.current_phase(), GamePhase::Showdown, "ショーダウンに進んでいません");
+        assert_eq!(game.community_cards().len(), 5, "ボードが最後まで配られていません");
+
+        // イベントから同じ状態を復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.community_cards(), game.community_cards(), "復元したボードが一致しません");
+        assert_eq!(restored.current_phase(), GamePhase::Showdown, "復元したフェーズが一致しません");
+    }
+
+    #[test]
+    fn 二人のときはプリフロップはボタンが先にフロップからは後に行動する() {
+        let mut game = create_no_limit_game(&[1000, 1000]);
+        assert_eq!(game.current_player_index(), 0, "プリフロップの最初の手番がボタンではありません");
+        assert_eq!(game.players()[0].current_bet(), 5, "ボタンがスモールブラインドを払っていません");
+
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        assert_eq!(game.current_player_index(), 1, "ビッグブラインドに手番が回っていません");
+        GameRuleService::process_action(&mut game, 1, BetAction::Check, None).unwrap();
+
+        // フロップからはビッグブラインドが先に行動する
+        assert_eq!(game.current_round(), Some(BettingRound::Flop), "フロップに進んでいません");
+        assert_eq!(game.current_player_index(), 1, "フロップの最初の手番がビッグブラインドではありません");
+        GameRuleService::process_action(&mut game, 1, BetAction::Check, None).unwrap();
+        assert_eq!(game.current_player_index(), 0, "ボタンに手番が回っていません");
+        GameRuleService::process_action(&mut game, 0, BetAction::Check, None).unwrap();
+        assert_eq!(game.current_round(), Some(BettingRound::Turn), "ターンに進んでいません");
+    }
+
+    // デッキの並びを指定してスタッドを始め、ブリングインまで進める（カードは (スート, ランク) で上から順に配られる）
+    fn create_stud_game(variant: GameVariant, player_count: usize, top: &[(Suit, u8)]) -> Game {
+        let mut game = Game::new_with_structure(variant, 5, 10, BettingStructure::fixed_limit(10), None).unwrap();
+        for i in 0..player_count {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), 1000)).unwrap();
+        }
+        let top = top.iter().map(|&(suit, rank)| Card::new(suit, rank).unwrap()).collect();
+        game.preset_deck(top).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_bring_in().unwrap();
+        game
+    }
+
+    // 全員がコールかチェックだけでショーダウンまで進める
+    fn play_to_showdown(game: &mut Game) {
+        while game.current_phase() == GamePhase::Betting {
+            let index = game.current_player_index();
+            let action = if GameRuleService::available_actions(game, index).contains(&BetAction::Call) {
+                BetAction::Call
+            } else {
+                BetAction::Check
+            };
+            GameRuleService::process_action(game, index, action, None).unwrap();
+        }
+    }
+
+    // 2人のスタッドの配り方（3rdストリートの3枚ずつの後、1枚ずつ交互に配る）
+    // プレイヤー1: A♠ 2♦ [3♣] 4♥ 6♠ 9♦ K♣ → ロー 6-4-3-2-A、ハイは K ハイ
+    // プレイヤー2: 8♣ 8♦ [9♣] 9♥ 10♣ J♦ Q♥ → ロー Q-J-10-9-8、ハイは Q ハイのストレート
+    const HEADS_UP_STUD_DECK: [(Suit, u8); 14] = [
+        (Suit::Spade, 1), (Suit::Diamond, 2), (Suit::Club, 3),
+        (Suit::Club, 8), (Suit::Diamond, 8), (Suit::Club, 9),
+        (Suit::Heart, 4), (Suit::Heart, 9),
+        (Suit::Spade, 6), (Suit::Club, 10),
+        (Suit::Diamond, 9), (Suit::Diamond, 11),
+        (Suit::Club, 13), (Suit::Heart, 12),
+    ];
+
+    #[test]
+    fn スタッドは表向きのカードが最も低いプレイヤーがブリングインを払う() {
+        // 表向きのカード: K♠ / 2♦ / 2♣（同じランクはスートの弱い♣が払う）
+        let game = create_stud_game(GameVariant::SevenCardStud, 3, &[
+            (Suit::Heart, 5), (Suit::Heart, 6), (Suit::Spade, 13),
+            (Suit::Club, 5), (Suit::Club, 6), (Suit::Diamond, 2),
+            (Suit::Diamond, 5), (Suit::Diamond, 6), (Suit::Club, 2),
+        ]);
+
+        assert_eq!(game.current_round(), Some(BettingRound::ThirdStreet), "3rdストリートから始まっていません");
+        assert_eq!(game.players()[2].current_bet(), 5, "2♣のプレイヤーがブリングインを払っていません");
+        assert_eq!(game.current_bet(), 5, "ブリングインがベット額になっていません");
+        assert_eq!(game.current_player_index(), 0, "ブリングインの次のプレイヤーから始まっていません");
+        assert_eq!(game.face_up_cards(1), vec![Card::new(Suit::Diamond, 2).unwrap()], "表向きのカードが一致しません");
+        assert_eq!(GameRuleService::raise_range(&game, 0), Some((10, 10)), "スモールベットまでのコンプリートになっていません");
+    }
+
+    #[test]
+    fn ラズは表向きのカードが最も高いプレイヤーがブリングインを払う() {
+        // 表向きのカード: K♦ / A♠ / K♠（Aは低く、同じランクはスートの強い♠が払う）
+        let game = create_stud_game(GameVariant::Razz, 3, &[
+            (Suit::Heart, 5), (Suit::Heart, 6), (Suit::Diamond, 13),
+            (Suit::Club, 5), (Suit::Club, 6), (Suit::Spade, 1),
+            (Suit::Diamond, 5), (Suit::Diamond, 6), (Suit::Spade, 13),
+        ]);
+
+        assert_eq!(game.players()[2].current_bet(), 5, "K♠のプレイヤーがブリングインを払っていません");
+        assert_eq!(game.current_player_index(), 0, "ブリングインの次のプレイヤーから始まっていません");
+    }
+
+    #[test]
+    fn スタッドの4thストリート以降は表向きの手が最も強いプレイヤーから行動する() {
+        // 4thストリートで表向きは K♠3♥ / 2♦2♥ / 2♣9♣ になる
+        let deck = [
+            (Suit::Heart, 5), (Suit::Heart, 6), (Suit::Spade, 13),
+            (Suit::Club, 5), (Suit::Club, 6), (Suit::Diamond, 2),
+            (Suit::Diamond, 5), (Suit::Diamond, 6), (Suit::Club, 2),
+            (Suit::Heart, 3), (Suit::Heart, 2), (Suit::Club, 9),
+        ];
+        let mut game = create_stud_game(GameVariant::SevenCardStud, 3, &deck);
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+
+        assert_eq!(game.current_round(), Some(BettingRound::FourthStreet), "ブリングインにそろった後に4thストリートへ進んでいません");
+        assert_eq!(game.players()[1].hand().cards().len(), 4, "4枚目が配られていません");
+        assert_eq!(game.current_player_index(), 1, "ペアを見せているプレイヤーから始まっていません");
+
+        // ラズでは表向きのローが最も小さいプレイヤーから（ブリングインは K♠）
+        let mut game = create_stud_game(GameVariant::Razz, 3, &deck);
+        GameRuleService::process_action(&mut game, 1, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 2, BetAction::Call, None).unwrap();
+
+        assert_eq!(game.current_round(), Some(BettingRound::FourthStreet), "4thストリートへ進んでいません");
+        assert_eq!(game.current_player_index(), 2, "9-2を見せているプレイヤーから始まっていません");
+    }
+
+    #[test]
+    fn ラズはローが最も小さい手が勝つ() {
+        let mut game = create_stud_game(GameVariant::Razz, 2, &HEADS_UP_STUD_DECK);
+        play_to_showdown(&mut game);
+
+        assert_eq!(game.current_phase(), GamePhase::Showdown, "ショーダウンに進んでいません");
+        assert_eq!(game.players()[0].hand().cards().len(), 7, "7枚配られていません");
+        let winners: Vec<usize> = GameRuleService::determine_winners(&game).into_iter().map(|(idx, _)| idx).collect();
+        assert_eq!(winners, vec![0], "6-4-3-2-Aのローが勝っていません");
+
+        GameRuleService::distribute_pot(&mut game).unwrap();
+        let showdown = &game.hand_history().unwrap().showdown;
+        assert!(showdown.iter().all(|record| record.low.is_some()), "ショーダウンの記録にローがありません");
+    }
+
+    #[test]
+    fn セブンカードスタッドは7枚から最強の5枚で勝負する() {
+        let mut game = create_stud_game(GameVariant::SevenCardStud, 2, &HEADS_UP_STUD_DECK);
+        play_to_showdown(&mut game);
+
+        let winners: Vec<usize> = GameRuleService::determine_winners(&game).into_iter().map(|(idx, _)| idx).collect();
+        assert_eq!(winners, vec![1], "ストレートが勝っていません");
+
+        // 途中のストリートで配ったカードもイベントから復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.players()[1].hand().cards(), game.players()[1].hand().cards(), "復元した手札が一致しません");
+        assert_eq!(restored.current_phase(), GamePhase::Showdown, "復元したフェーズが一致しません");
+    }
+
+    #[test]
+    fn トリプルドローは3回交換してデュース・トゥ・セブンのローで勝負する() {
+        // プレイヤー1: 7-5-4-3-2、プレイヤー2: A-5-4-3-2（Aは高く、ストレートにもならない）
+        let mut game = Game::new_with_structure(GameVariant::DeuceToSevenTripleDraw, 5, 10, BettingStructure::fixed_limit(10), None).unwrap();
+        for i in 1..=2 {
+            game.add_player(Player::new(format!("プレイヤー{}", i), 1000)).unwrap();
+        }
+        let top = [
+            (Suit::Spade, 7), (Suit::Diamond, 5), (Suit::Club, 4), (Suit::Heart, 3), (Suit::Spade, 2),
+            (Suit::Spade, 1), (Suit::Diamond, 2), (Suit::Club, 3), (Suit::Heart, 4), (Suit::Spade, 5),
+        ];
+        game.preset_deck(top.iter().map(|&(suit, rank)| Card::new(suit, rank).unwrap()).collect()).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+
+        // 交換はすべてスタンドパットにして、ラウンドごとのレイズ額を確かめる
+        let mut raise_sizes = Vec::new();
+        while game.current_phase() != GamePhase::Showdown {
+            let index = game.current_player_index();
+            if game.current_phase() == GamePhase::Drawing {
+                game.exchange_cards(index, &[]).unwrap();
+                continue;
+            }
+            if raise_sizes.last().map(|&(round, _)| round) != Some(game.current_round()) {
+                raise_sizes.push((game.current_round(), GameRuleService::raise_range(&game, index).map(|(min, _)| min)));
+            }
+            GameRuleService::process_action(&mut game, index, BetAction::Check, None).unwrap();
+        }
+
+        assert_eq!(raise_sizes, vec![
+            (Some(BettingRound::PreDraw), Some(10)),
+            (Some(BettingRound::PostDraw), Some(10)),
+            (Some(BettingRound::PostSecondDraw), Some(20)),
+            (Some(BettingRound::PostThirdDraw), Some(20)),
+        ], "ラウンドの順番かフィックスドリミットの額が一致しません");
+        let draws = &game.hand_history().unwrap().draws;
+        assert_eq!(draws.len(), 6, "3回の交換が記録されていません");
+        assert_eq!(draws[4].round, BettingRound::PostThirdDraw, "3回目の交換のラウンドが一致しません");
+
+        let winners: Vec<usize> = GameRuleService::determine_winners(&game).into_iter().map(|(idx, _)| idx).collect();
+        assert_eq!(winners, vec![0], "7-5-4-3-2が勝っていません");
+
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.current_phase(), GamePhase::Showdown, "復元したフェーズが一致しません");
+    }
+
+    // デッキの並びを指定してボードを使うゲームをショーダウンまで進める（手札はプレイヤー順に4枚ずつ、続けてボード5枚）
+    fn play_omaha_hi_lo(hands: &[[(Suit, u8); 4]], board: [(Suit, u8); 5], odd_chip_rule: OddChipRule) -> Game {
+        let mut game = Game::new(GameVariant::OmahaHiLo, 5, 10).unwrap();
+        for i in 0..hands.len() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), 1000)).unwrap();
+        }
+        game.set_rules(TableRules { odd_chip_rule, ..TableRules::default() }).unwrap();
+        let top = hands.iter().flatten().chain(&board)
+            .map(|&(suit, rank)| Card::new(suit, rank).unwrap())
+            .collect();
+        game.preset_deck(top).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        play_to_showdown(&mut game);
+        game
+    }
+
+    // K のスリーカードでハイを取る手
+    const KINGS: [(Suit, u8); 4] = [(Suit::Heart, 13), (Suit::Club, 13), (Suit::Spade, 12), (Suit::Spade, 11)];
+    // 7-4-3-2-A のローになるボード
+    const LOW_BOARD: [(Suit, u8); 5] = [(Suit::Club, 2), (Suit::Diamond, 4), (Suit::Heart, 7), (Suit::Spade, 13), (Suit::Diamond, 12)];
+
+    #[test]
+    fn オマハ・ハイローはハイとローでポットを半分ずつ分ける() {
+        let mut game = play_omaha_hi_lo(&[
+            KINGS,
+            [(Suit::Heart, 1), (Suit::Spade, 3), (Suit::Club, 9), (Suit::Club, 10)],
+            [(Suit::Heart, 5), (Suit::Heart, 6), (Suit::Diamond, 9), (Suit::Diamond, 10)],
+        ], LOW_BOARD, OddChipRule::LeftOfButton);
+        assert_eq!(game.current_phase(), GamePhase::Showdown, "ショーダウンに進んでいません");
+
+        let winners: Vec<usize> = GameRuleService::determine_winners(&game).into_iter().map(|(idx, _)| idx).collect();
+        assert_eq!(winners, vec![0, 1], "ハイとローの勝者が一致しません");
+
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        assert_eq!(distribution[0].amount, 30);
+        assert_eq!(distribution[0].winners, vec![(0, 15), (1, 15)], "ハイとローで半分ずつになっていません");
+        let showdown = &game.hand_history().unwrap().showdown;
+        assert!(showdown[0].low.is_none(), "8以下の手がないプレイヤーにローが記録されています");
+        assert!(showdown[1].low.is_some(), "ローの勝者にローが記録されていません");
+    }
+
+    #[test]
+    fn オマハ・ハイローでローが同じならロー側を分け合う() {
+        // ロー側の15チップを2人で分け、端数の1チップはディーラー（0）の左隣のプレイヤー2が受け取る
+        let hands = [
+            KINGS,
+            [(Suit::Heart, 1), (Suit::Spade, 3), (Suit::Club, 9), (Suit::Club, 10)],
+            [(Suit::Spade, 1), (Suit::Club, 3), (Suit::Heart, 9), (Suit::Heart, 10)],
+        ];
+        let mut game = play_omaha_hi_lo(&hands, LOW_BOARD, OddChipRule::LeftOfButton);
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        assert_eq!(distribution[0].winners, vec![(0, 15), (1, 8), (2, 7)], "ローが4分の1ずつになっていません");
+
+        // 最も高いカードから配る場合は A♠ を持つプレイヤー3が受け取る
+        let mut game = play_omaha_hi_lo(&hands, LOW_BOARD, OddChipRule::HighestCard);
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        assert_eq!(distribution[0].winners, vec![(0, 15), (2, 8), (1, 7)], "端数チップが最も高いカードのプレイヤーに渡っていません");
+    }
+
+    #[test]
+    fn オマハ・ハイローで条件を満たすローがなければハイが総取りする() {
+        let mut game = play_omaha_hi_lo(&[
+            KINGS,
+            [(Suit::Heart, 1), (Suit::Spade, 3), (Suit::Club, 9), (Suit::Club, 10)],
+        ], [(Suit::Club, 2), (Suit::Diamond, 9), (Suit::Heart, 10), (Suit::Spade, 13), (Suit::Diamond, 12)], OddChipRule::LeftOfButton);
+
+        let winners: Vec<usize> = GameRuleService::determine_winners(&game).into_iter().map(|(idx, _)| idx).collect();
+        assert_eq!(winners, vec![0], "ハイの勝者だけになっていません");
+        let distribution = GameRuleService::distribute_pot(&mut game).unwrap();
+        assert_eq!(distribution[0].winners, vec![(0, 20)], "ハイがポット全体を取っていません");
+    }
+
+    // デッキの並びを指定してショートデッキをショーダウンまで進める（手札はプレイヤー順に2枚ずつ、続けてボード5枚）
+    fn play_short_deck(hands: &[[(Suit, u8); 2]], board: [(Suit, u8); 5], trips_rule: ShortDeckTripsRule) -> Game {
+        let mut game = Game::new(GameVariant::ShortDeckHoldem, 5, 10).unwrap();
+        for i in 0..hands.len() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), 1000)).unwrap();
+        }
+        game.set_rules(TableRules { short_deck_trips_rule: trips_rule, ..TableRules::default() }).unwrap();
+        let top = hands.iter().flatten().chain(&board)
+            .map(|&(suit, rank)| Card::new(suit, rank).unwrap())
+            .collect();
+        game.preset_deck(top).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        play_to_showdown(&mut game);
+        game
+    }
+
+    fn winner_indices(game: &Game) -> Vec<usize> {
+        GameRuleService::determine_winners(game).into_iter().map(|(idx, _)| idx).collect()
+    }
+
+    #[test]
+    fn ショートデッキはフラッシュがフルハウスより強い() {
+        // プレイヤー1: A♥ 9♥ → ハートのフラッシュ、プレイヤー2: 8♣ 8♦ → 8 と K のフルハウス
+        let game = play_short_deck(&[
+            [(Suit::Heart, 1), (Suit::Heart, 9)],
+            [(Suit::Club, 8), (Suit::Diamond, 8)],
+        ], [(Suit::Heart, 13), (Suit::Diamond, 13), (Suit::Heart, 8), (Suit::Heart, 7), (Suit::Spade, 6)], ShortDeckTripsRule::TripsBeatStraight);
+        assert_eq!(game.current_phase(), GamePhase::Showdown, "ショーダウンに進んでいません");
+        assert_eq!(winner_indices(&game), vec![0], "フラッシュがフルハウスに勝っていません");
+        assert_eq!(game.deck().remaining(), 36 - 9, "デッキが36枚から配られていません");
+    }
+
+    #[test]
+    fn ショートデッキは_a_6_7_8_9_がストレートでスリーカードとの順番を選べる() {
+        // プレイヤー1: A♥ 9♣ → A-6-7-8-9 のストレート、プレイヤー2: K♣ K♥ → K のスリーカード
+        let hands = [
+            [(Suit::Heart, 1), (Suit::Club, 9)],
+            [(Suit::Club, 13), (Suit::Heart, 13)],
+        ];
+        let board = [(Suit::Club, 6), (Suit::Diamond, 7), (Suit::Heart, 8), (Suit::Spade, 13), (Suit::Diamond, 12)];
+
+        let mut game = play_short_deck(&hands, board, ShortDeckTripsRule::TripsBeatStraight);
+        assert_eq!(winner_indices(&game), vec![1], "スリーカードがストレートに勝っていません");
+        GameRuleService::distribute_pot(&mut game).unwrap();
+        let straight = &game.hand_history().unwrap().showdown[0].strength;
+        assert_eq!(straight.rank(), HandRank::Straight, "A-6-7-8-9 がストレートになっていません");
+        assert_eq!(straight.values(), &vec![9], "A-6-7-8-9 のストレートが9ハイになっていません");
+
+        let mut game = play_short_deck(&hands, board, ShortDeckTripsRule::StraightBeatsTrips);
+        assert_eq!(winner_indices(&game), vec![0], "ストレートがスリーカードに勝っていません");
+
+        // 順番の設定はイベントから復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.rules().short_deck_trips_rule, ShortDeckTripsRule::StraightBeatsTrips, "スリーカードとストレートの順番が復元されていません");
+    }
+
+    // デッキの並びを指定してワイルドカードありのホールデムをショーダウンまで進める（手札はプレイヤー順に2枚ずつ、続けてボード5枚）
+    fn play_wild_holdem(hands: &[[Card; 2]], board: [Card; 5], wild_cards: WildCards) -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        for i in 0..hands.len() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), 1000)).unwrap();
+        }
+        game.set_rules(TableRules { wild_cards, ..TableRules::default() }).unwrap();
+        game.preset_deck(hands.iter().flatten().chain(&board).copied().collect()).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        play_to_showdown(&mut game);
+        game
+    }
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    #[test]
+    fn デュースワイルドのファイブカードはロイヤルストレートフラッシュより強い() {
+        // プレイヤー1: 2♣ 2♦ → A のファイブカード、プレイヤー2: J♠ 10♠ → ロイヤルストレートフラッシュ
+        let deuces = WildCards::new(0, JokerRule::Wild, &[2]).unwrap();
+        let hands = [
+            [card(Suit::Club, 2), card(Suit::Diamond, 2)],
+            [card(Suit::Spade, 11), card(Suit::Spade, 10)],
+        ];
+        let board = [card(Suit::Spade, 1), card(Suit::Heart, 1), card(Suit::Diamond, 1), card(Suit::Spade, 13), card(Suit::Spade, 12)];
+        let mut game = play_wild_holdem(&hands, board, deuces);
+        assert_eq!(winner_indices(&game), vec![0], "ファイブカードが勝っていません");
+
+        // 2枚の2はどちらもAとして使われる
+        let best = HandEvaluationService::find_best_hand_detailed(&hands[0], &board, GameVariant::TexasHoldem, &game.hand_ranking_rules());
+        assert_eq!(best.strength.rank(), HandRank::FiveOfAKind, "ファイブカードになっていません");
+        assert_eq!(best.wild_usages.len(), 2, "ワイルドカードの使い方が2枚分ありません");
+        assert!(best.wild_usages.iter().all(|usage| usage.used_as.is_ace()), "2がAとして使われていません");
+
+        // ファイブカードを認めない決まりでは2をJと10にしてロイヤルストレートフラッシュにする
+        let rules = HandRankingRules::STANDARD.with_wild_cards(deuces.without_five_of_a_kind());
+        let best = HandEvaluationService::find_best_hand_detailed(&hands[0], &board, GameVariant::TexasHoldem, &rules);
+        assert_eq!(best.strength.rank(), HandRank::RoyalStraightFlush, "ファイブカードを認めない決まりでロイヤルストレートフラッシュになっていません");
+
+        // ワイルドカードの設定はイベントから復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.rules().wild_cards, deuces, "ワイルドカードの設定が復元されていません");
+    }
+
+    #[test]
+    fn バグのジョーカーはaかストレートとフラッシュにだけ使える() {
+        let rules = HandRankingRules::STANDARD.with_wild_cards(WildCards::new(1, JokerRule::Bug, &[]).unwrap());
+        let joker = Card::joker(Suit::Spade);
+
+        // K のペアにはAのキッカーとして加わるだけで、スリーカードにはならない
+        let best = HandEvaluationService::evaluate_with_wilds(&[joker, card(Suit::Spade, 13), card(Suit::Heart, 13), card(Suit::Diamond, 7), card(Suit::Club, 4)], &rules);
+        assert_eq!(best.strength.rank(), HandRank::OnePair, "バグがKとして使われています");
+        assert_eq!(best.strength.values(), &vec![13, 14, 7, 4], "バグがAのキッカーになっていません");
+        assert!(best.wild_usages[0].used_as.is_ace(), "バグの使い方がAになっていません");
+
+        // A のペアならスリーカードになる
+        let best = HandEvaluationService::evaluate_with_wilds(&[joker, card(Suit::Spade, 1), card(Suit::Heart, 1), card(Suit::Diamond, 7), card(Suit::Club, 4)], &rules);
+        assert_eq!(best.strength.rank(), HandRank::ThreeOfAKind, "バグでAのスリーカードになっていません");
+
+        // ストレートを完成させる場合はどのランクにもなる
+        let best = HandEvaluationService::evaluate_with_wilds(&[joker, card(Suit::Spade, 6), card(Suit::Heart, 7), card(Suit::Diamond, 8), card(Suit::Club, 9)], &rules);
+        assert_eq!(best.strength.rank(), HandRank::Straight, "バグでストレートになっていません");
+        assert_eq!(best.strength.values(), &vec![10], "バグが10として使われていません");
+
+        // 完全なワイルドならKのスリーカードにできる
+        let wild = HandRankingRules::STANDARD.with_wild_cards(WildCards::new(1, JokerRule::Wild, &[]).unwrap());
+        let best = HandEvaluationService::evaluate_with_wilds(&[joker, card(Suit::Spade, 13), card(Suit::Heart, 13), card(Suit::Diamond, 7), card(Suit::Club, 4)], &wild);
+        assert_eq!(best.strength.rank(), HandRank::ThreeOfAKind, "ジョーカーでKのスリーカードになっていません");
+    }
+
+    #[test]
+    fn ワイルドカードはハンドの間にローのゲーム以外でだけ設定できる() {
+        let deuces = WildCards::new(0, JokerRule::Wild, &[2]).unwrap();
+        let mut razz = Game::new(GameVariant::Razz, 5, 10).unwrap();
+        assert!(razz.set_rules(TableRules { wild_cards: deuces, ..TableRules::default() }).is_err(), "ラズでワイルドカードを設定できてしまいます");
+
+        let mut game = create_test_game();
+        assert!(game.set_rules(TableRules { wild_cards: deuces, ..game.rules() }).is_err(), "ハンドの途中でワイルドカードを設定できてしまいます");
+
+        let mut game = Game::new(GameVariant::FiveCardDraw, 5, 10).unwrap();
+        game.set_rules(TableRules { wild_cards: WildCards::new(2, JokerRule::Wild, &[]).unwrap(), ..TableRules::default() }).unwrap();
+        assert_eq!(game.deck().remaining(), 54, "ジョーカー入りのデッキになっていません");
+    }
+
+    #[test]
+    fn ゲームで決めた役の表でショーダウンする() {
+        // プレイヤー1: 2♣ 5♦ → 9-7-5-3-2 のスキート、プレイヤー2: 10♠ J♦ → K ハイのストレート
+        let hands = [
+            [card(Suit::Club, 2), card(Suit::Diamond, 5)],
+            [card(Suit::Spade, 10), card(Suit::Diamond, 11)],
+        ];
+        let board = [card(Suit::Heart, 9), card(Suit::Spade, 3), card(Suit::Diamond, 7), card(Suit::Club, 13), card(Suit::Heart, 12)];
+        let game = play_wild_holdem(&hands, board, WildCards::NONE);
+        assert_eq!(winner_indices(&game), vec![1], "通常の表でストレートが勝っていません");
+
+        let table = HandRankingTable::STANDARD.with_inserted_above(HandRank::Skeet, HandRank::Straight).unwrap();
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        for i in 0..hands.len() {
+            game.add_player(Player::new(format!("プレイヤー{}", i + 1), 1000)).unwrap();
+        }
+        game.set_rules(TableRules { hand_ranking_table: Some(table), ..TableRules::default() }).unwrap();
+        game.preset_deck(hands.iter().flatten().chain(&board).copied().collect()).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        assert!(game.set_rules(TableRules::default()).is_err(), "ハンドの途中で役の表を変更できてしまいます");
+        play_to_showdown(&mut game);
+        assert_eq!(winner_indices(&game), vec![0], "スキートがストレートに勝っていません");
+
+        // 役の表はイベントから復元できる
+        let restored = GameProjector::project(&game.take_events()).expect("復元に失敗");
+        assert_eq!(restored.rules().hand_ranking_table, Some(table), "役の表が復元されていません");
+        assert_eq!(restored.hand_ranking_rules().order(HandRank::Skeet), table.order(HandRank::Skeet), "復元した決まりに役の表が反映されていません");
+
+        let mut razz = Game::new(GameVariant::Razz, 5, 10).unwrap();
+        assert!(razz.set_rules(TableRules { hand_ranking_table: Some(table), ..TableRules::default() }).is_err(), "ラズで役の表を設定できてしまいます");
+    }
+}
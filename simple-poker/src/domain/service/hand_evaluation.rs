@@ -1,15 +1,27 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use crate::domain::model::card::Card;
+use serde::{Serialize, Deserialize};
+
+use crate::domain::model::card::{Card, Suit, WildCards};
 use crate::domain::model::game::GameVariant;
+use crate::domain::service::hand_evaluation_specification::{HandRankingTable, HandShape};
+use crate::domain::service::lookup_evaluator::LookupEvaluator;
 
 /// 役の強さを表す値のリスト（高いカードからのランク値）
 type HandValues = Vec<u8>;
 /// カード枚数のマッピング（ランク → 枚数）
 type RankCountMap = HashMap<u8, u8>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// 役の種類
+///
+/// 派生した順序は番号の順で、ローの比較と集計の並びにだけ使う。
+/// ハイの役どうしの強さは役の表で決まるため `HandRankingRules::order` で比べる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum HandRank {
+    BigBobtail = 12,  // 同じスートで4枚つながったカード（役の表に加えた場合だけできる）
+    Skeet = 11,       // 2・5・9と、その間のカード2枚（役の表に加えた場合だけできる）
+    FiveOfAKind = 10, // ワイルドカードを使う場合だけできる
     RoyalStraightFlush = 9,
     StraightFlush = 8,
     FourOfAKind = 7,
@@ -25,6 +37,9 @@ pub enum HandRank {
 impl std::fmt::Display for HandRank {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let rank_str = match self {
+            HandRank::BigBobtail => "ビッグボブテイル",
+            HandRank::Skeet => "スキート",
+            HandRank::FiveOfAKind => "ファイブカード",
             HandRank::RoyalStraightFlush => "ロイヤルストレートフラッシュ",
             HandRank::StraightFlush => "ストレートフラッシュ",
             HandRank::FourOfAKind => "フォーカード",
@@ -40,8 +55,23 @@ impl std::fmt::Display for HandRank {
     }
 }
 
-// 手の強さを表す構造体（役とタイブレーク用の情報）
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// 手の強さ（役と、同じ役どうしを比べるタイブレークの値）
+///
+/// values はスートを含まないランクの並びで、同じ役なら前から順に比べて大きいほど強い（Aは14）。
+/// 同じ強さになるのは、役が同じで5枚のランクの組み合わせも同じ場合だけになる。
+/// - ハイカード・フラッシュ・スキート: 5枚のランクを高い順
+/// - ワンペア: ペアのランク、残り3枚を高い順
+/// - ツーペア: 高いペア、低いペア、残りの1枚
+/// - スリーカード: 3枚のランク、残り2枚を高い順
+/// - ストレート・ストレートフラッシュ: 最も高いカード（A-2-3-4-5 は5、ショートデッキの A-6-7-8-9 は9）
+/// - フルハウス: 3枚のランク、2枚のランク
+/// - フォーカード: 4枚のランク、残りの1枚
+/// - ロイヤルストレートフラッシュ: 14
+/// - ファイブカード: 5枚のランク
+/// - ビッグボブテイル: つながった4枚の最も高いカード、残りの1枚
+///
+/// 役どうしの強さは役の表で決まるため、比べるときは必ず `HandRankingRules::compare` を使う。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HandStrength {
     rank: HandRank,
     values: HandValues, // ここで型エイリアスを使用
@@ -61,66 +91,375 @@ impl HandStrength {
     }
 }
 
+/// 役の決まり（デッキの構成によるストレートのつながり、役の表、ワイルドカード）
+///
+/// 役は表の強い順に条件を調べて決め、強さは表の順番で比べる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandRankingRules {
+    lowest_rank: u8,         // デッキで最も低いランク（Aと合わせて最も低いストレートを作る）
+    table: HandRankingTable, // 役の表
+    wild_cards: WildCards,   // ほかのカードの代わりになるカード
+}
+
+impl HandRankingRules {
+    /// 52枚のデッキの決まり
+    pub const STANDARD: Self = Self {
+        lowest_rank: 2,
+        table: HandRankingTable::STANDARD,
+        wild_cards: WildCards::NONE,
+    };
+    /// 2〜5を抜いたショートデッキの決まり（A-6-7-8-9 がストレートになる）
+    pub const SHORT_DECK: Self = Self {
+        lowest_rank: 6,
+        table: HandRankingTable::SHORT_DECK,
+        wild_cards: WildCards::NONE,
+    };
+    /// スタッドで表向きのカードを比べる決まり（ストレートとフラッシュは数えない）
+    pub const SHOWING: Self = Self {
+        lowest_rank: 2,
+        table: HandRankingTable::SAME_RANKS,
+        wild_cards: WildCards::NONE,
+    };
+    
+    /// スリーカードとストレートのどちらを強くするか（表の順番を入れ替える）
+    pub fn with_trips_beat_straight(self, trips_beat_straight: bool) -> Self {
+        if self.trips_beat_straight() == trips_beat_straight {
+            return self;
+        }
+        self.with_table(self.table.with_swapped(HandRank::ThreeOfAKind, HandRank::Straight))
+    }
+    
+    pub fn with_table(self, table: HandRankingTable) -> Self {
+        Self { table, ..self }
+    }
+    
+    pub fn with_wild_cards(self, wild_cards: WildCards) -> Self {
+        Self { wild_cards, ..self }
+    }
+    
+    pub fn table(&self) -> HandRankingTable {
+        self.table
+    }
+    
+    pub fn wild_cards(&self) -> WildCards {
+        self.wild_cards
+    }
+    
+    pub fn lowest_rank(&self) -> u8 {
+        self.lowest_rank
+    }
+    
+    pub fn flush_beats_full_house(&self) -> bool {
+        self.order(HandRank::Flush) > self.order(HandRank::FullHouse)
+    }
+    
+    pub fn trips_beat_straight(&self) -> bool {
+        self.order(HandRank::ThreeOfAKind) > self.order(HandRank::Straight)
+    }
+    
+    /// 役の順位（大きいほど強い）
+    pub fn order(&self, rank: HandRank) -> u8 {
+        self.table.order(rank)
+    }
+    
+    /// 役の順位、同じ役ならタイブレークの値で比べる
+    pub fn compare(&self, a: &HandStrength, b: &HandStrength) -> Ordering {
+        self.order(a.rank()).cmp(&self.order(b.rank())).then_with(|| a.values().cmp(b.values()))
+    }
+}
+
+impl Default for HandRankingRules {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// ローでの手の値（小さいほど強い）
+///
+/// エース・トゥ・ファイブではストレートとフラッシュは数えずにAを1として扱い、最も強いのは 5-4-3-2-A（ホイール）。
+/// デュース・トゥ・セブンではAは常に高く、ストレートとフラッシュも役になり、最も強いのは 7-5-4-3-2。
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct LowHand {
+    rank: HandRank,     // 役（ハイカードが最も強い）
+    values: HandValues, // 組み合わせのランク、残りのカードの高い順
+}
+
+impl LowHand {
+    pub fn rank(&self) -> HandRank {
+        self.rank
+    }
+    
+    pub fn values(&self) -> &HandValues {
+        &self.values
+    }
+    
+    /// ペアがなく、最も高いカードが max_rank 以下か（エイト・オア・ベターなら8）
+    pub fn qualifies(&self, max_rank: u8) -> bool {
+        self.rank == HandRank::HighCard && self.values.first().is_some_and(|&high| high <= max_rank)
+    }
+}
+
+/// ワイルドカードを何のカードとして使ったか
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WildUsage {
+    pub wild: Card,
+    pub used_as: Card,
+}
+
+/// 最も強い5枚の強さと、その中のワイルドカードの使い方
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BestHand {
+    pub strength: HandStrength,
+    pub wild_usages: Vec<WildUsage>,
+}
+
 pub struct HandEvaluationService;
 
 impl HandEvaluationService {
+    /// ハイ・ローでローとして認められる最も高いカード
+    pub const EIGHT_OR_BETTER: u8 = 8;
+    
     // 手札の役を評価する
     pub fn evaluate_hand(cards: &[Card]) -> HandStrength {
-        if cards.is_empty() {
-            return HandStrength::new(HandRank::HighCard, vec![]);
+        Self::evaluate_hand_with_rules(cards, &HandRankingRules::STANDARD)
+    }
+    
+    /// 役の決まりに従って手札の役を評価する（ストレートのつながりとワイルドカードが決まりで変わる）
+    pub fn evaluate_hand_with_rules(cards: &[Card], rules: &HandRankingRules) -> HandStrength {
+        Self::evaluate_with_wilds(cards, rules).strength
+    }
+    
+    /// ワイルドカードを最も強くなるカードに置き換えて評価する
+    ///
+    /// 置き換えるランクの組み合わせをすべて試し、スートはフラッシュにする場合としない場合を試す。
+    /// バグのジョーカーはAか、ストレート・フラッシュを完成させる場合だけ使える。
+    pub fn evaluate_with_wilds(cards: &[Card], rules: &HandRankingRules) -> BestHand {
+        // 6枚以上なら最も強い5枚で評価する（キッカーも役に使う5枚から数える）
+        if cards.len() > 5 {
+            return Self::find_best_five_card_hand(cards, rules);
+        }
+        let wild_cards = rules.wild_cards();
+        if wild_cards.is_empty() {
+            return BestHand { strength: Self::evaluate_natural(cards, rules), wild_usages: Vec::new() };
+        }
+        let (wilds, naturals): (Vec<Card>, Vec<Card>) = cards.iter().partition(|card| wild_cards.is_wild(card));
+        if wilds.is_empty() {
+            return BestHand { strength: Self::evaluate_natural(cards, rules), wild_usages: Vec::new() };
         }
         
-        let is_flush = Self::is_flush(cards);
-        let is_straight = Self::is_straight(cards);
-        let is_royal = Self::is_royal(cards);
-        let rank_counts = Self::count_ranks(cards);
+        // バグは後ろに並べ、ランクを個別に選ぶ（ワイルドは順番に関係ないので重複組み合わせで選ぶ）
+        let (bugs, full_wilds): (Vec<Card>, Vec<Card>) = wilds.iter().partition(|card| wild_cards.is_bug(card));
+        let flush_suit = naturals.first().map(Card::suit)
+            .filter(|&suit| naturals.iter().all(|card| card.suit() == suit));
+        let mut best: Option<BestHand> = None;
+        for_each_rank_choice(full_wilds.len(), bugs.len(), |ranks| {
+            let suit_choices = match (naturals.is_empty(), flush_suit) {
+                (true, _) => vec![Some(Suit::Spade), None],
+                (false, Some(suit)) => vec![Some(suit), None],
+                (false, None) => vec![None],
+            };
+            for suited in suit_choices {
+                let Some(used) = Self::substitute(&naturals, ranks, suited) else {
+                    continue;
+                };
+                let mut hand = naturals.clone();
+                hand.extend(&used);
+                let strength = Self::evaluate_natural(&hand, rules);
+                if strength.rank() == HandRank::FiveOfAKind && !wild_cards.five_of_a_kind() {
+                    continue;
+                }
+                let completes = matches!(
+                    strength.rank(),
+                    HandRank::Straight | HandRank::Flush | HandRank::StraightFlush | HandRank::RoyalStraightFlush
+                );
+                if !completes && used[full_wilds.len()..].iter().any(|card| !card.is_ace()) {
+                    continue;
+                }
+                if best.as_ref().is_none_or(|best| rules.compare(&strength, &best.strength) == Ordering::Greater) {
+                    let wild_usages = full_wilds.iter().chain(&bugs)
+                        .zip(&used)
+                        .map(|(&wild, &used_as)| WildUsage { wild, used_as })
+                        .collect();
+                    best = Some(BestHand { strength, wild_usages });
+                }
+            }
+        });
         
-        match (is_flush, is_straight, is_royal) {
-            (true, true, true) => {
-                HandStrength::new(HandRank::RoyalStraightFlush, vec![14]) // Aを14として扱う
-            },
-            (true, true, false) => {
-                let high_card = Self::get_high_card_value(cards);
-                HandStrength::new(HandRank::StraightFlush, vec![high_card])
-            },
-            (true, false, _) => {
-                let values = Self::get_values_for_flush(cards);
-                HandStrength::new(HandRank::Flush, values)
-            },
-            (false, true, _) => {
-                let high_card = Self::get_high_card_value(cards);
-                HandStrength::new(HandRank::Straight, vec![high_card])
-            },
-            _ => Self::evaluate_by_counts(rank_counts, cards),
+        // ファイブカードを作れず、バグも使えない組み合わせしかない場合はワイルドを使わずに評価する
+        best.unwrap_or_else(|| BestHand { strength: Self::evaluate_natural(&naturals, rules), wild_usages: Vec::new() })
+    }
+    
+    // ワイルドカードの代わりにするカード（suited ならそのスートでフラッシュにし、手にあるカードとは重ねない）
+    fn substitute(naturals: &[Card], ranks: &[u8], suited: Option<Suit>) -> Option<Vec<Card>> {
+        let mut used: Vec<Card> = Vec::with_capacity(ranks.len());
+        for &rank in ranks {
+            let taken = |card: &Card| naturals.contains(card) || used.contains(card);
+            let card = match suited {
+                Some(suit) => {
+                    let card = Card::new(suit, rank).ok()?;
+                    if taken(&card) {
+                        return None;
+                    }
+                    card
+                },
+                // フラッシュにならないよう、ほかのカードと違うスートを優先する
+                None => {
+                    let avoid = naturals.first().map(Card::suit);
+                    let candidates: Vec<Card> = Suit::all().iter().rev()
+                        .filter(|&&suit| Some(suit) != avoid)
+                        .filter_map(|&suit| Card::new(suit, rank).ok())
+                        .collect();
+                    candidates.iter().find(|card| !taken(card)).copied().unwrap_or(candidates[0])
+                },
+            };
+            used.push(card);
         }
+        Some(used)
+    }
+    
+    // ワイルドカードを含まない手札の役を役の表に従って評価する
+    fn evaluate_natural(cards: &[Card], rules: &HandRankingRules) -> HandStrength {
+        rules.table().evaluate(&HandShape::new(cards, rules.lowest_rank()))
     }
     
     // ホールデムやオマハで最適な5枚の組み合わせを見つける
     pub fn find_best_hand(hand: &[Card], community: &[Card], variant: GameVariant) -> HandStrength {
+        Self::find_best_hand_with_rules(hand, community, variant, &variant.hand_ranking_rules())
+    }
+    
+    /// 役の決まりを指定して最適な5枚の組み合わせを見つける
+    pub fn find_best_hand_with_rules(
+        hand: &[Card],
+        community: &[Card],
+        variant: GameVariant,
+        rules: &HandRankingRules,
+    ) -> HandStrength {
+        // 表で引ける枚数なら組み合わせを試さずに済む（表は通常の決まりで作られている）
+        if *rules == HandRankingRules::STANDARD {
+            if let Some(value) = LookupEvaluator::find_best(hand, community, variant) {
+                return value.strength();
+            }
+        }
+        Self::find_best_hand_detailed(hand, community, variant, rules).strength
+    }
+    
+    /// 最適な5枚の強さと、ワイルドカードを何として使ったか
+    pub fn find_best_hand_detailed(
+        hand: &[Card],
+        community: &[Card],
+        variant: GameVariant,
+        rules: &HandRankingRules,
+    ) -> BestHand {
         match variant {
-            GameVariant::FiveCardDraw => Self::evaluate_hand(hand),
-            GameVariant::TexasHoldem => Self::best_holdem_hand(hand, community),
-            GameVariant::Omaha => Self::best_omaha_hand(hand, community),
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw => Self::evaluate_with_wilds(hand, rules),
+            GameVariant::TexasHoldem | GameVariant::SevenCardStud | GameVariant::Razz
+                | GameVariant::ShortDeckHoldem => Self::best_holdem_hand(hand, community, rules),
+            GameVariant::Omaha | GameVariant::OmahaHiLo => Self::best_omaha_hand(hand, community, rules),
+        }
+    }
+    
+    /// 表向きのカードだけで見た強さ（スタッドで行動する順番を決めるため、ストレートとフラッシュは数えない）
+    pub fn evaluate_showing(cards: &[Card]) -> HandStrength {
+        Self::evaluate_natural(cards, &HandRankingRules::SHOWING)
+    }
+    
+    /// エース・トゥ・ファイブのローで評価する（5枚未満なら表向きのカードの比較にも使える）
+    pub fn evaluate_low(cards: &[Card]) -> LowHand {
+        let groups = Self::rank_groups(cards, false);
+        LowHand { rank: Self::rank_of_groups(&groups), values: groups.into_iter().map(|(_, rank)| rank).collect() }
+    }
+    
+    /// デュース・トゥ・セブンのローで評価する（Aは常に高く、A-2-3-4-5 はストレートにならない）
+    pub fn evaluate_deuce_to_seven(cards: &[Card]) -> LowHand {
+        let groups = Self::rank_groups(cards, true);
+        let values: HandValues = groups.iter().map(|&(_, rank)| rank).collect();
+        let is_flush = cards.len() == 5 && Self::is_flush(cards);
+        let is_straight = values.len() == 5 && values[0] - values[4] == 4;
+        let rank = match (is_straight, is_flush) {
+            (true, true) if values[0] == 14 => HandRank::RoyalStraightFlush,
+            (true, true) => HandRank::StraightFlush,
+            (true, false) => HandRank::Straight,
+            (false, true) => HandRank::Flush,
+            (false, false) => Self::rank_of_groups(&groups),
+        };
+        LowHand { rank, values }
+    }
+    
+    /// オマハ・ハイローのロー（手札から2枚とボードから3枚を使い、エイト・オア・ベターを満たす最も強いもの）
+    pub fn find_best_omaha_low(hole_cards: &[Card], community_cards: &[Card]) -> Option<LowHand> {
+        let mut best: Option<LowHand> = None;
+        for (i, &first) in hole_cards.iter().enumerate() {
+            for &second in &hole_cards[i + 1..] {
+                for (k, &third) in community_cards.iter().enumerate() {
+                    for (l, &fourth) in community_cards.iter().enumerate().skip(k + 1) {
+                        for &fifth in &community_cards[l + 1..] {
+                            let low = Self::evaluate_low(&[first, second, third, fourth, fifth]);
+                            if low.qualifies(Self::EIGHT_OR_BETTER) && best.as_ref().is_none_or(|best| low < *best) {
+                                best = Some(low);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+    
+    // 枚数の多い順、同じ枚数ならランクの高い順に並べた (枚数, ランク)
+    fn rank_groups(cards: &[Card], ace_high: bool) -> Vec<(u8, u8)> {
+        let mut groups: Vec<(u8, u8)> = Self::count_ranks(cards).into_iter()
+            .map(|(rank, count)| (count, if ace_high && rank == 1 { 14 } else { rank }))
+            .collect();
+        groups.sort_by(|a, b| b.cmp(a));
+        groups
+    }
+    
+    // 同じランクの枚数だけで決まる役
+    fn rank_of_groups(groups: &[(u8, u8)]) -> HandRank {
+        let counts: Vec<u8> = groups.iter().map(|&(count, _)| count).collect();
+        match counts.as_slice() {
+            [4, ..] => HandRank::FourOfAKind,
+            [3, 2, ..] => HandRank::FullHouse,
+            [3, ..] => HandRank::ThreeOfAKind,
+            [2, 2, ..] => HandRank::TwoPair,
+            [2, ..] => HandRank::OnePair,
+            _ => HandRank::HighCard,
         }
     }
     
+    /// 手札とボードから選べる5枚の中で最も強いロー
+    pub fn find_best_low_hand(hand: &[Card], community: &[Card]) -> LowHand {
+        let cards: Vec<Card> = hand.iter().chain(community).copied().collect();
+        (0u32..1 << cards.len())
+            .filter(|mask| mask.count_ones() == 5)
+            .map(|mask| {
+                let five: Vec<Card> = cards.iter().enumerate()
+                    .filter(|(i, _)| mask >> i & 1 == 1)
+                    .map(|(_, card)| *card)
+                    .collect();
+                Self::evaluate_low(&five)
+            })
+            .min()
+            .unwrap_or_else(|| Self::evaluate_low(&cards))
+    }
+    
     // テキサスホールデム用の最適な手札評価
-    fn best_holdem_hand(hole_cards: &[Card], community_cards: &[Card]) -> HandStrength {
+    fn best_holdem_hand(hole_cards: &[Card], community_cards: &[Card], rules: &HandRankingRules) -> BestHand {
         let mut all_cards = Vec::with_capacity(hole_cards.len() + community_cards.len());
         all_cards.extend_from_slice(hole_cards);
         all_cards.extend_from_slice(community_cards);
         
         // 全部の組み合わせから最強のものを探す
-        Self::find_best_five_card_hand(&all_cards)
+        Self::find_best_five_card_hand(&all_cards, rules)
     }
     
     // オマハ用の最適な手札評価（2枚のホールカードと3枚のコミュニティカードを使用）
-    fn best_omaha_hand(hole_cards: &[Card], community_cards: &[Card]) -> HandStrength {
+    fn best_omaha_hand(hole_cards: &[Card], community_cards: &[Card], rules: &HandRankingRules) -> BestHand {
+        let mut best_hand = BestHand { strength: HandStrength::new(HandRank::HighCard, vec![]), wild_usages: Vec::new() };
         if hole_cards.len() < 4 || community_cards.len() < 3 {
-            return HandStrength::new(HandRank::HighCard, vec![]);
+            return best_hand;
         }
         
-        let mut best_hand = HandStrength::new(HandRank::HighCard, vec![]);
         
         // ホールカードから2枚を選ぶ組み合わせ
         for i in 0..hole_cards.len() {
@@ -134,9 +473,9 @@ impl HandEvaluationService {
                                 community_cards[k], community_cards[l], community_cards[m],
                             ];
                             
-                            let strength = Self::evaluate_hand(&hand);
-                            if strength > best_hand {
-                                best_hand = strength;
+                            let evaluated = Self::evaluate_with_wilds(&hand, rules);
+                            if rules.compare(&evaluated.strength, &best_hand.strength) == Ordering::Greater {
+                                best_hand = evaluated;
                             }
                         }
                     }
@@ -148,12 +487,12 @@ impl HandEvaluationService {
     }
     
     // 7枚のカードから最適な5枚を見つける
-    fn find_best_five_card_hand(cards: &[Card]) -> HandStrength {
+    fn find_best_five_card_hand(cards: &[Card], rules: &HandRankingRules) -> BestHand {
         if cards.len() <= 5 {
-            return Self::evaluate_hand(cards);
+            return Self::evaluate_with_wilds(cards, rules);
         }
         
-        let mut best_hand = HandStrength::new(HandRank::HighCard, vec![]);
+        let mut best_hand = BestHand { strength: HandStrength::new(HandRank::HighCard, vec![]), wild_usages: Vec::new() };
         
         // 5枚のカードを選ぶすべての組み合わせを評価
         let mut indices = [0, 1, 2, 3, 4];
@@ -165,9 +504,9 @@ impl HandEvaluationService {
                 cards[indices[3]], cards[indices[4]],
             ];
             
-            let strength = Self::evaluate_hand(&hand);
-            if strength > best_hand {
-                best_hand = strength;
+            let evaluated = Self::evaluate_with_wilds(&hand, rules);
+            if rules.compare(&evaluated.strength, &best_hand.strength) == Ordering::Greater {
+                best_hand = evaluated;
             }
             
             // 次の組み合わせに進む
@@ -204,61 +543,6 @@ impl HandEvaluationService {
         cards.iter().all(|card| card.suit() == first_suit)
     }
     
-    // ストレートかどうかを判定
-    fn is_straight(cards: &[Card]) -> bool {
-        if cards.len() < 5 {
-            return false;
-        }
-        
-        let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank()).collect();
-        ranks.sort();
-        ranks.dedup(); // 重複を削除
-        
-        if ranks.len() < 5 {
-            return false;
-        }
-        
-        // 通常のストレートチェック
-        for window in ranks.windows(5) {
-            if window[4] - window[0] == 4 {
-                return true;
-            }
-        }
-        
-        // A-5-4-3-2のケース（Aを1として扱う場合）
-        if ranks.contains(&1) && ranks.contains(&2) && ranks.contains(&3) && ranks.contains(&4) && ranks.contains(&5) {
-            return true;
-        }
-        
-        false
-    }
-    
-    // ロイヤルストレートフラッシュかどうかを判定
-    fn is_royal(cards: &[Card]) -> bool {
-        if cards.len() < 5 {
-            return false;
-        }
-        
-        let mut has_ace = false;
-        let mut has_king = false;
-        let mut has_queen = false;
-        let mut has_jack = false;
-        let mut has_ten = false;
-        
-        for card in cards {
-            match card.rank() {
-                1 => has_ace = true,
-                13 => has_king = true,
-                12 => has_queen = true,
-                11 => has_jack = true,
-                10 => has_ten = true,
-                _ => {}
-            }
-        }
-        
-        has_ace && has_king && has_queen && has_jack && has_ten
-    }
-    
     // 各ランクの出現回数をカウント
     fn count_ranks(cards: &[Card]) -> RankCountMap {
         let mut rank_counts = HashMap::new();
@@ -267,112 +551,25 @@ impl HandEvaluationService {
         }
         rank_counts
     }
-    
-    // 出現回数に基づいて役を判定
-    fn evaluate_by_counts(rank_counts: RankCountMap, cards: &[Card]) -> HandStrength {
-        let mut pairs = Vec::new();
-        let mut three_of_a_kind = None;
-        let mut four_of_a_kind = None;
-        
-        for (&rank, &count) in &rank_counts {
-            match count {
-                2 => pairs.push(rank),
-                3 => three_of_a_kind = Some(rank),
-                4 => four_of_a_kind = Some(rank),
-                _ => {}
-            }
-        }
-        
-        // 役に関与しないカードの値（キッカー）を取得
-        pairs.sort_by(|a, b| b.cmp(a)); // 降順ソート
-        
-        if let Some(four) = four_of_a_kind {
-            let mut values = vec![four];
-            Self::add_kickers(&mut values, &rank_counts, &[four], 1);
-            return HandStrength::new(HandRank::FourOfAKind, values);
-        }
-        
-        if let Some(three) = three_of_a_kind {
-            if !pairs.is_empty() {
-                return HandStrength::new(HandRank::FullHouse, vec![three, pairs[0]]);
-            }
-            
-            let mut values = vec![three];
-            Self::add_kickers(&mut values, &rank_counts, &[three], 2);
-            return HandStrength::new(HandRank::ThreeOfAKind, values);
-        }
-        
-        if pairs.len() >= 2 {
-            let mut values = vec![pairs[0], pairs[1]];
-            Self::add_kickers(&mut values, &rank_counts, &[pairs[0], pairs[1]], 1);
-            return HandStrength::new(HandRank::TwoPair, values);
-        }
-        
-        if pairs.len() == 1 {
-            let mut values = vec![pairs[0]];
-            Self::add_kickers(&mut values, &rank_counts, &[pairs[0]], 3);
-            return HandStrength::new(HandRank::OnePair, values);
-        }
-        
-        // ハイカード
-        let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank()).collect();
-        ranks.sort_by(|a, b| b.cmp(a)); // 降順ソート
-        
-        // A（1）を14として扱う
-        for i in 0..ranks.len() {
-            if ranks[i] == 1 {
-                ranks[i] = 14;
-            }
-        }
-        
-        ranks.sort_by(|a, b| b.cmp(a)); // 再度ソート
-        ranks.truncate(5); // 最大5枚まで
-        
-        HandStrength::new(HandRank::HighCard, ranks)
-    }
-    
-    // キッカーを追加
-    fn add_kickers(values: &mut Vec<u8>, rank_counts: &HashMap<u8, u8>, used_ranks: &[u8], count: usize) {
-        let mut kickers: Vec<u8> = rank_counts
-            .iter()
-            .filter(|(&rank, _)| !used_ranks.contains(&rank))
-            .map(|(&rank, _)| if rank == 1 { 14 } else { rank })
-            .collect();
-        
-        kickers.sort_by(|a, b| b.cmp(a)); // 降順ソート
-        kickers.truncate(count);
-        
-        values.extend(kickers);
-    }
-    
-    // フラッシュのタイブレーク用の値を取得
-    fn get_values_for_flush(cards: &[Card]) -> Vec<u8> {
-        let mut ranks: Vec<u8> = cards.iter().map(|c| c.rank()).collect();
-        
-        // A（1）を14として扱う
-        for i in 0..ranks.len() {
-            if ranks[i] == 1 {
-                ranks[i] = 14;
-            }
-        }
-        
-        ranks.sort_by(|a, b| b.cmp(a)); // 降順ソート
-        ranks.truncate(5); // 最大5枚まで
-        
-        ranks
-    }
-    
-    // 最高ランクのカード値を取得（Aは14として扱う）
-    fn get_high_card_value(cards: &[Card]) -> u8 {
-        let mut high = 0;
-        
-        for card in cards {
-            let rank = if card.rank() == 1 { 14 } else { card.rank() };
-            if rank > high {
-                high = rank;
-            }
+}
+
+// ワイルドカードに割り当てるランク（1〜13）の選び方ごとに f を呼ぶ
+//
+// 最初の wild_count 枚は順番に関係ないので小さくならない順に選び（重複組み合わせ）、
+// 残りの bug_count 枚はそれぞれ自由に選ぶ。
+fn for_each_rank_choice(wild_count: usize, bug_count: usize, mut f: impl FnMut(&[u8])) {
+    let total = wild_count + bug_count;
+    let mut ranks = vec![1u8; total];
+    loop {
+        f(&ranks);
+        
+        // 右から増やせる位置を探して次の選び方に進む
+        let Some(i) = (0..total).rev().find(|&i| ranks[i] < 13) else {
+            return;
+        };
+        ranks[i] += 1;
+        for j in i + 1..total {
+            ranks[j] = if j < wild_count { ranks[i] } else { 1 };
         }
-        
-        high
     }
-} 
\ No newline at end of file
+}
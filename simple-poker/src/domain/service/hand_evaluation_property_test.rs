@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use proptest::prelude::*;
+    use proptest::sample::subsequence;
+
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRankingRules, HandStrength};
+    use crate::domain::service::lookup_evaluator::LookupEvaluator;
+
+    // 52枚のカード
+    fn full_deck() -> Vec<Card> {
+        Suit::all().iter()
+            .flat_map(|&suit| (1..=13).map(move |rank| Card::new(suit, rank).unwrap()))
+            .collect()
+    }
+
+    // 重複のない n 枚のカード（並びもばらばらにする）
+    fn cards(n: usize) -> impl Strategy<Value = Vec<Card>> {
+        subsequence(full_deck(), n).prop_shuffle()
+    }
+
+    fn evaluate(cards: &[Card]) -> HandStrength {
+        HandEvaluationService::evaluate_hand(cards)
+    }
+
+    fn compare(a: &HandStrength, b: &HandStrength) -> Ordering {
+        HandRankingRules::STANDARD.compare(a, b)
+    }
+
+    // 教科書どおりの5枚の比べ方（役の番号と比べる値）を、評価とは別の手順で求める
+    //
+    // ロイヤルストレートフラッシュはAハイのストレートフラッシュとして扱う。
+    fn reference_key(cards: &[Card]) -> (u8, Vec<u8>) {
+        let mut ranks: Vec<u8> = cards.iter().map(|card| if card.rank() == 1 { 14 } else { card.rank() }).collect();
+        ranks.sort_unstable_by(|a, b| b.cmp(a));
+
+        // (枚数, ランク) を枚数の多い順、同じ枚数ならランクの高い順
+        let mut groups: Vec<(usize, u8)> = Vec::new();
+        for &rank in &ranks {
+            let count = ranks.iter().filter(|&&other| other == rank).count();
+            if !groups.contains(&(count, rank)) {
+                groups.push((count, rank));
+            }
+        }
+        groups.sort_unstable_by(|a, b| b.cmp(a));
+        let counts: Vec<usize> = groups.iter().map(|&(count, _)| count).collect();
+        let by_groups: Vec<u8> = groups.iter().map(|&(_, rank)| rank).collect();
+
+        let flush = cards.iter().all(|card| card.suit() == cards[0].suit());
+        let straight_high = match ranks.as_slice() {
+            [14, 5, 4, 3, 2] => Some(5),
+            [high, .., low] if groups.len() == 5 && high - low == 4 => Some(*high),
+            _ => None,
+        };
+
+        match (straight_high, flush, counts.as_slice()) {
+            (Some(high), true, _) => (8, vec![high]),
+            (_, _, [4, 1]) => (7, by_groups),
+            (_, _, [3, 2]) => (6, by_groups),
+            (None, true, _) => (5, ranks),
+            (Some(high), false, _) => (4, vec![high]),
+            (_, _, [3, 1, 1]) => (3, by_groups),
+            (_, _, [2, 2, 1]) => (2, by_groups),
+            (_, _, [2, 1, 1, 1]) => (1, by_groups),
+            _ => (0, ranks),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn 五枚の比較は教科書どおりの順序と一致する(a in cards(5), b in cards(5)) {
+            let (strength_a, strength_b) = (evaluate(&a), evaluate(&b));
+            let expected = reference_key(&a).cmp(&reference_key(&b));
+            prop_assert_eq!(compare(&strength_a, &strength_b), expected, "{:?} と {:?} の比較が正しくありません", a, b);
+
+            // 同じ強さの手は同じ値になる（値が一意に決まる）
+            prop_assert_eq!(expected == Ordering::Equal, strength_a == strength_b, "同じ強さの値が一致しません");
+        }
+
+        #[test]
+        fn 比較は全順序になる(a in cards(5), b in cards(5), c in cards(5)) {
+            let (a, b, c) = (evaluate(&a), evaluate(&b), evaluate(&c));
+            prop_assert_eq!(compare(&a, &a), Ordering::Equal, "同じ手が同じ強さになっていません");
+            prop_assert_eq!(compare(&a, &b), compare(&b, &a).reverse(), "比較の向きで結果が食い違います");
+            if compare(&a, &b) != Ordering::Greater && compare(&b, &c) != Ordering::Greater {
+                prop_assert_ne!(compare(&a, &c), Ordering::Greater, "比較が推移的になっていません");
+            }
+        }
+
+        #[test]
+        fn 七枚の評価は最も強い5枚と表引きに一致する(hand in cards(7)) {
+            let strength = evaluate(&hand);
+
+            let mut best: Option<HandStrength> = None;
+            for skip_a in 0..7 {
+                for skip_b in skip_a + 1..7 {
+                    let five: Vec<Card> = (0..7).filter(|&i| i != skip_a && i != skip_b).map(|i| hand[i]).collect();
+                    let five = evaluate(&five);
+                    if best.as_ref().is_none_or(|best| compare(&five, best) == Ordering::Greater) {
+                        best = Some(five);
+                    }
+                }
+            }
+            prop_assert_eq!(Some(&strength), best.as_ref(), "{:?} の評価が最も強い5枚と一致しません", hand);
+
+            let looked_up = LookupEvaluator::evaluate(&hand).map(|value| value.strength());
+            prop_assert_eq!(Some(strength), looked_up, "{:?} の評価が表引きと一致しません", hand);
+        }
+    }
+}
@@ -1,5 +1,9 @@
 use std::collections::HashMap;
-use crate::domain::model::card::Card;
+
+use serde::{Serialize, Deserialize};
+
+use crate::domain::model::card::{Card, Suit};
+use crate::domain::service::hand_evaluation::{HandRank, HandStrength};
 
 /// 手札の評価条件を表す仕様インターフェース
 pub trait HandSpecification {
@@ -228,4 +232,464 @@ impl<T: HandSpecification> HandSpecification for NotSpecification<T> {
     fn name(&self) -> &'static str {
         self.name
     }
-} 
\ No newline at end of file
+}
+
+/// 役の表で条件を調べる手札（ランクの数え方とストレートのつながりを一度だけ計算しておく）
+pub struct HandShape<'a> {
+    cards: &'a [Card],
+    lowest_rank: u8,
+    ranks: Vec<u8>,           // Aを14とした降順のランク
+    groups: Vec<(u8, u8)>,    // (枚数, ランク) を枚数の多い順、同じ枚数ならランクの高い順に並べたもの
+    flush: bool,
+    straight_high: Option<u8>,
+}
+
+impl<'a> HandShape<'a> {
+    /// lowest_rank はデッキで最も低いランク（Aと合わせて最も低いストレートを作る）
+    pub fn new(cards: &'a [Card], lowest_rank: u8) -> Self {
+        let mut ranks: Vec<u8> = cards.iter().map(high_rank).collect();
+        ranks.sort_by(|a, b| b.cmp(a));
+        
+        let mut groups: Vec<(u8, u8)> = Vec::new();
+        for &rank in &ranks {
+            match groups.iter_mut().find(|(_, grouped)| *grouped == rank) {
+                Some((count, _)) => *count += 1,
+                None => groups.push((1, rank)),
+            }
+        }
+        groups.sort_by(|a, b| b.cmp(a));
+        
+        let flush = cards.len() >= 5 && cards.iter().all(|card| card.suit() == cards[0].suit());
+        let straight_high = if cards.len() >= 5 { straight_high(&ranks, lowest_rank, 5) } else { None };
+        Self { cards, lowest_rank, ranks, groups, flush, straight_high }
+    }
+    
+    pub fn cards(&self) -> &[Card] {
+        self.cards
+    }
+    
+    pub fn lowest_rank(&self) -> u8 {
+        self.lowest_rank
+    }
+    
+    /// Aを14とした降順のランク
+    pub fn ranks(&self) -> &[u8] {
+        &self.ranks
+    }
+    
+    /// 同じランクの枚数の組 (枚数, ランク)、枚数の多い順
+    pub fn groups(&self) -> &[(u8, u8)] {
+        &self.groups
+    }
+    
+    pub fn is_flush(&self) -> bool {
+        self.flush
+    }
+    
+    /// 5枚のストレートなら最も高いカードのランク
+    pub fn straight_high(&self) -> Option<u8> {
+        self.straight_high
+    }
+    
+    // 上から n 番目に多い組の枚数
+    fn group_size(&self, n: usize) -> u8 {
+        self.groups.get(n).map_or(0, |&(count, _)| count)
+    }
+    
+    // 枚数の多い組のランクを count 個並べ、残りのランクを高い順に kickers 枚続ける
+    fn grouped_values(&self, count: usize, kickers: usize) -> Vec<u8> {
+        let mut values: Vec<u8> = self.groups.iter().take(count).map(|&(_, rank)| rank).collect();
+        let mut rest: Vec<u8> = self.groups.iter().skip(count).map(|&(_, rank)| rank).collect();
+        rest.sort_by(|a, b| b.cmp(a));
+        values.extend(rest.into_iter().take(kickers));
+        values
+    }
+}
+
+// Aを14として扱ったランク
+fn high_rank(card: &Card) -> u8 {
+    if card.rank() == 1 { 14 } else { card.rank() }
+}
+
+// 降順のランクから length 枚つながる最も高いカード（Aはデッキで最も低いランクの下にもなる）
+fn straight_high(ranks: &[u8], lowest_rank: u8, length: usize) -> Option<u8> {
+    let mut ranks = ranks.to_vec();
+    if ranks.contains(&14) {
+        ranks.push(lowest_rank - 1);
+    }
+    ranks.sort();
+    ranks.dedup();
+    ranks.windows(length)
+        .rev()
+        .find(|window| window[length - 1] - window[0] == length as u8 - 1)
+        .map(|window| window[length - 1])
+}
+
+/// 役の表に載せる役の条件と、同じ役どうしを比べる値（タイブレーク）の取り出し方
+pub trait RankingSpecification: Sync {
+    /// この手札が役の条件を満たすかどうか
+    fn matches(&self, hand: &HandShape) -> bool;
+    /// 同じ役どうしを比べる値（前から順に比べ、大きいほど強い）
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8>;
+}
+
+/// ファイブカード（ワイルドカードで同じランク5枚）の仕様
+pub struct FiveOfAKindSpecification;
+
+impl RankingSpecification for FiveOfAKindSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.group_size(0) >= 5
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.grouped_values(1, 0)
+    }
+}
+
+impl RankingSpecification for RoyalStraightFlushSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.is_flush() && hand.straight_high() == Some(14)
+    }
+    
+    fn tie_break(&self, _hand: &HandShape) -> Vec<u8> {
+        vec![14]
+    }
+}
+
+/// ストレートフラッシュの仕様
+pub struct StraightFlushSpecification;
+
+impl RankingSpecification for StraightFlushSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.is_flush() && hand.straight_high().is_some()
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.straight_high().into_iter().collect()
+    }
+}
+
+impl RankingSpecification for FourOfAKindSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.group_size(0) >= 4
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.grouped_values(1, 1)
+    }
+}
+
+impl RankingSpecification for FullHouseSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.group_size(0) >= 3 && hand.group_size(1) >= 2
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.grouped_values(2, 0)
+    }
+}
+
+impl RankingSpecification for FlushSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.is_flush()
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.ranks().iter().copied().take(5).collect()
+    }
+}
+
+impl RankingSpecification for StraightSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.straight_high().is_some()
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.straight_high().into_iter().collect()
+    }
+}
+
+/// スリーカードの仕様
+pub struct ThreeOfAKindSpecification;
+
+impl RankingSpecification for ThreeOfAKindSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.group_size(0) >= 3
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.grouped_values(1, 2)
+    }
+}
+
+/// ツーペアの仕様
+pub struct TwoPairSpecification;
+
+impl RankingSpecification for TwoPairSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.group_size(0) >= 2 && hand.group_size(1) >= 2
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.grouped_values(2, 1)
+    }
+}
+
+/// ワンペアの仕様
+pub struct OnePairSpecification;
+
+impl RankingSpecification for OnePairSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        hand.group_size(0) >= 2
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.grouped_values(1, 3)
+    }
+}
+
+/// ハイカード（どの手札も満たす）の仕様
+pub struct HighCardSpecification;
+
+impl RankingSpecification for HighCardSpecification {
+    fn matches(&self, _hand: &HandShape) -> bool {
+        true
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.ranks().iter().copied().take(5).collect()
+    }
+}
+
+/// スキート（2・5・9と、2と5の間のカード1枚、5と9の間のカード1枚）の仕様
+pub struct SkeetSpecification;
+
+impl RankingSpecification for SkeetSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        let ranks = hand.ranks();
+        ranks.len() == 5
+            && hand.group_size(0) == 1
+            && [2, 5, 9].iter().all(|rank| ranks.contains(rank))
+            && ranks.iter().any(|rank| (3..5).contains(rank))
+            && ranks.iter().any(|rank| (6..9).contains(rank))
+    }
+    
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        hand.ranks().to_vec()
+    }
+}
+
+/// ビッグボブテイル（同じスートで4枚つながったカード）の仕様
+pub struct BigBobtailSpecification;
+
+impl BigBobtailSpecification {
+    // 4枚のストレートフラッシュの最も高いカード
+    fn run_high(hand: &HandShape) -> Option<u8> {
+        Suit::all().iter()
+            .filter_map(|&suit| {
+                let ranks: Vec<u8> = hand.cards().iter()
+                    .filter(|card| card.suit() == suit)
+                    .map(high_rank)
+                    .collect();
+                straight_high(&ranks, hand.lowest_rank(), 4)
+            })
+            .max()
+    }
+}
+
+impl RankingSpecification for BigBobtailSpecification {
+    fn matches(&self, hand: &HandShape) -> bool {
+        Self::run_high(hand).is_some()
+    }
+    
+    // つながりの最も高いカード、残りのカードの順に比べる
+    fn tie_break(&self, hand: &HandShape) -> Vec<u8> {
+        let Some(high) = Self::run_high(hand) else {
+            return Vec::new();
+        };
+        // つながりの4枚を1枚ずつ除いた残りがキッカーになる（Aから始まる場合のAは14として数えられている）
+        let mut rest = hand.ranks().to_vec();
+        for rank in high - 3..=high {
+            let rank = if rank < hand.lowest_rank() { 14 } else { rank };
+            if let Some(i) = rest.iter().position(|&r| r == rank) {
+                rest.remove(i);
+            }
+        }
+        let mut values = vec![high];
+        values.extend(rest);
+        values
+    }
+}
+
+/// 役の条件と同じ役どうしを比べる値の取り出し方
+pub fn ranking_specification(rank: HandRank) -> &'static dyn RankingSpecification {
+    match rank {
+        HandRank::FiveOfAKind => &FiveOfAKindSpecification,
+        HandRank::RoyalStraightFlush => &RoyalStraightFlushSpecification,
+        HandRank::StraightFlush => &StraightFlushSpecification,
+        HandRank::FourOfAKind => &FourOfAKindSpecification,
+        HandRank::FullHouse => &FullHouseSpecification,
+        HandRank::Flush => &FlushSpecification,
+        HandRank::Straight => &StraightSpecification,
+        HandRank::ThreeOfAKind => &ThreeOfAKindSpecification,
+        HandRank::TwoPair => &TwoPairSpecification,
+        HandRank::OnePair => &OnePairSpecification,
+        HandRank::HighCard => &HighCardSpecification,
+        HandRank::Skeet => &SkeetSpecification,
+        HandRank::BigBobtail => &BigBobtailSpecification,
+    }
+}
+
+/// 役の表（弱い順に並べた役）
+///
+/// 強い役から順に条件を調べ、最初に満たした役になる。強さは表の順番で比べる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "Vec<HandRank>", try_from = "Vec<HandRank>")]
+pub struct HandRankingTable {
+    ranks: [HandRank; Self::CAPACITY],
+    len: usize,
+}
+
+impl HandRankingTable {
+    /// 表に載せられる役の数
+    pub const CAPACITY: usize = 16;
+    
+    /// 通常の役の表（ファイブカードはワイルドカードを使う場合だけできる）
+    pub const STANDARD: Self = Self::from_ranks([
+        HandRank::HighCard,
+        HandRank::OnePair,
+        HandRank::TwoPair,
+        HandRank::ThreeOfAKind,
+        HandRank::Straight,
+        HandRank::Flush,
+        HandRank::FullHouse,
+        HandRank::FourOfAKind,
+        HandRank::StraightFlush,
+        HandRank::RoyalStraightFlush,
+        HandRank::FiveOfAKind,
+    ]);
+    
+    /// ショートデッキの役の表（スリーカードがストレートより、フラッシュがフルハウスより強い）
+    pub const SHORT_DECK: Self = Self::from_ranks([
+        HandRank::HighCard,
+        HandRank::OnePair,
+        HandRank::TwoPair,
+        HandRank::Straight,
+        HandRank::ThreeOfAKind,
+        HandRank::FullHouse,
+        HandRank::Flush,
+        HandRank::FourOfAKind,
+        HandRank::StraightFlush,
+        HandRank::RoyalStraightFlush,
+        HandRank::FiveOfAKind,
+    ]);
+    
+    /// 同じランクの枚数だけで決まる役の表（ストレートとフラッシュを数えない）
+    pub const SAME_RANKS: Self = Self::from_ranks([
+        HandRank::HighCard,
+        HandRank::OnePair,
+        HandRank::TwoPair,
+        HandRank::ThreeOfAKind,
+        HandRank::FullHouse,
+        HandRank::FourOfAKind,
+    ]);
+    
+    const fn from_ranks<const N: usize>(list: [HandRank; N]) -> Self {
+        let mut ranks = [HandRank::HighCard; Self::CAPACITY];
+        let mut i = 0;
+        while i < N {
+            ranks[i] = list[i];
+            i += 1;
+        }
+        Self { ranks, len: N }
+    }
+    
+    /// 弱い順に並べた役から表を作る（最も弱い役はハイカードで、同じ役は1度だけ）
+    pub fn new(list: &[HandRank]) -> Result<Self, &'static str> {
+        if list.first() != Some(&HandRank::HighCard) {
+            return Err("役の表の最も弱い役はハイカードでなければなりません");
+        }
+        if list.len() > Self::CAPACITY {
+            return Err("役の表に載せる役が多すぎます");
+        }
+        if list.iter().enumerate().any(|(i, rank)| list[..i].contains(rank)) {
+            return Err("役の表に同じ役が重複しています");
+        }
+        let mut ranks = [HandRank::HighCard; Self::CAPACITY];
+        ranks[..list.len()].copy_from_slice(list);
+        Ok(Self { ranks, len: list.len() })
+    }
+    
+    /// 弱い順に並べた役
+    pub fn ranks(&self) -> &[HandRank] {
+        &self.ranks[..self.len]
+    }
+    
+    pub fn contains(&self, rank: HandRank) -> bool {
+        self.ranks().contains(&rank)
+    }
+    
+    /// 役の順位（大きいほど強い、表にない役は0）
+    pub fn order(&self, rank: HandRank) -> u8 {
+        self.ranks().iter().position(|&listed| listed == rank).map_or(0, |i| i as u8 + 1)
+    }
+    
+    /// 2つの役の順番を入れ替える（どちらかが表になければそのまま）
+    pub fn with_swapped(self, a: HandRank, b: HandRank) -> Self {
+        let (Some(i), Some(j)) = (self.position(a), self.position(b)) else {
+            return self;
+        };
+        let mut table = self;
+        table.ranks.swap(i, j);
+        table
+    }
+    
+    /// 役を below のすぐ上に加える（すでに表にあれば移す）
+    pub fn with_inserted_above(self, rank: HandRank, below: HandRank) -> Result<Self, &'static str> {
+        let mut list: Vec<HandRank> = self.ranks().iter().copied().filter(|&listed| listed != rank).collect();
+        let Some(i) = list.iter().position(|&listed| listed == below) else {
+            return Err("基準にする役が表にありません");
+        };
+        list.insert(i + 1, rank);
+        Self::new(&list)
+    }
+    
+    fn position(&self, rank: HandRank) -> Option<usize> {
+        self.ranks().iter().position(|&listed| listed == rank)
+    }
+    
+    /// 強い役から順に条件を調べて手札の役を決める
+    pub fn evaluate(&self, hand: &HandShape) -> HandStrength {
+        self.ranks().iter().rev()
+            .find_map(|&rank| {
+                let specification = ranking_specification(rank);
+                specification.matches(hand).then(|| HandStrength::new(rank, specification.tie_break(hand)))
+            })
+            .unwrap_or_else(|| HandStrength::new(HandRank::HighCard, HighCardSpecification.tie_break(hand)))
+    }
+    
+    /// 役を弱い順に並べた説明（例: "ハイカード < ワンペア < ..."）
+    pub fn name(&self) -> String {
+        self.ranks().iter().map(|rank| rank.to_string()).collect::<Vec<_>>().join(" < ")
+    }
+}
+
+impl Default for HandRankingTable {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+impl From<HandRankingTable> for Vec<HandRank> {
+    fn from(table: HandRankingTable) -> Self {
+        table.ranks().to_vec()
+    }
+}
+
+impl TryFrom<Vec<HandRank>> for HandRankingTable {
+    type Error = &'static str;
+    
+    fn try_from(list: Vec<HandRank>) -> Result<Self, Self::Error> {
+        Self::new(&list)
+    }
+}
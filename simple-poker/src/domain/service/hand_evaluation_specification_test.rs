@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::domain::service::hand_evaluation_specification::*;
+    use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules, HandStrength};
     use crate::domain::model::card::{Card, Suit};
 
     fn テスト用手札(cards: Vec<(Suit, u8)>) -> Vec<Card> {
@@ -199,7 +200,7 @@ mod tests {
     }
 
     #[test]
-    fn 複合仕様_AND_成功() {
+    fn 複合仕様_and_成功() {
         let hand = テスト用手札(vec![
             (Suit::Heart, 5),
             (Suit::Heart, 6),
@@ -217,7 +218,7 @@ mod tests {
     }
 
     #[test]
-    fn 複合仕様_AND_失敗() {
+    fn 複合仕様_and_失敗() {
         let hand = テスト用手札(vec![
             (Suit::Heart, 5),
             (Suit::Heart, 6),
@@ -234,7 +235,7 @@ mod tests {
     }
 
     #[test]
-    fn 複合仕様_OR_成功() {
+    fn 複合仕様_or_成功() {
         let hand = テスト用手札(vec![
             (Suit::Club, 7),
             (Suit::Diamond, 7),
@@ -252,7 +253,7 @@ mod tests {
     }
 
     #[test]
-    fn 複合仕様_OR_両方成立() {
+    fn 複合仕様_or_両方成立() {
         // このケースはポーカーでは起こりえないが、テストのため
         let hand = テスト用手札(vec![
             (Suit::Heart, 7),
@@ -270,7 +271,7 @@ mod tests {
     }
 
     #[test]
-    fn 複合仕様_NOT_成功() {
+    fn 複合仕様_not_成功() {
         let hand = テスト用手札(vec![
             (Suit::Club, 2),
             (Suit::Diamond, 5),
@@ -287,7 +288,7 @@ mod tests {
     }
 
     #[test]
-    fn 複合仕様_NOT_失敗() {
+    fn 複合仕様_not_失敗() {
         let hand = テスト用手札(vec![
             (Suit::Heart, 2),
             (Suit::Heart, 5),
@@ -301,4 +302,98 @@ mod tests {
         
         assert!(!not_spec.is_satisfied_by(&hand));
     }
-} 
\ No newline at end of file
+
+    fn 表で評価(table: HandRankingTable, cards: Vec<(Suit, u8)>) -> HandStrength {
+        let hand = テスト用手札(cards);
+        table.evaluate(&HandShape::new(&hand, 2))
+    }
+
+    #[test]
+    fn 通常の表は強い役から順に条件を調べる() {
+        let full_house = 表で評価(HandRankingTable::STANDARD, vec![
+            (Suit::Club, 8), (Suit::Diamond, 8), (Suit::Heart, 8), (Suit::Spade, 4), (Suit::Club, 4),
+        ]);
+        assert_eq!(full_house.rank(), HandRank::FullHouse, "フルハウスになっていません");
+        assert_eq!(full_house.values(), &vec![8, 4], "フルハウスのタイブレークが正しくありません");
+
+        let wheel = 表で評価(HandRankingTable::STANDARD, vec![
+            (Suit::Club, 1), (Suit::Diamond, 2), (Suit::Heart, 3), (Suit::Spade, 4), (Suit::Club, 5),
+        ]);
+        assert_eq!(wheel.rank(), HandRank::Straight, "ホイールがストレートになっていません");
+        assert_eq!(wheel.values(), &vec![5], "ホイールが5ハイになっていません");
+
+        // 2枚だけの手札はペアかハイカードにしかならない
+        let suited = 表で評価(HandRankingTable::STANDARD, vec![(Suit::Heart, 1), (Suit::Heart, 13)]);
+        assert_eq!(suited.rank(), HandRank::HighCard, "2枚の手札がハイカードになっていません");
+        assert_eq!(suited.values(), &vec![14, 13], "ハイカードの値が正しくありません");
+    }
+
+    #[test]
+    fn スキートを加えた表ではスキートがストレートより強い() {
+        let table = HandRankingTable::STANDARD.with_inserted_above(HandRank::Skeet, HandRank::Straight).unwrap();
+        let skeet = vec![(Suit::Club, 9), (Suit::Diamond, 7), (Suit::Heart, 5), (Suit::Spade, 3), (Suit::Club, 2)];
+        assert_eq!(表で評価(HandRankingTable::STANDARD, skeet.clone()).rank(), HandRank::HighCard, "通常の表でスキートになっています");
+
+        let strength = 表で評価(table, skeet);
+        assert_eq!(strength.rank(), HandRank::Skeet, "スキートになっていません");
+        assert_eq!(strength.values(), &vec![9, 7, 5, 3, 2], "スキートのタイブレークが正しくありません");
+
+        let straight = 表で評価(table, vec![(Suit::Club, 10), (Suit::Diamond, 9), (Suit::Heart, 8), (Suit::Spade, 7), (Suit::Club, 6)]);
+        let rules = HandRankingRules::STANDARD.with_table(table);
+        assert_eq!(rules.compare(&strength, &straight), std::cmp::Ordering::Greater, "スキートがストレートより強くなっていません");
+
+        // 2と5の間のカードがなければスキートではない
+        let not_skeet = 表で評価(table, vec![(Suit::Club, 9), (Suit::Diamond, 7), (Suit::Heart, 5), (Suit::Spade, 6), (Suit::Club, 2)]);
+        assert_eq!(not_skeet.rank(), HandRank::HighCard, "スキートでない手がスキートになっています");
+    }
+
+    #[test]
+    fn ビッグボブテイルは4枚のストレートフラッシュでキッカーも比べる() {
+        let table = HandRankingTable::STANDARD.with_inserted_above(HandRank::BigBobtail, HandRank::Flush).unwrap();
+        let king_kicker = 表で評価(table, vec![(Suit::Heart, 5), (Suit::Heart, 6), (Suit::Heart, 7), (Suit::Heart, 8), (Suit::Club, 13)]);
+        assert_eq!(king_kicker.rank(), HandRank::BigBobtail, "ビッグボブテイルになっていません");
+        assert_eq!(king_kicker.values(), &vec![8, 13], "ビッグボブテイルのタイブレークが正しくありません");
+
+        let pair_kicker = 表で評価(table, vec![(Suit::Heart, 5), (Suit::Heart, 6), (Suit::Heart, 7), (Suit::Heart, 8), (Suit::Club, 8)]);
+        assert_eq!(pair_kicker.values(), &vec![8, 8], "つながりと同じランクのキッカーが数えられていません");
+        assert!(king_kicker.values() > pair_kicker.values(), "キッカーで比べられていません");
+
+        let ace_low = 表で評価(table, vec![(Suit::Spade, 1), (Suit::Spade, 2), (Suit::Spade, 3), (Suit::Spade, 4), (Suit::Diamond, 9)]);
+        assert_eq!(ace_low.rank(), HandRank::BigBobtail, "A-2-3-4 がビッグボブテイルになっていません");
+        assert_eq!(ace_low.values(), &vec![4, 9], "A-2-3-4 のビッグボブテイルが4ハイになっていません");
+
+        let rules = HandRankingRules::STANDARD.with_table(table);
+        let flush = 表で評価(table, vec![(Suit::Club, 1), (Suit::Club, 12), (Suit::Club, 9), (Suit::Club, 6), (Suit::Club, 3)]);
+        assert_eq!(rules.compare(&ace_low, &flush), std::cmp::Ordering::Greater, "ビッグボブテイルがフラッシュより強くなっていません");
+    }
+
+    #[test]
+    fn 表の順番を入れ替えると強さの比べ方が変わる() {
+        let flush = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 1), (Suit::Club, 12), (Suit::Club, 9), (Suit::Club, 6), (Suit::Club, 3),
+        ]));
+        let full_house = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 8), (Suit::Diamond, 8), (Suit::Heart, 8), (Suit::Spade, 4), (Suit::Club, 4),
+        ]));
+        assert_eq!(HandRankingRules::STANDARD.compare(&flush, &full_house), std::cmp::Ordering::Less, "通常はフルハウスが強くありません");
+
+        let swapped = HandRankingRules::STANDARD.with_table(HandRankingTable::STANDARD.with_swapped(HandRank::Flush, HandRank::FullHouse));
+        assert!(swapped.flush_beats_full_house(), "フラッシュとフルハウスが入れ替わっていません");
+        assert_eq!(swapped.compare(&flush, &full_house), std::cmp::Ordering::Greater, "入れ替えた表でフラッシュが強くなっていません");
+    }
+
+    #[test]
+    fn 役の表の作成と保存形式() {
+        assert!(HandRankingTable::new(&[HandRank::OnePair, HandRank::HighCard]).is_err(), "ハイカードが最も弱くない表を作れてしまいます");
+        assert!(HandRankingTable::new(&[HandRank::HighCard, HandRank::OnePair, HandRank::OnePair]).is_err(), "役が重複した表を作れてしまいます");
+        assert!(HandRankingTable::STANDARD.with_inserted_above(HandRank::Skeet, HandRank::BigBobtail).is_err(), "表にない役の上に加えられてしまいます");
+
+        // 保存するときは弱い順の役の並びになる
+        let table = HandRankingTable::new(&[HandRank::HighCard, HandRank::OnePair, HandRank::Skeet]).unwrap();
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(json, r#"["HighCard","OnePair","Skeet"]"#, "保存形式が役の並びになっていません");
+        let restored: HandRankingTable = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, table, "役の表が復元されていません");
+        assert!(serde_json::from_str::<HandRankingTable>(r#"["OnePair"]"#).is_err(), "不正な表を読み込めてしまいます");
+    }
+}
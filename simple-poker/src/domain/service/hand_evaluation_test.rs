@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::cmp::Ordering;
+
     use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::GameVariant;
+    use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules};
 
     fn テスト用手札(cards: Vec<(Suit, u8)>) -> Vec<Card> {
         cards.into_iter()
@@ -238,9 +241,233 @@ mod tests {
             (Suit::Heart, 10),
         ]);
         
-        let strength = HandEvaluationService::find_best_five_card_hand(&cards);
+        let strength = HandEvaluationService::evaluate_hand(&cards);
         assert_eq!(strength.rank(), HandRank::FourOfAKind);
         assert_eq!(strength.values()[0], 8);  // フォーカードの値
         assert_eq!(strength.values()[1], 10); // キッカーの値
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn ローはエースを1として高いカードから比べる() {
+        // ストレートでもフラッシュでもホイールは最も強いロー
+        let wheel = HandEvaluationService::evaluate_low(&テスト用手札(vec![
+            (Suit::Heart, 1), (Suit::Heart, 2), (Suit::Heart, 3), (Suit::Heart, 4), (Suit::Heart, 5),
+        ]));
+        let six_low = HandEvaluationService::evaluate_low(&テスト用手札(vec![
+            (Suit::Club, 6), (Suit::Diamond, 4), (Suit::Heart, 3), (Suit::Spade, 2), (Suit::Club, 1),
+        ]));
+        let king_low = HandEvaluationService::evaluate_low(&テスト用手札(vec![
+            (Suit::Club, 13), (Suit::Diamond, 4), (Suit::Heart, 3), (Suit::Spade, 2), (Suit::Club, 1),
+        ]));
+
+        assert_eq!(wheel.rank(), HandRank::HighCard, "ホイールがハイカードとして扱われていません");
+        assert_eq!(wheel.values(), &vec![5, 4, 3, 2, 1], "ローの値が高い順になっていません");
+        assert!(wheel < six_low, "ホイールが6ローより強くありません");
+        assert!(six_low < king_low, "6ローがKローより強くありません");
+    }
+
+    #[test]
+    fn ローではペアのある手が弱い() {
+        let king_low = HandEvaluationService::evaluate_low(&テスト用手札(vec![
+            (Suit::Club, 13), (Suit::Diamond, 12), (Suit::Heart, 11), (Suit::Spade, 10), (Suit::Club, 8),
+        ]));
+        let pair_of_twos = HandEvaluationService::evaluate_low(&テスト用手札(vec![
+            (Suit::Club, 2), (Suit::Diamond, 2), (Suit::Heart, 3), (Suit::Spade, 4), (Suit::Club, 5),
+        ]));
+
+        assert_eq!(pair_of_twos.rank(), HandRank::OnePair, "ペアが判定されていません");
+        assert!(king_low < pair_of_twos, "ペアのない手がペアより強くありません");
+    }
+
+    #[test]
+    fn 七枚から最も強いローを選ぶ() {
+        let hand = テスト用手札(vec![
+            (Suit::Club, 8), (Suit::Diamond, 8), (Suit::Heart, 1), (Suit::Spade, 7),
+            (Suit::Club, 3), (Suit::Diamond, 13), (Suit::Heart, 2),
+        ]);
+        let low = HandEvaluationService::find_best_low_hand(&hand, &[]);
+
+        assert_eq!(low.rank(), HandRank::HighCard, "ペアのないローが選ばれていません");
+        assert_eq!(low.values(), &vec![8, 7, 3, 2, 1], "最も強いローが選ばれていません");
+    }
+
+    #[test]
+    fn ペアを避けられないローは最も低いペアを選ぶ() {
+        let hand = テスト用手札(vec![
+            (Suit::Club, 1), (Suit::Diamond, 1), (Suit::Club, 2), (Suit::Diamond, 2),
+            (Suit::Club, 3), (Suit::Diamond, 3), (Suit::Heart, 13),
+        ]);
+        let low = HandEvaluationService::find_best_low_hand(&hand, &[]);
+
+        assert_eq!(low.rank(), HandRank::OnePair, "ツーペアより強いワンペアが選ばれていません");
+        assert_eq!(low.values(), &vec![1, 13, 3, 2], "最も低いAのペアが選ばれていません");
+    }
+
+    #[test]
+    fn 表向きのカードだけで強さを比べる() {
+        let pair = HandEvaluationService::evaluate_showing(&テスト用手札(vec![(Suit::Club, 2), (Suit::Diamond, 2)]));
+        let ace_king = HandEvaluationService::evaluate_showing(&テスト用手札(vec![(Suit::Club, 1), (Suit::Diamond, 13)]));
+
+        assert_eq!(pair.rank(), HandRank::OnePair, "表向きのペアが判定されていません");
+        assert_eq!(HandRankingRules::SHOWING.compare(&pair, &ace_king), Ordering::Greater, "ペアがハイカードより強くありません");
+
+        // 表向きの4枚はストレートやフラッシュにならず、ペアはハイカードより強い
+        let suited_connectors = HandEvaluationService::evaluate_showing(&テスト用手札(vec![
+            (Suit::Heart, 9), (Suit::Heart, 10), (Suit::Heart, 11), (Suit::Heart, 12),
+        ]));
+        let trips = HandEvaluationService::evaluate_showing(&テスト用手札(vec![
+            (Suit::Club, 3), (Suit::Diamond, 3), (Suit::Heart, 3), (Suit::Spade, 13),
+        ]));
+        assert_eq!(suited_connectors.rank(), HandRank::HighCard, "表向きのカードでストレートやフラッシュを数えています");
+        assert_eq!(HandRankingRules::SHOWING.compare(&pair, &suited_connectors), Ordering::Greater, "ペアがつながった同じスートの4枚より強くありません");
+        assert_eq!(HandRankingRules::SHOWING.compare(&trips, &pair), Ordering::Greater, "スリーカードがペアより強くありません");
+    }
+
+    #[test]
+    fn デュース・トゥ・セブンではエースが高くストレートとフラッシュも数える() {
+        let seven_five = HandEvaluationService::evaluate_deuce_to_seven(&テスト用手札(vec![
+            (Suit::Spade, 7), (Suit::Diamond, 5), (Suit::Club, 4), (Suit::Heart, 3), (Suit::Spade, 2),
+        ]));
+        let ace_high = HandEvaluationService::evaluate_deuce_to_seven(&テスト用手札(vec![
+            (Suit::Spade, 1), (Suit::Diamond, 2), (Suit::Club, 3), (Suit::Heart, 4), (Suit::Spade, 5),
+        ]));
+        let straight = HandEvaluationService::evaluate_deuce_to_seven(&テスト用手札(vec![
+            (Suit::Spade, 6), (Suit::Diamond, 5), (Suit::Club, 4), (Suit::Heart, 3), (Suit::Spade, 2),
+        ]));
+        let flush = HandEvaluationService::evaluate_deuce_to_seven(&テスト用手札(vec![
+            (Suit::Heart, 8), (Suit::Heart, 6), (Suit::Heart, 4), (Suit::Heart, 3), (Suit::Heart, 2),
+        ]));
+
+        assert_eq!(seven_five.rank(), HandRank::HighCard);
+        assert_eq!(ace_high.rank(), HandRank::HighCard, "A-2-3-4-5 がストレートとして扱われています");
+        assert_eq!(ace_high.values(), &vec![14, 5, 4, 3, 2], "Aが高いカードとして扱われていません");
+        assert_eq!(straight.rank(), HandRank::Straight, "ストレートが判定されていません");
+        assert_eq!(flush.rank(), HandRank::Flush, "フラッシュが判定されていません");
+        assert!(seven_five < ace_high, "7-5-4-3-2がAハイより強くありません");
+        assert!(ace_high < straight, "Aハイがストレートより強くありません");
+        assert!(straight < flush, "ストレートがフラッシュより強くありません");
+
+        // ペアのある手はどんなハイカードよりも弱い
+        let pair_of_twos = HandEvaluationService::evaluate_deuce_to_seven(&テスト用手札(vec![
+            (Suit::Spade, 2), (Suit::Diamond, 2), (Suit::Club, 3), (Suit::Heart, 4), (Suit::Spade, 5),
+        ]));
+        assert_eq!(pair_of_twos.rank(), HandRank::OnePair, "ペアが判定されていません");
+        assert!(ace_high < pair_of_twos, "Aハイが2のペアより強くありません");
+    }
+
+    #[test]
+    fn オマハのローは手札2枚とボード3枚で8以下のみ認める() {
+        let board = テスト用手札(vec![
+            (Suit::Club, 2), (Suit::Diamond, 4), (Suit::Heart, 7), (Suit::Spade, 13), (Suit::Diamond, 12),
+        ]);
+        // 手札の A と 3 を使い、同じランクの 4 は使えない
+        let low = HandEvaluationService::find_best_omaha_low(&テスト用手札(vec![
+            (Suit::Heart, 1), (Suit::Spade, 3), (Suit::Club, 4), (Suit::Club, 10),
+        ]), &board).expect("ローが見つかりません");
+        assert_eq!(low.values(), &vec![7, 4, 3, 2, 1], "最も強いローが選ばれていません");
+
+        // 手札に8以下が1枚しかなければ、ボードに低いカードが4枚あってもローにならない
+        let no_low = HandEvaluationService::find_best_omaha_low(&テスト用手札(vec![
+            (Suit::Heart, 1), (Suit::Spade, 9), (Suit::Club, 10), (Suit::Club, 11),
+        ]), &テスト用手札(vec![
+            (Suit::Club, 2), (Suit::Diamond, 3), (Suit::Heart, 4), (Suit::Spade, 5), (Suit::Diamond, 12),
+        ]));
+        assert!(no_low.is_none(), "手札を2枚使わないローが認められています");
+
+        // 9を含むと条件を満たさない
+        let nine_low = HandEvaluationService::find_best_omaha_low(&テスト用手札(vec![
+            (Suit::Heart, 9), (Suit::Spade, 3), (Suit::Club, 10), (Suit::Club, 11),
+        ]), &board);
+        assert!(nine_low.is_none(), "9ローが認められています");
+
+        // 8ちょうどは条件を満たし、ほかのローとは高いカードから比べる
+        let eight_low = HandEvaluationService::find_best_omaha_low(&テスト用手札(vec![
+            (Suit::Heart, 8), (Suit::Spade, 5), (Suit::Club, 10), (Suit::Club, 11),
+        ]), &board).expect("8ローが認められていません");
+        assert_eq!(eight_low.values(), &vec![8, 7, 5, 4, 2], "8ローの値が正しくありません");
+        assert!(low < eight_low, "7ローが8ローより強くありません");
+    }
+
+    #[test]
+    fn ホイールは最も弱いストレートでストレートフラッシュも同じ() {
+        let wheel = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 1), (Suit::Diamond, 2), (Suit::Heart, 3), (Suit::Spade, 4), (Suit::Club, 5),
+        ]));
+        let six_high = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 2), (Suit::Diamond, 3), (Suit::Heart, 4), (Suit::Spade, 5), (Suit::Club, 6),
+        ]));
+        let broadway = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 10), (Suit::Diamond, 11), (Suit::Heart, 12), (Suit::Spade, 13), (Suit::Club, 1),
+        ]));
+        assert_eq!(wheel.values(), &vec![5], "ホイールが5ハイになっていません");
+        assert_eq!(HandRankingRules::STANDARD.compare(&wheel, &six_high), Ordering::Less, "ホイールが6ハイのストレートより強くなっています");
+        assert_eq!(HandRankingRules::STANDARD.compare(&six_high, &broadway), Ordering::Less, "6ハイのストレートがAハイのストレートより強くなっています");
+
+        let steel_wheel = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Heart, 1), (Suit::Heart, 2), (Suit::Heart, 3), (Suit::Heart, 4), (Suit::Heart, 5),
+        ]));
+        let six_high_flush = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Spade, 2), (Suit::Spade, 3), (Suit::Spade, 4), (Suit::Spade, 5), (Suit::Spade, 6),
+        ]));
+        assert_eq!(steel_wheel.rank(), HandRank::StraightFlush, "A-2-3-4-5 のストレートフラッシュになっていません");
+        assert_eq!(steel_wheel.values(), &vec![5], "A-2-3-4-5 のストレートフラッシュが5ハイになっていません");
+        assert_eq!(HandRankingRules::STANDARD.compare(&steel_wheel, &six_high_flush), Ordering::Less, "A-2-3-4-5 のストレートフラッシュが6ハイより強くなっています");
+    }
+
+    #[test]
+    fn 七枚ではキッカーを役に使う5枚から数える() {
+        // フォーカードの残りはペアのKとQから最も高い1枚だけ
+        let quads_with_pair = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 8), (Suit::Diamond, 8), (Suit::Heart, 8), (Suit::Spade, 8),
+            (Suit::Club, 13), (Suit::Diamond, 13), (Suit::Heart, 12),
+        ]));
+        let quads_with_king = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 8), (Suit::Diamond, 8), (Suit::Heart, 8), (Suit::Spade, 8),
+            (Suit::Club, 13), (Suit::Diamond, 3), (Suit::Heart, 2),
+        ]));
+        assert_eq!(quads_with_pair.values(), &vec![8, 13], "フォーカードのキッカーが正しくありません");
+        assert_eq!(quads_with_pair, quads_with_king, "使わないカードで強さが変わっています");
+
+        // スリーカードが2組あれば高い方の3枚と低い方の2枚でフルハウスになる
+        let two_trips = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 5), (Suit::Diamond, 5), (Suit::Heart, 5),
+            (Suit::Club, 9), (Suit::Diamond, 9), (Suit::Heart, 9), (Suit::Spade, 13),
+        ]));
+        assert_eq!(two_trips.rank(), HandRank::FullHouse, "スリーカード2組がフルハウスになっていません");
+        assert_eq!(two_trips.values(), &vec![9, 5], "フルハウスの値が正しくありません");
+
+        // ペアが3組なら3組目のペアもキッカーの候補になる
+        let three_pairs = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 4), (Suit::Diamond, 4), (Suit::Heart, 7), (Suit::Spade, 7),
+            (Suit::Club, 10), (Suit::Diamond, 10), (Suit::Heart, 2),
+        ]));
+        assert_eq!(three_pairs.values(), &vec![10, 7, 4], "ツーペアのキッカーが正しくありません");
+
+        // 7枚のうち5枚が同じスートならフラッシュになる
+        let flush = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Heart, 2), (Suit::Heart, 6), (Suit::Heart, 9), (Suit::Heart, 11), (Suit::Heart, 13),
+            (Suit::Club, 1), (Suit::Heart, 4),
+        ]));
+        assert_eq!(flush.rank(), HandRank::Flush, "7枚の中のフラッシュが判定されていません");
+        assert_eq!(flush.values(), &vec![13, 11, 9, 6, 4], "フラッシュの値が使う5枚になっていません");
+    }
+
+    #[test]
+    fn 同じ役はタイブレークの値を前から比べる() {
+        let rules = HandRankingRules::STANDARD;
+        let pair_ace_kicker = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Club, 9), (Suit::Diamond, 9), (Suit::Heart, 1), (Suit::Spade, 4), (Suit::Club, 3),
+        ]));
+        let pair_king_kicker = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Heart, 9), (Suit::Spade, 9), (Suit::Heart, 13), (Suit::Spade, 12), (Suit::Club, 11),
+        ]));
+        assert_eq!(pair_ace_kicker.values(), &vec![9, 14, 4, 3], "ワンペアの値が正しくありません");
+        assert_eq!(rules.compare(&pair_ace_kicker, &pair_king_kicker), Ordering::Greater, "Aのキッカーが強くなっていません");
+
+        // スートだけが違う手は同じ強さ
+        let same_ranks = HandEvaluationService::evaluate_hand(&テスト用手札(vec![
+            (Suit::Heart, 9), (Suit::Spade, 9), (Suit::Diamond, 1), (Suit::Heart, 4), (Suit::Spade, 3),
+        ]));
+        assert_eq!(rules.compare(&pair_ace_kicker, &same_ranks), Ordering::Equal, "スートで強さが変わっています");
+    }
+}
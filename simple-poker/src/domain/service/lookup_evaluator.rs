@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::domain::model::card::{Card, Suit};
+use crate::domain::model::game::GameVariant;
+use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules, HandStrength};
+
+/// ランクごとの素数（エースから順）。積が同じならランクの組み合わせも同じになる
+const PRIMES: [u64; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// 手の強さを表す比較可能な値
+///
+/// 値の大小は通常の決まり（`HandRankingRules::STANDARD`）で比べた `HandStrength` の大小と一致し、
+/// 値が同じなら `HandStrength` も同じになる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandValue(u16);
+
+impl HandValue {
+    pub fn value(self) -> u16 {
+        self.0
+    }
+
+    pub fn rank(self) -> HandRank {
+        tables().strengths[self.0 as usize].rank()
+    }
+
+    /// 同じ強さを表す `HandStrength`
+    pub fn strength(self) -> HandStrength {
+        tables().strengths[self.0 as usize].clone()
+    }
+}
+
+/// 事前計算した表を引くだけで手の強さを求める役判定
+///
+/// 5〜7枚のカードから、その中の最適な5枚の強さを `HandValue` として返す。
+/// 表は `HandEvaluationService::evaluate_hand` の結果から最初の呼び出し時に作られるため、
+/// 順序は既存の役判定と常に一致する。判定時にはヒープ確保をしない。
+pub struct LookupEvaluator;
+
+impl LookupEvaluator {
+    /// 5〜7枚のカード（重複なし）の最適な5枚の強さ
+    ///
+    /// 枚数が範囲外の場合と、表にないジョーカーが含まれる場合は None。
+    pub fn evaluate(cards: &[Card]) -> Option<HandValue> {
+        if !(5..=7).contains(&cards.len()) || cards.iter().any(|card| card.is_joker()) {
+            return None;
+        }
+
+        let tables = tables();
+        let mut product = 1u64;
+        let mut suit_masks = [0u16; 4];
+        for card in cards {
+            let index = Self::rank_index(card);
+            product *= PRIMES[index];
+            suit_masks[Self::suit_index(card.suit())] |= 1 << index;
+        }
+
+        // 同じスートのカードが5枚以上あればフラッシュも候補になる
+        let mut best = tables.lookup(product);
+        for mask in suit_masks {
+            if mask.count_ones() >= 5 {
+                best = best.max(tables.flushes[mask as usize]);
+            }
+        }
+        Some(HandValue(best))
+    }
+
+    /// ゲームの種類に応じた最適な5枚の強さ（表で扱えない枚数やカードの場合は None）
+    ///
+    /// `HandEvaluationService::find_best_hand` と同じ組み合わせから選ぶ。
+    pub fn find_best(hand: &[Card], community: &[Card], variant: GameVariant) -> Option<HandValue> {
+        match variant {
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw if hand.len() == 5 => Self::evaluate(hand),
+            GameVariant::FiveCardDraw | GameVariant::DeuceToSevenTripleDraw => None,
+            GameVariant::TexasHoldem | GameVariant::SevenCardStud | GameVariant::Razz => {
+                let total = hand.len() + community.len();
+                if !(5..=7).contains(&total) {
+                    return None;
+                }
+                let mut cards = [*hand.iter().chain(community).next()?; 7];
+                cards[..hand.len()].copy_from_slice(hand);
+                cards[hand.len()..total].copy_from_slice(community);
+                Self::evaluate(&cards[..total])
+            },
+            GameVariant::Omaha | GameVariant::OmahaHiLo if hand.len() >= 4 => Self::evaluate_combinations(hand, 2, community, 3),
+            GameVariant::Omaha | GameVariant::OmahaHiLo => None,
+            // 表は52枚のデッキの決まりで作っているので、ショートデッキは扱わない
+            GameVariant::ShortDeckHoldem => None,
+        }
+    }
+
+    /// 指定した枚数ずつ手札とボードから選ぶ組み合わせの中で最強の値（オマハ用）
+    ///
+    /// ジョーカーが含まれる場合は None。
+    pub fn evaluate_combinations(hand: &[Card], hand_count: usize, board: &[Card], board_count: usize) -> Option<HandValue> {
+        if hand_count + board_count != 5 || hand.len() < hand_count || board.len() < board_count {
+            return None;
+        }
+        // 一部の組み合わせだけ評価すると最強を取り違えるので、ジョーカーがあれば全体を扱わない
+        if hand.iter().chain(board).any(|card| card.is_joker()) {
+            return None;
+        }
+
+        let mut best = None;
+        let mut cards = [hand[0]; 5];
+        for_each_subset(hand.len(), hand_count, |hand_indices| {
+            for (slot, &i) in hand_indices.iter().enumerate() {
+                cards[slot] = hand[i];
+            }
+            for_each_subset(board.len(), board_count, |board_indices| {
+                for (slot, &i) in board_indices.iter().enumerate() {
+                    cards[hand_count + slot] = board[i];
+                }
+                let value = Self::evaluate(&cards);
+                best = best.max(value);
+            });
+        });
+        best
+    }
+
+    fn rank_index(card: &Card) -> usize {
+        card.rank() as usize - 1
+    }
+
+    fn suit_index(suit: Suit) -> usize {
+        match suit {
+            Suit::Club => 0,
+            Suit::Diamond => 1,
+            Suit::Heart => 2,
+            Suit::Spade => 3,
+        }
+    }
+}
+
+// n 個から k 個を選ぶすべての組み合わせ（インデックス、昇順）について f を呼ぶ
+fn for_each_subset(n: usize, k: usize, mut f: impl FnMut(&[usize])) {
+    if k > n {
+        return;
+    }
+    let mut indices = [0usize; 5];
+    for (i, index) in indices.iter_mut().enumerate().take(k) {
+        *index = i;
+    }
+    loop {
+        f(&indices[..k]);
+        let Some(i) = (0..k).rev().find(|&i| indices[i] < n - k + i) else {
+            return;
+        };
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+    }
+}
+
+struct Tables {
+    strengths: Vec<HandStrength>, // 値 → 強さ（昇順）
+    flushes: Vec<u16>,            // 同じスートのランクのビット列 → その中で最強のフラッシュ系の値
+    products: Vec<(u64, u16)>,    // 5〜7枚のランクの素数の積 → フラッシュを除いた最強の値（積で昇順）
+}
+
+impl Tables {
+    fn lookup(&self, product: u64) -> u16 {
+        match self.products.binary_search_by_key(&product, |&(key, _)| key) {
+            Ok(index) => self.products[index].1,
+            Err(_) => 0,
+        }
+    }
+
+    fn build() -> Self {
+        // 5枚のランクの組み合わせ（各ランク4枚まで）を既存の役判定で評価する
+        let mut five_card = Vec::new();
+        let mut counts = [0u8; 13];
+        for_each_rank_multiset(&mut counts, 0, 5, &mut |counts| {
+            let ranks = ranks_of(counts);
+            let mixed = cards_of(&ranks, |i| Suit::all()[i % 4]);
+            let strength = HandEvaluationService::evaluate_hand(&mixed);
+            let flush = (ranks.len() == ranks_distinct(counts)).then(|| {
+                HandEvaluationService::evaluate_hand(&cards_of(&ranks, |_| Suit::Spade))
+            });
+            five_card.push((product_of(counts), mask_of(counts), strength, flush));
+        });
+
+        let mut strengths: Vec<HandStrength> = five_card.iter()
+            .flat_map(|(_, _, strength, flush)| std::iter::once(strength.clone()).chain(flush.clone()))
+            .collect();
+        let rules = HandRankingRules::STANDARD;
+        strengths.sort_by(|a, b| rules.compare(a, b));
+        strengths.dedup();
+        let value_of = |strength: &HandStrength| {
+            strengths.binary_search_by(|listed| rules.compare(listed, strength)).expect("評価済みの強さ") as u16
+        };
+
+        let mut flush_five = vec![0u16; 1 << 13];
+        let mut by_product: HashMap<u64, u16> = HashMap::new();
+        for (product, mask, strength, flush) in &five_card {
+            by_product.insert(*product, value_of(strength));
+            if let Some(flush) = flush {
+                flush_five[*mask as usize] = value_of(flush);
+            }
+        }
+
+        // 6・7枚はそこから1・2枚除いた組み合わせの中の最強
+        for size in 6..=7 {
+            let mut counts = [0u8; 13];
+            let mut entries = Vec::new();
+            for_each_rank_multiset(&mut counts, 0, size, &mut |counts| {
+                let mut reduced = *counts;
+                let mut best = 0;
+                for rank in 0..13 {
+                    if reduced[rank] == 0 {
+                        continue;
+                    }
+                    reduced[rank] -= 1;
+                    best = best.max(by_product[&product_of(&reduced)]);
+                    reduced[rank] += 1;
+                }
+                entries.push((product_of(counts), best));
+            });
+            by_product.extend(entries);
+        }
+
+        let mut flushes = vec![0u16; 1 << 13];
+        for (mask, best) in flushes.iter_mut().enumerate() {
+            let bits = (mask as u16).count_ones();
+            if !(5..=7).contains(&bits) {
+                continue;
+            }
+            let ranks: Vec<usize> = (0..13).filter(|rank| mask & (1 << rank) != 0).collect();
+            for_each_subset(ranks.len(), 5, |indices| {
+                let subset = indices.iter().fold(0usize, |subset, &i| subset | (1 << ranks[i]));
+                *best = (*best).max(flush_five[subset]);
+            });
+        }
+
+        let mut products: Vec<(u64, u16)> = by_product.into_iter().collect();
+        products.sort_unstable();
+        Self { strengths, flushes, products }
+    }
+}
+
+fn tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(Tables::build)
+}
+
+// 合計 remaining 枚になる各ランクの枚数（4枚まで）の組み合わせについて f を呼ぶ
+fn for_each_rank_multiset(counts: &mut [u8; 13], rank: usize, remaining: u8, f: &mut impl FnMut(&[u8; 13])) {
+    if remaining == 0 {
+        f(counts);
+        return;
+    }
+    if rank == 13 {
+        return;
+    }
+    for count in (0..=remaining.min(4)).rev() {
+        counts[rank] = count;
+        for_each_rank_multiset(counts, rank + 1, remaining - count, f);
+    }
+    counts[rank] = 0;
+}
+
+fn ranks_of(counts: &[u8; 13]) -> Vec<u8> {
+    (0..13u8).flat_map(|rank| std::iter::repeat_n(rank + 1, counts[rank as usize] as usize)).collect()
+}
+
+fn ranks_distinct(counts: &[u8; 13]) -> usize {
+    counts.iter().filter(|&&count| count > 0).count()
+}
+
+// 同じランクのカードには異なるスートを割り当てる
+fn cards_of(ranks: &[u8], suit: impl Fn(usize) -> Suit) -> Vec<Card> {
+    ranks.iter().enumerate()
+        .map(|(i, &rank)| Card::new(suit(i), rank).expect("有効なランク"))
+        .collect()
+}
+
+fn product_of(counts: &[u8; 13]) -> u64 {
+    counts.iter().enumerate().map(|(rank, &count)| PRIMES[rank].pow(count as u32)).product()
+}
+
+fn mask_of(counts: &[u8; 13]) -> u16 {
+    (0..13).filter(|&rank| counts[rank] > 0).fold(0, |mask, rank| mask | (1 << rank))
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::GameVariant;
+    use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules, HandStrength};
+    use crate::domain::service::lookup_evaluator::LookupEvaluator;
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    fn deck() -> Vec<Card> {
+        Suit::all().iter()
+            .flat_map(|&suit| (1..=13).map(move |rank| card(suit, rank)))
+            .collect()
+    }
+
+    // 5枚の組み合わせをすべて試して最強の役を求める（比較用）
+    fn slow_best(cards: &[Card]) -> HandStrength {
+        let n = cards.len();
+        let mut best = HandStrength::new(HandRank::HighCard, vec![]);
+        for a in 0..n {
+            for b in a + 1..n {
+                for c in b + 1..n {
+                    for d in c + 1..n {
+                        for e in d + 1..n {
+                            let strength = HandEvaluationService::evaluate_hand(&[cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                            if HandRankingRules::STANDARD.compare(&strength, &best) == Ordering::Greater {
+                                best = strength;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn すべての5枚の組み合わせで既存の役判定と一致する() {
+        let deck = deck();
+        let mut hands = 0;
+        let mut previous: Option<(HandStrength, u16)> = None;
+        for a in 0..52 {
+            for b in a + 1..52 {
+                for c in b + 1..52 {
+                    for d in c + 1..52 {
+                        for e in d + 1..52 {
+                            let cards = [deck[a], deck[b], deck[c], deck[d], deck[e]];
+                            let expected = HandEvaluationService::evaluate_hand(&cards);
+                            let value = LookupEvaluator::evaluate(&cards).expect("5枚は評価できるはずです");
+                            assert_eq!(value.strength(), expected, "{:?} の評価が一致しません", cards);
+
+                            // 値の大小も強さの大小と一致する
+                            if let Some((strength, previous_value)) = &previous {
+                                assert_eq!(HandRankingRules::STANDARD.compare(strength, &expected), previous_value.cmp(&value.value()), "{:?} の大小が一致しません", cards);
+                            }
+                            previous = Some((expected, value.value()));
+                            hands += 1;
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(hands, 2_598_960, "組み合わせの数が一致しません");
+    }
+
+    #[test]
+    fn 六枚と七枚では最適な5枚を選ぶ() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut deck = deck();
+        for size in [6, 7] {
+            for _ in 0..3_000 {
+                let (cards, _) = deck.partial_shuffle(&mut rng, size);
+                let value = LookupEvaluator::evaluate(cards).expect("評価できるはずです");
+                assert_eq!(value.strength(), slow_best(cards), "{:?} の評価が一致しません", cards);
+            }
+        }
+    }
+
+    #[test]
+    fn 表で扱えない枚数は評価しない() {
+        let deck = deck();
+        assert_eq!(LookupEvaluator::evaluate(&deck[..4]), None, "4枚で評価できてしまいます");
+        assert_eq!(LookupEvaluator::evaluate(&deck[..8]), None, "8枚で評価できてしまいます");
+        assert_eq!(LookupEvaluator::find_best(&deck[..2], &deck[2..4], GameVariant::TexasHoldem), None, "フロップ前に評価できてしまいます");
+    }
+
+    #[test]
+    fn ジョーカーを含む手は評価しない() {
+        let hand = [card(Suit::Spade, 1), card(Suit::Spade, 13), card(Suit::Spade, 12), card(Suit::Spade, 11), Card::joker(Suit::Spade)];
+        assert_eq!(LookupEvaluator::evaluate(&hand), None, "ジョーカーを含む5枚を評価できてしまいます");
+
+        let board = [card(Suit::Heart, 2), card(Suit::Club, 7), card(Suit::Diamond, 9)];
+        assert_eq!(LookupEvaluator::find_best(&hand[3..], &board, GameVariant::TexasHoldem), None, "ジョーカーを含むホールカードを評価できてしまいます");
+        assert_eq!(LookupEvaluator::evaluate_combinations(&hand[1..], 2, &board, 3), None, "ジョーカーを含むオマハの手を評価できてしまいます");
+    }
+
+    #[test]
+    fn オマハはホールカード2枚とボード3枚を使う() {
+        // ホールカードのハートが1枚なのでフラッシュにならない
+        let hand = [card(Suit::Heart, 13), card(Suit::Spade, 12), card(Suit::Club, 12), card(Suit::Diamond, 3)];
+        let board = [card(Suit::Heart, 2), card(Suit::Heart, 6), card(Suit::Heart, 9), card(Suit::Heart, 11), card(Suit::Diamond, 12)];
+
+        let value = LookupEvaluator::find_best(&hand, &board, GameVariant::Omaha).unwrap();
+        assert_eq!(value.rank(), HandRank::ThreeOfAKind, "役が一致しません");
+    }
+}
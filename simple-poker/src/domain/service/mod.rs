@@ -1,3 +1,35 @@
 pub mod hand_evaluation;
 pub mod game_rule;
 pub mod hand_evaluation_specification;
+pub mod game_projector;
+pub mod player_strategy;
+pub mod equity_calculator;
+pub mod lookup_evaluator;
+pub mod table_director;
+
+#[cfg(test)]
+mod hand_evaluation_test;
+
+#[cfg(test)]
+mod hand_evaluation_property_test;
+
+#[cfg(test)]
+mod game_rule_test;
+
+#[cfg(test)]
+mod game_projector_test;
+
+#[cfg(test)]
+mod hand_evaluation_specification_test;
+
+#[cfg(test)]
+mod player_strategy_test;
+
+#[cfg(test)]
+mod equity_calculator_test;
+
+#[cfg(test)]
+mod lookup_evaluator_test;
+
+#[cfg(test)]
+mod table_director_test;
@@ -0,0 +1,407 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::domain::model::bet::BetAction;
+use crate::domain::model::card::{Card, Suit};
+use crate::domain::model::error::DomainError;
+use crate::domain::model::game::{BettingRound, Game, GamePhase, GameVariant};
+use crate::domain::model::player::BotKind;
+use crate::domain::service::equity_calculator::{EquityCalculator, EquityParams};
+use crate::domain::service::game_rule::GameRuleService;
+use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules, HandStrength};
+
+/// 他のプレイヤーについて見える情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpponentInfo<'a> {
+    pub seat: usize,
+    pub name: &'a str,
+    pub chips: u32,
+    pub current_bet: u32,
+    pub is_folded: bool,
+    pub is_all_in: bool,
+}
+
+/// 1つの席から見たゲームの状態
+///
+/// 自分の手札とテーブル上の公開情報だけを参照でき、他のプレイヤーの手札やデッキは見えない。
+pub struct PlayerView<'a> {
+    game: &'a Game,
+    seat: usize,
+}
+
+impl<'a> PlayerView<'a> {
+    pub fn new(game: &'a Game, seat: usize) -> Result<Self, DomainError> {
+        if seat >= game.players().len() {
+            return Err(DomainError::InvalidGameOperation(format!("無効なプレイヤーインデックスです: {}", seat)));
+        }
+        Ok(Self { game, seat })
+    }
+
+    pub fn seat(&self) -> usize {
+        self.seat
+    }
+
+    pub fn variant(&self) -> GameVariant {
+        self.game.variant()
+    }
+
+    /// ゲームで使う役の決まり
+    pub fn hand_ranking_rules(&self) -> HandRankingRules {
+        self.game.hand_ranking_rules()
+    }
+
+    pub fn phase(&self) -> GamePhase {
+        self.game.current_phase()
+    }
+
+    pub fn round(&self) -> Option<BettingRound> {
+        self.game.current_round()
+    }
+
+    /// 自分の手札
+    pub fn hand(&self) -> &'a [Card] {
+        self.game.players()[self.seat].hand().cards()
+    }
+
+    pub fn community_cards(&self) -> &'a [Card] {
+        self.game.community_cards()
+    }
+
+    pub fn chips(&self) -> u32 {
+        self.game.players()[self.seat].chips()
+    }
+
+    /// このラウンドで自分が既にベットしている額
+    pub fn my_bet(&self) -> u32 {
+        self.game.players()[self.seat].current_bet()
+    }
+
+    pub fn pot(&self) -> u32 {
+        self.game.pot().total()
+    }
+
+    pub fn current_bet(&self) -> u32 {
+        self.game.current_bet()
+    }
+
+    pub fn big_blind(&self) -> u32 {
+        self.game.big_blind()
+    }
+
+    /// コールに必要な額
+    pub fn call_amount(&self) -> u32 {
+        self.current_bet().saturating_sub(self.my_bet())
+    }
+
+    /// レイズ後のベット額として指定できる範囲（レイズできない場合は None）
+    pub fn raise_range(&self) -> Option<(u32, u32)> {
+        GameRuleService::raise_range(self.game, self.seat)
+    }
+
+    /// レイズ後のベット額として指定できる最小値
+    pub fn min_raise_to(&self) -> u32 {
+        self.raise_range().map_or(self.current_bet() + self.big_blind(), |(min, _)| min)
+    }
+
+    /// ベットの決まりと手持ちのチップで到達できる最大のベット額
+    pub fn max_raise_to(&self) -> u32 {
+        self.raise_range().map_or(self.my_bet() + self.chips(), |(_, max)| max)
+    }
+
+    pub fn available_actions(&self) -> Vec<BetAction> {
+        GameRuleService::available_actions(self.game, self.seat)
+    }
+
+    pub fn opponents(&self) -> Vec<OpponentInfo<'a>> {
+        self.game.players().iter()
+            .enumerate()
+            .filter(|(seat, _)| *seat != self.seat)
+            .map(|(seat, player)| OpponentInfo {
+                seat,
+                name: player.name(),
+                chips: player.chips(),
+                current_bet: player.current_bet(),
+                is_folded: player.is_folded(),
+                is_all_in: player.is_all_in(),
+            })
+            .collect()
+    }
+
+    /// フォールドしていない相手の数
+    pub fn active_opponent_count(&self) -> usize {
+        self.opponents().iter().filter(|opponent| !opponent.is_folded).count()
+    }
+
+    /// 現時点で完成している役（5枚に満たない場合は None）
+    pub fn hand_strength(&self) -> Option<HandStrength> {
+        let hand = self.hand();
+        let community = self.community_cards();
+        let ready = match self.variant() {
+            GameVariant::FiveCardDraw => hand.len() >= 5,
+            GameVariant::TexasHoldem | GameVariant::ShortDeckHoldem | GameVariant::SevenCardStud => {
+                hand.len() + community.len() >= 5
+            },
+            // ローで勝負するので役の強さでは判断しない
+            GameVariant::Razz | GameVariant::DeuceToSevenTripleDraw => false,
+            GameVariant::Omaha | GameVariant::OmahaHiLo => hand.len() >= 4 && community.len() >= 3,
+        };
+        ready.then(|| HandEvaluationService::find_best_hand_with_rules(hand, community, self.variant(), &self.hand_ranking_rules()))
+    }
+}
+
+/// 席を操作するコンピュータの戦略
+///
+/// どちらのメソッドも、その席の手番で呼ばれる。
+pub trait PlayerStrategy {
+    /// ベッティングラウンドでのアクションと、レイズの場合はレイズ後のベット額
+    fn decide_action(&mut self, view: &PlayerView) -> (BetAction, Option<u32>);
+
+    /// カード交換で捨てる手札の位置
+    fn choose_discards(&mut self, view: &PlayerView) -> Vec<usize>;
+}
+
+/// 種類に応じた戦略を作成する（シードを指定すると判断が再現できる）
+pub fn create_strategy(kind: BotKind, seed: Option<u64>) -> Box<dyn PlayerStrategy> {
+    let rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    match kind {
+        BotKind::Random => Box::new(RandomStrategy { rng }),
+        BotKind::TightPassive => Box::new(TightPassiveStrategy),
+        BotKind::PotOdds => Box::new(PotOddsStrategy { rng, samples: PotOddsStrategy::DEFAULT_SAMPLES }),
+    }
+}
+
+/// 合法なアクションからランダムに選ぶ戦略
+pub struct RandomStrategy {
+    rng: StdRng,
+}
+
+impl RandomStrategy {
+    pub fn new(seed: u64) -> Self {
+        Self { rng: StdRng::seed_from_u64(seed) }
+    }
+}
+
+impl PlayerStrategy for RandomStrategy {
+    fn decide_action(&mut self, view: &PlayerView) -> (BetAction, Option<u32>) {
+        let actions = view.available_actions();
+        let Some(&action) = actions.choose(&mut self.rng) else {
+            return (BetAction::Fold, None);
+        };
+
+        match action {
+            BetAction::Raise => {
+                let raise_to = self.rng.gen_range(view.min_raise_to()..=view.max_raise_to());
+                (BetAction::Raise, Some(raise_to))
+            },
+            _ => (action, None),
+        }
+    }
+
+    fn choose_discards(&mut self, view: &PlayerView) -> Vec<usize> {
+        (0..view.hand().len()).filter(|_| self.rng.gen_bool(0.5)).collect()
+    }
+}
+
+/// 強い手だけで参加し、自分からはベット・レイズしない戦略
+pub struct TightPassiveStrategy;
+
+impl TightPassiveStrategy {
+    // 強い手でない場合にコールする上限（ビッグブラインドの倍数）
+    const CALL_LIMIT_IN_BIG_BLINDS: u32 = 4;
+
+    // 0: 弱い、1: 参加できる、2: 強い
+    fn hand_class(view: &PlayerView) -> u8 {
+        if view.variant() == GameVariant::DeuceToSevenTripleDraw {
+            // ペアにならない8以下のカードの枚数で判断する
+            return match lowball_keep(view.hand()).len() {
+                5 => 2,
+                4 => 1,
+                _ => 0,
+            };
+        }
+        if let Some(strength) = view.hand_strength() {
+            return match strength.rank() {
+                HandRank::HighCard => 0,
+                HandRank::OnePair => 1,
+                _ => 2,
+            };
+        }
+
+        // ボードが開く前はホールカードだけで判断する
+        let hand = view.hand();
+        let high_cards = hand.iter().filter(|card| high_rank(card) >= 10).count();
+        let pair = hand.iter().enumerate()
+            .filter_map(|(i, a)| hand[i + 1..].iter().find(|b| a.rank() == b.rank()).map(|_| high_rank(a)))
+            .max();
+        match pair {
+            Some(rank) if rank >= 10 => 2,
+            Some(_) => 1,
+            None if high_cards >= 2 => 1,
+            None => 0,
+        }
+    }
+}
+
+impl PlayerStrategy for TightPassiveStrategy {
+    fn decide_action(&mut self, view: &PlayerView) -> (BetAction, Option<u32>) {
+        let actions = view.available_actions();
+        if actions.contains(&BetAction::Check) {
+            return (BetAction::Check, None);
+        }
+
+        let call_limit = view.big_blind() * Self::CALL_LIMIT_IN_BIG_BLINDS;
+        let willing = match Self::hand_class(view) {
+            2 => true,
+            1 => view.current_bet() <= call_limit,
+            _ => false,
+        };
+        if willing && actions.contains(&BetAction::Call) {
+            (BetAction::Call, None)
+        } else if willing && actions.contains(&BetAction::AllIn) && view.chips() <= view.call_amount() {
+            // 足りないチップでのコールはオールインになる
+            (BetAction::AllIn, None)
+        } else {
+            (BetAction::Fold, None)
+        }
+    }
+
+    fn choose_discards(&mut self, view: &PlayerView) -> Vec<usize> {
+        variant_discards(view)
+    }
+}
+
+/// モンテカルロ法で見積もった勝率とポットオッズを比べて判断する戦略
+pub struct PotOddsStrategy {
+    rng: StdRng,
+    samples: usize,
+}
+
+impl PotOddsStrategy {
+    const DEFAULT_SAMPLES: usize = 300;
+    // この勝率以上ならレイズする
+    const RAISE_EQUITY: f64 = 0.6;
+
+    pub fn new(seed: u64, samples: usize) -> Self {
+        Self { rng: StdRng::seed_from_u64(seed), samples: samples.max(1) }
+    }
+
+    /// 残っている相手の手札とこれから開くボードをランダムに配り、勝率（引き分けは等分）を見積もる
+    pub fn estimate_equity(&mut self, view: &PlayerView) -> f64 {
+        let hand = view.hand();
+        if hand.is_empty() {
+            return 0.0;
+        }
+
+        // 相手の手札は見えないので、すべてランダムに配る
+        let mut hands = vec![hand.to_vec()];
+        hands.resize(view.active_opponent_count().max(1) + 1, Vec::new());
+        let params = EquityParams {
+            board: view.community_cards().to_vec(),
+            samples: self.samples,
+            seed: Some(self.rng.gen()),
+            rules: view.hand_ranking_rules(),
+            ..EquityParams::new(view.variant(), hands)
+        };
+        EquityCalculator::calculate(&params)
+            .map(|result| result.players[0].equity)
+            .unwrap_or(0.0)
+    }
+}
+
+impl PlayerStrategy for PotOddsStrategy {
+    fn decide_action(&mut self, view: &PlayerView) -> (BetAction, Option<u32>) {
+        let actions = view.available_actions();
+        let equity = self.estimate_equity(view);
+        let call_amount = view.call_amount();
+
+        if equity >= Self::RAISE_EQUITY && actions.contains(&BetAction::Raise) {
+            // ポットに見合った額までレイズする
+            let raise_to = (view.current_bet() + view.pot().max(view.big_blind()))
+                .clamp(view.min_raise_to(), view.max_raise_to());
+            return (BetAction::Raise, Some(raise_to));
+        }
+        if actions.contains(&BetAction::Check) {
+            return (BetAction::Check, None);
+        }
+
+        // コール額に対してポットから得られる見返りが勝率に見合うか
+        let pot_odds = call_amount as f64 / (view.pot() + call_amount) as f64;
+        if equity >= pot_odds {
+            if actions.contains(&BetAction::Call) {
+                return (BetAction::Call, None);
+            }
+            if actions.contains(&BetAction::AllIn) {
+                return (BetAction::AllIn, None);
+            }
+        }
+        (BetAction::Fold, None)
+    }
+
+    fn choose_discards(&mut self, view: &PlayerView) -> Vec<usize> {
+        variant_discards(view)
+    }
+}
+
+// エースを最も強いカードとして扱ったランク
+fn high_rank(card: &Card) -> u8 {
+    if card.is_ace() { 14 } else { card.rank() }
+}
+
+// ゲームの種類に応じて捨てるカードを選ぶ
+fn variant_discards(view: &PlayerView) -> Vec<usize> {
+    match view.variant() {
+        GameVariant::DeuceToSevenTripleDraw => {
+            let keep = lowball_keep(view.hand());
+            (0..view.hand().len()).filter(|i| !keep.contains(i)).collect()
+        },
+        _ => standard_discards(view.hand(), &view.hand_ranking_rules()),
+    }
+}
+
+// デュース・トゥ・セブンで残すカードの位置（8以下で、同じランクは1枚だけ）
+fn lowball_keep(hand: &[Card]) -> Vec<usize> {
+    let mut keep: Vec<usize> = Vec::new();
+    for (i, card) in hand.iter().enumerate() {
+        if high_rank(card) <= 8 && keep.iter().all(|&k| hand[k].rank() != card.rank()) {
+            keep.push(i);
+        }
+    }
+    keep
+}
+
+// 役に関わるカードを残し、それ以外を捨てる
+//
+// ストレート以上はすべて残す。4枚のフラッシュドローは残りの1枚だけを捨てる。
+// 役がない場合は最も強いカードだけを残す。ワイルドカードは必ず残す。
+fn standard_discards(hand: &[Card], rules: &HandRankingRules) -> Vec<usize> {
+    let wild_cards = rules.wild_cards();
+    natural_discards(hand, rules).into_iter()
+        .filter(|&i| !wild_cards.is_wild(&hand[i]))
+        .collect()
+}
+
+fn natural_discards(hand: &[Card], rules: &HandRankingRules) -> Vec<usize> {
+    if hand.len() < 5 || rules.order(HandEvaluationService::evaluate_hand_with_rules(hand, rules).rank()) >= rules.order(HandRank::Straight) {
+        return Vec::new();
+    }
+
+    for suit in Suit::all() {
+        if hand.iter().filter(|card| card.suit() == suit).count() == hand.len() - 1 {
+            return (0..hand.len()).filter(|&i| hand[i].suit() != suit).collect();
+        }
+    }
+
+    let paired: Vec<bool> = hand.iter()
+        .map(|card| hand.iter().filter(|other| other.rank() == card.rank()).count() >= 2)
+        .collect();
+    if paired.iter().any(|&p| p) {
+        return (0..hand.len()).filter(|&i| !paired[i]).collect();
+    }
+
+    let best = (0..hand.len()).max_by_key(|&i| high_rank(&hand[i])).unwrap_or(0);
+    (0..hand.len()).filter(|&i| i != best).collect()
+}
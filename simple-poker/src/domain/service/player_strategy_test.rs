@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::{Game, GameVariant};
+    use crate::domain::model::player::{BotKind, Player};
+    use crate::domain::service::game_rule::GameRuleService;
+    use crate::domain::service::player_strategy::{
+        create_strategy, PlayerStrategy, PlayerView, PotOddsStrategy, RandomStrategy, TightPassiveStrategy,
+    };
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    // 3人のファイブカードドローで、席2に指定した手札を配る（席1がレイズ額を指定すればレイズ済み）
+    fn draw_game(seat2: Vec<Card>, raise_to: Option<u32>) -> Game {
+        let mut game = Game::new(GameVariant::FiveCardDraw, 5, 10).unwrap();
+        for name in ["プレイヤー1", "プレイヤー2", "プレイヤー3"] {
+            game.add_player(Player::new(name.to_string(), 1000)).unwrap();
+        }
+        let mut top = vec![
+            card(Suit::Heart, 2), card(Suit::Spade, 5), card(Suit::Club, 8), card(Suit::Diamond, 11), card(Suit::Heart, 13),
+            card(Suit::Spade, 3), card(Suit::Club, 6), card(Suit::Diamond, 9), card(Suit::Heart, 12), card(Suit::Club, 4),
+        ];
+        top.extend(seat2);
+        game.preset_deck(top).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        if let Some(raise_to) = raise_to {
+            GameRuleService::process_action(&mut game, 1, BetAction::Raise, Some(raise_to)).unwrap();
+        }
+        game
+    }
+
+    fn weak_hand() -> Vec<Card> {
+        vec![card(Suit::Spade, 2), card(Suit::Heart, 4), card(Suit::Diamond, 7), card(Suit::Club, 9), card(Suit::Spade, 12)]
+    }
+
+    fn trips() -> Vec<Card> {
+        vec![card(Suit::Spade, 10), card(Suit::Heart, 10), card(Suit::Diamond, 10), card(Suit::Club, 3), card(Suit::Spade, 7)]
+    }
+
+    #[test]
+    fn 席から見える情報() {
+        let game = draw_game(trips(), Some(50));
+        let view = PlayerView::new(&game, 2).unwrap();
+
+        assert_eq!(view.hand(), &trips()[..], "自分の手札が一致しません");
+        assert_eq!(view.call_amount(), 50, "コール額が一致しません");
+        // 50のベットに対しては同じ額を上乗せした100が最小
+        assert_eq!(view.min_raise_to(), 100, "最小レイズ額が一致しません");
+        assert_eq!(view.max_raise_to(), 1000, "最大レイズ額が一致しません");
+        assert_eq!(view.pot(), 50, "ポットが一致しません");
+        assert_eq!(view.active_opponent_count(), 2, "相手の数が一致しません");
+        assert!(view.opponents().iter().all(|opponent| opponent.seat != 2), "自分が相手に含まれています");
+        assert!(view.hand_strength().is_some(), "役が評価されていません");
+
+        assert!(PlayerView::new(&game, 3).is_err(), "存在しない席のビューが作れてしまいます");
+    }
+
+    #[test]
+    fn ランダムな戦略は合法なアクションだけを選ぶ() {
+        let game = draw_game(weak_hand(), Some(50));
+        let view = PlayerView::new(&game, 2).unwrap();
+        let available = view.available_actions();
+
+        for seed in 0..200 {
+            let mut strategy = RandomStrategy::new(seed);
+            let (action, amount) = strategy.decide_action(&view);
+            assert!(available.contains(&action), "合法でないアクションです: {:?}", action);
+            if action == BetAction::Raise {
+                let raise_to = amount.expect("レイズ額がありません");
+                assert!((view.min_raise_to()..=view.max_raise_to()).contains(&raise_to), "レイズ額が範囲外です: {}", raise_to);
+            }
+
+            let mut played = game.clone();
+            assert!(GameRuleService::process_action(&mut played, 2, action, amount).is_ok(), "アクションを実行できません: {:?}", action);
+
+            let discards = strategy.choose_discards(&view);
+            assert!(discards.iter().all(|&i| i < 5), "手札の範囲外のカードを捨てています");
+        }
+    }
+
+    #[test]
+    fn タイトパッシブな戦略は強い手でだけコールする() {
+        let mut strategy = TightPassiveStrategy;
+
+        let game = draw_game(weak_hand(), Some(50));
+        let view = PlayerView::new(&game, 2).unwrap();
+        assert_eq!(strategy.decide_action(&view), (BetAction::Fold, None), "弱い手で降りていません");
+
+        let game = draw_game(trips(), Some(50));
+        let view = PlayerView::new(&game, 2).unwrap();
+        assert_eq!(strategy.decide_action(&view), (BetAction::Call, None), "強い手でコールしていません");
+
+        // ベットがなければ強い手でもチェックする
+        let game = draw_game(trips(), None);
+        let view = PlayerView::new(&game, 2).unwrap();
+        assert_eq!(strategy.decide_action(&view), (BetAction::Check, None), "自分からベットしています");
+
+        // スリーカードを残して2枚交換する
+        assert_eq!(strategy.choose_discards(&view), vec![3, 4], "交換するカードが一致しません");
+    }
+
+    #[test]
+    fn トリプルドローでは8以下の重ならないカードを残す() {
+        let mut game = Game::new(GameVariant::DeuceToSevenTripleDraw, 5, 10).unwrap();
+        for name in ["プレイヤー1", "プレイヤー2"] {
+            game.add_player(Player::new(name.to_string(), 1000)).unwrap();
+        }
+        // A は高いカード、2 のペアは片方だけ残す
+        game.preset_deck(vec![
+            card(Suit::Spade, 1), card(Suit::Heart, 2), card(Suit::Club, 2), card(Suit::Diamond, 5), card(Suit::Heart, 7),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+
+        let view = PlayerView::new(&game, 0).unwrap();
+        assert_eq!(TightPassiveStrategy.choose_discards(&view), vec![0, 2], "交換するカードが一致しません");
+    }
+
+    #[test]
+    fn ポットオッズの戦略は勝率に応じて判断する() {
+        let mut strategy = PotOddsStrategy::new(7, 300);
+
+        // フォーカードならレイズする
+        let quads = vec![card(Suit::Spade, 7), card(Suit::Heart, 7), card(Suit::Diamond, 7), card(Suit::Club, 7), card(Suit::Diamond, 3)];
+        let game = draw_game(quads, Some(50));
+        let view = PlayerView::new(&game, 2).unwrap();
+        assert!(strategy.estimate_equity(&view) > 0.9, "勝率が低すぎます");
+        let (action, amount) = strategy.decide_action(&view);
+        assert_eq!(action, BetAction::Raise, "強い手でレイズしていません");
+        assert!(amount.unwrap() >= view.min_raise_to(), "レイズ額が最小額未満です");
+
+        // 弱い手でポットと同額のベットにはコールしない
+        let game = draw_game(weak_hand(), Some(500));
+        let view = PlayerView::new(&game, 2).unwrap();
+        assert!(strategy.estimate_equity(&view) < 0.5, "勝率が高すぎます");
+        assert_eq!(strategy.decide_action(&view), (BetAction::Fold, None), "弱い手で降りていません");
+    }
+
+    #[test]
+    fn 種類ごとに戦略を作成できる() {
+        let game = draw_game(trips(), Some(50));
+        let view = PlayerView::new(&game, 2).unwrap();
+        let available = view.available_actions();
+
+        for kind in BotKind::all() {
+            let mut strategy = create_strategy(kind, Some(1));
+            let (action, _) = strategy.decide_action(&view);
+            assert!(available.contains(&action), "{}の戦略が合法でないアクションを選びました", kind.name());
+        }
+    }
+}
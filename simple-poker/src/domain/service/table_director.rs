@@ -0,0 +1,229 @@
+use crate::domain::model::error::DomainError;
+use crate::domain::model::game::{Game, GameId, GamePhase, GameVariant};
+use crate::domain::model::player::{Player, PlayerId};
+use crate::domain::model::tournament::Elimination;
+
+/// テーブル間でのプレイヤーの移動
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableMove {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub from: GameId,
+    pub to: GameId,
+    pub seat: usize, // 移動先の席の番号
+}
+
+/// テーブルの調整の結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableChanges {
+    pub eliminations: Vec<Elimination>, // 脱落したプレイヤー（順位の低い順）
+    pub broken_tables: Vec<GameId>,     // 解散したテーブル
+    pub moves: Vec<TableMove>,
+}
+
+/// 複数のテーブル（`Game`）を持ち、人数に合わせてプレイヤーを移動させるサービス
+///
+/// ハンドが終わったテーブルごとに `finish_hand` を呼ぶと、脱落者を外したうえで、
+/// 必要なテーブル数を超えていれば最も人数の少ないテーブルを解散し、
+/// テーブル間の人数の差が2人以上あれば多いテーブルから少ないテーブルへ移動させる。
+/// 移動はハンドの間のテーブル同士でのみ行い、ハンドの途中のテーブルは次の機会に調整する。
+///
+/// 移動するのは次のハンドでビッグブラインドを払う予定のプレイヤーで、移動先でも
+/// 次のハンドでビッグブラインドを払う位置に座る。ブラインドを払わずに済む移動は起こらない。
+pub struct TableDirector {
+    tables: Vec<Game>,
+    max_seats: usize,
+    eliminations: Vec<Elimination>, // 脱落した順
+}
+
+impl TableDirector {
+    pub const DEFAULT_MAX_SEATS: usize = 9;
+
+    /// 参加者を席数に合わせた最小のテーブル数に均等に振り分ける
+    pub fn seat_players(
+        variant: GameVariant,
+        small_blind: u32,
+        big_blind: u32,
+        players: Vec<Player>,
+        max_seats: usize,
+    ) -> Result<Self, DomainError> {
+        if !(2..=10).contains(&max_seats) {
+            return Err(DomainError::InvalidGameOperation(format!("1テーブルの席数は2〜10です（{}が指定されています）", max_seats)));
+        }
+        if players.len() < 2 {
+            return Err(DomainError::InvalidGameOperation("最低2人のプレイヤーが必要です".into()));
+        }
+
+        let table_count = players.len().div_ceil(max_seats);
+        let mut tables = (0..table_count)
+            .map(|_| Game::new(variant, small_blind, big_blind))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (i, player) in players.into_iter().enumerate() {
+            tables[i % table_count].add_player(player)?;
+        }
+        Ok(Self { tables, max_seats, eliminations: Vec::new() })
+    }
+
+    pub fn tables(&self) -> &[Game] {
+        &self.tables
+    }
+
+    pub fn table(&self, table_id: &GameId) -> Option<&Game> {
+        self.tables.iter().find(|table| table.id() == table_id)
+    }
+
+    pub fn table_mut(&mut self, table_id: &GameId) -> Option<&mut Game> {
+        self.tables.iter_mut().find(|table| table.id() == table_id)
+    }
+
+    pub fn max_seats(&self) -> usize {
+        self.max_seats
+    }
+
+    /// すべてのテーブルに残っているプレイヤーの数
+    pub fn player_count(&self) -> usize {
+        self.tables.iter().map(|table| table.players().len()).sum()
+    }
+
+    pub fn eliminations(&self) -> &[Elimination] {
+        &self.eliminations
+    }
+
+    /// プレイヤーが座っているテーブルと席の番号
+    pub fn find_player(&self, player_id: &PlayerId) -> Option<(&GameId, usize)> {
+        self.tables.iter().find_map(|table| {
+            table.players().iter()
+                .find(|player| player.id() == player_id)
+                .map(|player| (table.id(), player.seat()))
+        })
+    }
+
+    /// 1つのテーブルにまとまったか
+    pub fn is_final_table(&self) -> bool {
+        self.tables.len() == 1
+    }
+
+    /// 残りが1人になったか
+    pub fn is_finished(&self) -> bool {
+        self.player_count() <= 1
+    }
+
+    /// ポットの分配が終わったテーブルのハンドを締めくくり、テーブルを調整する
+    ///
+    /// 脱落者を外してディーラーボタンを回したあと、`balance` と同じ調整を行う。
+    pub fn finish_hand(&mut self, table_id: &GameId) -> Result<TableChanges, DomainError> {
+        let remaining = self.player_count();
+        let table = self.table_mut(table_id)
+            .ok_or_else(|| DomainError::ResourceNotFound(format!("テーブル {} がありません", table_id.value())))?;
+        let eliminations = Elimination::from_finished_hand(table, remaining)?;
+
+        // ボタンを回してから脱落者を外す（ボタンが脱落者に回った場合は次の席に移る）
+        table.reset_for_new_hand()?;
+        for elimination in &eliminations {
+            table.remove_player(&elimination.player_id)?;
+        }
+        self.eliminations.extend(eliminations.iter().cloned());
+
+        let mut changes = self.balance()?;
+        changes.eliminations = eliminations;
+        Ok(changes)
+    }
+
+    /// ハンドの間のテーブル同士で、テーブルの解散と人数の調整を行う
+    pub fn balance(&mut self) -> Result<TableChanges, DomainError> {
+        let mut changes = TableChanges::default();
+        while self.break_table(&mut changes)? {}
+        while self.move_one_player(&mut changes)? {}
+        Ok(changes)
+    }
+
+    // 必要なテーブル数より多ければ、最も人数の少ないテーブルを解散する（解散したら true）
+    fn break_table(&mut self, changes: &mut TableChanges) -> Result<bool, DomainError> {
+        let needed = self.player_count().div_ceil(self.max_seats).max(1);
+        if self.tables.len() <= needed {
+            return Ok(false);
+        }
+        let Some(index) = self.idle_tables().min_by_key(|&i| self.tables[i].players().len()) else {
+            return Ok(false);
+        };
+
+        // 他のハンドの間のテーブルに全員が座れなければ後で解散する
+        let moving = self.tables[index].players().len();
+        let room: usize = self.idle_tables()
+            .filter(|&i| i != index)
+            .map(|i| self.max_seats - self.tables[i].players().len())
+            .sum();
+        if room < moving {
+            return Ok(false);
+        }
+
+        // 次にビッグブラインドを払うプレイヤーから順に移動する
+        let broken = self.tables.remove(index);
+        let first = Self::big_blind_index(&broken);
+        let count = broken.players().len();
+        for offset in 0..count {
+            let player = broken.players()[(first + offset) % count].clone();
+            let to = self.idle_tables()
+                .filter(|&i| self.tables[i].players().len() < self.max_seats)
+                .min_by_key(|&i| self.tables[i].players().len())
+                .ok_or_else(|| DomainError::InvalidState("移動先のテーブルがありません".into()))?;
+            changes.moves.push(self.seat_moved_player(player, broken.id().clone(), to)?);
+        }
+        changes.broken_tables.push(broken.id().clone());
+        Ok(true)
+    }
+
+    // 人数の差が2人以上あれば、最も多いテーブルから最も少ないテーブルへ1人移動する（移動したら true）
+    fn move_one_player(&mut self, changes: &mut TableChanges) -> Result<bool, DomainError> {
+        let size = |i: usize| self.tables[i].players().len();
+        let (Some(from), Some(to)) = (
+            self.idle_tables().max_by_key(|&i| (size(i), std::cmp::Reverse(i))),
+            self.idle_tables().min_by_key(|&i| size(i)),
+        ) else {
+            return Ok(false);
+        };
+        if size(from) < size(to) + 2 {
+            return Ok(false);
+        }
+
+        let source = &mut self.tables[from];
+        let player_id = source.players()[Self::big_blind_index(source)].id().clone();
+        let player = source.remove_player(&player_id)?;
+        let from_id = source.id().clone();
+        changes.moves.push(self.seat_moved_player(player, from_id, to)?);
+        Ok(true)
+    }
+
+    // 移動してきたプレイヤーを、次のハンドのスモールブラインドの後ろの空いている席
+    // （次のハンドでビッグブラインドを払う位置）に座らせる
+    fn seat_moved_player(&mut self, player: Player, from: GameId, to: usize) -> Result<TableMove, DomainError> {
+        let max_seats = self.max_seats;
+        let table = &mut self.tables[to];
+        let start = table.next_positions().map_or(0, |positions| positions.small_blind + 1);
+        let seat = (0..max_seats)
+            .map(|offset| (start + offset) % max_seats)
+            .find(|&seat| table.player_index_at_seat(seat).is_none())
+            .ok_or_else(|| DomainError::InvalidState("移動先のテーブルに空いている席がありません".into()))?;
+        let table_move = TableMove {
+            player_id: player.id().clone(),
+            name: player.name().to_string(),
+            from,
+            to: table.id().clone(),
+            seat,
+        };
+        table.seat_player(player, seat)?;
+        Ok(table_move)
+    }
+
+    // 次のハンドでビッグブラインドを払うプレイヤーのインデックス
+    fn big_blind_index(table: &Game) -> usize {
+        table.next_positions()
+            .and_then(|positions| table.player_index_at_seat(positions.big_blind))
+            .unwrap_or(0)
+    }
+
+    // ハンドの間のテーブル
+    fn idle_tables(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.tables.len()).filter(|&i| self.tables[i].current_phase() == GamePhase::NotStarted)
+    }
+}
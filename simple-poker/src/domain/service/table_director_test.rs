@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::{Game, GamePhase, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::domain::service::game_projector::GameProjector;
+    use crate::domain::service::game_rule::GameRuleService;
+    use crate::domain::service::table_director::TableDirector;
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    // 各テーブルの最初の席に座るプレイヤーだけスタックを多くする
+    fn director(player_count: usize, max_seats: usize) -> TableDirector {
+        let table_count = player_count.div_ceil(max_seats);
+        let players = (0..player_count)
+            .map(|i| Player::new(format!("プレイヤー{}", i + 1), if i < table_count { 10000 } else { 1000 }))
+            .collect();
+        TableDirector::seat_players(GameVariant::TexasHoldem, 5, 10, players, max_seats).unwrap()
+    }
+
+    fn table_sizes(director: &TableDirector) -> Vec<usize> {
+        director.tables().iter().map(|table| table.players().len()).collect()
+    }
+
+    // 最初の席のプレイヤーがAのペアでオールインし、busted の席がオールインで負けて、他はフォールドする
+    fn play_hand(game: &mut Game, busted: &[usize]) {
+        let junk = [
+            (card(Suit::Spade, 2), card(Suit::Heart, 3)),
+            (card(Suit::Club, 5), card(Suit::Diamond, 6)),
+            (card(Suit::Heart, 7), card(Suit::Club, 8)),
+            (card(Suit::Club, 2), card(Suit::Diamond, 3)),
+            (card(Suit::Spade, 5), card(Suit::Heart, 6)),
+            (card(Suit::Diamond, 7), card(Suit::Spade, 8)),
+            (card(Suit::Heart, 2), card(Suit::Spade, 3)),
+            (card(Suit::Diamond, 5), card(Suit::Club, 6)),
+        ];
+        let mut deck = vec![card(Suit::Spade, 1), card(Suit::Heart, 1)];
+        for &(first, second) in junk.iter().take(game.players().len() - 1) {
+            deck.push(first);
+            deck.push(second);
+        }
+        deck.extend([
+            card(Suit::Club, 9), card(Suit::Diamond, 10), card(Suit::Spade, 12),
+            card(Suit::Heart, 4),
+            card(Suit::Club, 13),
+        ]);
+        game.preset_deck(deck).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+
+        GameRuleService::process_action(game, 0, BetAction::AllIn, None).unwrap();
+        for seat in 1..game.players().len() {
+            let action = if busted.contains(&seat) { BetAction::AllIn } else { BetAction::Fold };
+            GameRuleService::process_action(game, seat, action, None).unwrap();
+        }
+        while game.current_phase() != GamePhase::Showdown {
+            game.end_betting_round().unwrap();
+        }
+        GameRuleService::distribute_pot(game).unwrap();
+    }
+
+    #[test]
+    fn 参加者を最小のテーブル数に均等に座らせる() {
+        let director = director(20, 9);
+        assert_eq!(table_sizes(&director), vec![7, 7, 6], "テーブルごとの人数が一致しません");
+        assert_eq!(director.player_count(), 20, "参加人数が一致しません");
+        assert!(!director.is_final_table(), "3テーブルなのにファイナルテーブルになっています");
+
+        let players = || (0..4).map(|i| Player::new(format!("プレイヤー{}", i + 1), 1000)).collect::<Vec<_>>();
+        assert!(TableDirector::seat_players(GameVariant::TexasHoldem, 5, 10, players(), 1).is_err(), "1席のテーブルで作成できてしまいます");
+        assert!(TableDirector::seat_players(GameVariant::TexasHoldem, 5, 10, players(), 11).is_err(), "11席のテーブルで作成できてしまいます");
+        assert!(TableDirector::seat_players(GameVariant::TexasHoldem, 5, 10, players()[..1].to_vec(), 9).is_err(), "1人で作成できてしまいます");
+    }
+
+    #[test]
+    fn 人数の差が2人以上になるとビッグブラインドのプレイヤーを移動する() {
+        let mut director = director(24, 9);
+        assert_eq!(table_sizes(&director), vec![8, 8, 8], "テーブルごとの人数が一致しません");
+        let table_ids: Vec<_> = director.tables().iter().map(|table| table.id().clone()).collect();
+        let source = &director.tables()[0];
+        let moving = source.players()[(source.dealer_index() + 2) % source.players().len()].id().clone();
+
+        play_hand(director.table_mut(&table_ids[2]).unwrap(), &[3, 5]);
+        let changes = director.finish_hand(&table_ids[2]).unwrap();
+
+        let places: Vec<usize> = changes.eliminations.iter().map(|elimination| elimination.place).collect();
+        // 同じスタックで脱落した2人は同着
+        assert_eq!(places, vec![23, 23], "脱落者の順位が一致しません");
+        assert!(changes.broken_tables.is_empty(), "テーブルが解散しています");
+        assert_eq!(changes.moves.len(), 1, "移動の数が一致しません");
+        assert_eq!(table_sizes(&director), vec![7, 8, 7], "移動後の人数が一致しません");
+
+        // 移動元で次にビッグブラインドを払うプレイヤーが、移動先でも次にビッグブラインドを払う
+        let table_move = &changes.moves[0];
+        assert_eq!((&table_move.player_id, &table_move.from, &table_move.to), (&moving, &table_ids[0], &table_ids[2]), "移動の内容が一致しません");
+        let destination = director.table_mut(&table_ids[2]).unwrap();
+        let big_blind = (destination.dealer_index() + 2) % destination.players().len();
+        assert_eq!(table_move.seat, big_blind, "ビッグブラインドの位置に座っていません");
+        assert_eq!(destination.players()[big_blind].id(), &moving, "ビッグブラインドの位置のプレイヤーが一致しません");
+
+        // 移動先のテーブルはイベントから同じ席順に復元できる
+        let restored = GameProjector::project(&destination.take_events()).expect("復元に失敗");
+        let ids = |game: &Game| game.players().iter().map(|player| player.id().clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&restored), ids(destination), "復元した席順が一致しません");
+        assert_eq!(restored.dealer_index(), destination.dealer_index(), "復元したボタンの位置が一致しません");
+    }
+
+    #[test]
+    fn ハンドの途中のテーブルからは移動しない() {
+        let mut director = director(24, 9);
+        let table_ids: Vec<_> = director.tables().iter().map(|table| table.id().clone()).collect();
+        director.table_mut(&table_ids[0]).unwrap().start_game().unwrap();
+
+        play_hand(director.table_mut(&table_ids[2]).unwrap(), &[3, 5]);
+        let changes = director.finish_hand(&table_ids[2]).unwrap();
+        assert_eq!(changes.moves.len(), 1, "移動の数が一致しません");
+        assert_eq!(changes.moves[0].from, table_ids[1], "ハンドの途中のテーブルから移動しています");
+        assert_eq!(table_sizes(&director), vec![8, 7, 7], "移動後の人数が一致しません");
+    }
+
+    #[test]
+    fn テーブルが不要になると解散して他のテーブルに振り分ける() {
+        let mut director = director(20, 9);
+        let table_ids: Vec<_> = director.tables().iter().map(|table| table.id().clone()).collect();
+
+        play_hand(director.table_mut(&table_ids[2]).unwrap(), &[1, 2]);
+        let changes = director.finish_hand(&table_ids[2]).unwrap();
+        assert_eq!(changes.broken_tables, vec![table_ids[2].clone()], "解散したテーブルが一致しません");
+        assert_eq!(changes.moves.len(), 4, "移動の数が一致しません");
+        assert_eq!(table_sizes(&director), vec![9, 9], "解散後の人数が一致しません");
+        assert!(director.table(&table_ids[2]).is_none(), "解散したテーブルが残っています");
+        for table_move in &changes.moves {
+            assert_eq!(director.find_player(&table_move.player_id).map(|(table_id, _)| table_id), Some(&table_move.to), "移動先のテーブルに座っていません");
+            assert_eq!(director.find_player(&table_move.player_id).map(|(_, seat)| seat), Some(table_move.seat), "移動先の席の番号が一致しません");
+        }
+    }
+
+    #[test]
+    fn 残りが1テーブル分になるとファイナルテーブルにまとめる() {
+        let mut director = director(10, 9);
+        assert_eq!(table_sizes(&director), vec![5, 5], "テーブルごとの人数が一致しません");
+        let table_ids: Vec<_> = director.tables().iter().map(|table| table.id().clone()).collect();
+
+        play_hand(director.table_mut(&table_ids[0]).unwrap(), &[4]);
+        let changes = director.finish_hand(&table_ids[0]).unwrap();
+        assert_eq!(changes.eliminations.len(), 1, "脱落者の数が一致しません");
+        assert_eq!(changes.eliminations[0].place, 10, "脱落者の順位が一致しません");
+        assert!(director.is_final_table(), "ファイナルテーブルにまとまっていません");
+        assert_eq!(table_sizes(&director), vec![9], "ファイナルテーブルの人数が一致しません");
+        assert_eq!(director.eliminations().len(), 1, "脱落の記録が一致しません");
+        assert!(!director.is_finished(), "9人残っているのに終了しています");
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::domain::model::error::DomainError;
+use crate::domain::model::event::GameEvent;
+use crate::domain::model::game::GameId;
+use crate::domain::repository::event_store::{event_game_id, EventStore};
+use crate::infrastructure::repository::file::file_store::FileStore;
+
+/// イベントファイルの拡張子
+const EVENTS_EXTENSION: &str = ".events.jsonl";
+
+/// ゲームごとに1行1イベントの JSON Lines ファイルへ追記するイベントストア
+///
+/// 追記の途中でクラッシュして最後の行が途切れた場合、その行は読み込み時に無視し、
+/// 次の追記の前に切り詰める。
+#[derive(Clone)]
+pub struct FileEventStore {
+    directory: PathBuf,
+    store: FileStore,
+}
+
+impl FileEventStore {
+    pub fn new<P: AsRef<Path>>(directory: P) -> Result<Self, String> {
+        let directory = directory.as_ref().to_path_buf();
+
+        // ディレクトリが存在しない場合は作成
+        let store = FileStore::new(&directory, "events")
+            .map_err(|e| format!("ディレクトリの作成に失敗しました: {}", e))?;
+
+        Ok(Self { directory, store })
+    }
+
+    fn events_path(&self, game_id: &GameId) -> PathBuf {
+        self.directory.join(format!("{}{}", game_id.value(), EVENTS_EXTENSION))
+    }
+
+    fn read_contents(path: &Path) -> Result<String, DomainError> {
+        if !path.exists() {
+            return Ok(String::new());
+        }
+        let mut contents = String::new();
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut contents))
+            .map_err(|e| DomainError::InvalidState(format!("イベントファイルの読み込みに失敗しました: {}", e)))?;
+        Ok(contents)
+    }
+
+    // 改行で終わっている行だけが書き込み済みのイベント
+    fn complete_lines(contents: &str) -> impl Iterator<Item = &str> {
+        let complete = match contents.rfind('\n') {
+            Some(end) => &contents[..=end],
+            None => "",
+        };
+        complete.lines().filter(|line| !line.trim().is_empty())
+    }
+}
+
+impl EventStore for FileEventStore {
+    fn append(&self, event: &GameEvent) -> Result<usize, DomainError> {
+        let mut line = serde_json::to_string(event)
+            .map_err(|e| DomainError::InvalidState(format!("イベントのシリアライズに失敗しました: {}", e)))?;
+        line.push('\n');
+
+        let _lock = self.store.lock_exclusive()
+            .map_err(|e| DomainError::InvalidState(format!("ロックの取得に失敗しました: {}", e)))?;
+        let path = self.events_path(&event_game_id(event));
+        let contents = Self::read_contents(&path)?;
+        let index = Self::complete_lines(&contents).count();
+
+        let result = (|| {
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            // 途切れた最後の行を切り詰めてから追記する
+            let complete_len = contents.rfind('\n').map(|end| end + 1).unwrap_or(0);
+            if complete_len < contents.len() {
+                file.set_len(complete_len as u64)?;
+            }
+            file.write_all(line.as_bytes())?;
+            file.sync_all()
+        })();
+        result.map_err(|e| DomainError::InvalidState(format!("イベントの追記に失敗しました: {}", e)))?;
+
+        Ok(index)
+    }
+
+    fn load(&self, game_id: &GameId) -> Result<Vec<GameEvent>, DomainError> {
+        let _lock = self.store.lock_shared()
+            .map_err(|e| DomainError::InvalidState(format!("ロックの取得に失敗しました: {}", e)))?;
+        let contents = Self::read_contents(&self.events_path(game_id))?;
+
+        Self::complete_lines(&contents)
+            .enumerate()
+            .map(|(index, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    DomainError::InvalidState(format!("{}番目のイベントを読み込めません: {}", index, e))
+                })
+            })
+            .collect()
+    }
+
+    fn game_ids(&self) -> Result<Vec<GameId>, DomainError> {
+        let entries = fs::read_dir(&self.directory)
+            .map_err(|e| DomainError::InvalidState(format!("ディレクトリの読み込みに失敗しました: {}", e)))?;
+
+        let mut ids: Vec<GameId> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                name.strip_suffix(EVENTS_EXTENSION).map(|id| GameId::from_string(id.to_string()))
+            })
+            .collect();
+        ids.sort_by(|a, b| a.value().cmp(b.value()));
+        Ok(ids)
+    }
+}
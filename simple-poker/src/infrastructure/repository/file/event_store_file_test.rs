@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::game::{Game, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::domain::repository::event_store::EventStore;
+    use crate::domain::service::game_projector::GameProjector;
+    use crate::domain::service::game_rule::GameRuleService;
+    use crate::infrastructure::repository::file::event_store_file::FileEventStore;
+    use tempfile::TempDir;
+
+    // 2人のホールデムでプリフロップまで進めたゲーム
+    fn create_game() -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        // 2人のときはボタンがスモールブラインドで、プリフロップは最初に行動する
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        game
+    }
+
+    #[test]
+    fn イベントを追記して読み込む() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FileEventStore::new(temp_dir.path()).unwrap();
+        let mut game = create_game();
+        let events = game.take_events();
+
+        for (i, event) in events.iter().enumerate() {
+            assert_eq!(store.append(event).unwrap(), i, "イベントの番号が一致しません");
+        }
+
+        // 別のインスタンスからも同じイベントを読める
+        let reopened = FileEventStore::new(temp_dir.path()).unwrap();
+        let loaded = reopened.load(game.id()).unwrap();
+        assert_eq!(loaded, events, "イベントが一致しません");
+        assert_eq!(reopened.game_ids().unwrap(), vec![game.id().clone()], "ゲームIDが一致しません");
+
+        let projected = GameProjector::project(&loaded).expect("復元に失敗");
+        assert_eq!(projected.players()[1].chips(), game.players()[1].chips(), "復元したチップが一致しません");
+    }
+
+    #[test]
+    fn 途切れた最後の行は無視して追記できる() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FileEventStore::new(temp_dir.path()).unwrap();
+        let mut game = create_game();
+        let events = game.take_events();
+        store.append(&events[0]).unwrap();
+
+        // 追記の途中でクラッシュした状態を再現
+        let path = fs::read_dir(temp_dir.path()).unwrap()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .find(|path| path.to_string_lossy().ends_with(".events.jsonl"))
+            .unwrap();
+        OpenOptions::new().append(true).open(&path).unwrap()
+            .write_all(b"{\"PlayerAdded\":{\"game_id\"").unwrap();
+        assert_eq!(store.load(game.id()).unwrap().len(), 1, "途切れた行が読み込まれています");
+
+        assert_eq!(store.append(&events[1]).unwrap(), 1, "イベントの番号が一致しません");
+        assert_eq!(store.load(game.id()).unwrap(), events[..2].to_vec(), "追記したイベントが一致しません");
+    }
+
+    #[test]
+    fn 壊れたイベントはエラーになる() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FileEventStore::new(temp_dir.path()).unwrap();
+        let mut game = create_game();
+        for event in game.take_events() {
+            store.append(&event).unwrap();
+        }
+
+        let path = temp_dir.path().join(format!("{}.events.jsonl", game.id().value()));
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::write(&path, contents.replacen("PlayerAdded", "Unknown", 1)).unwrap();
+
+        assert!(store.load(game.id()).is_err(), "壊れたイベントを読み込めてしまいます");
+    }
+}
@@ -0,0 +1,158 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::Utc;
+
+/// 破損ファイルを隔離するサブディレクトリ名
+const QUARANTINE_DIR: &str = "quarantine";
+/// 書き込み途中の一時ファイルに付ける印
+const TEMP_MARKER: &str = ".tmp.";
+
+/// 隔離した破損ファイルの報告
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorruptFileReport {
+    pub original_path: PathBuf,
+    pub quarantined_path: PathBuf,
+    pub reason: String,
+}
+
+/// ロックファイルを保持している間だけ有効なロック
+pub struct StoreLock {
+    _file: File,
+}
+
+/// ファイルリポジトリ共通のファイル操作
+///
+/// - 一時ファイルに書いてから rename するアトミックな書き込み
+/// - ディレクトリ単位のアドバイザリロック（複数プロセスからの同時書き込み対策）
+/// - 読み込めないファイルの隔離と報告
+#[derive(Clone)]
+pub struct FileStore {
+    directory: PathBuf,
+    lock_path: PathBuf,
+    reports: Arc<Mutex<Vec<CorruptFileReport>>>,
+}
+
+impl FileStore {
+    pub fn new<P: AsRef<Path>>(directory: P, lock_name: &str) -> io::Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+
+        // ディレクトリが存在しない場合は作成
+        if !directory.exists() {
+            fs::create_dir_all(&directory)?;
+        }
+
+        Ok(Self {
+            lock_path: directory.join(format!(".{}.lock", lock_name)),
+            directory,
+            reports: Arc::new(Mutex::new(Vec::new())),
+        })
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// 排他ロックを取得する（書き込み用）
+    ///
+    /// 返されたガードを破棄するとロックが解放される。
+    pub fn lock_exclusive(&self) -> io::Result<StoreLock> {
+        let file = self.open_lock_file()?;
+        file.lock()?;
+        Ok(StoreLock { _file: file })
+    }
+
+    /// 共有ロックを取得する（読み込み用）
+    pub fn lock_shared(&self) -> io::Result<StoreLock> {
+        let file = self.open_lock_file()?;
+        file.lock_shared()?;
+        Ok(StoreLock { _file: file })
+    }
+
+    fn open_lock_file(&self) -> io::Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&self.lock_path)
+    }
+
+    /// 一時ファイルに書き込んでから置き換える
+    ///
+    /// 書き込み途中でクラッシュしても、元のファイルか新しいファイルのどちらかが残る。
+    pub fn write_atomic(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let file_name = path.file_name()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "ファイル名がありません"))?
+            .to_string_lossy()
+            .to_string();
+        let temp_path = path.with_file_name(format!(".{}{}{}", file_name, TEMP_MARKER, std::process::id()));
+
+        let result = (|| {
+            let mut file = File::create(&temp_path)?;
+            file.write_all(contents)?;
+            file.sync_all()?;
+            fs::rename(&temp_path, path)?;
+            Self::sync_directory(path)
+        })();
+
+        if result.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    // rename をディスクに反映させる（ディレクトリの fsync は Unix のみ）
+    fn sync_directory(path: &Path) -> io::Result<()> {
+        #[cfg(unix)]
+        if let Some(parent) = path.parent() {
+            File::open(parent)?.sync_all()?;
+        }
+        #[cfg(not(unix))]
+        let _ = path;
+        Ok(())
+    }
+
+    /// 読み込めないファイルを隔離ディレクトリへ移動し、報告として記録する
+    pub fn quarantine(&self, path: &Path, reason: &str) -> io::Result<CorruptFileReport> {
+        let quarantine_dir = self.directory.join(QUARANTINE_DIR);
+        fs::create_dir_all(&quarantine_dir)?;
+
+        let file_name = path.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let quarantined_path = quarantine_dir.join(format!("{}.{}", file_name, Utc::now().format("%Y%m%dT%H%M%S%.f")));
+        fs::rename(path, &quarantined_path)?;
+
+        let report = CorruptFileReport {
+            original_path: path.to_path_buf(),
+            quarantined_path,
+            reason: reason.to_string(),
+        };
+        if let Ok(mut reports) = self.reports.lock() {
+            reports.push(report.clone());
+        }
+        Ok(report)
+    }
+
+    /// 書き込み途中で残った一時ファイルを削除する
+    pub fn remove_stale_temp_files(&self) -> io::Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        for entry in fs::read_dir(&self.directory)?.filter_map(Result::ok) {
+            let path = entry.path();
+            let is_temp = path.file_name()
+                .is_some_and(|name| name.to_string_lossy().contains(TEMP_MARKER));
+            if path.is_file() && is_temp {
+                fs::remove_file(&path)?;
+                removed.push(path);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// これまでに隔離したファイルの報告を取得する
+    pub fn corrupt_file_reports(&self) -> Vec<CorruptFileReport> {
+        self.reports.lock().map(|reports| reports.clone()).unwrap_or_default()
+    }
+}
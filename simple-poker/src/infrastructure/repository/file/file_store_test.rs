@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::infrastructure::repository::file::file_store::FileStore;
+    use std::fs;
+    use tempfile::TempDir;
+
+    // ディレクトリ内のファイル名一覧（隔離ディレクトリを除く）
+    fn file_names(store: &FileStore) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(store.directory()).unwrap()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_file())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn アトミック書き込みで内容が置き換わり一時ファイルが残らない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let store = FileStore::new(temp_dir.path(), "test").unwrap();
+        let path = temp_dir.path().join("data.json");
+        
+        store.write_atomic(&path, b"first").unwrap();
+        store.write_atomic(&path, b"second").unwrap();
+        
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second", "内容が置き換わっていません");
+        assert!(file_names(&store).iter().all(|name| !name.contains(".tmp.")), "一時ファイルが残っています");
+    }
+
+    #[test]
+    fn 破損ファイルを隔離して報告する() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let store = FileStore::new(temp_dir.path(), "test").unwrap();
+        let path = temp_dir.path().join("broken.json");
+        fs::write(&path, "{\"truncated\": ").unwrap();
+        
+        let report = store.quarantine(&path, "JSONのパースに失敗しました").unwrap();
+        
+        assert!(!path.exists(), "元の場所にファイルが残っています");
+        assert!(report.quarantined_path.exists(), "隔離先にファイルがありません");
+        assert_eq!(fs::read_to_string(&report.quarantined_path).unwrap(), "{\"truncated\": ", "隔離したファイルの内容が変わっています");
+        assert_eq!(store.corrupt_file_reports(), vec![report], "報告が記録されていません");
+    }
+
+    #[test]
+    fn 書き込み途中の一時ファイルを掃除する() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let store = FileStore::new(temp_dir.path(), "test").unwrap();
+        fs::write(temp_dir.path().join(".data.json.tmp.12345"), "{").unwrap();
+        fs::write(temp_dir.path().join("data.json"), "{}").unwrap();
+        
+        let removed = store.remove_stale_temp_files().unwrap();
+        
+        assert_eq!(removed.len(), 1, "削除された一時ファイルの数が一致しません");
+        assert!(file_names(&store).contains(&"data.json".to_string()), "通常のファイルが削除されています");
+        assert!(file_names(&store).iter().all(|name| !name.contains(".tmp.")), "一時ファイルが残っています");
+    }
+
+    #[test]
+    fn 共有ロックは同時に取得できる() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let store = FileStore::new(temp_dir.path(), "test").unwrap();
+        
+        let first = store.lock_shared();
+        let second = store.lock_shared();
+        
+        assert!(first.is_ok() && second.is_ok(), "共有ロックの取得に失敗しました");
+    }
+}
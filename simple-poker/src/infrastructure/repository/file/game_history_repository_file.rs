@@ -1,15 +1,18 @@
-use std::fs::{self, File};
-use std::io::{Read, Write};
+use std::fs::File;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use serde::{Deserialize, Serialize};
 use chrono::DateTime;
 
 use crate::domain::model::game::GameId;
+use crate::domain::model::hand_history::HandHistory;
 use crate::domain::model::player::PlayerId;
 use crate::domain::model::error::DomainError;
 use crate::domain::repository::game_history_repository::{GameHistoryEntry, GameHistoryRepository};
+use crate::infrastructure::repository::file::file_store::{CorruptFileReport, FileStore};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct SerializableGameHistoryEntry {
@@ -19,6 +22,8 @@ struct SerializableGameHistoryEntry {
     pot_amount: u32,
     variant: String,
     player_count: usize,
+    #[serde(default)]
+    hand_history: Option<HandHistory>,
 }
 
 impl From<&GameHistoryEntry> for SerializableGameHistoryEntry {
@@ -30,6 +35,7 @@ impl From<&GameHistoryEntry> for SerializableGameHistoryEntry {
             pot_amount: entry.pot_amount,
             variant: entry.variant.clone(),
             player_count: entry.player_count,
+            hand_history: entry.hand_history.clone(),
         }
     }
 }
@@ -54,6 +60,7 @@ impl TryFrom<SerializableGameHistoryEntry> for GameHistoryEntry {
             pot_amount: serializable.pot_amount,
             variant: serializable.variant,
             player_count: serializable.player_count,
+            hand_history: serializable.hand_history,
         })
     }
 }
@@ -61,7 +68,8 @@ impl TryFrom<SerializableGameHistoryEntry> for GameHistoryEntry {
 #[derive(Clone)]
 pub struct FileGameHistoryRepository {
     directory: PathBuf,
-    entries: HashMap<String, GameHistoryEntry>,
+    store: FileStore,
+    entries: Arc<Mutex<HashMap<String, GameHistoryEntry>>>, // クローン間で共有するキャッシュ
 }
 
 impl FileGameHistoryRepository {
@@ -69,28 +77,37 @@ impl FileGameHistoryRepository {
         let directory = directory.as_ref().to_path_buf();
         
         // ディレクトリが存在しない場合は作成
-        if !directory.exists() {
-            fs::create_dir_all(&directory)
-                .map_err(|e| format!("ディレクトリの作成に失敗しました: {}", e))?;
-        }
+        let store = FileStore::new(&directory, "history")
+            .map_err(|e| format!("ディレクトリの作成に失敗しました: {}", e))?;
         
-        let mut repo = Self {
+        let repo = Self {
             directory,
-            entries: HashMap::new(),
+            store,
+            entries: Arc::new(Mutex::new(HashMap::new())),
         };
         
-        // 既存のファイルを読み込む
-        repo.load_entries()?;
+        // 既存のファイルを読み込む（壊れていれば隔離するので排他ロックを取る）
+        let _lock = repo.store.lock_exclusive()
+            .map_err(|e| format!("ロックの取得に失敗しました: {}", e))?;
+        let entries = repo.load_entries()?;
+        *repo.lock_entries()? = entries;
         
         Ok(repo)
     }
     
-    fn load_entries(&mut self) -> Result<(), String> {
-        self.entries.clear();
-        
-        let entries_path = self.directory.join("game_history.json");
+    fn entries_path(&self) -> PathBuf {
+        self.directory.join("game_history.json")
+    }
+    
+    fn entry_key(entry: &GameHistoryEntry) -> String {
+        format!("{}_{}", entry.game_id.value(), entry.timestamp.to_rfc3339())
+    }
+    
+    // 壊れたファイルは隔離し、空の履歴として扱う
+    fn load_entries(&self) -> Result<HashMap<String, GameHistoryEntry>, String> {
+        let entries_path = self.entries_path();
         if !entries_path.exists() {
-            return Ok(());
+            return Ok(HashMap::new());
         }
         
         let mut file = File::open(&entries_path)
@@ -101,62 +118,90 @@ impl FileGameHistoryRepository {
             .map_err(|e| format!("ファイルの読み込みに失敗しました: {}", e))?;
             
         if contents.is_empty() {
-            return Ok(());
+            return Ok(HashMap::new());
         }
         
-        let serializable_entries: Vec<SerializableGameHistoryEntry> = serde_json::from_str(&contents)
+        match Self::parse_entries(&contents) {
+            Ok(entries) => Ok(entries),
+            Err(reason) => {
+                self.store.quarantine(&entries_path, &reason)
+                    .map_err(|e| format!("破損ファイルの隔離に失敗しました: {}", e))?;
+                Ok(HashMap::new())
+            }
+        }
+    }
+    
+    fn parse_entries(contents: &str) -> Result<HashMap<String, GameHistoryEntry>, String> {
+        let serializable_entries: Vec<SerializableGameHistoryEntry> = serde_json::from_str(contents)
             .map_err(|e| format!("JSONのパースに失敗しました: {}", e))?;
             
+        let mut entries = HashMap::new();
         for serializable in serializable_entries {
             let entry = GameHistoryEntry::try_from(serializable)?;
-            let key = format!("{}_{}", entry.game_id.value(), entry.timestamp.to_rfc3339());
-            self.entries.insert(key, entry);
+            entries.insert(Self::entry_key(&entry), entry);
         }
         
-        Ok(())
+        Ok(entries)
     }
     
-    fn save_entries(&self) -> Result<(), String> {
-        let serializable_entries: Vec<SerializableGameHistoryEntry> = self.entries.values()
-            .map(|entry| SerializableGameHistoryEntry::from(entry))
+    fn lock_entries(&self) -> Result<MutexGuard<'_, HashMap<String, GameHistoryEntry>>, String> {
+        self.entries.lock().map_err(|_| "ロックの取得に失敗しました".to_string())
+    }
+    
+    fn save_entries(&self, entries: &HashMap<String, GameHistoryEntry>) -> Result<(), String> {
+        let serializable_entries: Vec<SerializableGameHistoryEntry> = entries.values()
+            .map(SerializableGameHistoryEntry::from)
             .collect();
             
         let json = serde_json::to_string_pretty(&serializable_entries)
             .map_err(|e| format!("JSONへの変換に失敗しました: {}", e))?;
             
-        let entries_path = self.directory.join("game_history.json");
-        let mut file = File::create(&entries_path)
-            .map_err(|e| format!("ファイルの作成に失敗しました: {}", e))?;
-            
-        file.write_all(json.as_bytes())
+        self.store.write_atomic(&self.entries_path(), json.as_bytes())
             .map_err(|e| format!("ファイルの書き込みに失敗しました: {}", e))?;
             
         Ok(())
     }
+    
+    fn cached_entries(&self) -> Vec<GameHistoryEntry> {
+        self.lock_entries()
+            .map(|entries| entries.values().cloned().collect())
+            .unwrap_or_default()
+    }
+    
+    /// これまでに隔離した破損ファイルの報告
+    pub fn corrupt_file_reports(&self) -> Vec<CorruptFileReport> {
+        self.store.corrupt_file_reports()
+    }
 }
 
 impl GameHistoryRepository for FileGameHistoryRepository {
     fn save(&mut self, entry: &GameHistoryEntry) -> Result<(), DomainError> {
-        let key = format!("{}_{}", entry.game_id.value(), entry.timestamp.to_rfc3339());
-        self.entries.insert(key, entry.clone());
-        self.save_entries().map_err(|e| DomainError::InvalidState(e))
+        let _lock = self.store.lock_exclusive()
+            .map_err(|e| DomainError::InvalidState(format!("ロックの取得に失敗しました: {}", e)))?;
+        
+        // 他のプロセスが追加したエントリを失わないよう、ロック中に読み直してから書き込む
+        let mut entries = self.load_entries().map_err(DomainError::InvalidState)?;
+        let mut cached = self.lock_entries().map_err(DomainError::InvalidState)?;
+        entries.extend(cached.drain());
+        entries.insert(Self::entry_key(entry), entry.clone());
+        *cached = entries;
+        
+        self.save_entries(&cached).map_err(DomainError::InvalidState)
     }
     
     fn find_by_game_id(&self, game_id: &GameId) -> Option<GameHistoryEntry> {
-        self.entries.values()
+        self.cached_entries().into_iter()
             .filter(|e| e.game_id.value() == game_id.value())
             .max_by_key(|e| e.timestamp)
-            .cloned()
     }
     
     fn find_by_player_id(&self, player_id: &PlayerId) -> Vec<GameHistoryEntry> {
-        self.entries.values()
-            .filter(|e| e.winner_ids.iter().any(|id| id.value() == player_id.value()))
-            .cloned()
+        self.cached_entries().into_iter()
+            .filter(|e| e.involves(player_id))
             .collect()
     }
     
     fn find_all(&self) -> Vec<GameHistoryEntry> {
-        self.entries.values().cloned().collect()
+        self.cached_entries()
     }
 } 
\ No newline at end of file
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use crate::domain::model::game::GameId;
+    use crate::domain::model::game::{GameId, GameVariant};
+    use crate::domain::model::hand_history::{HandHistory, PayoutRecord, SeatRecord};
     use crate::domain::model::player::PlayerId;
     use crate::domain::repository::game_history_repository::{GameHistoryEntry, GameHistoryRepository};
     use crate::infrastructure::repository::file::game_history_repository_file::FileGameHistoryRepository;
@@ -25,9 +26,36 @@ mod tests {
             pot_amount,
             variant: variant.to_string(),
             player_count,
+            hand_history: None,
         }
     }
 
+    // 2人が参加し、1人目が勝ったハンドの記録
+    fn create_hand_history(game_id: &str, winner: &str, loser: &str) -> HandHistory {
+        let seat = |seat: usize, id: &str| SeatRecord {
+            seat,
+            player_id: PlayerId::from_string(id.to_string()),
+            name: id.to_string(),
+            starting_stack: 1000,
+            hole_cards: Vec::new(),
+        };
+        let mut history = HandHistory::new(
+            GameId::from_string(game_id.to_string()),
+            0,
+            GameVariant::FiveCardDraw,
+            5,
+            10,
+            0,
+            vec![seat(0, winner), seat(1, loser)],
+        );
+        history.payouts.push(PayoutRecord {
+            amount: 40,
+            eligible_player_ids: history.participant_ids(),
+            winners: vec![(PlayerId::from_string(winner.to_string()), 40)],
+        });
+        history
+    }
+
     #[test]
     fn ファイルへのエントリー保存と読み込み() {
         // 一時ディレクトリを作成
@@ -221,4 +249,64 @@ mod tests {
         assert!(result.is_ok());
         assert!(non_existent_dir.exists());
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn 破損した履歴ファイルは隔離して空の履歴から始める() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let history_path = temp_dir.path().join("game_history.json");
+        std::fs::write(&history_path, "[{\"game_id\": \"game-1\"").unwrap();
+        
+        let repo = FileGameHistoryRepository::new(temp_dir.path())
+            .expect("破損ファイルがあっても初期化できるはず");
+        
+        assert!(repo.find_all().is_empty(), "破損した履歴が読み込まれています");
+        let reports = repo.corrupt_file_reports();
+        assert_eq!(reports.len(), 1, "破損ファイルが報告されていません");
+        assert!(!history_path.exists(), "破損ファイルが元の場所に残っています");
+        assert!(reports[0].quarantined_path.exists(), "隔離先にファイルがありません");
+    }
+    
+    #[test]
+    fn 別インスタンスからの保存を上書きしない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let now = Utc::now();
+        
+        // 同じディレクトリを開いた複数のリポジトリから並行して保存する
+        let handles: Vec<_> = (0..4).map(|i| {
+            let path = temp_dir.path().to_path_buf();
+            std::thread::spawn(move || {
+                let mut repo = FileGameHistoryRepository::new(&path).unwrap();
+                for j in 0..5 {
+                    let entry = create_test_entry(
+                        &format!("game-{}-{}", i, j),
+                        vec!["player-1"],
+                        100,
+                        now,
+                        "FiveCardDraw",
+                        2,
+                    );
+                    repo.save(&entry).unwrap();
+                }
+            })
+        }).collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        
+        let repo = FileGameHistoryRepository::new(temp_dir.path()).unwrap();
+        assert_eq!(repo.find_all().len(), 20, "他のインスタンスが保存したエントリが失われています");
+    }
+
+    #[test]
+    fn ハンドの記録を保存して参加者で検索() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameHistoryRepository::new(temp_dir.path()).unwrap();
+        let entry = GameHistoryEntry::from_hand_history(create_hand_history("game-hand", "player-w", "player-l"), Utc::now());
+        repo.save(&entry).unwrap();
+        
+        let found = repo.find_by_player_id(&PlayerId::from_string("player-l".to_string()));
+        assert_eq!(found.len(), 1, "負けたプレイヤーで検索できません");
+        assert_eq!(found[0].hand_history, entry.hand_history, "ハンドの記録が一致しません");
+        assert_eq!(found[0].winner_ids, vec![PlayerId::from_string("player-w".to_string())], "勝者が一致しません");
+    }
+}
@@ -1,228 +1,17 @@
 use std::fs::{self, File};
-use std::io::{Read, Write};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 
-use crate::domain::model::game::{Game, GameId, GameVariant, GamePhase, BettingRound, GameSerializedData};
-use crate::domain::model::player::{Player, PlayerId, PlayerSerializedData};
-use crate::domain::model::card::{Card, Suit};
+use crate::domain::model::game::{Game, GameId};
 use crate::domain::model::error::DomainError;
 use crate::domain::repository::game_repository::GameRepository;
-
-mod serializable {
-    use serde::{Serialize, Deserialize};
-    
-    #[derive(Serialize, Deserialize)]
-    pub struct SerializableGame {
-        pub id: String,
-        pub variant: String,
-        pub players: Vec<SerializablePlayer>,
-        pub community_cards: Vec<SerializableCard>,
-        pub pot: u32,
-        pub current_phase: String,
-        pub current_round: Option<String>,
-        pub current_player_index: usize,
-        pub dealer_index: usize,
-        pub small_blind: u32,
-        pub big_blind: u32,
-        pub current_bet: u32,
-    }
-    
-    #[derive(Serialize, Deserialize)]
-    pub struct SerializablePlayer {
-        pub id: String,
-        pub name: String,
-        pub chips: u32,
-        pub hand: Vec<SerializableCard>,
-        pub current_bet: u32,
-        pub is_folded: bool,
-        pub is_all_in: bool,
-        pub is_dealer: bool,
-    }
-    
-    #[derive(Serialize, Deserialize)]
-    pub struct SerializableCard {
-        pub suit: String,
-        pub rank: u8,
-    }
-}
-
-struct GameSerializer;
-
-impl GameSerializer {
-    // ゲームをシリアライズ可能な形式に変換
-    fn to_serializable(game: &Game) -> serializable::SerializableGame {
-        serializable::SerializableGame {
-            id: game.id().value().to_string(),
-            variant: match game.variant() {
-                GameVariant::FiveCardDraw => "FiveCardDraw".to_string(),
-                GameVariant::TexasHoldem => "TexasHoldem".to_string(),
-                GameVariant::Omaha => "Omaha".to_string(),
-            },
-            players: game.players().iter().map(Self::player_to_serializable).collect(),
-            community_cards: game.community_cards().iter().map(Self::card_to_serializable).collect(),
-            pot: game.pot().total(),
-            current_phase: match game.current_phase() {
-                GamePhase::NotStarted => "NotStarted".to_string(),
-                GamePhase::Dealing => "Dealing".to_string(),
-                GamePhase::Betting => "Betting".to_string(),
-                GamePhase::Drawing => "Drawing".to_string(),
-                GamePhase::Showdown => "Showdown".to_string(),
-                GamePhase::Complete => "Complete".to_string(),
-            },
-            current_round: game.current_round().map(|round| match round {
-                BettingRound::PreDraw => "PreDraw".to_string(),
-                BettingRound::PostDraw => "PostDraw".to_string(),
-                BettingRound::PreFlop => "PreFlop".to_string(),
-                BettingRound::Flop => "Flop".to_string(),
-                BettingRound::Turn => "Turn".to_string(),
-                BettingRound::River => "River".to_string(),
-            }),
-            current_player_index: game.current_player_index(),
-            dealer_index: game.dealer_index(),
-            small_blind: game.small_blind(),
-            big_blind: game.big_blind(),
-            current_bet: game.current_bet(),
-        }
-    }
-    
-    // プレイヤーをシリアライズ可能な形式に変換
-    fn player_to_serializable(player: &Player) -> serializable::SerializablePlayer {
-        serializable::SerializablePlayer {
-            id: player.id().value().to_string(),
-            name: player.name().to_string(),
-            chips: player.chips(),
-            hand: player.hand().cards().iter().map(Self::card_to_serializable).collect(),
-            current_bet: player.current_bet(),
-            is_folded: player.is_folded(),
-            is_all_in: player.is_all_in(),
-            is_dealer: player.is_dealer(),
-        }
-    }
-    
-    // カードをシリアライズ可能な形式に変換
-    fn card_to_serializable(card: &Card) -> serializable::SerializableCard {
-        serializable::SerializableCard {
-            suit: match card.suit() {
-                Suit::Club => "Club".to_string(),
-                Suit::Diamond => "Diamond".to_string(),
-                Suit::Heart => "Heart".to_string(),
-                Suit::Spade => "Spade".to_string(),
-            },
-            rank: card.rank(),
-        }
-    }
-    
-    // シリアライズ済みのゲームからゲームモデルに変換
-    fn from_serializable(serializable: serializable::SerializableGame) -> Result<Game, DomainError> {
-        // バリアントを復元
-        let variant = match serializable.variant.as_str() {
-            "FiveCardDraw" => GameVariant::FiveCardDraw,
-            "TexasHoldem" => GameVariant::TexasHoldem,
-            "Omaha" => GameVariant::Omaha,
-            _ => return Err(DomainError::InvalidGameOperation(format!("不明なゲームバリアント: {}", serializable.variant))),
-        };
-        
-        // フェーズを復元
-        let phase = match serializable.current_phase.as_str() {
-            "NotStarted" => GamePhase::NotStarted,
-            "Dealing" => GamePhase::Dealing,
-            "Betting" => GamePhase::Betting,
-            "Drawing" => GamePhase::Drawing,
-            "Showdown" => GamePhase::Showdown,
-            "Complete" => GamePhase::Complete,
-            _ => return Err(DomainError::InvalidGameOperation(format!("不明なゲームフェーズ: {}", serializable.current_phase))),
-        };
-        
-        // ラウンドを復元
-        let round = if let Some(round_str) = serializable.current_round.as_ref() {
-            match round_str.as_str() {
-                "PreDraw" => Some(BettingRound::PreDraw),
-                "PostDraw" => Some(BettingRound::PostDraw),
-                "PreFlop" => Some(BettingRound::PreFlop),
-                "Flop" => Some(BettingRound::Flop),
-                "Turn" => Some(BettingRound::Turn),
-                "River" => Some(BettingRound::River),
-                _ => return Err(DomainError::InvalidGameOperation(format!("不明なベッティングラウンド: {}", round_str))),
-            }
-        } else {
-            None
-        };
-        
-        // プレイヤーの復元
-        let players = serializable.players.into_iter()
-            .map(Self::player_from_serializable)
-            .collect::<Result<Vec<_>, _>>()?;
-        
-        // コミュニティカードの復元
-        let community_cards = serializable.community_cards.iter()
-            .map(Self::card_from_serializable)
-            .collect::<Result<Vec<_>, _>>()?;
-        
-        // GameIDを復元
-        let id = GameId::from_string(serializable.id);
-        
-        // ゲームを復元（デシリアライズのファクトリメソッドを使用）
-        Game::from_serialized(
-            GameSerializedData {
-                id,
-                variant,
-                players,
-                community_cards,
-                pot_total: serializable.pot,
-                current_phase: phase,
-                current_round: round,
-                current_player_index: serializable.current_player_index,
-                dealer_index: serializable.dealer_index,
-                small_blind: serializable.small_blind,
-                big_blind: serializable.big_blind,
-                current_bet: serializable.current_bet,
-            }
-        ).map_err(|e| DomainError::InvalidGameOperation(format!("ゲームの復元に失敗しました: {}", e)))
-    }
-    
-    // シリアライズ済みのプレイヤーからプレイヤーモデルに変換
-    fn player_from_serializable(serializable: serializable::SerializablePlayer) -> Result<Player, DomainError> {
-        // プレイヤーIDを復元
-        let player_id = PlayerId::from_string(serializable.id);
-        
-        // 手札を復元
-        let cards = serializable.hand.iter()
-            .map(Self::card_from_serializable)
-            .collect::<Result<Vec<_>, _>>()?;
-        
-        // プレイヤーを復元（デシリアライズのファクトリメソッドを使用）
-        Player::from_serialized(
-            PlayerSerializedData {
-                id: player_id,
-                name: serializable.name,
-                chips_amount: serializable.chips,
-                cards,
-                current_bet: serializable.current_bet,
-                is_folded: serializable.is_folded,
-                is_all_in: serializable.is_all_in,
-                is_dealer: serializable.is_dealer,
-            }
-        ).map_err(|e| DomainError::InvalidPlayerOperation(format!("プレイヤーの復元に失敗しました: {}", e)))
-    }
-    
-    // シリアライズ済みのカードからカードモデルに変換
-    fn card_from_serializable(serializable: &serializable::SerializableCard) -> Result<Card, DomainError> {
-        let suit = match serializable.suit.as_str() {
-            "Club" => Suit::Club,
-            "Diamond" => Suit::Diamond,
-            "Heart" => Suit::Heart,
-            "Spade" => Suit::Spade,
-            _ => return Err(DomainError::InvalidCard(format!("不明なスート: {}", serializable.suit))),
-        };
-        
-        Card::new(suit, serializable.rank)
-            .map_err(|e| DomainError::InvalidCard(format!("カードの作成に失敗しました: {}", e)))
-    }
-}
+use crate::infrastructure::repository::file::file_store::{CorruptFileReport, FileStore};
+use crate::infrastructure::repository::serialization::{game_from_json, game_to_json, CURRENT_SCHEMA_VERSION};
 
 #[derive(Clone)]
 pub struct FileGameRepository {
     directory: PathBuf,
+    store: FileStore,
 }
 
 impl FileGameRepository {
@@ -230,13 +19,12 @@ impl FileGameRepository {
         let directory = directory.as_ref().to_path_buf();
         
         // ディレクトリが存在しない場合は作成
-        if !directory.exists() {
-            fs::create_dir_all(&directory)
-                .map_err(|e| DomainError::InvalidState(format!("ディレクトリの作成に失敗しました: {}", e)))?;
-        }
+        let store = FileStore::new(&directory, "games")
+            .map_err(|e| DomainError::InvalidState(format!("ディレクトリの作成に失敗しました: {}", e)))?;
         
         let repo = Self {
             directory,
+            store,
         };
         
         Ok(repo)
@@ -246,26 +34,50 @@ impl FileGameRepository {
         self.directory.join(format!("game_{}.json", id.value()))
     }
     
+    fn lock_error(e: std::io::Error) -> DomainError {
+        DomainError::InvalidState(format!("ロックの取得に失敗しました: {}", e))
+    }
+    
     fn save_game(&self, game: &Game) -> Result<(), DomainError> {
-        // ゲームをシリアライズ可能な形式に変換
-        let serializable = GameSerializer::to_serializable(game);
-        
         // JSONに変換
-        let json = serde_json::to_string_pretty(&serializable)
-            .map_err(|e| DomainError::InvalidState(format!("JSONへの変換に失敗しました: {}", e)))?;
+        let json = game_to_json(game)?;
             
-        // ファイルに保存
+        // 一時ファイル経由で置き換える
+        let _lock = self.store.lock_exclusive().map_err(Self::lock_error)?;
         let game_path = self.get_game_path(game.id());
-        let mut file = File::create(&game_path)
-            .map_err(|e| DomainError::InvalidState(format!("ファイルの作成に失敗しました: {}", e)))?;
-            
-        file.write_all(json.as_bytes())
+        self.store.write_atomic(&game_path, json.as_bytes())
             .map_err(|e| DomainError::InvalidState(format!("ファイルの書き込みに失敗しました: {}", e)))?;
             
         Ok(())
     }
     
     fn load_game(&self, id: &GameId) -> Result<Game, DomainError> {
+        game_from_json(&self.read_game_file(id)?)
+    }
+    
+    // 読み直して、まだ壊れていれば隔離して報告する（新しいバージョンで保存されたファイルはそのまま残す）
+    //
+    // 共有ロックのまま隔離すると、同時に読み込んだ別の読み手と移動が競合するため、排他ロックを持って呼び出す。
+    fn load_game_or_quarantine(&self, id: &GameId) -> Result<Game, DomainError> {
+        let contents = self.read_game_file(id)?;
+        game_from_json(&contents).inspect_err(|e| {
+            if !Self::is_newer_schema(&contents) {
+                let _ = self.store.quarantine(&self.get_game_path(id), &format!("{}", e));
+            }
+        })
+    }
+    
+    // 共有ロックで読めなかったゲームを、排他ロックに切り替えてから読み直す
+    fn reload_failed_games(&self, ids: &[GameId]) -> Vec<Game> {
+        let Ok(_lock) = self.store.lock_exclusive() else {
+            return Vec::new();
+        };
+        ids.iter()
+            .filter_map(|id| self.load_game_or_quarantine(id).ok())
+            .collect()
+    }
+    
+    fn read_game_file(&self, id: &GameId) -> Result<String, DomainError> {
         let game_path = self.get_game_path(id);
         
         if !game_path.exists() {
@@ -279,13 +91,59 @@ impl FileGameRepository {
         let mut contents = String::new();
         file.read_to_string(&mut contents)
             .map_err(|e| DomainError::InvalidState(format!("ファイルの読み込みに失敗しました: {}", e)))?;
-            
-        // JSONをデシリアライズ
-        let serializable: serializable::SerializableGame = serde_json::from_str(&contents)
-            .map_err(|e| DomainError::InvalidState(format!("JSONのパースに失敗しました: {}", e)))?;
-            
-        // ゲームオブジェクトに変換
-        GameSerializer::from_serializable(serializable)
+        Ok(contents)
+    }
+    
+    fn is_newer_schema(contents: &str) -> bool {
+        serde_json::from_str::<serde_json::Value>(contents).ok()
+            .and_then(|json| json.get("schema_version").and_then(|v| v.as_u64()))
+            .is_some_and(|version| version > CURRENT_SCHEMA_VERSION as u64)
+    }
+    
+    fn game_ids(&self) -> Vec<GameId> {
+        let mut ids = Vec::new();
+        
+        if let Ok(entries) = fs::read_dir(&self.directory) {
+            for entry in entries.filter_map(Result::ok) {
+                let path = entry.path();
+                if !path.is_file() {
+                    continue;
+                }
+                
+                if let Some(filename_os) = path.file_name() {
+                    let filename = filename_os.to_string_lossy();
+                    
+                    // game_とjsonの間の部分を取得
+                    if let Some(id_str) = filename.strip_prefix("game_").and_then(|s| s.strip_suffix(".json")) {
+                        ids.push(GameId::from_string(id_str.to_string()));
+                    }
+                }
+            }
+        }
+        
+        ids
+    }
+    
+    /// 書き込み途中の一時ファイルを削除し、読み込めないゲームファイルを隔離する
+    ///
+    /// 起動時に呼び出す想定。今回隔離したファイルの報告を返す。
+    pub fn recover(&self) -> Result<Vec<CorruptFileReport>, DomainError> {
+        let _lock = self.store.lock_exclusive().map_err(Self::lock_error)?;
+        let already_reported = self.store.corrupt_file_reports().len();
+        
+        self.store.remove_stale_temp_files()
+            .map_err(|e| DomainError::InvalidState(format!("一時ファイルの削除に失敗しました: {}", e)))?;
+        
+        for id in self.game_ids() {
+            let _ = self.load_game_or_quarantine(&id);
+        }
+        
+        Ok(self.store.corrupt_file_reports().into_iter().skip(already_reported).collect())
+    }
+    
+    /// これまでに隔離した破損ファイルの報告
+    pub fn corrupt_file_reports(&self) -> Vec<CorruptFileReport> {
+        self.store.corrupt_file_reports()
     }
 }
 
@@ -295,44 +153,44 @@ impl GameRepository for FileGameRepository {
     }
     
     fn find_by_id(&self, id: &GameId) -> Option<Game> {
-        match self.load_game(id) {
+        let loaded = {
+            let _lock = self.store.lock_shared().ok()?;
+            self.load_game(id)
+        };
+        
+        match loaded {
             Ok(game) => Some(game),
-            Err(_) => None,
+            Err(DomainError::ResourceNotFound(_)) => None,
+            Err(_) => self.reload_failed_games(std::slice::from_ref(id)).pop(),
         }
     }
     
     fn find_all(&self) -> Vec<Game> {
         let mut games = Vec::new();
-        
-        if let Ok(entries) = fs::read_dir(&self.directory) {
-            for entry in entries.filter_map(Result::ok) {
-                let path = entry.path();
-                
-                if path.is_file() && path.extension().map_or(false, |ext| ext == "json") {
-                    if let Some(filename_os) = path.file_name() {
-                        let filename = filename_os.to_string_lossy();
-                        
-                        if filename.starts_with("game_") && filename.ends_with(".json") {
-                            // game_とjsonの間の部分を取得
-                            if let Some(id_str) = filename.strip_prefix("game_") {
-                                if let Some(id_str) = id_str.strip_suffix(".json") {
-                                    let id = GameId::from_string(id_str.to_string());
-                                    
-                                    if let Ok(game) = self.load_game(&id) {
-                                        games.push(game);
-                                    }
-                                }
-                            }
-                        }
-                    }
+        let mut failed = Vec::new();
+        {
+            let _lock = match self.store.lock_shared() {
+                Ok(lock) => lock,
+                Err(_) => return Vec::new(),
+            };
+            for id in self.game_ids() {
+                match self.load_game(&id) {
+                    Ok(game) => games.push(game),
+                    Err(DomainError::ResourceNotFound(_)) => {},
+                    Err(_) => failed.push(id),
                 }
             }
         }
         
+        // 読み込めないファイルは排他ロックを取ってから隔離される
+        if !failed.is_empty() {
+            games.extend(self.reload_failed_games(&failed));
+        }
         games
     }
     
     fn delete(&mut self, id: &GameId) -> Result<(), DomainError> {
+        let _lock = self.store.lock_exclusive().map_err(Self::lock_error)?;
         let game_path = self.get_game_path(id);
         
         if !game_path.exists() {
@@ -0,0 +1,406 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::card::{Card, JokerRule, Suit, WildCards};
+    use crate::domain::model::game::{Game, GameVariant, GamePhase, BettingRound, OddChipRule, ShortDeckTripsRule, TableRules};
+    use crate::domain::model::player::{BotKind, Player};
+    use crate::domain::repository::game_repository::GameRepository;
+    use crate::domain::service::game_rule::GameRuleService;
+    use crate::domain::service::hand_evaluation::HandRank;
+    use crate::domain::service::hand_evaluation_specification::HandRankingTable;
+    use crate::infrastructure::repository::file::game_repository_file::FileGameRepository;
+    use crate::infrastructure::repository::serialization::CURRENT_SCHEMA_VERSION;
+    use serde_json::Value;
+    use std::collections::HashSet;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Barrier;
+    use std::thread;
+    use tempfile::TempDir;
+
+    // テスト用にプリフロップまで進めたホールデムのゲームを作成
+    fn create_holdem_game_in_progress() -> Game {
+        let mut game = Game::new_with_seed(GameVariant::TexasHoldem, 5, 10, Some(99)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.add_player(Player::new_bot("プレイヤー3".to_string(), 1000, BotKind::PotOdds)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        game
+    }
+
+    fn game_file_path(dir: &Path, game: &Game) -> std::path::PathBuf {
+        dir.join(format!("game_{}.json", game.id().value()))
+    }
+
+    // 保存済みのJSONを書き換える
+    fn rewrite_json(path: &Path, edit: impl FnOnce(&mut Value)) {
+        let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        edit(&mut json);
+        fs::write(path, serde_json::to_string_pretty(&json).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn ハンド途中の状態を保存して復元() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let mut game = create_holdem_game_in_progress();
+        let side_pot_player = game.players()[1].id().value().to_string();
+        game.pot_mut().create_side_pot(40, vec![side_pot_player]);
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        repo.save(&game).expect("ゲームの保存に失敗");
+        
+        let loaded = repo.find_by_id(game.id()).expect("ゲームの読み込みに失敗");
+        
+        assert_eq!(loaded.deck().cards_in_draw_order(), game.deck().cards_in_draw_order(), "デッキの並びが一致しません");
+        assert_eq!(loaded.current_round(), Some(BettingRound::PreFlop), "ラウンドが一致しません");
+        assert_eq!(loaded.pot().main_pot(), game.pot().main_pot(), "メインポットが一致しません");
+        assert_eq!(loaded.pot().side_pots().len(), 1, "サイドポットが復元されていません");
+        assert_eq!(loaded.pot().side_pots()[0].0.amount(), 40, "サイドポットの額が一致しません");
+        assert_eq!(loaded.seed(), Some(99), "シードが一致しません");
+        assert_eq!(loaded.hand_history(), game.hand_history(), "ハンドの記録が一致しません");
+        assert_eq!(loaded.acted_players(), game.acted_players(), "行動済みのプレイヤーが一致しません");
+        assert_eq!(loaded.current_player_index(), 1, "手番が一致しません");
+        assert_eq!(loaded.positions(), game.positions(), "ボタンとブラインドの席が一致しません");
+        assert_eq!(loaded.rules(), game.rules(), "卓のルールが一致しません");
+        for (original, restored) in game.players().iter().zip(loaded.players()) {
+            assert_eq!(restored.hand().cards(), original.hand().cards(), "手札が一致しません");
+            assert_eq!(restored.total_bet(), original.total_bet(), "ハンド全体の拠出額が一致しません");
+            assert_eq!(restored.bot(), original.bot(), "コンピュータの種類が一致しません");
+            assert_eq!(restored.seat(), original.seat(), "席の番号が一致しません");
+        }
+    }
+
+    #[test]
+    fn 復元後に配られるカードは元のゲームと同じ() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let mut game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        let mut loaded = repo.find_by_id(game.id()).unwrap();
+        
+        game.end_betting_round().unwrap();
+        loaded.end_betting_round().unwrap();
+        
+        assert_eq!(loaded.community_cards(), game.community_cards(), "フロップが一致しません");
+        
+        // フロップのカードは誰の手札とも重複しない
+        let hole_cards: HashSet<Card> = loaded.players().iter()
+            .flat_map(|p| p.hand().cards().iter().copied())
+            .collect();
+        assert!(loaded.community_cards().iter().all(|c| !hole_cards.contains(c)), "配られたカードが再度配られています");
+    }
+
+    #[test]
+    fn トリプルドローの2回目の交換と端数チップの渡し方を保存して復元() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+
+        let mut game = Game::new_with_seed(GameVariant::DeuceToSevenTripleDraw, 5, 10, Some(7)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.set_rules(TableRules { odd_chip_rule: OddChipRule::HighestCard, ..TableRules::default() }).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        // 1回目の交換の後のベッティングまで終えて、2回目の交換の途中で保存する
+        while game.current_round() != Some(BettingRound::PostSecondDraw) {
+            let index = game.current_player_index();
+            if game.current_phase() == GamePhase::Drawing {
+                game.exchange_cards(index, &[0]).unwrap();
+            } else {
+                GameRuleService::process_action(&mut game, index, BetAction::Check, None).unwrap();
+            }
+        }
+        game.exchange_cards(game.current_player_index(), &[1]).unwrap();
+        repo.save(&game).expect("ゲームの保存に失敗");
+
+        let loaded = repo.find_by_id(game.id()).expect("ゲームの読み込みに失敗");
+        assert_eq!(loaded.variant(), GameVariant::DeuceToSevenTripleDraw, "ゲームの種類が一致しません");
+        assert_eq!(loaded.current_round(), Some(BettingRound::PostSecondDraw), "ラウンドが一致しません");
+        assert_eq!(loaded.current_phase(), GamePhase::Drawing, "フェーズが一致しません");
+        assert_eq!(loaded.rules().odd_chip_rule, OddChipRule::HighestCard, "端数チップの渡し方が一致しません");
+        assert_eq!(loaded.hand_history(), game.hand_history(), "ハンドの記録が一致しません");
+        assert_eq!(loaded.deck().cards_in_draw_order(), game.deck().cards_in_draw_order(), "デッキの並びが一致しません");
+    }
+
+    #[test]
+    fn ショートデッキとスリーカードとストレートの順番を保存して復元() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+
+        let mut game = Game::new_with_seed(GameVariant::ShortDeckHoldem, 5, 10, Some(11)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.set_rules(TableRules { short_deck_trips_rule: ShortDeckTripsRule::StraightBeatsTrips, ..TableRules::default() }).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        repo.save(&game).expect("ゲームの保存に失敗");
+
+        let loaded = repo.find_by_id(game.id()).expect("ゲームの読み込みに失敗");
+        assert_eq!(loaded.variant(), GameVariant::ShortDeckHoldem, "ゲームの種類が一致しません");
+        assert_eq!(loaded.rules().short_deck_trips_rule, ShortDeckTripsRule::StraightBeatsTrips, "スリーカードとストレートの順番が一致しません");
+        assert_eq!(loaded.deck().remaining(), 32, "デッキの枚数が一致しません");
+        assert_eq!(loaded.deck().cards_in_draw_order(), game.deck().cards_in_draw_order(), "デッキの並びが一致しません");
+    }
+
+    #[test]
+    fn ワイルドカードとジョーカーと役の表を保存して復元() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+
+        let wild_cards = WildCards::new(1, JokerRule::Bug, &[2]).unwrap();
+        let mut game = Game::new_with_seed(GameVariant::FiveCardDraw, 5, 10, Some(5)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.set_rules(TableRules {
+            wild_cards,
+            hand_ranking_table: Some(HandRankingTable::STANDARD.with_swapped(HandRank::Flush, HandRank::FullHouse)),
+            ..TableRules::default()
+        }).unwrap();
+        game.preset_deck(vec![Card::joker(Suit::Spade)]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        repo.save(&game).expect("ゲームの保存に失敗");
+
+        let loaded = repo.find_by_id(game.id()).expect("ゲームの読み込みに失敗");
+        assert_eq!(loaded.rules().wild_cards, wild_cards, "ワイルドカードの設定が一致しません");
+        assert_eq!(loaded.rules().hand_ranking_table, game.rules().hand_ranking_table, "役の表が一致しません");
+        assert!(loaded.players()[0].hand().cards().contains(&Card::joker(Suit::Spade)), "ジョーカーが手札に復元されていません");
+        assert_eq!(loaded.deck().cards_in_draw_order(), game.deck().cards_in_draw_order(), "デッキの並びが一致しません");
+    }
+
+    #[test]
+    fn 保存ファイルにバージョンが記録される() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        let json: Value = serde_json::from_str(&fs::read_to_string(game_file_path(temp_dir.path(), &game)).unwrap()).unwrap();
+        assert_eq!(json["schema_version"], CURRENT_SCHEMA_VERSION, "バージョンが記録されていません");
+        assert_eq!(json["deck"].as_array().unwrap().len(), 52 - 3 * 2, "デッキの枚数が一致しません");
+    }
+
+    #[test]
+    fn 重複したカードを含むファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        // プレイヤー2の手札をプレイヤー1と同じカードにする
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            let duplicated = json["players"][0]["hand"][0].clone();
+            json["players"][1]["hand"][0] = duplicated;
+        });
+        
+        assert!(repo.find_by_id(game.id()).is_none(), "重複したカードを含むゲームが読み込まれています");
+    }
+
+    #[test]
+    fn デッキと手札で重複するファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            let hole_card = json["players"][0]["hand"][0].clone();
+            json["deck"][0] = hole_card;
+        });
+        
+        assert!(repo.find_by_id(game.id()).is_none(), "デッキと手札で重複したゲームが読み込まれています");
+    }
+
+    #[test]
+    fn 存在しないカードを含むファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            json["players"][0]["hand"][0]["rank"] = Value::from(14);
+        });
+        
+        assert!(repo.find_by_id(game.id()).is_none(), "ランク14のカードを含むゲームが読み込まれています");
+    }
+
+    #[test]
+    fn ショートデッキにないカードがデッキに残るファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+
+        let mut game = Game::new_with_seed(GameVariant::ShortDeckHoldem, 5, 10, Some(11)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        repo.save(&game).unwrap();
+
+        // 枚数は変えずに、デッキの1枚を2に置き換える
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            json["deck"][0]["rank"] = Value::from(2);
+        });
+
+        assert!(repo.find_by_id(game.id()).is_none(), "デッキに2を含むショートデッキのゲームが読み込まれています");
+    }
+
+    #[test]
+    fn ジョーカーを使わないゲームでデッキにジョーカーがあるファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            json["deck"][0]["rank"] = Value::from(0);
+        });
+
+        assert!(repo.find_by_id(game.id()).is_none(), "デッキにジョーカーを含むゲームが読み込まれています");
+    }
+
+    #[test]
+    fn 手札の枚数がバリアントと矛盾するファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        // ホールデムで3枚目の手札を持たせる（デッキから移動）
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            let card = json["deck"].as_array_mut().unwrap().remove(0);
+            json["players"][0]["hand"].as_array_mut().unwrap().push(card);
+        });
+        
+        assert!(repo.find_by_id(game.id()).is_none(), "手札が3枚のホールデムのゲームが読み込まれています");
+    }
+
+    #[test]
+    fn バージョン1のファイルは未使用のカードからデッキを再構築() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        // 旧形式のファイルを再現
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            let object = json.as_object_mut().unwrap();
+            object.remove("schema_version");
+            object.remove("deck");
+            object.remove("side_pots");
+        });
+        
+        let loaded = repo.find_by_id(game.id()).expect("バージョン1のファイルが読み込めません");
+        let deck: HashSet<Card> = loaded.deck().cards_in_draw_order().into_iter().collect();
+        assert_eq!(deck.len(), 52 - 3 * 2, "デッキの枚数が一致しません");
+        for player in loaded.players() {
+            for card in player.hand().cards() {
+                assert!(!deck.contains(card), "配られたカードがデッキに残っています");
+            }
+        }
+        assert_eq!(loaded.pot().total(), game.pot().total(), "ポット合計が一致しません");
+    }
+
+    #[test]
+    fn 未対応のバージョンのファイルは読み込まない() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        
+        rewrite_json(&game_file_path(temp_dir.path(), &game), |json| {
+            json["schema_version"] = Value::from(CURRENT_SCHEMA_VERSION + 1);
+        });
+        
+        assert!(repo.find_by_id(game.id()).is_none(), "未対応のバージョンのファイルが読み込まれています");
+        assert!(game_file_path(temp_dir.path(), &game).exists(), "新しいバージョンのファイルが隔離されています");
+        assert!(repo.corrupt_file_reports().is_empty(), "新しいバージョンのファイルが破損として報告されています");
+    }
+
+    #[test]
+    fn 書き込み途中で途切れたファイルは隔離して報告する() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let healthy = create_holdem_game_in_progress();
+        let broken = create_holdem_game_in_progress();
+        repo.save(&healthy).unwrap();
+        repo.save(&broken).unwrap();
+        
+        let broken_path = game_file_path(temp_dir.path(), &broken);
+        let contents = fs::read_to_string(&broken_path).unwrap();
+        fs::write(&broken_path, &contents[..contents.len() / 2]).unwrap();
+        
+        let games = repo.find_all();
+        
+        assert_eq!(games.len(), 1, "正常なゲームだけが読み込まれていません");
+        assert_eq!(games[0].id().value(), healthy.id().value(), "読み込まれたゲームが一致しません");
+        let reports = repo.corrupt_file_reports();
+        assert_eq!(reports.len(), 1, "破損ファイルが報告されていません");
+        assert_eq!(reports[0].original_path, broken_path, "報告されたファイルが一致しません");
+        assert!(!broken_path.exists(), "破損ファイルが元の場所に残っています");
+        assert!(reports[0].quarantined_path.exists(), "隔離先にファイルがありません");
+    }
+
+    #[test]
+    fn 同時に読み込んでも破損ファイルは一度だけ隔離する() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        let broken_path = game_file_path(temp_dir.path(), &game);
+        fs::write(&broken_path, "{").unwrap();
+        
+        // 別々のプロセスを想定して、インスタンスごとに同時に読み込む
+        let readers: Vec<FileGameRepository> = (0..4)
+            .map(|_| FileGameRepository::new(temp_dir.path()).unwrap())
+            .collect();
+        let barrier = Barrier::new(readers.len());
+        thread::scope(|scope| {
+            for reader in &readers {
+                let (barrier, id) = (&barrier, game.id());
+                scope.spawn(move || {
+                    barrier.wait();
+                    assert!(reader.find_by_id(id).is_none(), "破損したゲームが読み込まれています");
+                });
+            }
+        });
+        
+        let reports: usize = readers.iter().map(|reader| reader.corrupt_file_reports().len()).sum();
+        assert_eq!(reports, 1, "破損ファイルの報告が一度になっていません");
+        assert!(!broken_path.exists(), "破損ファイルが元の場所に残っています");
+        assert_eq!(fs::read_dir(temp_dir.path().join("quarantine")).unwrap().count(), 1, "隔離先のファイル数が一致しません");
+    }
+
+    #[test]
+    fn 起動時の点検で一時ファイルを削除し破損ファイルを隔離する() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let mut repo = FileGameRepository::new(temp_dir.path()).expect("リポジトリの初期化に失敗");
+        
+        let game = create_holdem_game_in_progress();
+        repo.save(&game).unwrap();
+        let stale_temp = temp_dir.path().join(format!(".game_{}.json.tmp.999", game.id().value()));
+        fs::write(&stale_temp, "{").unwrap();
+        fs::write(temp_dir.path().join("game_broken.json"), "").unwrap();
+        
+        let reports = repo.recover().expect("点検に失敗");
+        
+        assert!(!stale_temp.exists(), "一時ファイルが残っています");
+        assert_eq!(reports.len(), 1, "破損ファイルの報告数が一致しません");
+        assert!(reports[0].original_path.ends_with("game_broken.json"), "報告されたファイルが一致しません");
+        assert!(repo.find_by_id(game.id()).is_some(), "正常なゲームが読み込めません");
+    }
+}
@@ -1,4 +1,12 @@
 pub mod game_repository_file;
 pub mod game_history_repository_file;
+pub mod file_store;
+pub mod event_store_file;
 #[cfg(test)]
-mod game_history_repository_file_test; 
\ No newline at end of file
+mod game_history_repository_file_test;
+#[cfg(test)]
+mod game_repository_file_test; 
+#[cfg(test)]
+mod file_store_test;
+#[cfg(test)]
+mod event_store_file_test;
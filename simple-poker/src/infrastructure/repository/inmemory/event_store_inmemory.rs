@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::domain::model::error::DomainError;
+use crate::domain::model::event::GameEvent;
+use crate::domain::model::game::GameId;
+use crate::domain::repository::event_store::{event_game_id, EventStore};
+
+/// ゲームIDとイベント列のマッピング
+type EventMap = HashMap<String, Vec<GameEvent>>;
+
+#[derive(Clone, Default)]
+pub struct InMemoryEventStore {
+    events: Arc<Mutex<EventMap>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    fn lock_events(&self) -> Result<MutexGuard<'_, EventMap>, DomainError> {
+        self.events.lock().map_err(|_| DomainError::InvalidState("ロックの取得に失敗しました".into()))
+    }
+}
+
+impl EventStore for InMemoryEventStore {
+    fn append(&self, event: &GameEvent) -> Result<usize, DomainError> {
+        let mut events = self.lock_events()?;
+        let stream = events.entry(event_game_id(event).value().to_string()).or_default();
+        stream.push(event.clone());
+        Ok(stream.len() - 1)
+    }
+    
+    fn load(&self, game_id: &GameId) -> Result<Vec<GameEvent>, DomainError> {
+        let events = self.lock_events()?;
+        Ok(events.get(game_id.value()).cloned().unwrap_or_default())
+    }
+    
+    fn game_ids(&self) -> Result<Vec<GameId>, DomainError> {
+        let events = self.lock_events()?;
+        Ok(events.keys().map(|id| GameId::from_string(id.clone())).collect())
+    }
+}
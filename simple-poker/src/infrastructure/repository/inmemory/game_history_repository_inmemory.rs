@@ -1,44 +1,53 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
 use crate::domain::model::game::GameId;
 use crate::domain::model::player::PlayerId;
 use crate::domain::model::error::DomainError;
 use crate::domain::repository::game_history_repository::{GameHistoryEntry, GameHistoryRepository};
 
-#[derive(Default)]
+/// スレッドセーフな履歴エントリのマップ
+type ThreadSafeEntryMap = Arc<Mutex<HashMap<String, GameHistoryEntry>>>;
+
+#[derive(Clone, Default)]
 pub struct InMemoryGameHistoryRepository {
-    entries: HashMap<String, GameHistoryEntry>,
+    entries: ThreadSafeEntryMap,
 }
 
 impl InMemoryGameHistoryRepository {
     pub fn new() -> Self {
         Self {
-            entries: HashMap::new(),
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+    
+    fn matching(&self, predicate: impl Fn(&GameHistoryEntry) -> bool) -> Vec<GameHistoryEntry> {
+        match self.entries.lock() {
+            Ok(entries) => entries.values().filter(|e| predicate(e)).cloned().collect(),
+            Err(_) => Vec::new(),
         }
     }
 }
 
 impl GameHistoryRepository for InMemoryGameHistoryRepository {
     fn save(&mut self, entry: &GameHistoryEntry) -> Result<(), DomainError> {
+        let mut entries = self.entries.lock().map_err(|_| DomainError::InvalidState("ロックの取得に失敗しました".into()))?;
         let key = format!("{}_{}", entry.game_id.value(), entry.timestamp.to_rfc3339());
-        self.entries.insert(key, entry.clone());
+        entries.insert(key, entry.clone());
         Ok(())
     }
     
     fn find_by_game_id(&self, game_id: &GameId) -> Option<GameHistoryEntry> {
-        self.entries.values()
-            .filter(|e| e.game_id.value() == game_id.value())
+        self.matching(|e| e.game_id.value() == game_id.value())
+            .into_iter()
             .max_by_key(|e| e.timestamp)
-            .cloned()
     }
     
     fn find_by_player_id(&self, player_id: &PlayerId) -> Vec<GameHistoryEntry> {
-        self.entries.values()
-            .filter(|e| e.winner_ids.iter().any(|id| id.value() == player_id.value()))
-            .cloned()
-            .collect()
+        self.matching(|e| e.involves(player_id))
     }
     
     fn find_all(&self) -> Vec<GameHistoryEntry> {
-        self.entries.values().cloned().collect()
+        self.matching(|_| true)
     }
-} 
\ No newline at end of file
+}
@@ -32,7 +32,10 @@ impl Default for InMemoryGameRepository {
 impl GameRepository for InMemoryGameRepository {
     fn save(&mut self, game: &Game) -> Result<(), DomainError> {
         let mut games = self.games.lock().map_err(|_| DomainError::InvalidState("ロックの取得に失敗しました".into()))?;
-        games.insert(game.id().value().to_string(), game.clone());
+        // 未通知のイベントはスナップショットに含めない
+        let mut snapshot = game.clone();
+        snapshot.take_events();
+        games.insert(game.id().value().to_string(), snapshot);
         Ok(())
     }
     
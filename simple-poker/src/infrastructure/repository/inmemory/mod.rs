@@ -1,3 +1,4 @@
 pub mod player_repository_inmemory;
 pub mod game_repository_inmemory;
-pub mod game_history_repository_inmemory; 
\ No newline at end of file
+pub mod game_history_repository_inmemory;
+pub mod event_store_inmemory;
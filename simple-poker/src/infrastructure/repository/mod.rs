@@ -1,2 +1,4 @@
 pub mod inmemory;
 pub mod file;
+pub mod sqlite;
+pub mod serialization;
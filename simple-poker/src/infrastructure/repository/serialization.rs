@@ -0,0 +1,403 @@
+use crate::domain::model::game::{Game, GameId, GameVariant, GamePhase, BettingRound, GameSerializedData};
+use crate::domain::model::player::{BotKind, Player, PlayerId, PlayerSerializedData};
+use crate::domain::model::card::{Card, Suit, JOKER_RANK};
+use crate::domain::model::error::DomainError;
+
+/// 現在の保存形式（JSON）のバージョン（ファイルとSQLiteのリポジトリで共通）
+///
+/// - 1: プレイヤー・コミュニティカード・ポット合計のみ（デッキは保存されない）
+/// - 2: 残りのデッキの並び・サイドポットを含むハンドの完全な状態
+/// - 3: 進行中のハンドの記録（アクション・ドロー・ショーダウン）
+/// - 4: コンピュータが操作する席
+/// - 5: アンティ
+/// - 6: 離席中と払っていないブラインド
+/// - 7: ベット・レイズできる額の決まり
+/// - 8: ラウンド内で行動済みのプレイヤー
+/// - 9: 席の番号・ボタンの動かし方・ボタンとブラインドの席
+/// - 10: スプリットポットの端数チップの渡し方
+/// - 11: ショートデッキでのスリーカードとストレートの順番
+/// - 12: ジョーカーとワイルドカード
+/// - 13: ゲームで決めた役の表
+pub const CURRENT_SCHEMA_VERSION: u32 = 13;
+
+mod serializable {
+    use serde::{Serialize, Deserialize};
+    use crate::domain::model::bet::BettingStructure;
+    use crate::domain::model::game::{TablePositions, TableRules};
+    use crate::domain::model::hand_history::HandHistory;
+    
+    // バージョン情報のない古いファイルはバージョン1として扱う
+    fn legacy_schema_version() -> u32 {
+        1
+    }
+    
+    #[derive(Serialize, Deserialize)]
+    pub struct SerializableGame {
+        #[serde(default = "legacy_schema_version")]
+        pub schema_version: u32,
+        pub id: String,
+        pub variant: String,
+        pub players: Vec<SerializablePlayer>,
+        pub community_cards: Vec<SerializableCard>,
+        #[serde(default)]
+        pub deck: Option<Vec<SerializableCard>>, // 引かれる順
+        pub pot: u32, // バージョン1ではポット合計、バージョン2以降はメインポット
+        #[serde(default)]
+        pub side_pots: Vec<SerializableSidePot>,
+        pub current_phase: String,
+        pub current_round: Option<String>,
+        pub current_player_index: usize,
+        pub dealer_index: usize,
+        #[serde(flatten)]
+        pub rules: TableRules, // 各ルールを項目として並べる（ない項目は既定のルール）
+        #[serde(default)]
+        pub positions: Option<TablePositions>,
+        pub small_blind: u32,
+        pub big_blind: u32,
+        #[serde(default)]
+        pub ante: u32,
+        #[serde(default)]
+        pub betting_structure: BettingStructure,
+        pub current_bet: u32,
+        #[serde(default)]
+        pub acted_players: Vec<String>, // プレイヤーID
+        #[serde(default)]
+        pub seed: Option<u64>,
+        #[serde(default)]
+        pub hand_number: u32,
+        #[serde(default)]
+        pub hand_history: Option<HandHistory>,
+    }
+    
+    #[derive(Serialize, Deserialize)]
+    pub struct SerializablePlayer {
+        pub id: String,
+        pub name: String,
+        pub chips: u32,
+        pub hand: Vec<SerializableCard>,
+        pub current_bet: u32,
+        #[serde(default)]
+        pub total_bet: u32,
+        pub is_folded: bool,
+        pub is_all_in: bool,
+        pub is_dealer: bool,
+        #[serde(default)]
+        pub bot: Option<String>,
+        #[serde(default)]
+        pub sitting_out: bool,
+        #[serde(default)]
+        pub missed_blind: bool,
+        #[serde(default)]
+        pub seat: usize, // バージョン8以前は席の並び順で振り直す
+    }
+    
+    #[derive(Serialize, Deserialize)]
+    pub struct SerializableSidePot {
+        pub amount: u32,
+        pub player_ids: Vec<String>,
+    }
+    
+    #[derive(Serialize, Deserialize)]
+    pub struct SerializableCard {
+        pub suit: String,
+        pub rank: u8,
+    }
+}
+
+struct GameSerializer;
+
+impl GameSerializer {
+    // ゲームをシリアライズ可能な形式に変換
+    fn to_serializable(game: &Game) -> serializable::SerializableGame {
+        serializable::SerializableGame {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            id: game.id().value().to_string(),
+            variant: match game.variant() {
+                GameVariant::FiveCardDraw => "FiveCardDraw".to_string(),
+                GameVariant::TexasHoldem => "TexasHoldem".to_string(),
+                GameVariant::Omaha => "Omaha".to_string(),
+                GameVariant::SevenCardStud => "SevenCardStud".to_string(),
+                GameVariant::Razz => "Razz".to_string(),
+                GameVariant::DeuceToSevenTripleDraw => "DeuceToSevenTripleDraw".to_string(),
+                GameVariant::OmahaHiLo => "OmahaHiLo".to_string(),
+                GameVariant::ShortDeckHoldem => "ShortDeckHoldem".to_string(),
+            },
+            players: game.players().iter().map(Self::player_to_serializable).collect(),
+            community_cards: game.community_cards().iter().map(Self::card_to_serializable).collect(),
+            deck: Some(game.deck().cards_in_draw_order().iter().map(Self::card_to_serializable).collect()),
+            pot: game.pot().main_pot(),
+            side_pots: game.pot().side_pots().iter()
+                .map(|(chips, player_ids)| serializable::SerializableSidePot {
+                    amount: chips.amount(),
+                    player_ids: player_ids.clone(),
+                })
+                .collect(),
+            current_phase: match game.current_phase() {
+                GamePhase::NotStarted => "NotStarted".to_string(),
+                GamePhase::Dealing => "Dealing".to_string(),
+                GamePhase::Betting => "Betting".to_string(),
+                GamePhase::Drawing => "Drawing".to_string(),
+                GamePhase::Showdown => "Showdown".to_string(),
+                GamePhase::Complete => "Complete".to_string(),
+            },
+            current_round: game.current_round().map(|round| match round {
+                BettingRound::PreDraw => "PreDraw".to_string(),
+                BettingRound::PostDraw => "PostDraw".to_string(),
+                BettingRound::PostSecondDraw => "PostSecondDraw".to_string(),
+                BettingRound::PostThirdDraw => "PostThirdDraw".to_string(),
+                BettingRound::PreFlop => "PreFlop".to_string(),
+                BettingRound::Flop => "Flop".to_string(),
+                BettingRound::Turn => "Turn".to_string(),
+                BettingRound::River => "River".to_string(),
+                BettingRound::ThirdStreet => "ThirdStreet".to_string(),
+                BettingRound::FourthStreet => "FourthStreet".to_string(),
+                BettingRound::FifthStreet => "FifthStreet".to_string(),
+                BettingRound::SixthStreet => "SixthStreet".to_string(),
+                BettingRound::SeventhStreet => "SeventhStreet".to_string(),
+            }),
+            current_player_index: game.current_player_index(),
+            dealer_index: game.dealer_index(),
+            rules: game.rules(),
+            positions: game.positions(),
+            small_blind: game.small_blind(),
+            big_blind: game.big_blind(),
+            ante: game.ante(),
+            betting_structure: game.betting_structure(),
+            current_bet: game.current_bet(),
+            acted_players: game.acted_players().iter().map(|id| id.value().to_string()).collect(),
+            seed: game.seed(),
+            hand_number: game.hand_number(),
+            hand_history: game.hand_history().cloned(),
+        }
+    }
+    
+    // プレイヤーをシリアライズ可能な形式に変換
+    fn player_to_serializable(player: &Player) -> serializable::SerializablePlayer {
+        serializable::SerializablePlayer {
+            id: player.id().value().to_string(),
+            name: player.name().to_string(),
+            chips: player.chips(),
+            hand: player.hand().cards().iter().map(Self::card_to_serializable).collect(),
+            current_bet: player.current_bet(),
+            total_bet: player.total_bet(),
+            is_folded: player.is_folded(),
+            is_all_in: player.is_all_in(),
+            is_dealer: player.is_dealer(),
+            bot: player.bot().map(|kind| match kind {
+                BotKind::Random => "Random".to_string(),
+                BotKind::TightPassive => "TightPassive".to_string(),
+                BotKind::PotOdds => "PotOdds".to_string(),
+            }),
+            sitting_out: player.is_sitting_out(),
+            missed_blind: player.has_missed_blind(),
+            seat: player.seat(),
+        }
+    }
+    
+    // カードをシリアライズ可能な形式に変換
+    fn card_to_serializable(card: &Card) -> serializable::SerializableCard {
+        serializable::SerializableCard {
+            suit: match card.suit() {
+                Suit::Club => "Club".to_string(),
+                Suit::Diamond => "Diamond".to_string(),
+                Suit::Heart => "Heart".to_string(),
+                Suit::Spade => "Spade".to_string(),
+            },
+            rank: card.rank(),
+        }
+    }
+    
+    // シリアライズ済みのゲームからゲームモデルに変換
+    fn from_serializable(serializable: serializable::SerializableGame) -> Result<Game, DomainError> {
+        if serializable.schema_version == 0 || serializable.schema_version > CURRENT_SCHEMA_VERSION {
+            return Err(DomainError::InvalidState(format!(
+                "未対応のファイル形式のバージョンです: {}（対応: 1〜{}）",
+                serializable.schema_version, CURRENT_SCHEMA_VERSION
+            )));
+        }
+        
+        // バリアントを復元
+        let variant = match serializable.variant.as_str() {
+            "FiveCardDraw" => GameVariant::FiveCardDraw,
+            "TexasHoldem" => GameVariant::TexasHoldem,
+            "Omaha" => GameVariant::Omaha,
+            "SevenCardStud" => GameVariant::SevenCardStud,
+            "Razz" => GameVariant::Razz,
+            "DeuceToSevenTripleDraw" => GameVariant::DeuceToSevenTripleDraw,
+            "OmahaHiLo" => GameVariant::OmahaHiLo,
+            "ShortDeckHoldem" => GameVariant::ShortDeckHoldem,
+            _ => return Err(DomainError::InvalidGameOperation(format!("不明なゲームバリアント: {}", serializable.variant))),
+        };
+        
+        // フェーズを復元
+        let phase = match serializable.current_phase.as_str() {
+            "NotStarted" => GamePhase::NotStarted,
+            "Dealing" => GamePhase::Dealing,
+            "Betting" => GamePhase::Betting,
+            "Drawing" => GamePhase::Drawing,
+            "Showdown" => GamePhase::Showdown,
+            "Complete" => GamePhase::Complete,
+            _ => return Err(DomainError::InvalidGameOperation(format!("不明なゲームフェーズ: {}", serializable.current_phase))),
+        };
+        
+        // ラウンドを復元
+        let round = if let Some(round_str) = serializable.current_round.as_ref() {
+            match round_str.as_str() {
+                "PreDraw" => Some(BettingRound::PreDraw),
+                "PostDraw" => Some(BettingRound::PostDraw),
+                "PostSecondDraw" => Some(BettingRound::PostSecondDraw),
+                "PostThirdDraw" => Some(BettingRound::PostThirdDraw),
+                "PreFlop" => Some(BettingRound::PreFlop),
+                "Flop" => Some(BettingRound::Flop),
+                "Turn" => Some(BettingRound::Turn),
+                "River" => Some(BettingRound::River),
+                "ThirdStreet" => Some(BettingRound::ThirdStreet),
+                "FourthStreet" => Some(BettingRound::FourthStreet),
+                "FifthStreet" => Some(BettingRound::FifthStreet),
+                "SixthStreet" => Some(BettingRound::SixthStreet),
+                "SeventhStreet" => Some(BettingRound::SeventhStreet),
+                _ => return Err(DomainError::InvalidGameOperation(format!("不明なベッティングラウンド: {}", round_str))),
+            }
+        } else {
+            None
+        };
+        
+        // プレイヤーの復元
+        let players = serializable.players.into_iter()
+            .map(Self::player_from_serializable)
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        // コミュニティカードの復元
+        let community_cards = serializable.community_cards.iter()
+            .map(Self::card_from_serializable)
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        // デッキを復元（バージョン1のファイルには保存されていない）
+        let deck = match serializable.deck.as_ref() {
+            Some(cards) if serializable.schema_version >= 2 => Some(
+                cards.iter()
+                    .map(Self::card_from_serializable)
+                    .collect::<Result<Vec<_>, _>>()?
+            ),
+            _ => None,
+        };
+        
+        // サイドポットを復元（バージョン1ではポット合計がメインポットになる）
+        let side_pots = serializable.side_pots.into_iter()
+            .map(|side_pot| (side_pot.amount, side_pot.player_ids))
+            .collect();
+        
+        // GameIDを復元
+        let id = GameId::from_string(serializable.id);
+        
+        // ゲームを復元（デシリアライズのファクトリメソッドを使用）
+        Game::from_serialized(
+            GameSerializedData {
+                id,
+                variant,
+                players,
+                community_cards,
+                deck,
+                main_pot: serializable.pot,
+                side_pots,
+                current_phase: phase,
+                current_round: round,
+                current_player_index: serializable.current_player_index,
+                dealer_index: serializable.dealer_index,
+                rules: serializable.rules,
+                positions: serializable.positions,
+                small_blind: serializable.small_blind,
+                big_blind: serializable.big_blind,
+                ante: serializable.ante,
+                betting_structure: serializable.betting_structure,
+                current_bet: serializable.current_bet,
+                acted_players: serializable.acted_players.into_iter().map(PlayerId::from_string).collect(),
+                seed: serializable.seed,
+                hand_number: serializable.hand_number,
+                hand_history: serializable.hand_history,
+            }
+        ).map_err(|e| DomainError::InvalidGameOperation(format!("ゲームの復元に失敗しました: {}", e)))
+    }
+    
+    // シリアライズ済みのプレイヤーからプレイヤーモデルに変換
+    fn player_from_serializable(serializable: serializable::SerializablePlayer) -> Result<Player, DomainError> {
+        // プレイヤーIDを復元
+        let player_id = PlayerId::from_string(serializable.id);
+        
+        // 手札を復元
+        let cards = serializable.hand.iter()
+            .map(Self::card_from_serializable)
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        // 操作するコンピュータの種類を復元
+        let bot = match serializable.bot.as_deref() {
+            None => None,
+            Some("Random") => Some(BotKind::Random),
+            Some("TightPassive") => Some(BotKind::TightPassive),
+            Some("PotOdds") => Some(BotKind::PotOdds),
+            Some(other) => return Err(DomainError::InvalidPlayerOperation(format!("不明なコンピュータの種類: {}", other))),
+        };
+        
+        // プレイヤーを復元（デシリアライズのファクトリメソッドを使用）
+        Player::from_serialized(
+            PlayerSerializedData {
+                id: player_id,
+                name: serializable.name,
+                chips_amount: serializable.chips,
+                cards,
+                current_bet: serializable.current_bet,
+                total_bet: serializable.total_bet,
+                is_folded: serializable.is_folded,
+                is_all_in: serializable.is_all_in,
+                is_dealer: serializable.is_dealer,
+                bot,
+                sitting_out: serializable.sitting_out,
+                missed_blind: serializable.missed_blind,
+                seat: serializable.seat,
+            }
+        ).map_err(|e| DomainError::InvalidPlayerOperation(format!("プレイヤーの復元に失敗しました: {}", e)))
+    }
+    
+    // シリアライズ済みのカードからカードモデルに変換
+    fn card_from_serializable(serializable: &serializable::SerializableCard) -> Result<Card, DomainError> {
+        let suit = match serializable.suit.as_str() {
+            "Club" => Suit::Club,
+            "Diamond" => Suit::Diamond,
+            "Heart" => Suit::Heart,
+            "Spade" => Suit::Spade,
+            _ => return Err(DomainError::InvalidCard(format!("不明なスート: {}", serializable.suit))),
+        };
+        
+        if serializable.rank == JOKER_RANK {
+            return Ok(Card::joker(suit));
+        }
+        Card::new(suit, serializable.rank)
+            .map_err(|e| DomainError::InvalidCard(format!("カードの作成に失敗しました: {}", e)))
+    }
+}
+
+/// ゲームを保存用のJSONに変換する
+pub(crate) fn game_to_json(game: &Game) -> Result<String, DomainError> {
+    serde_json::to_string_pretty(&GameSerializer::to_serializable(game))
+        .map_err(|e| DomainError::InvalidState(format!("JSONへの変換に失敗しました: {}", e)))
+}
+
+/// 保存用のJSONからゲームを復元する
+pub(crate) fn game_from_json(contents: &str) -> Result<Game, DomainError> {
+    let serializable: serializable::SerializableGame = serde_json::from_str(contents)
+        .map_err(|e| DomainError::InvalidState(format!("JSONのパースに失敗しました: {}", e)))?;
+    GameSerializer::from_serializable(serializable)
+}
+
+/// プレイヤーを保存用のJSONに変換する
+pub(crate) fn player_to_json(player: &Player) -> Result<String, DomainError> {
+    serde_json::to_string(&GameSerializer::player_to_serializable(player))
+        .map_err(|e| DomainError::InvalidState(format!("JSONへの変換に失敗しました: {}", e)))
+}
+
+/// 保存用のJSONからプレイヤーを復元する
+pub(crate) fn player_from_json(contents: &str) -> Result<Player, DomainError> {
+    let serializable: serializable::SerializablePlayer = serde_json::from_str(contents)
+        .map_err(|e| DomainError::InvalidState(format!("JSONのパースに失敗しました: {}", e)))?;
+    GameSerializer::player_from_serializable(serializable)
+}
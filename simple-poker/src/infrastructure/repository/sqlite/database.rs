@@ -0,0 +1,154 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use rusqlite::Connection;
+
+use crate::domain::model::error::DomainError;
+
+/// スキーマのマイグレーション（順に適用され、適用済みの数は user_version に記録される）
+///
+/// 既存の要素は変更せず、新しいマイグレーションを末尾に追加すること。
+const MIGRATIONS: &[&str] = &[
+    // 1: ゲーム・プレイヤー・ゲーム履歴
+    r#"
+    CREATE TABLE games (
+        id TEXT PRIMARY KEY,
+        variant TEXT NOT NULL,
+        phase TEXT NOT NULL,
+        state TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+    CREATE TABLE game_players (
+        game_id TEXT NOT NULL REFERENCES games(id) ON DELETE CASCADE,
+        player_id TEXT NOT NULL,
+        seat INTEGER NOT NULL,
+        PRIMARY KEY (game_id, player_id)
+    );
+    CREATE INDEX idx_game_players_player_id ON game_players(player_id);
+
+    CREATE TABLE players (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        chips INTEGER NOT NULL,
+        state TEXT NOT NULL
+    );
+    CREATE INDEX idx_players_name ON players(name);
+
+    CREATE TABLE game_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        game_id TEXT NOT NULL,
+        timestamp TEXT NOT NULL,
+        pot_amount INTEGER NOT NULL,
+        variant TEXT NOT NULL,
+        player_count INTEGER NOT NULL,
+        UNIQUE (game_id, timestamp)
+    );
+    CREATE INDEX idx_game_history_game_id ON game_history(game_id, timestamp);
+    CREATE TABLE game_history_winners (
+        history_id INTEGER NOT NULL REFERENCES game_history(id) ON DELETE CASCADE,
+        player_id TEXT NOT NULL,
+        PRIMARY KEY (history_id, player_id)
+    );
+    CREATE INDEX idx_game_history_winners_player_id ON game_history_winners(player_id);
+    "#,
+    // 2: ハンドの完全な記録と参加者の索引（既存の履歴は勝者を参加者とする）
+    r#"
+    ALTER TABLE game_history ADD COLUMN hand_history TEXT;
+    CREATE TABLE game_history_participants (
+        history_id INTEGER NOT NULL REFERENCES game_history(id) ON DELETE CASCADE,
+        player_id TEXT NOT NULL,
+        PRIMARY KEY (history_id, player_id)
+    );
+    CREATE INDEX idx_game_history_participants_player_id ON game_history_participants(player_id);
+    INSERT INTO game_history_participants (history_id, player_id)
+        SELECT history_id, player_id FROM game_history_winners;
+    "#,
+];
+
+/// SQLiteの操作エラーをドメインエラーに変換する
+pub(crate) fn sql_error(context: &str) -> impl Fn(rusqlite::Error) -> DomainError + '_ {
+    move |e| DomainError::InvalidState(format!("{}: {}", context, e))
+}
+
+/// 3つのリポジトリで共有するSQLiteの接続
+#[derive(Clone)]
+pub struct SqliteDatabase {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteDatabase {
+    /// データベースファイルを開き、未適用のマイグレーションを適用する
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DomainError> {
+        if let Some(parent) = path.as_ref().parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| DomainError::InvalidState(format!("ディレクトリの作成に失敗しました: {}", e)))?;
+            }
+        }
+        
+        let connection = Connection::open(path)
+            .map_err(sql_error("データベースを開けませんでした"))?;
+        Self::from_connection(connection)
+    }
+    
+    /// メモリ上のデータベースを開く（テスト用）
+    pub fn open_in_memory() -> Result<Self, DomainError> {
+        let connection = Connection::open_in_memory()
+            .map_err(sql_error("データベースを開けませんでした"))?;
+        Self::from_connection(connection)
+    }
+    
+    fn from_connection(mut connection: Connection) -> Result<Self, DomainError> {
+        // 複数プロセスからの同時アクセスに備えて待ち時間とWALを設定
+        connection.busy_timeout(std::time::Duration::from_secs(5))
+            .map_err(sql_error("データベースの設定に失敗しました"))?;
+        connection.pragma_update(None, "foreign_keys", true)
+            .map_err(sql_error("データベースの設定に失敗しました"))?;
+        connection.pragma_update(None, "journal_mode", "WAL")
+            .map_err(sql_error("データベースの設定に失敗しました"))?;
+        
+        Self::migrate(&mut connection)?;
+        
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+    
+    fn migrate(connection: &mut Connection) -> Result<(), DomainError> {
+        let version: usize = connection.query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(sql_error("スキーマのバージョンを取得できませんでした"))?;
+        
+        if version > MIGRATIONS.len() {
+            return Err(DomainError::InvalidState(format!(
+                "未対応のスキーマのバージョンです: {}（対応: {}まで）",
+                version, MIGRATIONS.len()
+            )));
+        }
+        
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            let tx = connection.transaction()
+                .map_err(sql_error("マイグレーションを開始できませんでした"))?;
+            tx.execute_batch(migration)
+                .map_err(sql_error("マイグレーションに失敗しました"))?;
+            tx.pragma_update(None, "user_version", index + 1)
+                .map_err(sql_error("スキーマのバージョンを記録できませんでした"))?;
+            tx.commit()
+                .map_err(sql_error("マイグレーションを確定できませんでした"))?;
+        }
+        
+        Ok(())
+    }
+    
+    /// 適用済みのマイグレーションの数
+    pub fn schema_version(&self) -> Result<usize, DomainError> {
+        self.connection()?.query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(sql_error("スキーマのバージョンを取得できませんでした"))
+    }
+    
+    pub(crate) fn connection(&self) -> Result<MutexGuard<'_, Connection>, DomainError> {
+        self.connection.lock().map_err(|_| DomainError::InvalidState("ロックの取得に失敗しました".into()))
+    }
+}
+
+/// 最新のスキーマのバージョン
+pub const CURRENT_SCHEMA_VERSION: usize = MIGRATIONS.len();
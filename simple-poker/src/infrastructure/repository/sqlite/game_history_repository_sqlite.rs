@@ -0,0 +1,139 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection};
+
+use crate::domain::model::game::GameId;
+use crate::domain::model::hand_history::HandHistory;
+use crate::domain::model::player::PlayerId;
+use crate::domain::model::error::DomainError;
+use crate::domain::repository::game_history_repository::{GameHistoryEntry, GameHistoryRepository};
+use crate::infrastructure::repository::sqlite::database::{sql_error, SqliteDatabase};
+
+const SELECT_ENTRY: &str = "SELECT h.id, h.game_id, h.timestamp, h.pot_amount, h.variant, h.player_count, h.hand_history FROM game_history h";
+
+#[derive(Clone)]
+pub struct SqliteGameHistoryRepository {
+    database: SqliteDatabase,
+}
+
+impl SqliteGameHistoryRepository {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+    
+    // 文字列の並びが時刻の並びと一致するよう、桁数を固定して保存する
+    fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+        timestamp.to_rfc3339_opts(SecondsFormat::Nanos, true)
+    }
+    
+    fn query_entries(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<GameHistoryEntry>, DomainError> {
+        let connection = self.database.connection()?;
+        let rows = {
+            let mut statement = connection.prepare(sql)
+                .map_err(sql_error("クエリの準備に失敗しました"))?;
+            let rows = statement.query_map(params, |row| Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, u32>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, usize>(5)?,
+                row.get::<_, Option<String>>(6)?,
+            ))).map_err(sql_error("ゲーム履歴の検索に失敗しました"))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(sql_error("ゲーム履歴の読み込みに失敗しました"))?
+        };
+        
+        rows.into_iter()
+            .map(|(history_id, game_id, timestamp, pot_amount, variant, player_count, hand_history)| {
+                let timestamp = DateTime::parse_from_rfc3339(&timestamp)
+                    .map_err(|e| DomainError::InvalidState(format!("日時のパースエラー: {}", e)))?
+                    .into();
+                let hand_history = hand_history
+                    .map(|json| serde_json::from_str::<HandHistory>(&json))
+                    .transpose()
+                    .map_err(|e| DomainError::InvalidState(format!("ハンドの記録のパースに失敗しました: {}", e)))?;
+                Ok(GameHistoryEntry {
+                    game_id: GameId::from_string(game_id),
+                    timestamp,
+                    winner_ids: Self::winner_ids(&connection, history_id)?,
+                    pot_amount,
+                    variant,
+                    player_count,
+                    hand_history,
+                })
+            })
+            .collect()
+    }
+    
+    fn winner_ids(connection: &Connection, history_id: i64) -> Result<Vec<PlayerId>, DomainError> {
+        let mut statement = connection.prepare(
+            "SELECT player_id FROM game_history_winners WHERE history_id = ?1 ORDER BY rowid"
+        ).map_err(sql_error("クエリの準備に失敗しました"))?;
+        let rows = statement.query_map(params![history_id], |row| row.get::<_, String>(0))
+            .map_err(sql_error("勝者の検索に失敗しました"))?;
+        rows.map(|row| row.map(PlayerId::from_string))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(sql_error("勝者の読み込みに失敗しました"))
+    }
+}
+
+impl GameHistoryRepository for SqliteGameHistoryRepository {
+    fn save(&mut self, entry: &GameHistoryEntry) -> Result<(), DomainError> {
+        let timestamp = Self::format_timestamp(&entry.timestamp);
+        let hand_history = entry.hand_history.as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| DomainError::InvalidState(format!("JSONへの変換に失敗しました: {}", e)))?;
+        let mut connection = self.database.connection()?;
+        let tx = connection.transaction().map_err(sql_error("トランザクションを開始できませんでした"))?;
+        
+        // 同じゲーム・同じ時刻のエントリは置き換える（勝者はカスケード削除される）
+        tx.execute(
+            "DELETE FROM game_history WHERE game_id = ?1 AND timestamp = ?2",
+            params![entry.game_id.value(), timestamp],
+        ).map_err(sql_error("ゲーム履歴の保存に失敗しました"))?;
+        tx.execute(
+            "INSERT INTO game_history (game_id, timestamp, pot_amount, variant, player_count, hand_history)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![entry.game_id.value(), timestamp, entry.pot_amount, entry.variant, entry.player_count, hand_history],
+        ).map_err(sql_error("ゲーム履歴の保存に失敗しました"))?;
+        
+        let history_id = tx.last_insert_rowid();
+        for winner_id in &entry.winner_ids {
+            tx.execute(
+                "INSERT OR IGNORE INTO game_history_winners (history_id, player_id) VALUES (?1, ?2)",
+                params![history_id, winner_id.value()],
+            ).map_err(sql_error("ゲーム履歴の保存に失敗しました"))?;
+        }
+        for participant_id in entry.participant_ids() {
+            tx.execute(
+                "INSERT OR IGNORE INTO game_history_participants (history_id, player_id) VALUES (?1, ?2)",
+                params![history_id, participant_id.value()],
+            ).map_err(sql_error("ゲーム履歴の保存に失敗しました"))?;
+        }
+        
+        tx.commit().map_err(sql_error("ゲーム履歴の保存を確定できませんでした"))
+    }
+    
+    fn find_by_game_id(&self, game_id: &GameId) -> Option<GameHistoryEntry> {
+        self.query_entries(
+            &format!("{} WHERE h.game_id = ?1 ORDER BY h.timestamp DESC LIMIT 1", SELECT_ENTRY),
+            params![game_id.value()],
+        ).ok()?.pop()
+    }
+    
+    fn find_by_player_id(&self, player_id: &PlayerId) -> Vec<GameHistoryEntry> {
+        self.query_entries(
+            &format!(
+                "{} JOIN game_history_participants p ON p.history_id = h.id WHERE p.player_id = ?1 ORDER BY h.timestamp",
+                SELECT_ENTRY
+            ),
+            params![player_id.value()],
+        ).unwrap_or_default()
+    }
+    
+    fn find_all(&self) -> Vec<GameHistoryEntry> {
+        self.query_entries(&format!("{} ORDER BY h.timestamp", SELECT_ENTRY), [])
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::game::{GameId, GameVariant};
+    use crate::domain::model::hand_history::{HandHistory, PayoutRecord, SeatRecord};
+    use crate::domain::model::player::PlayerId;
+    use crate::domain::repository::game_history_repository::{GameHistoryEntry, GameHistoryRepository};
+    use crate::infrastructure::repository::sqlite::database::SqliteDatabase;
+    use crate::infrastructure::repository::sqlite::game_history_repository_sqlite::SqliteGameHistoryRepository;
+    use chrono::{DateTime, Duration, Utc};
+
+    fn create_test_entry(game_id: &str, winner_ids: Vec<&str>, pot_amount: u32, timestamp: DateTime<Utc>) -> GameHistoryEntry {
+        GameHistoryEntry {
+            game_id: GameId::from_string(game_id.to_string()),
+            timestamp,
+            winner_ids: winner_ids.into_iter()
+                .map(|id| PlayerId::from_string(id.to_string()))
+                .collect(),
+            pot_amount,
+            variant: "FiveCardDraw".to_string(),
+            player_count: 3,
+            hand_history: None,
+        }
+    }
+
+    // 2人が参加し、1人目が勝ったハンドの記録
+    fn create_hand_history(game_id: &str, winner: &str, loser: &str) -> HandHistory {
+        let seat = |seat: usize, id: &str| SeatRecord {
+            seat,
+            player_id: PlayerId::from_string(id.to_string()),
+            name: id.to_string(),
+            starting_stack: 1000,
+            hole_cards: Vec::new(),
+        };
+        let mut history = HandHistory::new(
+            GameId::from_string(game_id.to_string()),
+            0,
+            GameVariant::FiveCardDraw,
+            5,
+            10,
+            0,
+            vec![seat(0, winner), seat(1, loser)],
+        );
+        history.payouts.push(PayoutRecord {
+            amount: 40,
+            eligible_player_ids: history.participant_ids(),
+            winners: vec![(PlayerId::from_string(winner.to_string()), 40)],
+        });
+        history
+    }
+
+    #[test]
+    fn エントリーの保存と検索() {
+        let mut repo = SqliteGameHistoryRepository::new(SqliteDatabase::open_in_memory().unwrap());
+        let now = Utc::now();
+        repo.save(&create_test_entry("game-1", vec!["player-1"], 500, now)).unwrap();
+        repo.save(&create_test_entry("game-2", vec!["player-2"], 800, now + Duration::minutes(5))).unwrap();
+        repo.save(&create_test_entry("game-3", vec!["player-1", "player-2"], 1200, now + Duration::minutes(10))).unwrap();
+        
+        let found = repo.find_by_game_id(&GameId::from_string("game-2".to_string())).expect("エントリが見つかりません");
+        assert_eq!(found.pot_amount, 800, "ポット額が一致しません");
+        assert_eq!(found.timestamp, now + Duration::minutes(5), "日時が一致しません");
+        
+        let player1_entries = repo.find_by_player_id(&PlayerId::from_string("player-1".to_string()));
+        assert_eq!(player1_entries.len(), 2, "プレイヤー1のエントリ数が一致しません");
+        assert_eq!(player1_entries[1].winner_ids.len(), 2, "勝者の数が一致しません");
+        
+        assert_eq!(repo.find_all().len(), 3, "全エントリ数が一致しません");
+    }
+
+    #[test]
+    fn 同一ゲームの最新エントリー取得() {
+        let mut repo = SqliteGameHistoryRepository::new(SqliteDatabase::open_in_memory().unwrap());
+        let now = Utc::now();
+        
+        // 保存の順序は関係ない
+        repo.save(&create_test_entry("game-duplicate", vec!["player-1", "player-2"], 1000, now + Duration::minutes(30))).unwrap();
+        repo.save(&create_test_entry("game-duplicate", vec!["player-1"], 500, now)).unwrap();
+        
+        let found = repo.find_by_game_id(&GameId::from_string("game-duplicate".to_string())).unwrap();
+        assert_eq!(found.pot_amount, 1000, "最新のエントリが返されていません");
+        assert_eq!(found.winner_ids.len(), 2, "勝者の数が一致しません");
+    }
+
+    #[test]
+    fn 同じゲームと日時のエントリーは置き換えられる() {
+        let mut repo = SqliteGameHistoryRepository::new(SqliteDatabase::open_in_memory().unwrap());
+        let now = Utc::now();
+        
+        repo.save(&create_test_entry("game-1", vec!["player-1"], 500, now)).unwrap();
+        repo.save(&create_test_entry("game-1", vec!["player-2"], 700, now)).unwrap();
+        
+        let all = repo.find_all();
+        assert_eq!(all.len(), 1, "エントリが重複しています");
+        assert_eq!(all[0].winner_ids[0].value(), "player-2", "勝者が置き換えられていません");
+        assert!(repo.find_by_player_id(&PlayerId::from_string("player-1".to_string())).is_empty(), "古い勝者が残っています");
+    }
+
+    #[test]
+    fn ハンドの記録を保存して参加者で検索() {
+        let mut repo = SqliteGameHistoryRepository::new(SqliteDatabase::open_in_memory().unwrap());
+        let entry = GameHistoryEntry::from_hand_history(create_hand_history("game-hand", "player-w", "player-l"), Utc::now());
+        repo.save(&entry).unwrap();
+        
+        let found = repo.find_by_player_id(&PlayerId::from_string("player-l".to_string()));
+        assert_eq!(found.len(), 1, "負けたプレイヤーで検索できません");
+        assert_eq!(found[0].hand_history, entry.hand_history, "ハンドの記録が一致しません");
+        assert_eq!(found[0].winner_ids, vec![PlayerId::from_string("player-w".to_string())], "勝者が一致しません");
+    }
+}
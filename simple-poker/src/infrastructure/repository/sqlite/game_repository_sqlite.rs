@@ -0,0 +1,111 @@
+use chrono::Utc;
+use rusqlite::{params, OptionalExtension};
+
+use crate::domain::model::game::{Game, GameId};
+use crate::domain::model::player::PlayerId;
+use crate::domain::model::error::DomainError;
+use crate::domain::repository::game_repository::GameRepository;
+use crate::infrastructure::repository::serialization::{game_from_json, game_to_json};
+use crate::infrastructure::repository::sqlite::database::{sql_error, SqliteDatabase};
+
+#[derive(Clone)]
+pub struct SqliteGameRepository {
+    database: SqliteDatabase,
+}
+
+impl SqliteGameRepository {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+    
+    /// 指定したプレイヤーが着席しているゲームを取得する
+    pub fn find_by_player_id(&self, player_id: &PlayerId) -> Result<Vec<Game>, DomainError> {
+        let states = self.query_states(
+            "SELECT g.state FROM games g
+             JOIN game_players gp ON gp.game_id = g.id
+             WHERE gp.player_id = ?1
+             ORDER BY g.updated_at",
+            params![player_id.value()],
+        )?;
+        
+        states.iter().map(|state| game_from_json(state)).collect()
+    }
+    
+    fn query_states(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<String>, DomainError> {
+        let connection = self.database.connection()?;
+        let mut statement = connection.prepare(sql)
+            .map_err(sql_error("クエリの準備に失敗しました"))?;
+        let rows = statement.query_map(params, |row| row.get(0))
+            .map_err(sql_error("ゲームの検索に失敗しました"))?;
+        rows.collect::<Result<Vec<String>, _>>()
+            .map_err(sql_error("ゲームの読み込みに失敗しました"))
+    }
+}
+
+impl GameRepository for SqliteGameRepository {
+    fn save(&mut self, game: &Game) -> Result<(), DomainError> {
+        let state = game_to_json(game)?;
+        
+        let mut connection = self.database.connection()?;
+        let tx = connection.transaction().map_err(sql_error("トランザクションを開始できませんでした"))?;
+        
+        tx.execute(
+            "INSERT INTO games (id, variant, phase, state, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(id) DO UPDATE SET
+                variant = excluded.variant, phase = excluded.phase,
+                state = excluded.state, updated_at = excluded.updated_at",
+            params![
+                game.id().value(),
+                format!("{:?}", game.variant()),
+                format!("{:?}", game.current_phase()),
+                state,
+                Utc::now().to_rfc3339(),
+            ],
+        ).map_err(sql_error("ゲームの保存に失敗しました"))?;
+        
+        // 着席中のプレイヤーの索引を作り直す
+        tx.execute("DELETE FROM game_players WHERE game_id = ?1", params![game.id().value()])
+            .map_err(sql_error("ゲームの保存に失敗しました"))?;
+        for player in game.players() {
+            tx.execute(
+                "INSERT INTO game_players (game_id, player_id, seat) VALUES (?1, ?2, ?3)",
+                params![game.id().value(), player.id().value(), player.seat() as i64],
+            ).map_err(sql_error("ゲームの保存に失敗しました"))?;
+        }
+        
+        tx.commit().map_err(sql_error("ゲームの保存を確定できませんでした"))
+    }
+    
+    fn find_by_id(&self, id: &GameId) -> Option<Game> {
+        let state: String = {
+            let connection = self.database.connection().ok()?;
+            connection.query_row(
+                "SELECT state FROM games WHERE id = ?1",
+                params![id.value()],
+                |row| row.get(0),
+            ).optional().ok()??
+        };
+        
+        game_from_json(&state).ok()
+    }
+    
+    fn find_all(&self) -> Vec<Game> {
+        self.query_states("SELECT state FROM games ORDER BY updated_at", [])
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|state| game_from_json(state).ok())
+            .collect()
+    }
+    
+    fn delete(&mut self, id: &GameId) -> Result<(), DomainError> {
+        let connection = self.database.connection()?;
+        let deleted = connection.execute("DELETE FROM games WHERE id = ?1", params![id.value()])
+            .map_err(sql_error("ゲームの削除に失敗しました"))?;
+        
+        if deleted == 0 {
+            return Err(DomainError::ResourceNotFound(format!("ゲーム {} が見つかりません", id.value())));
+        }
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::game::{Game, GameId, GameVariant};
+    use crate::domain::model::player::Player;
+    use crate::domain::repository::game_repository::GameRepository;
+    use crate::infrastructure::repository::sqlite::database::{SqliteDatabase, CURRENT_SCHEMA_VERSION};
+    use crate::infrastructure::repository::sqlite::game_repository_sqlite::SqliteGameRepository;
+    use tempfile::TempDir;
+
+    fn create_started_game() -> Game {
+        let mut game = Game::new_with_seed(GameVariant::TexasHoldem, 5, 10, Some(7)).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        game
+    }
+
+    #[test]
+    fn ゲームの保存と読み込み() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        let mut repo = SqliteGameRepository::new(database);
+        let game = create_started_game();
+        
+        repo.save(&game).expect("ゲームの保存に失敗");
+        let loaded = repo.find_by_id(game.id()).expect("保存したゲームが見つかりません");
+        
+        assert_eq!(loaded.deck().cards_in_draw_order(), game.deck().cards_in_draw_order(), "デッキの並びが一致しません");
+        assert_eq!(loaded.pot().total(), game.pot().total(), "ポットが一致しません");
+        for (original, restored) in game.players().iter().zip(loaded.players()) {
+            assert_eq!(restored.hand().cards(), original.hand().cards(), "手札が一致しません");
+        }
+    }
+
+    #[test]
+    fn 空席を挟んだ席の番号を保存して復元() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        let mut repo = SqliteGameRepository::new(database.clone());
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.seat_player(Player::new("プレイヤー1".to_string(), 1000), 1).unwrap();
+        game.seat_player(Player::new("プレイヤー2".to_string(), 1000), 4).unwrap();
+        
+        repo.save(&game).unwrap();
+        
+        let seats: Vec<(String, i64)> = {
+            let connection = database.connection().unwrap();
+            let mut statement = connection.prepare("SELECT player_id, seat FROM game_players WHERE game_id = ?1 ORDER BY seat").unwrap();
+            let rows = statement.query_map([game.id().value()], |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+            rows.map(Result::unwrap).collect()
+        };
+        let expected: Vec<(String, i64)> = game.players().iter()
+            .map(|player| (player.id().value().to_string(), player.seat() as i64))
+            .collect();
+        assert_eq!(seats, expected, "索引の席の番号が実際の席と一致しません");
+        assert_eq!(seats.iter().map(|(_, seat)| *seat).collect::<Vec<_>>(), vec![1, 4], "空席を詰めて保存しています");
+        
+        let loaded = repo.find_by_id(game.id()).unwrap();
+        let loaded_seats: Vec<usize> = loaded.players().iter().map(|player| player.seat()).collect();
+        assert_eq!(loaded_seats, vec![1, 4], "復元した席の番号が一致しません");
+    }
+
+    #[test]
+    fn 同じゲームを保存すると上書きされる() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        let mut repo = SqliteGameRepository::new(database);
+        let mut game = create_started_game();
+        
+        repo.save(&game).unwrap();
+        game.end_betting_round().unwrap();
+        repo.save(&game).unwrap();
+        
+        assert_eq!(repo.find_all().len(), 1, "ゲームが重複して保存されています");
+        let loaded = repo.find_by_id(game.id()).unwrap();
+        assert_eq!(loaded.community_cards(), game.community_cards(), "最新の状態が保存されていません");
+    }
+
+    #[test]
+    fn プレイヤーが着席しているゲームを検索() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        let mut repo = SqliteGameRepository::new(database);
+        let game1 = create_started_game();
+        let game2 = create_started_game();
+        repo.save(&game1).unwrap();
+        repo.save(&game2).unwrap();
+        
+        let player_id = game1.players()[0].id().clone();
+        let games = repo.find_by_player_id(&player_id).unwrap();
+        
+        assert_eq!(games.len(), 1, "検索結果の数が一致しません");
+        assert_eq!(games[0].id().value(), game1.id().value(), "検索されたゲームが一致しません");
+    }
+
+    #[test]
+    fn ゲームの削除() {
+        let database = SqliteDatabase::open_in_memory().unwrap();
+        let mut repo = SqliteGameRepository::new(database);
+        let game = create_started_game();
+        repo.save(&game).unwrap();
+        
+        repo.delete(game.id()).expect("ゲームの削除に失敗");
+        
+        assert!(repo.find_by_id(game.id()).is_none(), "削除したゲームが残っています");
+        assert!(repo.find_by_player_id(game.players()[0].id()).unwrap().is_empty(), "着席情報が残っています");
+        assert!(repo.delete(&GameId::new()).is_err(), "存在しないゲームの削除が成功しています");
+    }
+
+    #[test]
+    fn ファイルを開き直しても保存内容が残りマイグレーションは一度だけ適用される() {
+        let temp_dir = TempDir::new().expect("一時ディレクトリの作成に失敗");
+        let path = temp_dir.path().join("nested").join("poker.db");
+        let game = create_started_game();
+        
+        {
+            let database = SqliteDatabase::open(&path).expect("データベースの作成に失敗");
+            SqliteGameRepository::new(database).save(&game).unwrap();
+        }
+        
+        let database = SqliteDatabase::open(&path).expect("データベースの再オープンに失敗");
+        assert_eq!(database.schema_version().unwrap(), CURRENT_SCHEMA_VERSION, "スキーマのバージョンが一致しません");
+        assert!(SqliteGameRepository::new(database).find_by_id(game.id()).is_some(), "保存したゲームが見つかりません");
+    }
+}
@@ -0,0 +1,10 @@
+pub mod database;
+pub mod game_repository_sqlite;
+pub mod player_repository_sqlite;
+pub mod game_history_repository_sqlite;
+#[cfg(test)]
+mod game_repository_sqlite_test;
+#[cfg(test)]
+mod player_repository_sqlite_test;
+#[cfg(test)]
+mod game_history_repository_sqlite_test;
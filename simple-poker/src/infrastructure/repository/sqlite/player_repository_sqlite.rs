@@ -0,0 +1,78 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::domain::model::player::{Player, PlayerId};
+use crate::domain::model::error::DomainError;
+use crate::domain::repository::player_repository::PlayerRepository;
+use crate::infrastructure::repository::serialization::{player_from_json, player_to_json};
+use crate::infrastructure::repository::sqlite::database::{sql_error, SqliteDatabase};
+
+#[derive(Clone)]
+pub struct SqlitePlayerRepository {
+    database: SqliteDatabase,
+}
+
+impl SqlitePlayerRepository {
+    pub fn new(database: SqliteDatabase) -> Self {
+        Self { database }
+    }
+    
+    /// 名前でプレイヤーを検索する
+    pub fn find_by_name(&self, name: &str) -> Result<Vec<Player>, DomainError> {
+        let states = self.query_states("SELECT state FROM players WHERE name = ?1 ORDER BY id", params![name])?;
+        states.iter().map(|state| player_from_json(state)).collect()
+    }
+    
+    fn query_states(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<String>, DomainError> {
+        let connection = self.database.connection()?;
+        let mut statement = connection.prepare(sql)
+            .map_err(sql_error("クエリの準備に失敗しました"))?;
+        let rows = statement.query_map(params, |row| row.get(0))
+            .map_err(sql_error("プレイヤーの検索に失敗しました"))?;
+        rows.collect::<Result<Vec<String>, _>>()
+            .map_err(sql_error("プレイヤーの読み込みに失敗しました"))
+    }
+}
+
+impl PlayerRepository for SqlitePlayerRepository {
+    fn save(&mut self, player: &Player) -> Result<(), DomainError> {
+        let state = player_to_json(player)?;
+        let connection = self.database.connection()?;
+        
+        connection.execute(
+            "INSERT INTO players (id, name, chips, state) VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name, chips = excluded.chips, state = excluded.state",
+            params![player.id().value(), player.name(), player.chips(), state],
+        ).map_err(sql_error("プレイヤーの保存に失敗しました"))?;
+        
+        Ok(())
+    }
+    
+    fn find_by_id(&self, id: &PlayerId) -> Option<Player> {
+        let state: String = {
+            let connection = self.database.connection().ok()?;
+            connection.query_row(
+                "SELECT state FROM players WHERE id = ?1",
+                params![id.value()],
+                |row| row.get(0),
+            ).optional().ok()??
+        };
+        
+        player_from_json(&state).ok()
+    }
+    
+    fn find_all(&self) -> Vec<Player> {
+        self.query_states("SELECT state FROM players ORDER BY id", [])
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|state| player_from_json(state).ok())
+            .collect()
+    }
+    
+    fn delete(&mut self, id: &PlayerId) -> Result<(), DomainError> {
+        let connection = self.database.connection()?;
+        connection.execute("DELETE FROM players WHERE id = ?1", params![id.value()])
+            .map_err(sql_error("プレイヤーの削除に失敗しました"))?;
+        Ok(())
+    }
+}
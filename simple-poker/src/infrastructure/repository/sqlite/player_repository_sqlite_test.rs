@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::player::{Player, PlayerId};
+    use crate::domain::repository::player_repository::PlayerRepository;
+    use crate::infrastructure::repository::sqlite::database::SqliteDatabase;
+    use crate::infrastructure::repository::sqlite::player_repository_sqlite::SqlitePlayerRepository;
+
+    #[test]
+    fn プレイヤーの保存と読み込み() {
+        let mut repo = SqlitePlayerRepository::new(SqliteDatabase::open_in_memory().unwrap());
+        let mut player = Player::new("テストプレイヤー".to_string(), 1000);
+        player.place_bet(200).unwrap();
+        
+        repo.save(&player).expect("プレイヤーの保存に失敗");
+        let loaded = repo.find_by_id(player.id()).expect("保存したプレイヤーが見つかりません");
+        
+        assert_eq!(loaded.name(), "テストプレイヤー", "名前が一致しません");
+        assert_eq!(loaded.chips(), 800, "チップ数が一致しません");
+        assert_eq!(loaded.current_bet(), 200, "ベット額が一致しません");
+    }
+
+    #[test]
+    fn 名前による検索と削除() {
+        let mut repo = SqlitePlayerRepository::new(SqliteDatabase::open_in_memory().unwrap());
+        let alice = Player::new("アリス".to_string(), 1000);
+        let bob = Player::new("ボブ".to_string(), 500);
+        repo.save(&alice).unwrap();
+        repo.save(&bob).unwrap();
+        
+        let found = repo.find_by_name("ボブ").unwrap();
+        assert_eq!(found.len(), 1, "検索結果の数が一致しません");
+        assert_eq!(found[0].id().value(), bob.id().value(), "検索されたプレイヤーが一致しません");
+        
+        repo.delete(alice.id()).unwrap();
+        assert_eq!(repo.find_all().len(), 1, "削除後のプレイヤー数が一致しません");
+        assert!(repo.find_by_id(&PlayerId::new()).is_none(), "存在しないプレイヤーが見つかっています");
+    }
+}
@@ -53,6 +53,7 @@ impl EventSubscriber for InMemoryEventPublisher {
 mod tests {
     use super::*;
     use crate::domain::model::game::{GameId, GameVariant};
+    use crate::domain::model::bet::BettingStructure;
     use chrono::Utc;
     
     #[test]
@@ -65,12 +66,9 @@ mod tests {
         
         // サブスクライバーを登録
         publisher.subscribe(Box::new(move |event| {
-            match event {
-                GameEvent::GameCreated { .. } => {
-                    let mut count = counter_clone.lock().unwrap();
-                    *count += 1;
-                },
-                _ => {}
+            if let GameEvent::GameCreated { .. } = event {
+                let mut count = counter_clone.lock().unwrap();
+                *count += 1;
             }
         }));
         
@@ -80,6 +78,8 @@ mod tests {
             variant: GameVariant::FiveCardDraw,
             small_blind: 5,
             big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            seed: None,
             time: Utc::now(),
         };
         
@@ -4,7 +4,7 @@ mod tests {
     use crate::domain::model::event::{GameEvent, EventPublisher, EventSubscriber};
     use crate::domain::model::game::{GameId, GameVariant};
     use crate::domain::model::player::PlayerId;
-    use crate::domain::model::bet::BetAction;
+    use crate::domain::model::bet::{BetAction, BettingStructure};
     use crate::infrastructure::service::event::inmemory_event_publisher::InMemoryEventPublisher;
     use chrono::Utc;
 
@@ -18,12 +18,9 @@ mod tests {
         
         // サブスクライバーを登録
         publisher.subscribe(Box::new(move |event| {
-            match event {
-                GameEvent::GameCreated { .. } => {
-                    let mut count = counter_clone.lock().unwrap();
-                    *count += 1;
-                },
-                _ => {}
+            if let GameEvent::GameCreated { .. } = event {
+                let mut count = counter_clone.lock().unwrap();
+                *count += 1;
             }
         }));
         
@@ -33,6 +30,8 @@ mod tests {
             variant: GameVariant::FiveCardDraw,
             small_blind: 5,
             big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            seed: None,
             time: Utc::now(),
         };
         
@@ -79,6 +78,8 @@ mod tests {
             variant: GameVariant::FiveCardDraw,
             small_blind: 5,
             big_blind: 10,
+            betting_structure: BettingStructure::NoLimit,
+            seed: None,
             time: Utc::now(),
         };
         
@@ -118,6 +119,9 @@ mod tests {
         let event = GameEvent::GameStarted {
             game_id,
             player_count: 4,
+            hand_number: 0,
+            dealer_index: 0,
+            deck: Vec::new(),
             time: Utc::now(),
         };
         
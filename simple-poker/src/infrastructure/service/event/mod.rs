@@ -1,3 +1,4 @@
 pub mod inmemory_event_publisher;
+pub mod storing_event_publisher;
 #[cfg(test)]
-mod inmemory_event_publisher_test; 
\ No newline at end of file
+mod inmemory_event_publisher_test;
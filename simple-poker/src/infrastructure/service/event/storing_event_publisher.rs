@@ -0,0 +1,35 @@
+use crate::domain::model::error::DomainError;
+use crate::domain::model::event::{EventPublisher, EventSubscriber, GameEvent};
+use crate::domain::repository::event_store::EventStore;
+
+/// イベントをイベントストアに追記してから通知するパブリッシャー
+///
+/// 追記に失敗したイベントは通知しない。
+#[derive(Clone)]
+pub struct StoringEventPublisher<S: EventStore, P: EventPublisher> {
+    store: S,
+    publisher: P,
+}
+
+impl<S: EventStore, P: EventPublisher> StoringEventPublisher<S, P> {
+    pub fn new(store: S, publisher: P) -> Self {
+        Self { store, publisher }
+    }
+    
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+impl<S: EventStore, P: EventPublisher> EventPublisher for StoringEventPublisher<S, P> {
+    fn publish(&self, event: GameEvent) -> Result<(), DomainError> {
+        self.store.append(&event)?;
+        self.publisher.publish(event)
+    }
+}
+
+impl<S: EventStore, P: EventPublisher + EventSubscriber> EventSubscriber for StoringEventPublisher<S, P> {
+    fn subscribe(&mut self, callback: Box<dyn Fn(&GameEvent) + Send + 'static>) {
+        self.publisher.subscribe(callback);
+    }
+}
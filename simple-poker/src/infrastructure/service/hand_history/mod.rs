@@ -0,0 +1,3 @@
+pub mod pokerstars_format;
+#[cfg(test)]
+mod pokerstars_format_test;
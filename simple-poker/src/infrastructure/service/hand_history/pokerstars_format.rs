@@ -0,0 +1,1031 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, TimeZone, Utc};
+
+use crate::domain::model::bet::{BetAction, BettingStructure};
+use crate::domain::model::card::{Card, Suit, JOKER_RANK};
+use crate::domain::model::error::DomainError;
+use crate::domain::model::game::{BettingRound, Game, GameId, GameVariant};
+use crate::domain::model::hand_history::{
+    ActionRecord, BlindKind, BlindRecord, BoardRecord, DrawRecord, HandHistory, PayoutRecord, ReturnRecord, SeatRecord,
+    ShowdownRecord,
+};
+use crate::domain::model::player::PlayerId;
+use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules, HandStrength, LowHand};
+
+/// テーブルの最大人数（ゲームに参加できる最大人数）
+const MAX_SEATS: usize = 10;
+/// ハンドの先頭行
+const HAND_HEADER: &str = "PokerStars Hand #";
+
+/// PokerStars 形式のテキストのハンド履歴
+///
+/// 書き出しはエンジンが記録した `HandHistory` をそのまま文章にする。
+/// 読み込みでは、金額に通貨記号や小数点が含まれる場合はセント単位（×100）のチップに換算し、
+/// プレイヤーIDには表示名をそのまま使う。ショーダウンの役は記載された説明ではなく
+/// `HandEvaluationService` で評価し直す。
+pub struct PokerStarsFormat;
+
+impl PokerStarsFormat {
+    /// ゲームで記録中のハンドを書き出す
+    pub fn export_game(game: &Game) -> Result<String, DomainError> {
+        game.hand_history()
+            .map(Self::export)
+            .ok_or_else(|| DomainError::InvalidState("ハンドの記録がありません".to_string()))
+    }
+
+    /// ハンドの記録を PokerStars 形式のテキストにする
+    pub fn export(history: &HandHistory) -> String {
+        let mut lines = Vec::new();
+        let name_of = |player_id: &PlayerId| {
+            history.seat_of(player_id)
+                .map(|seat| seat.name.clone())
+                .unwrap_or_else(|| player_id.value().to_string())
+        };
+
+        lines.push(format!(
+            "{}{}: {} ({}/{}) - {}",
+            HAND_HEADER,
+            history.hand_number,
+            Self::game_name(history.variant, history.betting_structure),
+            history.small_blind,
+            history.big_blind,
+            history.started_at.format("%Y/%m/%d %H:%M:%S UTC"),
+        ));
+        lines.push(format!(
+            "Table '{}' {}-max Seat #{} is the button",
+            history.game_id.value(),
+            MAX_SEATS,
+            history.dealer_seat + 1,
+        ));
+        for seat in &history.seats {
+            lines.push(format!("Seat {}: {} ({} in chips)", seat.seat + 1, seat.name, seat.starting_stack));
+        }
+        for blind in &history.blinds {
+            let kind = match blind.kind {
+                BlindKind::Small => "small blind",
+                BlindKind::Big => "big blind",
+                BlindKind::Ante => "the ante",
+                // ブリングインはカードを配った後に書く
+                BlindKind::BringIn => continue,
+            };
+            lines.push(format!("{}: posts {} {}", name_of(&blind.player_id), kind, blind.amount));
+        }
+
+        for (i, &round) in Self::rounds(history.variant).iter().enumerate() {
+            let actions = history.actions_in(round);
+            // ラウンド内でそれぞれが出したチップ（プリフロップはブラインドを含む）
+            let mut contributed: HashMap<&PlayerId, u32> = HashMap::new();
+
+            if history.variant.is_stud() {
+                let dealt = Self::stud_dealt_lines(history, i);
+                let board = history.board.iter().find(|board| board.round == round);
+                if i > 0 && dealt.is_empty() && board.is_none() {
+                    break;
+                }
+                match board {
+                    Some(board) => lines.push(format!("*** {} *** [{}]", Self::stud_street_name(i), Self::cards_text(&board.cards))),
+                    None => lines.push(format!("*** {} ***", Self::stud_street_name(i))),
+                }
+                lines.extend(dealt);
+                if i == 0 {
+                    for blind in history.blinds.iter().filter(|blind| blind.kind == BlindKind::BringIn) {
+                        lines.push(format!("{}: brings in for {}", name_of(&blind.player_id), blind.amount));
+                        *contributed.entry(&blind.player_id).or_insert(0) += blind.amount;
+                    }
+                }
+            } else if i == 0 {
+                lines.push(match history.variant.is_draw() {
+                    true => "*** DEALING HANDS ***".to_string(),
+                    false => "*** HOLE CARDS ***".to_string(),
+                });
+                for seat in history.seats.iter().filter(|seat| !seat.hole_cards.is_empty()) {
+                    lines.push(format!("Dealt to {} [{}]", seat.name, Self::cards_text(&seat.hole_cards)));
+                }
+                // アンティはラウンドのベット額に含めない
+                for blind in history.blinds.iter().filter(|blind| blind.kind != BlindKind::Ante) {
+                    *contributed.entry(&blind.player_id).or_insert(0) += blind.amount;
+                }
+            } else if history.variant.is_draw() {
+                let draws: Vec<&DrawRecord> = history.draws.iter().filter(|draw| draw.round == round).collect();
+                if draws.is_empty() && actions.is_empty() {
+                    break;
+                }
+                lines.push(format!("*** {} ***", Self::draw_name(round)));
+                for draw in draws {
+                    lines.extend(Self::draw_lines(history, draw));
+                }
+            } else {
+                let Some(board) = history.board.iter().find(|board| board.round == round) else {
+                    break;
+                };
+                let previous: Vec<Card> = history.board.iter()
+                    .take_while(|record| record.round != round)
+                    .flat_map(|record| record.cards.iter().copied())
+                    .collect();
+                let street = match round {
+                    BettingRound::Flop => "FLOP",
+                    BettingRound::Turn => "TURN",
+                    _ => "RIVER",
+                };
+                if previous.is_empty() {
+                    lines.push(format!("*** {} *** [{}]", street, Self::cards_text(&board.cards)));
+                } else {
+                    lines.push(format!(
+                        "*** {} *** [{}] [{}]",
+                        street,
+                        Self::cards_text(&previous),
+                        Self::cards_text(&board.cards),
+                    ));
+                }
+            }
+
+            let mut highest = contributed.values().copied().max().unwrap_or(0);
+            for action in actions {
+                let before = contributed.get(&action.player_id).copied().unwrap_or(0);
+                let after = before + action.amount;
+                let mut text = match action.action {
+                    BetAction::Fold => "folds".to_string(),
+                    BetAction::Check => "checks".to_string(),
+                    BetAction::Call => format!("calls {}", action.amount),
+                    BetAction::Raise | BetAction::AllIn => {
+                        if after <= highest {
+                            format!("calls {}", action.amount)
+                        } else if highest == 0 {
+                            format!("bets {}", action.amount)
+                        } else {
+                            format!("raises {} to {}", after - highest, after)
+                        }
+                    },
+                };
+                if action.action == BetAction::AllIn {
+                    text.push_str(" and is all-in");
+                }
+                lines.push(format!("{}: {}", name_of(&action.player_id), text));
+                contributed.insert(&action.player_id, after);
+                highest = highest.max(after);
+            }
+            for returned in history.returned.iter().filter(|returned| returned.round == round) {
+                lines.push(format!("Uncalled bet ({}) returned to {}", returned.amount, name_of(&returned.player_id)));
+            }
+        }
+
+        if !history.showdown.is_empty() {
+            lines.push("*** SHOW DOWN ***".to_string());
+            for record in &history.showdown {
+                lines.push(format!(
+                    "{}: shows [{}] ({})",
+                    name_of(&record.player_id),
+                    Self::cards_text(&record.cards),
+                    Self::describe_record(history.variant, record),
+                ));
+            }
+        }
+        // 本家と同じくサイドポットから順に書く
+        for (index, payout) in history.payouts.iter().enumerate().rev() {
+            for (player_id, amount) in &payout.winners {
+                lines.push(format!(
+                    "{} collected {} from {}",
+                    name_of(player_id),
+                    amount,
+                    Self::pot_name(index, history.payouts.len()),
+                ));
+            }
+        }
+
+        lines.push("*** SUMMARY ***".to_string());
+        if history.payouts.len() > 1 {
+            let pots: Vec<String> = history.payouts.iter().enumerate()
+                .map(|(index, payout)| format!("{} {}.", Self::capitalize(&Self::pot_name(index, history.payouts.len())), payout.amount))
+                .collect();
+            lines.push(format!("Total pot {} {} | Rake 0", history.total_pot(), pots.join(" ")));
+        } else {
+            lines.push(format!("Total pot {} | Rake 0", history.total_pot()));
+        }
+        let community = history.community_cards();
+        if !community.is_empty() {
+            lines.push(format!("Board [{}]", Self::cards_text(&community)));
+        }
+        for seat in &history.seats {
+            lines.push(format!("Seat {}: {}{} {}", seat.seat + 1, seat.name, Self::position(history, seat), Self::outcome(history, seat)));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// 1ハンド分のテキストを読み込む
+    pub fn import(text: &str) -> Result<HandHistory, DomainError> {
+        let mut hands = Self::import_all(text)?;
+        match hands.len() {
+            1 => Ok(hands.remove(0)),
+            0 => Err(DomainError::InvalidState("ハンドが見つかりません".to_string())),
+            n => Err(DomainError::InvalidState(format!("{}件のハンドが含まれています", n))),
+        }
+    }
+
+    /// 複数のハンドを含むテキストを読み込む
+    pub fn import_all(text: &str) -> Result<Vec<HandHistory>, DomainError> {
+        let mut blocks: Vec<Vec<&str>> = Vec::new();
+        for line in text.lines() {
+            // BOM や前後の空白を取り除く
+            let line = line.trim_start_matches('\u{feff}').trim();
+            if line.starts_with(HAND_HEADER) {
+                blocks.push(Vec::new());
+            }
+            if let Some(block) = blocks.last_mut() {
+                if !line.is_empty() {
+                    block.push(line);
+                }
+            }
+        }
+
+        blocks.iter().enumerate()
+            .map(|(index, block)| HandParser::parse(block, index as u32 + 1))
+            .collect()
+    }
+
+    fn game_name(variant: GameVariant, betting_structure: BettingStructure) -> String {
+        let game = match variant {
+            GameVariant::FiveCardDraw => "5 Card Draw",
+            GameVariant::TexasHoldem => "Hold'em",
+            GameVariant::Omaha => "Omaha",
+            GameVariant::SevenCardStud => "7 Card Stud",
+            GameVariant::Razz => "Razz",
+            GameVariant::DeuceToSevenTripleDraw => "Triple Draw 2-7 Lowball",
+            GameVariant::OmahaHiLo => "Omaha Hi/Lo",
+            GameVariant::ShortDeckHoldem => "Hold'em Short Deck",
+        };
+        let limit = match betting_structure {
+            BettingStructure::NoLimit => "No Limit",
+            BettingStructure::PotLimit => "Pot Limit",
+            BettingStructure::FixedLimit { .. } => "Limit",
+        };
+        format!("{} {}", game, limit)
+    }
+
+    fn rounds(variant: GameVariant) -> Vec<BettingRound> {
+        match variant {
+            GameVariant::FiveCardDraw => vec![BettingRound::PreDraw, BettingRound::PostDraw],
+            GameVariant::DeuceToSevenTripleDraw => vec![
+                BettingRound::PreDraw,
+                BettingRound::PostDraw,
+                BettingRound::PostSecondDraw,
+                BettingRound::PostThirdDraw,
+            ],
+            GameVariant::TexasHoldem | GameVariant::Omaha | GameVariant::OmahaHiLo | GameVariant::ShortDeckHoldem => {
+                vec![BettingRound::PreFlop, BettingRound::Flop, BettingRound::Turn, BettingRound::River]
+            },
+            GameVariant::SevenCardStud | GameVariant::Razz => vec![
+                BettingRound::ThirdStreet,
+                BettingRound::FourthStreet,
+                BettingRound::FifthStreet,
+                BettingRound::SixthStreet,
+                BettingRound::SeventhStreet,
+            ],
+        }
+    }
+    
+    // スタッドの区切りの名前（7枚目は本家に合わせて RIVER）
+    fn stud_street_name(street: usize) -> &'static str {
+        match street {
+            0 => "3rd STREET",
+            1 => "4th STREET",
+            2 => "5th STREET",
+            3 => "6th STREET",
+            _ => "RIVER",
+        }
+    }
+    
+    // スタッドでそのストリートに配られたカード（3rdストリートは3枚、以降は [それまでの手札] [配られた1枚]）
+    fn stud_dealt_lines(history: &HandHistory, street: usize) -> Vec<String> {
+        let dealt_count = 3 + street;
+        history.seats.iter()
+            .filter(|seat| seat.hole_cards.len() >= dealt_count)
+            .map(|seat| match street {
+                0 => format!("Dealt to {} [{}]", seat.name, Self::cards_text(&seat.hole_cards[..3])),
+                _ => format!(
+                    "Dealt to {} [{}] [{}]",
+                    seat.name,
+                    Self::cards_text(&seat.hole_cards[..dealt_count - 1]),
+                    Self::card_text(&seat.hole_cards[dealt_count - 1]),
+                ),
+            })
+            .collect()
+    }
+
+    // ドローの区切りの名前
+    fn draw_name(round: BettingRound) -> &'static str {
+        match round {
+            BettingRound::PostSecondDraw => "SECOND DRAW",
+            BettingRound::PostThirdDraw => "THIRD DRAW",
+            _ => "FIRST DRAW",
+        }
+    }
+
+    fn draw_lines(history: &HandHistory, draw: &DrawRecord) -> Vec<String> {
+        let Some(seat) = history.seat_of(&draw.player_id) else {
+            return Vec::new();
+        };
+        if draw.discarded.is_empty() {
+            return vec![format!("{}: stands pat", seat.name)];
+        }
+
+        // それまでの交換を反映した手札から捨てたカードを除く
+        let mut hand = seat.hole_cards.clone();
+        for earlier in history.draws.iter()
+            .take_while(|earlier| earlier.round != draw.round)
+            .filter(|earlier| earlier.player_id == draw.player_id)
+        {
+            hand.retain(|card| !earlier.discarded.contains(card));
+            hand.extend(&earlier.drawn);
+        }
+        let kept: Vec<Card> = hand.into_iter()
+            .filter(|card| !draw.discarded.contains(card))
+            .collect();
+        let count = draw.discarded.len();
+        vec![
+            format!(
+                "{}: discards {} card{} [{}]",
+                seat.name,
+                count,
+                if count == 1 { "" } else { "s" },
+                Self::cards_text(&draw.discarded),
+            ),
+            format!("Dealt to {} [{}] [{}]", seat.name, Self::cards_text(&kept), Self::cards_text(&draw.drawn)),
+        ]
+    }
+
+    fn pot_name(index: usize, pot_count: usize) -> String {
+        match (index, pot_count) {
+            (_, 0 | 1) => "pot".to_string(),
+            (0, _) => "main pot".to_string(),
+            (_, 2) => "side pot".to_string(),
+            (n, _) => format!("side pot-{}", n),
+        }
+    }
+
+    fn capitalize(text: &str) -> String {
+        let mut chars = text.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        }
+    }
+
+    // サマリーでの席の役割
+    fn position(history: &HandHistory, seat: &SeatRecord) -> String {
+        let mut roles = Vec::new();
+        if seat.seat == history.dealer_seat {
+            roles.push(" (button)");
+        }
+        for blind in history.blinds.iter().filter(|blind| blind.player_id == seat.player_id) {
+            roles.push(match blind.kind {
+                BlindKind::Small => " (small blind)",
+                BlindKind::Big => " (big blind)",
+                BlindKind::Ante => "",
+                BlindKind::BringIn => " (bring-in)",
+            });
+        }
+        roles.concat()
+    }
+
+    // サマリーでの席ごとの結果
+    fn outcome(history: &HandHistory, seat: &SeatRecord) -> String {
+        if let Some(round) = history.folded_in(&seat.player_id) {
+            return match round {
+                BettingRound::PreFlop => "folded before Flop",
+                BettingRound::Flop => "folded on the Flop",
+                BettingRound::Turn => "folded on the Turn",
+                BettingRound::River => "folded on the River",
+                BettingRound::PreDraw => "folded before the Draw",
+                BettingRound::PostDraw if history.variant == GameVariant::DeuceToSevenTripleDraw => "folded after the 1st Draw",
+                BettingRound::PostDraw => "folded after the Draw",
+                BettingRound::PostSecondDraw => "folded after the 2nd Draw",
+                BettingRound::PostThirdDraw => "folded after the 3rd Draw",
+                BettingRound::ThirdStreet => "folded on the 3rd Street",
+                BettingRound::FourthStreet => "folded on the 4th Street",
+                BettingRound::FifthStreet => "folded on the 5th Street",
+                BettingRound::SixthStreet => "folded on the 6th Street",
+                BettingRound::SeventhStreet => "folded on the River",
+            }.to_string();
+        }
+
+        let won: u32 = history.payouts.iter()
+            .flat_map(|payout| payout.winners.iter())
+            .filter(|(player_id, _)| player_id == &seat.player_id)
+            .map(|(_, amount)| amount)
+            .sum();
+        match history.showdown.iter().find(|record| record.player_id == seat.player_id) {
+            Some(record) if won > 0 => format!(
+                "showed [{}] and won ({}) with {}",
+                Self::cards_text(&record.cards),
+                won,
+                Self::describe_record(history.variant, record),
+            ),
+            Some(record) => format!(
+                "showed [{}] and lost with {}",
+                Self::cards_text(&record.cards),
+                Self::describe_record(history.variant, record),
+            ),
+            None if won > 0 => format!("collected ({})", won),
+            None => "mucked".to_string(),
+        }
+    }
+
+    /// 役の英語での説明（例: "a pair of Aces"）
+    pub fn describe(strength: &HandStrength) -> String {
+        Self::describe_with_rules(strength, &HandRankingRules::STANDARD)
+    }
+    
+    /// 役の決まりに合わせた説明（ショートデッキの A-6-7-8-9 は "Ace to Nine"）
+    pub fn describe_with_rules(strength: &HandStrength, rules: &HandRankingRules) -> String {
+        let values = strength.values();
+        let value = |i: usize| values.get(i).copied().unwrap_or(0);
+        // Aから始まるストレートは最も低いランクの3つ上が最も高いカードになる
+        let straight_low = if value(0) == rules.lowest_rank() + 3 { 1 } else { value(0).saturating_sub(4) };
+        match strength.rank() {
+            HandRank::HighCard => format!("high card {}", Self::rank_name(value(0))),
+            HandRank::OnePair => format!("a pair of {}", Self::rank_plural(value(0))),
+            HandRank::TwoPair => format!("two pair, {} and {}", Self::rank_plural(value(0)), Self::rank_plural(value(1))),
+            HandRank::ThreeOfAKind => format!("three of a kind, {}", Self::rank_plural(value(0))),
+            HandRank::Straight => format!(
+                "a straight, {} to {}",
+                Self::rank_name(straight_low),
+                Self::rank_name(value(0)),
+            ),
+            HandRank::Flush => format!("a flush, {} high", Self::rank_name(value(0))),
+            HandRank::FullHouse => format!("a full house, {} full of {}", Self::rank_plural(value(0)), Self::rank_plural(value(1))),
+            HandRank::FourOfAKind => format!("four of a kind, {}", Self::rank_plural(value(0))),
+            HandRank::StraightFlush => format!(
+                "a straight flush, {} to {}",
+                Self::rank_name(straight_low),
+                Self::rank_name(value(0)),
+            ),
+            HandRank::RoyalStraightFlush => "a Royal Flush".to_string(),
+            HandRank::FiveOfAKind => format!("five of a kind, {}", Self::rank_plural(value(0))),
+            HandRank::Skeet => format!("a skeet, {} high", Self::rank_name(value(0))),
+            HandRank::BigBobtail => format!(
+                "a big bobtail, {} to {}",
+                Self::rank_name(if value(0) == rules.lowest_rank() + 2 { 1 } else { value(0).saturating_sub(3) }),
+                Self::rank_name(value(0)),
+            ),
+        }
+    }
+
+    // ショーダウンした手の説明（ローだけで勝負した場合はローの説明、ハイローは両方）
+    fn describe_record(variant: GameVariant, record: &ShowdownRecord) -> String {
+        let high = Self::describe_with_rules(&record.strength, &variant.hand_ranking_rules());
+        match (&record.low, variant.is_hi_lo()) {
+            (Some(low), true) => format!("HI: {}; LO: {}", high, Self::low_ranks_text(low)),
+            (None, true) => format!("HI: {}", high),
+            (Some(low), false) => Self::describe_low(low),
+            (None, false) => high,
+        }
+    }
+    
+    /// ローの英語での説明（例: "Lo: 8,5,4,3,A"、ペアなどがあれば役の説明）
+    pub fn describe_low(low: &LowHand) -> String {
+        if low.rank() != HandRank::HighCard {
+            return Self::describe(&HandStrength::new(low.rank(), low.values().clone()));
+        }
+        format!("Lo: {}", Self::low_ranks_text(low))
+    }
+    
+    // ローのランクを高い順にカンマで区切る（例: "8,5,4,3,A"）
+    fn low_ranks_text(low: &LowHand) -> String {
+        let ranks: Vec<String> = low.values().iter()
+            .map(|&value| match value {
+                1 | 14 => "A".to_string(),
+                10 => "T".to_string(),
+                11 => "J".to_string(),
+                12 => "Q".to_string(),
+                13 => "K".to_string(),
+                n => n.to_string(),
+            })
+            .collect();
+        ranks.join(",")
+    }
+    
+    // 役の値はAを1と14のどちらでも持つことがある
+    fn rank_name(value: u8) -> &'static str {
+        match value {
+            1 | 14 => "Ace",
+            2 => "Deuce",
+            3 => "Three",
+            4 => "Four",
+            5 => "Five",
+            6 => "Six",
+            7 => "Seven",
+            8 => "Eight",
+            9 => "Nine",
+            10 => "Ten",
+            11 => "Jack",
+            12 => "Queen",
+            13 => "King",
+            _ => "?",
+        }
+    }
+
+    fn rank_plural(value: u8) -> String {
+        match value {
+            6 => "Sixes".to_string(),
+            _ => format!("{}s", Self::rank_name(value)),
+        }
+    }
+
+    /// カードを "As" や "Td" のように書く
+    pub fn card_text(card: &Card) -> String {
+        let rank = match card.rank() {
+            JOKER_RANK => 'X', // ジョーカー（本家にはないので独自の表記）
+            1 => 'A',
+            10 => 'T',
+            11 => 'J',
+            12 => 'Q',
+            13 => 'K',
+            n => (b'0' + n) as char,
+        };
+        let suit = match card.suit() {
+            Suit::Spade => 's',
+            Suit::Heart => 'h',
+            Suit::Diamond => 'd',
+            Suit::Club => 'c',
+        };
+        format!("{}{}", rank, suit)
+    }
+
+    fn cards_text(cards: &[Card]) -> String {
+        cards.iter().map(Self::card_text).collect::<Vec<_>>().join(" ")
+    }
+
+    /// "As" や "Td" のような表記をカードにする
+    pub fn parse_card(text: &str) -> Result<Card, DomainError> {
+        let invalid = || DomainError::InvalidCard(format!("カードの表記が不正です: {}", text));
+        let mut chars = text.chars();
+        let (Some(rank), Some(suit), None) = (chars.next(), chars.next(), chars.next()) else {
+            return Err(invalid());
+        };
+        let rank = match rank.to_ascii_uppercase() {
+            'A' => 1,
+            'T' => 10,
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'X' => JOKER_RANK,
+            c @ '2'..='9' => c as u8 - b'0',
+            _ => return Err(invalid()),
+        };
+        let suit = match suit.to_ascii_lowercase() {
+            's' => Suit::Spade,
+            'h' => Suit::Heart,
+            'd' => Suit::Diamond,
+            'c' => Suit::Club,
+            _ => return Err(invalid()),
+        };
+        if rank == JOKER_RANK {
+            return Ok(Card::joker(suit));
+        }
+        Card::new(suit, rank).map_err(|_| invalid())
+    }
+}
+
+// 1ハンド分の行を読み進める状態
+struct HandParser {
+    history: HandHistory,
+    in_cents: bool,
+    round: Option<BettingRound>,
+    contributed: HashMap<PlayerId, u32>,
+    // (ポットの番号, プレイヤー, 獲得額)
+    collected: Vec<(usize, PlayerId, u32)>,
+}
+
+impl HandParser {
+    fn parse(lines: &[&str], position: u32) -> Result<HandHistory, DomainError> {
+        let header = lines.first()
+            .ok_or_else(|| DomainError::InvalidState("ハンドの先頭行がありません".to_string()))?;
+        let mut parser = Self::from_header(header, position)?;
+
+        for (offset, line) in lines.iter().enumerate().skip(1) {
+            if *line == "*** SUMMARY ***" {
+                break;
+            }
+            parser.parse_line(line).map_err(|e| {
+                DomainError::InvalidState(format!("{}行目を読み込めません: {}（{}）", offset + 1, line, e))
+            })?;
+        }
+
+        parser.finish()
+    }
+
+    fn from_header(header: &str, position: u32) -> Result<Self, DomainError> {
+        let invalid = |message: &str| DomainError::InvalidState(format!("{}: {}", message, header));
+        let rest = header.strip_prefix(HAND_HEADER).ok_or_else(|| invalid("ハンドの先頭行ではありません"))?;
+        let (hand_id, rest) = rest.split_once(':').ok_or_else(|| invalid("ハンド番号がありません"))?;
+        // 本家のハンド番号は u32 に収まらないことが多いので、その場合はファイル内での順番を使う
+        let hand_number = hand_id.trim().parse::<u32>().unwrap_or(position);
+
+        let variant = if rest.contains("Hold'em Short Deck") {
+            GameVariant::ShortDeckHoldem
+        } else if rest.contains("Hold'em") {
+            GameVariant::TexasHoldem
+        } else if rest.contains("Omaha Hi/Lo") {
+            GameVariant::OmahaHiLo
+        } else if rest.contains("Omaha") {
+            GameVariant::Omaha
+        } else if rest.contains("Triple Draw 2-7") {
+            GameVariant::DeuceToSevenTripleDraw
+        } else if rest.contains("5 Card Draw") {
+            GameVariant::FiveCardDraw
+        } else if rest.contains("7 Card Stud") {
+            GameVariant::SevenCardStud
+        } else if rest.contains("Razz") {
+            GameVariant::Razz
+        } else {
+            return Err(invalid("対応していないゲームです"));
+        };
+
+        // 最初の "(小/大)" がブラインド
+        let blinds = rest.split('(')
+            .skip(1)
+            .filter_map(|part| part.split_once(')').map(|(inside, _)| inside))
+            .find(|inside| inside.contains('/'))
+            .ok_or_else(|| invalid("ブラインドがありません"))?;
+        let in_cents = blinds.contains(['$', '€', '£', '.']);
+        let (small, big) = blinds.split_once('/').ok_or_else(|| invalid("ブラインドがありません"))?;
+        let small_blind = parse_amount(small, in_cents)?;
+        let big_blind = parse_amount(big, in_cents)?;
+
+        let started_at = rest.rsplit(" - ")
+            .find_map(|part| {
+                let mut tokens = part.split_whitespace();
+                let date = format!("{} {}", tokens.next()?, tokens.next()?);
+                NaiveDateTime::parse_from_str(&date, "%Y/%m/%d %H:%M:%S").ok()
+            })
+            .map(|naive| Utc.from_utc_datetime(&naive))
+            .ok_or_else(|| invalid("日時がありません"))?;
+
+        let mut history = HandHistory::new(
+            GameId::from_string(String::new()),
+            hand_number,
+            variant,
+            small_blind,
+            big_blind,
+            0,
+            Vec::new(),
+        );
+        history.started_at = started_at;
+        // "No Limit" と "Pot Limit" 以外の "Limit" はフィックスドリミット
+        history.betting_structure = if rest.contains("No Limit") {
+            BettingStructure::NoLimit
+        } else if rest.contains("Pot Limit") {
+            BettingStructure::PotLimit
+        } else if rest.contains("Limit") {
+            BettingStructure::fixed_limit(big_blind)
+        } else {
+            BettingStructure::NoLimit
+        };
+
+        Ok(Self {
+            history,
+            in_cents,
+            round: None,
+            contributed: HashMap::new(),
+            collected: Vec::new(),
+        })
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), DomainError> {
+        if let Some(rest) = line.strip_prefix("Table '") {
+            let (table, rest) = rest.rsplit_once('\'').ok_or_else(|| invalid_line("テーブル名"))?;
+            self.history.game_id = GameId::from_string(table.to_string());
+            if let Some((_, button)) = rest.split_once("Seat #") {
+                let number: usize = button.split_whitespace().next().unwrap_or("").parse()
+                    .map_err(|_| invalid_line("ボタンの席"))?;
+                self.history.dealer_seat = number.saturating_sub(1);
+            }
+            return Ok(());
+        }
+
+        if let Some(rest) = line.strip_prefix("*** ") {
+            return self.parse_section(rest);
+        }
+
+        if let Some(rest) = line.strip_prefix("Seat ") {
+            // 座席の行はベッティングが始まる前だけ（サマリーは読まない）
+            if self.round.is_none() {
+                self.parse_seat(rest)?;
+            }
+            return Ok(());
+        }
+
+        if let Some(rest) = line.strip_prefix("Dealt to ") {
+            return self.parse_dealt(rest);
+        }
+
+        if let Some((player_id, rest)) = self.split_player(line, ": ") {
+            return self.parse_player_line(player_id, rest);
+        }
+
+        if let Some((player_id, rest)) = self.split_player(line, " collected ") {
+            let (amount, pot) = rest.split_once(" from ").ok_or_else(|| invalid_line("獲得額"))?;
+            let amount = parse_amount(amount, self.in_cents)?;
+            let pot_index = match pot.trim() {
+                "pot" | "main pot" => 0,
+                "side pot" => 1,
+                other => other.strip_prefix("side pot-")
+                    .and_then(|n| n.parse().ok())
+                    .ok_or_else(|| invalid_line("ポット名"))?,
+            };
+            self.collected.push((pot_index, player_id, amount));
+            return Ok(());
+        }
+
+        if let Some(rest) = line.strip_prefix("Uncalled bet (") {
+            return self.parse_uncalled_bet(rest);
+        }
+
+        // それ以外（チャット、離席など）は読み飛ばす
+        Ok(())
+    }
+
+    // "Uncalled bet (額) returned to 名前" の分はプレイヤーの出したチップから除く
+    fn parse_uncalled_bet(&mut self, rest: &str) -> Result<(), DomainError> {
+        let (amount, name) = rest.split_once(") returned to ").ok_or_else(|| invalid_line("返されたベット"))?;
+        let amount = parse_amount(amount, self.in_cents)?;
+        let player_id = self.history.seats.iter()
+            .find(|seat| seat.name == name)
+            .map(|seat| seat.player_id.clone())
+            .ok_or_else(|| invalid_line("プレイヤー"))?;
+        let round = self.round.ok_or_else(|| invalid_line("ラウンド"))?;
+
+        if let Some(contributed) = self.contributed.get_mut(&player_id) {
+            *contributed = contributed.saturating_sub(amount);
+        }
+        self.history.returned.push(ReturnRecord { round, player_id, amount });
+        Ok(())
+    }
+
+    fn parse_section(&mut self, rest: &str) -> Result<(), DomainError> {
+        let (name, cards) = rest.split_once(" ***").ok_or_else(|| invalid_line("区切り"))?;
+        let stud = self.history.variant.is_stud();
+        let round = match name {
+            "HOLE CARDS" => BettingRound::PreFlop,
+            "DEALING HANDS" => BettingRound::PreDraw,
+            "FIRST DRAW" => BettingRound::PostDraw,
+            "SECOND DRAW" => BettingRound::PostSecondDraw,
+            "THIRD DRAW" => BettingRound::PostThirdDraw,
+            "FLOP" => BettingRound::Flop,
+            "TURN" => BettingRound::Turn,
+            "RIVER" if stud => BettingRound::SeventhStreet,
+            "RIVER" => BettingRound::River,
+            "3rd STREET" => BettingRound::ThirdStreet,
+            "4th STREET" => BettingRound::FourthStreet,
+            "5th STREET" => BettingRound::FifthStreet,
+            "6th STREET" => BettingRound::SixthStreet,
+            _ => return Ok(()), // SHOW DOWN など
+        };
+
+        // 新しいカードは最後の [] に書かれている（スタッドはカードが足りずに共通のカードを開いた場合だけ）
+        let board = bracketed(cards).pop();
+        if matches!(round, BettingRound::Flop | BettingRound::Turn | BettingRound::River) || (stud && board.is_some()) {
+            let cards = board.ok_or_else(|| invalid_line("ボード"))?;
+            self.history.board.push(BoardRecord { round, cards: parse_cards(cards)? });
+        }
+        // ブラインドとブリングインは最初のラウンドのベットに含める
+        if !matches!(round, BettingRound::PreFlop | BettingRound::PreDraw | BettingRound::ThirdStreet) {
+            self.contributed.clear();
+        }
+        self.round = Some(round);
+        Ok(())
+    }
+
+    fn parse_seat(&mut self, rest: &str) -> Result<(), DomainError> {
+        let (number, rest) = rest.split_once(": ").ok_or_else(|| invalid_line("座席"))?;
+        let number: usize = number.trim().parse().map_err(|_| invalid_line("座席番号"))?;
+        let (name, stack) = rest.rsplit_once(" (").ok_or_else(|| invalid_line("スタック"))?;
+        let stack = stack.split(" in chips").next().ok_or_else(|| invalid_line("スタック"))?;
+
+        self.history.seats.push(SeatRecord {
+            seat: number.saturating_sub(1),
+            player_id: PlayerId::from_string(name.to_string()),
+            name: name.to_string(),
+            starting_stack: parse_amount(stack, self.in_cents)?,
+            hole_cards: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn parse_dealt(&mut self, rest: &str) -> Result<(), DomainError> {
+        let (player_id, cards) = self.split_player(rest, " [")
+            .map(|(player_id, _)| (player_id, bracketed(rest)))
+            .ok_or_else(|| invalid_line("プレイヤー"))?;
+
+        // ドロー後は [残したカード] [引いたカード] の形になる
+        if self.round.is_some_and(|round| round.starts_with_draw()) {
+            let drawn = parse_cards(cards.last().copied().unwrap_or(""))?;
+            let draw = self.draw_mut(&player_id);
+            draw.drawn = if cards.len() > 1 { drawn } else { Vec::new() };
+            return Ok(());
+        }
+
+        // スタッドの4thストリート以降は [それまでの手札] [配られたカード] の形になる
+        let stud_street = self.history.variant.is_stud() && self.round != Some(BettingRound::ThirdStreet);
+        let Some(seat) = self.history.seats.iter_mut().find(|seat| seat.player_id == player_id) else {
+            return Ok(());
+        };
+        if stud_street {
+            seat.hole_cards.extend(parse_cards(cards.last().copied().unwrap_or(""))?);
+        } else {
+            seat.hole_cards = parse_cards(cards.first().copied().unwrap_or(""))?;
+        }
+        Ok(())
+    }
+
+    fn parse_player_line(&mut self, player_id: PlayerId, rest: &str) -> Result<(), DomainError> {
+        if let Some(amount) = rest.strip_prefix("posts small blind ") {
+            return self.post_blind(player_id, BlindKind::Small, amount);
+        }
+        if let Some(amount) = rest.strip_prefix("posts big blind ") {
+            return self.post_blind(player_id, BlindKind::Big, amount);
+        }
+        if let Some(amount) = rest.strip_prefix("posts the ante ") {
+            return self.post_blind(player_id, BlindKind::Ante, amount);
+        }
+        if let Some(amount) = rest.strip_prefix("posts small & big blinds ") {
+            return self.post_blind(player_id, BlindKind::Big, amount);
+        }
+        if let Some(amount) = rest.strip_prefix("brings in for ") {
+            return self.post_blind(player_id, BlindKind::BringIn, amount);
+        }
+
+        if let Some(shown) = rest.strip_prefix("shows ") {
+            let cards = bracketed(shown).first().map(|cards| parse_cards(cards)).transpose()?
+                .ok_or_else(|| invalid_line("公開したカード"))?;
+            let community = self.history.community_cards();
+            let strength = HandEvaluationService::find_best_hand(&cards, &community, self.history.variant);
+            let low = match self.history.variant {
+                GameVariant::Razz => Some(HandEvaluationService::find_best_low_hand(&cards, &community)),
+                GameVariant::DeuceToSevenTripleDraw => Some(HandEvaluationService::evaluate_deuce_to_seven(&cards)),
+                GameVariant::OmahaHiLo => HandEvaluationService::find_best_omaha_low(&cards, &community),
+                _ => None,
+            };
+            self.history.showdown.push(ShowdownRecord { player_id, cards, strength, low });
+            return Ok(());
+        }
+
+        if rest == "stands pat" {
+            self.draw_mut(&player_id);
+            return Ok(());
+        }
+        if let Some(discards) = rest.strip_prefix("discards ") {
+            let discarded = match bracketed(discards).first() {
+                Some(cards) => parse_cards(cards)?,
+                None => Vec::new(), // 他人の捨て札は枚数しか分からない
+            };
+            self.draw_mut(&player_id).discarded = discarded;
+            return Ok(());
+        }
+
+        let Some(round) = self.round else {
+            return Ok(());
+        };
+        let all_in = rest.ends_with(" and is all-in");
+        let rest = rest.trim_end_matches(" and is all-in");
+        let before = self.contributed.get(&player_id).copied().unwrap_or(0);
+
+        let (action, amount) = if rest == "folds" {
+            (BetAction::Fold, 0)
+        } else if rest == "checks" {
+            (BetAction::Check, 0)
+        } else if let Some(amount) = rest.strip_prefix("calls ") {
+            (BetAction::Call, parse_amount(amount, self.in_cents)?)
+        } else if let Some(amount) = rest.strip_prefix("bets ") {
+            (BetAction::Raise, parse_amount(amount, self.in_cents)?)
+        } else if let Some(raise) = rest.strip_prefix("raises ") {
+            let (_, to) = raise.split_once(" to ").ok_or_else(|| invalid_line("レイズ額"))?;
+            let to = parse_amount(to, self.in_cents)?;
+            (BetAction::Raise, to.saturating_sub(before))
+        } else {
+            return Ok(()); // タイムアウトや離席など
+        };
+        let action = if all_in { BetAction::AllIn } else { action };
+
+        let bet_to = before + amount;
+        self.contributed.insert(player_id.clone(), bet_to);
+        self.history.actions.push(ActionRecord { round, player_id, action, amount, bet_to });
+        Ok(())
+    }
+
+    fn post_blind(&mut self, player_id: PlayerId, kind: BlindKind, amount: &str) -> Result<(), DomainError> {
+        let amount = parse_amount(amount.trim_end_matches(" and is all-in"), self.in_cents)?;
+        if kind != BlindKind::Ante {
+            *self.contributed.entry(player_id.clone()).or_insert(0) += amount;
+        }
+        self.history.blinds.push(BlindRecord { player_id, kind, amount });
+        Ok(())
+    }
+
+    // 今のドローでのプレイヤーの交換の記録
+    fn draw_mut(&mut self, player_id: &PlayerId) -> &mut DrawRecord {
+        let round = self.round.filter(|round| round.starts_with_draw()).unwrap_or(BettingRound::PostDraw);
+        let index = match self.history.draws.iter().position(|draw| &draw.player_id == player_id && draw.round == round) {
+            Some(index) => index,
+            None => {
+                self.history.draws.push(DrawRecord {
+                    round,
+                    player_id: player_id.clone(),
+                    discarded: Vec::new(),
+                    drawn: Vec::new(),
+                });
+                self.history.draws.len() - 1
+            },
+        };
+        &mut self.history.draws[index]
+    }
+
+    // 行の先頭のプレイヤー名と区切りの後ろを分ける（名前に区切りを含む場合に備えて最長一致）
+    fn split_player<'a>(&self, line: &'a str, separator: &str) -> Option<(PlayerId, &'a str)> {
+        self.history.seats.iter()
+            .filter(|seat| line.starts_with(&format!("{}{}", seat.name, separator)))
+            .max_by_key(|seat| seat.name.len())
+            .map(|seat| (seat.player_id.clone(), &line[seat.name.len() + separator.len()..]))
+    }
+
+    fn finish(mut self) -> Result<HandHistory, DomainError> {
+        if self.history.seats.is_empty() {
+            return Err(DomainError::InvalidState("座席がありません".to_string()));
+        }
+
+        // 降りていないプレイヤーの出した額の段階ごとにポットができる
+        let contenders: Vec<&SeatRecord> = self.history.seats.iter()
+            .filter(|seat| self.history.folded_in(&seat.player_id).is_none())
+            .collect();
+        let mut levels: Vec<u32> = contenders.iter()
+            .map(|seat| self.history.total_contributed(&seat.player_id))
+            .collect();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let pot_count = self.collected.iter().map(|(index, _, _)| index + 1).max().unwrap_or(0);
+        let mut payouts = Vec::with_capacity(pot_count);
+        for index in 0..pot_count {
+            let level = levels.get(index).copied().unwrap_or(0);
+            let eligible_player_ids = contenders.iter()
+                .filter(|seat| self.history.total_contributed(&seat.player_id) >= level)
+                .map(|seat| seat.player_id.clone())
+                .collect();
+            let winners: Vec<(PlayerId, u32)> = self.collected.iter()
+                .filter(|(pot, _, _)| *pot == index)
+                .map(|(_, player_id, amount)| (player_id.clone(), *amount))
+                .collect();
+            payouts.push(PayoutRecord {
+                amount: winners.iter().map(|(_, amount)| amount).sum(),
+                eligible_player_ids,
+                winners,
+            });
+        }
+        self.history.payouts = payouts;
+
+        Ok(self.history)
+    }
+}
+
+fn invalid_line(what: &str) -> DomainError {
+    DomainError::InvalidState(format!("{}を読み取れません", what))
+}
+
+// [..] で囲まれた部分を順に取り出す
+fn bracketed(text: &str) -> Vec<&str> {
+    text.split('[')
+        .skip(1)
+        .filter_map(|part| part.split_once(']').map(|(inside, _)| inside))
+        .collect()
+}
+
+fn parse_cards(text: &str) -> Result<Vec<Card>, DomainError> {
+    text.split_whitespace().map(PokerStarsFormat::parse_card).collect()
+}
+
+// "$1.05" や "1,500" のような金額をチップにする（セント単位の場合は ×100）
+fn parse_amount(text: &str, in_cents: bool) -> Result<u32, DomainError> {
+    let invalid = || DomainError::InvalidBet(format!("金額を読み取れません: {}", text));
+    let cleaned: String = text.trim()
+        .trim_end_matches(" USD")
+        .trim_end_matches(" EUR")
+        .trim_end_matches(" GBP")
+        .chars()
+        .filter(|c| !matches!(c, '$' | '€' | '£' | ','))
+        .collect();
+    let (whole, fraction) = cleaned.split_once('.').unwrap_or((&cleaned, ""));
+    let whole: u32 = whole.parse().map_err(|_| invalid())?;
+    if !in_cents {
+        return if fraction.is_empty() { Ok(whole) } else { Err(invalid()) };
+    }
+
+    let cents: u32 = match fraction.len() {
+        0 => 0,
+        1 => fraction.parse::<u32>().map_err(|_| invalid())? * 10,
+        2 => fraction.parse().map_err(|_| invalid())?,
+        _ => return Err(invalid()),
+    };
+    whole.checked_mul(100).and_then(|chips| chips.checked_add(cents)).ok_or_else(invalid)
+}
@@ -0,0 +1,531 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::domain::model::bet::{BetAction, BettingStructure};
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::{BettingRound, Game, GamePhase, GameSerializedData, GameVariant, TableRules};
+    use crate::domain::model::hand_history::{BlindKind, HandHistory};
+    use crate::domain::model::player::{Player, PlayerId, PlayerSerializedData};
+    use crate::domain::service::game_rule::GameRuleService;
+    use crate::domain::service::hand_evaluation::{HandEvaluationService, HandRank, HandRankingRules};
+    use crate::infrastructure::service::hand_history::pokerstars_format::PokerStarsFormat;
+
+    // 実際のクライアントが書き出す形式のハンド（ボブのオールインでサイドポットができる）
+    const SIDE_POT_HAND: &str = "\u{feff}PokerStars Hand #208474536720:  Hold'em No Limit ($0.05/$0.10 USD) - 2020/01/15 19:24:55 CET [2020/01/15 13:24:55 ET]
+Table 'Aase III' 6-max Seat #1 is the button
+Seat 1: alice ($10 in chips)
+Seat 2: bob ($4.50 in chips)
+Seat 3: carol ($10.25 in chips)
+bob: posts small blind $0.05
+carol: posts big blind $0.10
+*** HOLE CARDS ***
+Dealt to alice [Kh Jh]
+alice: raises $0.20 to $0.30
+bob: calls $0.25
+carol: calls $0.20
+*** FLOP *** [Ah 7c 2d]
+bob: bets $4.20 and is all-in
+carol: calls $4.20
+alice: calls $4.20
+*** TURN *** [Ah 7c 2d] [Ks]
+carol: bets $2
+alice: calls $2
+*** RIVER *** [Ah 7c 2d Ks] [3h]
+carol: checks
+alice: checks
+*** SHOW DOWN ***
+carol: shows [Qd Kc] (a pair of Kings)
+alice: shows [Kh Jh] (a pair of Kings - lower kicker)
+carol collected $4 from side pot
+bob: shows [7h 7d] (three of a kind, Sevens)
+bob collected $13.50 from main pot
+*** SUMMARY ***
+Total pot $17.50 Main pot $13.50. Side pot $4. | Rake $0
+Board [Ah 7c 2d Ks 3h]
+Seat 1: alice (button) showed [Kh Jh] and lost with a pair of Kings
+Seat 2: bob (small blind) showed [7h 7d] and won ($13.50) with three of a kind, Sevens
+Seat 3: carol (big blind) showed [Qd Kc] and won ($4) with a pair of Kings
+";
+
+    // ショーダウンなしで終わり、コールされなかったレイズが返される PokerStars のハンド
+    const UNCALLED_BET_HAND: &str = "PokerStars Hand #208474601151:  Hold'em No Limit ($0.01/$0.02 USD) - 2020/01/15 19:31:08 CET [2020/01/15 13:31:08 ET]
+Table 'Aase III' 6-max Seat #1 is the button
+Seat 1: alice ($2 in chips)
+Seat 2: bob ($1.87 in chips)
+Seat 3: carol ($2.15 in chips)
+bob: posts small blind $0.01
+carol: posts big blind $0.02
+*** HOLE CARDS ***
+Dealt to alice [Ah Kd]
+alice: raises $0.04 to $0.06
+bob: folds
+carol: folds
+Uncalled bet ($0.04) returned to alice
+alice collected $0.05 from pot
+alice: doesn't show hand
+*** SUMMARY ***
+Total pot $0.05 | Rake $0
+Seat 1: alice (button) collected ($0.05)
+Seat 2: bob (small blind) folded before Flop
+Seat 3: carol (big blind) folded before Flop
+";
+
+    fn card(suit: Suit, rank: u8) -> Card {
+        Card::new(suit, rank).unwrap()
+    }
+
+    fn player_id(name: &str) -> PlayerId {
+        PlayerId::from_string(name.to_string())
+    }
+
+    // 3人のホールデムをショーダウンまで進めてポットを分配する（ante が0でなければアンティも投入する）
+    fn play_holdem_hand(ante: u32) -> Game {
+        let mut game = Game::new(GameVariant::TexasHoldem, 5, 10).unwrap();
+        game.set_blinds(5, 10, ante).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー3".to_string(), 1000)).unwrap();
+        game.preset_deck(vec![
+            card(Suit::Spade, 1), card(Suit::Heart, 1),
+            card(Suit::Spade, 2), card(Suit::Heart, 7),
+            card(Suit::Club, 3), card(Suit::Diamond, 8),
+            card(Suit::Club, 9), card(Suit::Diamond, 10), card(Suit::Spade, 12),
+            card(Suit::Heart, 4),
+            card(Suit::Club, 13),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        if ante > 0 {
+            game.post_antes().unwrap();
+        }
+        game.post_blinds().unwrap();
+
+        GameRuleService::process_action(&mut game, 0, BetAction::Call, None).unwrap();
+        GameRuleService::process_action(&mut game, 1, BetAction::Fold, None).unwrap();
+        while game.current_phase() != GamePhase::Showdown {
+            game.end_betting_round().unwrap();
+        }
+        GameRuleService::distribute_pot(&mut game).unwrap();
+        game
+    }
+
+    // 読み込んだハンドをショーダウン直前の状態のゲームにする
+    fn game_at_showdown(history: &HandHistory) -> Game {
+        let players = history.seats.iter()
+            .map(|seat| {
+                let total_bet = history.total_contributed(&seat.player_id);
+                let cards = history.showdown.iter()
+                    .find(|record| record.player_id == seat.player_id)
+                    .map(|record| record.cards.clone())
+                    .unwrap_or_default();
+                Player::from_serialized(PlayerSerializedData {
+                    id: seat.player_id.clone(),
+                    name: seat.name.clone(),
+                    chips_amount: seat.starting_stack - total_bet,
+                    cards,
+                    current_bet: 0,
+                    total_bet,
+                    is_folded: history.folded_in(&seat.player_id).is_some(),
+                    is_all_in: total_bet == seat.starting_stack,
+                    is_dealer: seat.seat == history.dealer_seat,
+                    bot: None,
+                    sitting_out: false,
+                    missed_blind: false,
+                    seat: seat.seat,
+                }).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let main_pot = history.seats.iter().map(|seat| history.total_contributed(&seat.player_id)).sum();
+        let dealer_index = history.seats.iter().position(|seat| seat.seat == history.dealer_seat).unwrap_or(0);
+
+        Game::from_serialized(GameSerializedData {
+            id: history.game_id.clone(),
+            variant: history.variant,
+            players,
+            community_cards: history.community_cards(),
+            deck: None,
+            main_pot,
+            side_pots: Vec::new(),
+            current_phase: GamePhase::Showdown,
+            current_round: None,
+            current_player_index: 0,
+            dealer_index,
+            rules: TableRules::default(),
+            positions: None,
+            small_blind: history.small_blind,
+            big_blind: history.big_blind,
+            ante: 0,
+            betting_structure: history.betting_structure,
+            current_bet: 0,
+            acted_players: Vec::new(),
+            seed: None,
+            hand_number: history.hand_number,
+            hand_history: None,
+        }).unwrap()
+    }
+
+    #[test]
+    fn 記録したハンドを書き出して読み込むと同じ内容になる() {
+        let game = play_holdem_hand(0);
+        let original = game.hand_history().unwrap();
+        let text = PokerStarsFormat::export_game(&game).expect("書き出しに失敗");
+
+        assert!(text.starts_with("PokerStars Hand #0: Hold'em No Limit (5/10) - "), "先頭行が一致しません: {}", text);
+        assert!(text.contains("Dealt to プレイヤー1 [As Ah]"), "手札の行がありません: {}", text);
+        assert!(text.contains("プレイヤー1: calls 10"), "コールの行がありません: {}", text);
+        assert!(text.contains("*** RIVER *** [9c Td Qs 4h] [Kc]"), "リバーの行がありません: {}", text);
+        assert!(text.contains("プレイヤー1: shows [As Ah] (a pair of Aces)"), "ショーダウンの行がありません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        let name_of = |id: &PlayerId| original.seat_of(id).unwrap().name.clone();
+
+        assert_eq!(imported.game_id, original.game_id, "ゲームIDが一致しません");
+        assert_eq!(imported.variant, GameVariant::TexasHoldem, "ゲームの種類が一致しません");
+        assert_eq!(imported.betting_structure, BettingStructure::NoLimit, "ベットの決まりが一致しません");
+        assert_eq!((imported.small_blind, imported.big_blind), (5, 10), "ブラインドが一致しません");
+        assert_eq!(imported.dealer_seat, original.dealer_seat, "ボタンの位置が一致しません");
+        assert_eq!(imported.started_at.timestamp(), original.started_at.timestamp(), "日時が一致しません");
+        for (imported_seat, seat) in imported.seats.iter().zip(&original.seats) {
+            assert_eq!(imported_seat.name, seat.name, "座席の名前が一致しません");
+            assert_eq!(imported_seat.starting_stack, seat.starting_stack, "スタックが一致しません");
+            assert_eq!(imported_seat.hole_cards, seat.hole_cards, "手札が一致しません");
+        }
+        let blinds: Vec<_> = original.blinds.iter().map(|b| (name_of(&b.player_id), b.kind, b.amount)).collect();
+        let imported_blinds: Vec<_> = imported.blinds.iter().map(|b| (b.player_id.value().to_string(), b.kind, b.amount)).collect();
+        assert_eq!(imported_blinds, blinds, "ブラインドの記録が一致しません");
+        let actions: Vec<_> = original.actions.iter().map(|a| (a.round, name_of(&a.player_id), a.action, a.amount)).collect();
+        let imported_actions: Vec<_> = imported.actions.iter()
+            .map(|a| (a.round, a.player_id.value().to_string(), a.action, a.amount))
+            .collect();
+        assert_eq!(imported_actions, actions, "アクションが一致しません");
+        assert_eq!(imported.board, original.board, "ボードが一致しません");
+
+        assert_eq!(imported.showdown.len(), original.showdown.len(), "ショーダウンの数が一致しません");
+        for (imported_record, record) in imported.showdown.iter().zip(&original.showdown) {
+            assert_eq!(imported_record.player_id.value(), name_of(&record.player_id), "公開したプレイヤーが一致しません");
+            assert_eq!(imported_record.cards, record.cards, "公開したカードが一致しません");
+            assert_eq!(imported_record.strength, record.strength, "役が一致しません");
+        }
+        assert_eq!(imported.total_pot(), original.total_pot(), "ポット額が一致しません");
+        let winners: Vec<String> = original.winner_ids().iter().map(name_of).collect();
+        let imported_winners: Vec<String> = imported.winner_ids().iter().map(|id| id.value().to_string()).collect();
+        assert_eq!(imported_winners, winners, "勝者が一致しません");
+    }
+
+    #[test]
+    fn アンティを書き出して読み込む() {
+        let game = play_holdem_hand(2);
+        let text = PokerStarsFormat::export_game(&game).expect("書き出しに失敗");
+        assert!(text.contains("プレイヤー3: posts the ante 2"), "アンティの行がありません: {}", text);
+        // アンティはラウンドのベット額に含めない
+        assert!(text.contains("プレイヤー1: calls 10"), "コール額が一致しません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        let antes: Vec<_> = imported.blinds.iter().filter(|blind| blind.kind == BlindKind::Ante).collect();
+        assert_eq!(antes.len(), 3, "アンティの数が一致しません");
+        assert_eq!(imported.total_contributed(&player_id("プレイヤー1")), 12, "拠出額が一致しません");
+        assert_eq!(imported.total_pot(), 31, "ポット額が一致しません");
+    }
+
+    #[test]
+    fn 実際のハンドを読み込む() {
+        let history = PokerStarsFormat::import(SIDE_POT_HAND).expect("読み込みに失敗");
+
+        // 本家のハンド番号は u32 に収まらないのでファイル内の順番になる
+        assert_eq!(history.hand_number, 1, "ハンド番号が一致しません");
+        assert_eq!(history.game_id.value(), "Aase III", "テーブル名が一致しません");
+        assert_eq!(history.started_at.format("%Y/%m/%d %H:%M:%S").to_string(), "2020/01/15 19:24:55", "日時が一致しません");
+        assert_eq!((history.small_blind, history.big_blind), (5, 10), "ブラインドがセント単位になっていません");
+        assert_eq!(history.dealer_seat, 0, "ボタンの位置が一致しません");
+        let stacks: Vec<u32> = history.seats.iter().map(|seat| seat.starting_stack).collect();
+        assert_eq!(stacks, vec![1000, 450, 1025], "スタックが一致しません");
+        assert_eq!(history.seats[0].hole_cards, vec![card(Suit::Heart, 13), card(Suit::Heart, 11)], "手札が一致しません");
+        assert_eq!(history.blinds[1].kind, BlindKind::Big, "ブラインドの種類が一致しません");
+
+        let preflop = history.actions_in(BettingRound::PreFlop);
+        assert_eq!(preflop[0].action, BetAction::Raise, "レイズが読み込めていません");
+        assert_eq!((preflop[0].amount, preflop[0].bet_to), (30, 30), "レイズ額が一致しません");
+        assert_eq!((preflop[1].amount, preflop[1].bet_to), (25, 30), "スモールブラインドのコール額が一致しません");
+        let flop = history.actions_in(BettingRound::Flop);
+        assert_eq!(flop[0].action, BetAction::AllIn, "オールインが読み込めていません");
+        assert_eq!(history.total_contributed(&player_id("bob")), 450, "ボブの拠出額が一致しません");
+        assert_eq!(history.total_contributed(&player_id("carol")), 650, "キャロルの拠出額が一致しません");
+
+        assert_eq!(history.community_cards().len(), 5, "ボードが一致しません");
+        assert_eq!(history.payouts.len(), 2, "ポットの数が一致しません");
+        assert_eq!(history.payouts[0].winners, vec![(player_id("bob"), 1350)], "メインポットが一致しません");
+        assert_eq!(history.payouts[1].winners, vec![(player_id("carol"), 400)], "サイドポットが一致しません");
+        assert!(!history.payouts[1].eligible_player_ids.contains(&player_id("bob")), "オールインしたプレイヤーがサイドポットの対象になっています");
+    }
+
+    #[test]
+    fn 返されたベットを拠出額から除く() {
+        let history = PokerStarsFormat::import(UNCALLED_BET_HAND).expect("読み込みに失敗");
+
+        assert_eq!(history.returned.len(), 1, "返されたベットが読み込めていません");
+        assert_eq!(history.returned[0].round, BettingRound::PreFlop, "返されたラウンドが一致しません");
+        assert_eq!(history.total_contributed(&player_id("alice")), 2, "アリスの拠出額から返された分が除かれていません");
+        let contributed: u32 = history.seats.iter().map(|seat| history.total_contributed(&seat.player_id)).sum();
+        assert_eq!(contributed, history.total_pot(), "拠出額の合計がポットと一致しません");
+        assert_eq!(history.payouts.len(), 1, "ポットの数が一致しません");
+        assert_eq!(history.payouts[0].amount, 5, "ポットの額が一致しません");
+        assert_eq!(history.payouts[0].eligible_player_ids, vec![player_id("alice")], "ポットの対象が一致しません");
+
+        // 書き出して読み込み直しても返されたベットが残る
+        let reimported = PokerStarsFormat::import(&PokerStarsFormat::export(&history)).expect("読み込み直しに失敗");
+        assert_eq!(reimported.returned, history.returned, "書き出した後に返されたベットが一致しません");
+    }
+
+    #[test]
+    fn 読み込んだハンドで役の評価を検証できる() {
+        let history = PokerStarsFormat::import(SIDE_POT_HAND).unwrap();
+        let shown = |name: &str| history.showdown.iter().find(|record| record.player_id == player_id(name)).unwrap();
+
+        assert_eq!(shown("bob").strength.rank(), HandRank::ThreeOfAKind, "ボブの役が一致しません");
+        assert_eq!(shown("carol").strength.rank(), HandRank::OnePair, "キャロルの役が一致しません");
+        assert_eq!(shown("alice").strength.rank(), HandRank::OnePair, "アリスの役が一致しません");
+        // 同じキングのペアでもキッカーでキャロルが勝つ
+        let board = history.community_cards();
+        let carol = HandEvaluationService::find_best_hand(&shown("carol").cards, &board, GameVariant::TexasHoldem);
+        let alice = HandEvaluationService::find_best_hand(&shown("alice").cards, &board, GameVariant::TexasHoldem);
+        assert_eq!(HandRankingRules::STANDARD.compare(&carol, &alice), Ordering::Greater, "キッカーの比較が一致しません");
+    }
+
+    #[test]
+    fn 読み込んだハンドでポットの分配を検証できる() {
+        let history = PokerStarsFormat::import(SIDE_POT_HAND).unwrap();
+        let mut game = game_at_showdown(&history);
+
+        let distributions = GameRuleService::distribute_pot(&mut game).expect("分配に失敗");
+        let results: Vec<Vec<(String, u32)>> = distributions.iter()
+            .map(|distribution| distribution.winners.iter()
+                .map(|&(idx, won)| (game.players()[idx].name().to_string(), won))
+                .collect())
+            .collect();
+        let expected: Vec<Vec<(String, u32)>> = history.payouts.iter()
+            .map(|payout| payout.winners.iter()
+                .map(|(id, won)| (id.value().to_string(), *won))
+                .collect())
+            .collect();
+        assert_eq!(results, expected, "分配が実際のハンドと一致しません");
+    }
+
+    #[test]
+    fn ドローの交換を書き出して読み込む() {
+        let mut game = Game::new(GameVariant::FiveCardDraw, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.end_betting_round().unwrap();
+        game.exchange_cards(0, &[0, 1]).unwrap();
+        game.exchange_cards(1, &[]).unwrap();
+
+        let original = game.hand_history().unwrap().clone();
+        let text = PokerStarsFormat::export(&original);
+        assert!(text.contains("*** DEALING HANDS ***"), "配札の区切りがありません: {}", text);
+        assert!(text.contains("プレイヤー1: discards 2 cards ["), "交換の行がありません: {}", text);
+        assert!(text.contains("プレイヤー2: stands pat"), "交換しなかった行がありません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        assert_eq!(imported.variant, GameVariant::FiveCardDraw, "ゲームの種類が一致しません");
+        assert_eq!(imported.seats[0].hole_cards, original.seats[0].hole_cards, "配られた手札が一致しません");
+        assert_eq!(imported.draws.len(), 2, "交換の数が一致しません");
+        assert_eq!(imported.draws[0].discarded, original.draws[0].discarded, "捨てたカードが一致しません");
+        assert_eq!(imported.draws[0].drawn, original.draws[0].drawn, "引いたカードが一致しません");
+        assert!(imported.draws[1].discarded.is_empty(), "交換しなかったプレイヤーが交換しています");
+    }
+
+    #[test]
+    fn ラズのストリートとブリングインを書き出して読み込む() {
+        let mut game = Game::new_with_structure(GameVariant::Razz, 5, 10, BettingStructure::fixed_limit(10), None).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.preset_deck(vec![
+            card(Suit::Spade, 1), card(Suit::Diamond, 2), card(Suit::Club, 3),
+            card(Suit::Club, 8), card(Suit::Diamond, 8), card(Suit::Club, 9),
+            card(Suit::Heart, 4), card(Suit::Heart, 9),
+            card(Suit::Spade, 6), card(Suit::Club, 10),
+            card(Suit::Diamond, 9), card(Suit::Diamond, 11),
+            card(Suit::Club, 13), card(Suit::Heart, 12),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_bring_in().unwrap();
+        while game.current_phase() == GamePhase::Betting {
+            let index = game.current_player_index();
+            let action = if GameRuleService::available_actions(&game, index).contains(&BetAction::Call) {
+                BetAction::Call
+            } else {
+                BetAction::Check
+            };
+            GameRuleService::process_action(&mut game, index, action, None).unwrap();
+        }
+        GameRuleService::distribute_pot(&mut game).unwrap();
+
+        let original = game.hand_history().unwrap();
+        let text = PokerStarsFormat::export(original);
+        assert!(text.starts_with("PokerStars Hand #0: Razz Limit (5/10) - "), "先頭行が一致しません: {}", text);
+        assert!(text.contains("*** 3rd STREET ***\nDealt to プレイヤー1 [As 2d 3c]"), "3rdストリートの行がありません: {}", text);
+        assert!(text.contains("プレイヤー2: brings in for 5"), "ブリングインの行がありません: {}", text);
+        assert!(text.contains("Dealt to プレイヤー1 [As 2d 3c 4h] [6s]"), "5thストリートの行がありません: {}", text);
+        assert!(text.contains("プレイヤー1: shows [As 2d 3c 4h 6s 9d Kc] (Lo: 6,4,3,2,A)"), "ローの説明がありません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        assert_eq!(imported.variant, GameVariant::Razz, "ゲームの種類が一致しません");
+        for (imported_seat, seat) in imported.seats.iter().zip(&original.seats) {
+            assert_eq!(imported_seat.hole_cards, seat.hole_cards, "手札が一致しません");
+        }
+        assert_eq!(imported.blinds[0].kind, BlindKind::BringIn, "ブリングインが読み込まれていません");
+        let rounds: Vec<BettingRound> = imported.actions.iter().map(|action| action.round).collect();
+        let original_rounds: Vec<BettingRound> = original.actions.iter().map(|action| action.round).collect();
+        assert_eq!(rounds, original_rounds, "アクションのストリートが一致しません");
+        assert_eq!(imported.showdown[0].low, original.showdown[0].low, "ローが一致しません");
+        assert_eq!(imported.total_pot(), original.total_pot(), "ポット額が一致しません");
+    }
+
+    // 全員がコールかチェックだけで進め、交換では plan に従ってカードを捨てる（(何回目のドロー, 席) → 捨てる位置）
+    fn play_to_showdown(game: &mut Game, plan: &[((usize, usize), &[usize])]) {
+        while game.current_phase() != GamePhase::Showdown {
+            let index = game.current_player_index();
+            if game.current_phase() == GamePhase::Drawing {
+                let draw = match game.current_round() {
+                    Some(BettingRound::PostSecondDraw) => 1,
+                    Some(BettingRound::PostThirdDraw) => 2,
+                    _ => 0,
+                };
+                let discards = plan.iter().find(|(key, _)| *key == (draw, index)).map_or(&[][..], |(_, discards)| discards);
+                game.exchange_cards(index, discards).unwrap();
+                continue;
+            }
+            let action = if GameRuleService::available_actions(game, index).contains(&BetAction::Call) {
+                BetAction::Call
+            } else {
+                BetAction::Check
+            };
+            GameRuleService::process_action(game, index, action, None).unwrap();
+        }
+        GameRuleService::distribute_pot(game).unwrap();
+    }
+
+    #[test]
+    fn トリプルドローの3回の交換を書き出して読み込む() {
+        let mut game = Game::new_with_structure(GameVariant::DeuceToSevenTripleDraw, 5, 10, BettingStructure::fixed_limit(10), None).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.preset_deck(vec![
+            card(Suit::Spade, 7), card(Suit::Diamond, 5), card(Suit::Club, 4), card(Suit::Heart, 3), card(Suit::Spade, 13),
+            card(Suit::Club, 8), card(Suit::Diamond, 8), card(Suit::Heart, 6), card(Suit::Diamond, 2), card(Suit::Club, 9),
+            card(Suit::Spade, 2), card(Suit::Heart, 12),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        // 1回目はプレイヤー1が K♠ を、2回目はプレイヤー2が 8♣ を捨てる
+        play_to_showdown(&mut game, &[((0, 0), &[4]), ((1, 1), &[0])]);
+
+        let original = game.hand_history().unwrap();
+        let text = PokerStarsFormat::export(original);
+        assert!(text.starts_with("PokerStars Hand #0: Triple Draw 2-7 Lowball Limit (5/10) - "), "先頭行が一致しません: {}", text);
+        assert!(text.contains("*** FIRST DRAW ***"), "1回目のドローの区切りがありません: {}", text);
+        assert!(text.contains("Dealt to プレイヤー1 [7s 5d 4c 3h] [2s]"), "1回目の交換の行がありません: {}", text);
+        assert!(text.contains("*** SECOND DRAW ***"), "2回目のドローの区切りがありません: {}", text);
+        assert!(text.contains("Dealt to プレイヤー2 [8d 6h 2d 9c] [Qh]"), "2回目の交換の行がありません: {}", text);
+        assert!(text.contains("*** THIRD DRAW ***\nプレイヤー2: stands pat\nプレイヤー1: stands pat"), "3回目のドローの行がありません: {}", text);
+        assert!(text.contains("(Lo: 7,5,4,3,2)"), "ローの説明がありません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        assert_eq!(imported.variant, GameVariant::DeuceToSevenTripleDraw, "ゲームの種類が一致しません");
+        let draws = |history: &HandHistory| -> Vec<_> {
+            history.draws.iter().map(|draw| (draw.round, draw.discarded.clone(), draw.drawn.clone())).collect()
+        };
+        assert_eq!(draws(&imported), draws(original), "交換が一致しません");
+        let rounds: Vec<BettingRound> = imported.actions.iter().map(|action| action.round).collect();
+        let original_rounds: Vec<BettingRound> = original.actions.iter().map(|action| action.round).collect();
+        assert_eq!(rounds, original_rounds, "アクションのラウンドが一致しません");
+        assert_eq!(imported.showdown[0].low, original.showdown[0].low, "ローが一致しません");
+    }
+
+    #[test]
+    fn ハイローのショーダウンと分けたポットを書き出して読み込む() {
+        let mut game = Game::new(GameVariant::OmahaHiLo, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.preset_deck(vec![
+            card(Suit::Heart, 13), card(Suit::Club, 13), card(Suit::Spade, 12), card(Suit::Spade, 11),
+            card(Suit::Heart, 1), card(Suit::Spade, 3), card(Suit::Club, 9), card(Suit::Club, 10),
+            card(Suit::Club, 2), card(Suit::Diamond, 4), card(Suit::Heart, 7), card(Suit::Spade, 13), card(Suit::Diamond, 12),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        play_to_showdown(&mut game, &[]);
+
+        let original = game.hand_history().unwrap();
+        let text = PokerStarsFormat::export(original);
+        assert!(text.starts_with("PokerStars Hand #0: Omaha Hi/Lo No Limit (5/10) - "), "先頭行が一致しません: {}", text);
+        assert!(text.contains("プレイヤー1: shows [Kh Kc Qs Js] (HI: three of a kind, Kings)"), "ハイだけの説明がありません: {}", text);
+        assert!(text.contains("; LO: 7,4,3,2,A)"), "ローの説明がありません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        assert_eq!(imported.variant, GameVariant::OmahaHiLo, "ゲームの種類が一致しません");
+        assert_eq!(imported.showdown[1].low, original.showdown[1].low, "ローが一致しません");
+        let amounts: Vec<u32> = imported.payouts[0].winners.iter().map(|&(_, amount)| amount).collect();
+        assert_eq!(amounts, vec![10, 10], "ハイとローの配当が一致しません");
+    }
+
+    #[test]
+    fn ショートデッキのハンドを書き出して読み込む() {
+        let mut game = Game::new(GameVariant::ShortDeckHoldem, 5, 10).unwrap();
+        game.add_player(Player::new("プレイヤー1".to_string(), 1000)).unwrap();
+        game.add_player(Player::new("プレイヤー2".to_string(), 1000)).unwrap();
+        game.preset_deck(vec![
+            card(Suit::Heart, 1), card(Suit::Club, 9),
+            card(Suit::Club, 13), card(Suit::Heart, 12),
+            card(Suit::Club, 6), card(Suit::Diamond, 7), card(Suit::Heart, 8), card(Suit::Spade, 13), card(Suit::Diamond, 12),
+        ]).unwrap();
+        game.start_game().unwrap();
+        game.deal_cards().unwrap();
+        game.post_blinds().unwrap();
+        play_to_showdown(&mut game, &[]);
+
+        let original = game.hand_history().unwrap();
+        let text = PokerStarsFormat::export(original);
+        assert!(text.starts_with("PokerStars Hand #0: Hold'em Short Deck No Limit (5/10) - "), "先頭行が一致しません: {}", text);
+        assert!(text.contains("プレイヤー1: shows [Ah 9c] (a straight, Ace to Nine)"), "A-6-7-8-9 のストレートの説明がありません: {}", text);
+
+        let imported = PokerStarsFormat::import(&text).expect("読み込みに失敗");
+        assert_eq!(imported.variant, GameVariant::ShortDeckHoldem, "ゲームの種類が一致しません");
+        assert_eq!(imported.showdown[0].strength, original.showdown[0].strength, "ストレートの強さが一致しません");
+    }
+
+    #[test]
+    fn 複数のハンドを読み込む() {
+        let text = format!("{}\n\n\n{}", SIDE_POT_HAND, SIDE_POT_HAND.replace("#208474536720", "#42"));
+        let hands = PokerStarsFormat::import_all(&text).expect("読み込みに失敗");
+
+        assert_eq!(hands.len(), 2, "ハンドの数が一致しません");
+        assert_eq!(hands[1].hand_number, 42, "ハンド番号が一致しません");
+        assert!(PokerStarsFormat::import(&text).is_err(), "複数のハンドを1つとして読み込めてしまいます");
+    }
+
+    #[test]
+    fn 不正なハンドはエラーになる() {
+        assert!(PokerStarsFormat::import("").is_err(), "空のテキストを読み込めてしまいます");
+        assert!(PokerStarsFormat::import(&SIDE_POT_HAND.replace("[Ah 7c 2d]", "[Ah 7x 2d]")).is_err(), "不正なカードを読み込めてしまいます");
+        assert!(PokerStarsFormat::import(&SIDE_POT_HAND.replace("Hold'em No Limit", "Badugi")).is_err(), "対応していないゲームを読み込めてしまいます");
+        assert!(PokerStarsFormat::parse_card("10h").is_err(), "不正なカード表記を読み込めてしまいます");
+        assert_eq!(PokerStarsFormat::parse_card("Td").unwrap(), card(Suit::Diamond, 10), "カード表記が一致しません");
+    }
+
+    #[test]
+    fn ゲーム名からベットの決まりを読み込む() {
+        let structure_of = |name: &str| {
+            PokerStarsFormat::import(&SIDE_POT_HAND.replace("Hold'em No Limit", name)).expect("読み込みに失敗").betting_structure
+        };
+
+        assert_eq!(structure_of("Hold'em Pot Limit"), BettingStructure::PotLimit, "ポットリミットを読み込めません");
+        // ブラインドは $0.05/$0.10 なので1セント単位のビッグブラインドは10
+        assert_eq!(structure_of("Hold'em Limit"), BettingStructure::fixed_limit(10), "フィックスドリミットを読み込めません");
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::model::card::Card;
+use crate::domain::model::game::GameVariant;
+use crate::domain::service::equity_calculator::{EquityCalculator, EquityParams, EquityResult};
+use crate::infrastructure::service::hand_history::pokerstars_format::PokerStarsFormat;
+
+/// 勝率計算コマンドの使い方
+pub const USAGE: &str = "\
+使い方: poker_equity [オプション] 手札 手札 ...
+
+  手札は \"AsKd\" のようにカードを続けて書く（\"?\" は分からない手札、足りない分はランダム）
+
+オプション:
+  --variant 種類   ポーカーの種類（holdem|shortdeck|omaha|omaha8|draw|27td|stud|razz、既定: holdem）
+  --straight-over-trips
+                   ショートデッキでストレートをスリーカードより強くする
+  --board カード   開いているコミュニティカード（例: \"Ah7d2c\"）
+  --dead カード    配られないことが分かっているカード
+  --samples 回数   全列挙しない場合の試行回数
+  --seed シード    試行の結果を再現する";
+
+/// コマンドラインから勝率を計算するコマンド
+pub struct EquityCommand;
+
+impl EquityCommand {
+    /// 引数（プログラム名を除く）を解析して計算し、結果の表示を返す
+    pub fn run(args: &[String]) -> Result<String, String> {
+        let params = Self::parse_args(args)?;
+        let result = EquityCalculator::calculate(&params).map_err(|e| e.to_string())?;
+        Ok(Self::format_result(&params, &result))
+    }
+
+    pub fn parse_args(args: &[String]) -> Result<EquityParams, String> {
+        let mut params = EquityParams::new(GameVariant::TexasHoldem, Vec::new());
+        let mut straight_over_trips = false;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next().ok_or_else(|| format!("{} の値がありません", name))
+            };
+            match arg.as_str() {
+                "--variant" => {
+                    params.variant = match value(arg)?.to_lowercase().as_str() {
+                        "holdem" => GameVariant::TexasHoldem,
+                        "shortdeck" => GameVariant::ShortDeckHoldem,
+                        "omaha" => GameVariant::Omaha,
+                        "omaha8" => GameVariant::OmahaHiLo,
+                        "draw" => GameVariant::FiveCardDraw,
+                        "27td" => GameVariant::DeuceToSevenTripleDraw,
+                        "stud" => GameVariant::SevenCardStud,
+                        "razz" => GameVariant::Razz,
+                        other => return Err(format!("不明なポーカーの種類です: {}", other)),
+                    };
+                },
+                "--straight-over-trips" => straight_over_trips = true,
+                "--board" => params.board = Self::parse_cards(value(arg)?)?,
+                "--dead" => params.dead_cards = Self::parse_cards(value(arg)?)?,
+                "--samples" => {
+                    let samples = value(arg)?;
+                    params.samples = samples.parse().map_err(|_| format!("試行回数が不正です: {}", samples))?;
+                },
+                "--seed" => {
+                    let seed = value(arg)?;
+                    params.seed = Some(seed.parse().map_err(|_| format!("シードが不正です: {}", seed))?);
+                },
+                option if option.starts_with("--") => return Err(format!("不明なオプションです: {}", option)),
+                hand => params.hands.push(Self::parse_cards(hand)?),
+            }
+        }
+        params.rules = params.variant.hand_ranking_rules();
+        if straight_over_trips {
+            params.rules = params.rules.with_trips_beat_straight(false);
+        }
+        Ok(params)
+    }
+
+    // "AsKd" や "As Kd" のような表記をカードの列にする（"?" は空）
+    fn parse_cards(text: &str) -> Result<Vec<Card>, String> {
+        let compact: Vec<char> = text.chars()
+            .filter(|c| !c.is_whitespace() && *c != ',' && *c != '?')
+            .collect();
+        if !compact.len().is_multiple_of(2) {
+            return Err(format!("カードの表記が不正です: {}", text));
+        }
+        compact.chunks(2)
+            .map(|pair| PokerStarsFormat::parse_card(&pair.iter().collect::<String>()).map_err(|e| e.to_string()))
+            .collect()
+    }
+
+    pub fn format_result(params: &EquityParams, result: &EquityResult) -> String {
+        let mut lines = Vec::new();
+        let method = if result.exact {
+            format!("全列挙（{}通り）", result.trials)
+        } else {
+            format!("モンテカルロ法（{}回）", result.trials)
+        };
+        lines.push(format!("{}: {}", params.variant.name(), method));
+        if !params.board.is_empty() {
+            lines.push(format!("ボード: {}", Self::cards_text(&params.board)));
+        }
+
+        for (i, (hand, equity)) in params.hands.iter().zip(&result.players).enumerate() {
+            let hand_text = if hand.is_empty() { "?".to_string() } else { Self::cards_text(hand) };
+            lines.push(format!(
+                "プレイヤー{} {}: 勝ち {:.2}% / 引き分け {:.2}% / 負け {:.2}% / エクイティ {:.2}%",
+                i + 1,
+                hand_text,
+                equity.win_percent(),
+                equity.tie_percent(),
+                equity.loss_percent(),
+                equity.equity * 100.0,
+            ));
+
+            // 強い役から順に内訳を表示（ショートデッキでは役の順番が入れ替わる）
+            let mut ranks: Vec<_> = equity.rank_counts.keys().copied().collect();
+            ranks.sort_by_key(|&rank| std::cmp::Reverse(params.rules.order(rank)));
+            let breakdown: Vec<String> = ranks.into_iter()
+                .map(|rank| format!("{} {:.2}%", rank, equity.rank_percent(rank)))
+                .collect();
+            lines.push(format!("  {}", breakdown.join(" / ")));
+        }
+        lines.join("\n")
+    }
+
+    fn cards_text(cards: &[Card]) -> String {
+        cards.iter().map(PokerStarsFormat::card_text).collect::<Vec<_>>().join(" ")
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::model::card::{Card, Suit};
+    use crate::domain::model::game::GameVariant;
+    use crate::presentation::cli::equity_command::EquityCommand;
+
+    fn args(text: &[&str]) -> Vec<String> {
+        text.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn 引数から計算の条件を作る() {
+        let params = EquityCommand::parse_args(&args(&[
+            "--variant", "omaha", "--board", "Ah 7d,2c", "--dead", "9s", "--seed", "3", "--samples", "100",
+            "AsKsQsJs", "?",
+        ])).unwrap();
+
+        assert_eq!(params.variant, GameVariant::Omaha, "ポーカーの種類が一致しません");
+        assert_eq!(params.board, vec![
+            Card::new(Suit::Heart, 1).unwrap(), Card::new(Suit::Diamond, 7).unwrap(), Card::new(Suit::Club, 2).unwrap(),
+        ], "ボードが一致しません");
+        assert_eq!(params.dead_cards, vec![Card::new(Suit::Spade, 9).unwrap()], "捨て札が一致しません");
+        assert_eq!((params.seed, params.samples), (Some(3), 100), "試行の設定が一致しません");
+        assert_eq!(params.hands.len(), 2, "手札の数が一致しません");
+        assert_eq!(params.hands[0].len(), 4, "1人目の手札が一致しません");
+        assert!(params.hands[1].is_empty(), "分からない手札が空になっていません");
+    }
+
+    #[test]
+    fn 不正な引数はエラーになる() {
+        assert!(EquityCommand::parse_args(&args(&["--variant", "badugi"])).is_err(), "不明な種類を受け付けています");
+        assert!(EquityCommand::parse_args(&args(&["--board"])).is_err(), "値のないオプションを受け付けています");
+        assert!(EquityCommand::parse_args(&args(&["AsK"])).is_err(), "途切れたカードを受け付けています");
+        assert!(EquityCommand::parse_args(&args(&["--unknown", "1"])).is_err(), "不明なオプションを受け付けています");
+    }
+
+    #[test]
+    fn 計算結果を表示する() {
+        let output = EquityCommand::run(&args(&["--board", "2c7d9s3h", "KsKc", "QhQd"])).unwrap();
+
+        assert!(output.contains("全列挙（44通り）"), "計算方法が表示されていません: {}", output);
+        assert!(output.contains("プレイヤー1 Ks Kc: 勝ち 95.45%"), "勝率が表示されていません: {}", output);
+        assert!(output.contains("ツーペア 27.27%"), "役の内訳が表示されていません: {}", output);
+    }
+}
@@ -1,8 +1,9 @@
-use crate::domain::model::bet::BetAction;
-use crate::domain::model::game::{Game, GamePhase, GameVariant};
-use crate::domain::model::player::Player;
-use crate::domain::service::game_rule::GameRuleService;
-use crate::domain::service::hand_evaluation::HandEvaluationService;
+use crate::domain::model::bet::{BetAction, BettingStructure};
+use crate::domain::model::card::{JokerRule, WildCards};
+use crate::domain::model::game::{ButtonRule, Game, GamePhase, GameVariant, ShortDeckTripsRule};
+use crate::domain::model::player::{BotKind, Player};
+use crate::domain::service::game_rule::{GameRuleService, PotDistribution};
+use crate::domain::service::hand_evaluation::{BestHand, HandEvaluationService, HandRank, LowHand};
 use crate::presentation::cli::input_handler::InputHandler;
 
 pub struct GameView;
@@ -10,13 +11,27 @@ pub struct GameView;
 impl GameView {
     pub fn display_game_info(game: &Game) {
         println!("\n====================");
-        println!("ポーカーゲーム: {}", game.variant().name());
+        println!("ポーカーゲーム: {}（{}）", game.variant().name(), game.betting_structure().name());
+        let rules = game.rules();
+        println!("ボタン: {}", rules.button_rule.name());
+        if game.variant() == GameVariant::ShortDeckHoldem {
+            println!("スリーカードとストレート: {}", rules.short_deck_trips_rule.name());
+        }
+        if !rules.wild_cards.is_empty() {
+            println!("ワイルドカード: {}", rules.wild_cards.name());
+        }
+        if let Some(table) = rules.hand_ranking_table {
+            println!("役の順位: {}", table.name());
+        }
         println!("フェーズ: {}", Self::phase_to_string(game.current_phase()));
         if let Some(round) = game.current_round() {
             println!("ラウンド: {}", round.name());
         }
         println!("ポット: {}チップ", game.pot().total());
         println!("現在のベット: {}チップ", game.current_bet());
+        if let Some(seed) = game.seed() {
+            println!("シード: {} (ハンド#{})", seed, game.hand_number() + 1);
+        }
         println!("====================\n");
     }
     
@@ -33,12 +48,27 @@ impl GameView {
                 ""
             };
             
+            let bot = player.bot()
+                .map(|kind| format!("【{}】", kind.name()))
+                .unwrap_or_default();
+            
+            // スタッドでは表向きのカードは全員に見えている
+            let face_up = game.face_up_cards(i);
+            let showing = if face_up.is_empty() {
+                String::new()
+            } else {
+                format!(" - 表: {}", face_up.iter().map(|card| card.to_string()).collect::<Vec<_>>().join(" "))
+            };
+            
             println!(
-                "{}. {} - {}チップ - 現在のベット: {}チップ {}{}",
+                "{}. 席{} {}{} - {}チップ - 現在のベット: {}チップ{} {}{}",
                 i + 1,
+                player.seat() + 1,
                 player.name(),
+                bot,
                 player.chips(),
                 player.current_bet(),
+                showing,
                 if player.is_dealer() { "【ディーラー】" } else { "" },
                 status
             );
@@ -58,20 +88,87 @@ impl GameView {
         println!("\n");
     }
     
-    pub fn display_player_hand(player: &Player) {
+    pub fn display_player_hand(game: &Game, player: &Player) {
         println!("\n--{}の手札--", player.name());
+        let variant = game.variant();
         for (i, card) in player.hand().cards().iter().enumerate() {
-            println!("{}. {}", i + 1, card);
+            if variant.is_stud() {
+                // 表向きのカードは相手からも見えている
+                println!("{}. {} （{}）", i + 1, card, if variant.is_face_up(i) { "表" } else { "伏" });
+            } else {
+                println!("{}. {}", i + 1, card);
+            }
         }
         
-        // プレイヤーの手の評価を表示（オプション）
-        if !player.hand().is_empty() {
-            let hand_strength = HandEvaluationService::evaluate_hand(player.hand().cards());
-            println!("役: {}", hand_strength.rank());
+        // プレイヤーの手の評価を表示（オプション、スタッドは5枚そろってから）
+        let cards = player.hand().cards();
+        match variant {
+            GameVariant::Razz if cards.len() + game.community_cards().len() >= 5 => {
+                Self::display_low(&HandEvaluationService::find_best_low_hand(cards, game.community_cards()));
+            },
+            GameVariant::DeuceToSevenTripleDraw if !cards.is_empty() => {
+                Self::display_low(&HandEvaluationService::evaluate_deuce_to_seven(cards));
+            },
+            GameVariant::OmahaHiLo if game.community_cards().len() >= 3 => {
+                Self::display_best_hand(&HandEvaluationService::find_best_hand_detailed(
+                    cards, game.community_cards(), variant, &game.hand_ranking_rules()
+                ));
+                match HandEvaluationService::find_best_omaha_low(cards, game.community_cards()) {
+                    Some(low) => println!("ロー: {}", Self::low_to_string(&low)),
+                    None => println!("ロー: なし"),
+                }
+            },
+            // ボードを使うゲームはショーダウンと同じ組み合わせから最適な5枚を選ぶ
+            GameVariant::Omaha if game.community_cards().len() >= 3 => {
+                Self::display_best_hand(&HandEvaluationService::find_best_hand_detailed(
+                    cards, game.community_cards(), variant, &game.hand_ranking_rules()
+                ));
+            },
+            GameVariant::TexasHoldem | GameVariant::SevenCardStud | GameVariant::ShortDeckHoldem
+                if cards.len() + game.community_cards().len() >= 5 => {
+                Self::display_best_hand(&HandEvaluationService::find_best_hand_detailed(
+                    cards, game.community_cards(), variant, &game.hand_ranking_rules()
+                ));
+            },
+            GameVariant::SevenCardStud | GameVariant::Razz => {},
+            // ドロー系の手札と、ボードが開く前のホールカード
+            _ if !cards.is_empty() => {
+                Self::display_best_hand(&HandEvaluationService::evaluate_with_wilds(cards, &game.hand_ranking_rules()));
+            },
+            _ => {},
         }
         println!();
     }
     
+    // 役と、ワイルドカードを何として使ったか
+    fn display_best_hand(best: &BestHand) {
+        println!("役: {}", best.strength.rank());
+        for usage in &best.wild_usages {
+            println!("ワイルド: {} → {}", usage.wild, usage.used_as);
+        }
+    }
+    
+    fn display_low(low: &LowHand) {
+        match low.rank() {
+            HandRank::HighCard => println!("ロー: {}", Self::low_to_string(low)),
+            rank => println!("ロー: {}（{}）", Self::low_to_string(low), rank),
+        }
+    }
+    
+    // ローの値を高い順に並べた表示（例: 8-5-4-3-A）
+    fn low_to_string(low: &LowHand) -> String {
+        low.values().iter()
+            .map(|&value| match value {
+                1 | 14 => "A".to_string(),
+                11 => "J".to_string(),
+                12 => "Q".to_string(),
+                13 => "K".to_string(),
+                n => n.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+    
     pub fn get_player_action(game: &Game, player_index: usize) -> Result<(BetAction, Option<u32>), String> {
         let available_actions = GameRuleService::available_actions(game, player_index);
         if available_actions.is_empty() {
@@ -91,10 +188,15 @@ impl GameView {
         let action = available_actions[action_index];
         let bet_amount = match action {
             BetAction::Raise => {
-                let current_bet = game.current_bet();
-                let min_raise = current_bet + game.big_blind();
-                println!("最小レイズ額: {}チップ", min_raise);
-                Some(InputHandler::get_u32("レイズ額")?)
+                let (min_raise, max_raise) = GameRuleService::raise_range(game, player_index)
+                    .ok_or_else(|| "レイズできません".to_string())?;
+                if min_raise == max_raise {
+                    println!("レイズ額: {}チップ（{}）", min_raise, game.betting_structure().name());
+                    Some(min_raise)
+                } else {
+                    println!("レイズ額: 最小 {}チップ / 最大 {}チップ（{}）", min_raise, max_raise, game.betting_structure().name());
+                    Some(InputHandler::get_u32("レイズ額")?)
+                }
             },
             _ => None,
         };
@@ -102,21 +204,32 @@ impl GameView {
         Ok((action, bet_amount))
     }
     
-    pub fn display_winners(game: &Game, winners: &[(usize, String)]) {
+    pub fn display_pot_distributions(game: &Game, distributions: &[PotDistribution]) {
         println!("\n--ゲーム結果--");
-        if winners.is_empty() {
+        if distributions.is_empty() {
             println!("勝者はいません");
             return;
         }
         
-        let pot_per_winner = game.pot().total() / winners.len() as u32;
-        
-        for (idx, name) in winners {
-            println!("勝者: {} - {}チップ獲得", name, pot_per_winner);
+        let mut shown_winners = Vec::new();
+        for (i, distribution) in distributions.iter().enumerate() {
+            let pot_name = if i == 0 { "メインポット".to_string() } else { format!("サイドポット{}", i) };
+            println!("{}: {}チップ", pot_name, distribution.amount);
             
-            // 勝利した手の表示
-            if let Some(player) = game.players().get(*idx) {
-                Self::display_player_hand(player);
+            for &(idx, amount) in &distribution.winners {
+                if let Some(player) = game.players().get(idx) {
+                    println!("  勝者: {} - {}チップ獲得", player.name(), amount);
+                }
+                if !shown_winners.contains(&idx) {
+                    shown_winners.push(idx);
+                }
+            }
+        }
+        
+        // 勝利した手の表示
+        for idx in shown_winners {
+            if let Some(player) = game.players().get(idx) {
+                Self::display_player_hand(game, player);
             }
         }
         println!();
@@ -147,8 +260,8 @@ impl GameView {
         }
     }
     
-    pub fn get_card_exchange(player: &Player) -> Result<Vec<usize>, String> {
-        Self::display_player_hand(player);
+    pub fn get_card_exchange(game: &Game, player: &Player) -> Result<Vec<usize>, String> {
+        Self::display_player_hand(game, player);
         println!("交換したいカードの番号を入力してください（スペース区切り、何も入力せずにEnterでスキップ）");
         
         let input = InputHandler::get_string("");
@@ -169,8 +282,13 @@ impl GameView {
         println!("1. ファイブカードドロー");
         println!("2. テキサスホールデム");
         println!("3. オマハ");
+        println!("4. セブンカードスタッド");
+        println!("5. ラズ");
+        println!("6. 2-7 トリプルドロー");
+        println!("7. オマハ・ハイロー");
+        println!("8. ショートデッキ・ホールデム");
         
-        let choice = match InputHandler::get_menu_choice(3) {
+        let choice = match InputHandler::get_menu_choice(8) {
             Ok(choice) => choice,
             Err(_) => {
                 println!("無効な選択です。デフォルトでファイブカードドローを選択します。");
@@ -181,10 +299,123 @@ impl GameView {
         match choice {
             2 => GameVariant::TexasHoldem,
             3 => GameVariant::Omaha,
+            4 => GameVariant::SevenCardStud,
+            5 => GameVariant::Razz,
+            6 => GameVariant::DeuceToSevenTripleDraw,
+            7 => GameVariant::OmahaHiLo,
+            8 => GameVariant::ShortDeckHoldem,
             _ => GameVariant::FiveCardDraw,
         }
     }
     
+    pub fn prompt_for_betting_structure(big_blind: u32) -> BettingStructure {
+        println!("\n--ベットの決まりを選択--");
+        println!("1. ノーリミット");
+        println!("2. ポットリミット");
+        println!("3. フィックスドリミット");
+        
+        let choice = match InputHandler::get_menu_choice(3) {
+            Ok(choice) => choice,
+            Err(_) => {
+                println!("無効な選択です。デフォルトでノーリミットを選択します。");
+                1
+            }
+        };
+        
+        match choice {
+            2 => BettingStructure::PotLimit,
+            3 => BettingStructure::fixed_limit(big_blind),
+            _ => BettingStructure::NoLimit,
+        }
+    }
+    
+    pub fn prompt_for_button_rule() -> ButtonRule {
+        println!("\n--ボタンの動かし方を選択--");
+        println!("1. デッドボタン（ビッグブラインドが必ず1席ずつ進む）");
+        println!("2. ムービングボタン（ボタンが必ず次のプレイヤーへ進む）");
+        
+        match InputHandler::get_menu_choice(2) {
+            Ok(2) => ButtonRule::MovingButton,
+            Ok(_) => ButtonRule::DeadButton,
+            Err(_) => {
+                println!("無効な選択です。デフォルトでデッドボタンを選択します。");
+                ButtonRule::DeadButton
+            }
+        }
+    }
+    
+    pub fn prompt_for_short_deck_trips_rule() -> ShortDeckTripsRule {
+        println!("\n--スリーカードとストレートの順番を選択--");
+        println!("1. スリーカードが上（ショートデッキの一般的な決まり）");
+        println!("2. ストレートが上");
+        
+        match InputHandler::get_menu_choice(2) {
+            Ok(2) => ShortDeckTripsRule::StraightBeatsTrips,
+            Ok(_) => ShortDeckTripsRule::TripsBeatStraight,
+            Err(_) => {
+                println!("無効な選択です。デフォルトでスリーカードが上を選択します。");
+                ShortDeckTripsRule::TripsBeatStraight
+            }
+        }
+    }
+    
+    pub fn prompt_for_wild_cards() -> WildCards {
+        println!("\n--ワイルドカードを選択--");
+        println!("1. なし");
+        println!("2. デュースワイルド（2がワイルド）");
+        println!("3. ジョーカー1枚（ワイルド）");
+        println!("4. ジョーカー1枚（バグ：Aかストレート・フラッシュを完成させるときだけ使える）");
+        
+        let wild_cards = match InputHandler::get_menu_choice(4) {
+            Ok(2) => WildCards::new(0, JokerRule::Wild, &[2]),
+            Ok(3) => WildCards::new(1, JokerRule::Wild, &[]),
+            Ok(4) => WildCards::new(1, JokerRule::Bug, &[]),
+            Ok(_) => Ok(WildCards::NONE),
+            Err(_) => {
+                println!("無効な選択です。デフォルトでワイルドカードなしを選択します。");
+                Ok(WildCards::NONE)
+            }
+        };
+        wild_cards.unwrap_or(WildCards::NONE)
+    }
+    
+    pub fn prompt_for_bot_kind() -> Option<BotKind> {
+        println!("コンピュータが操作しますか？ (y/n)");
+        if !InputHandler::get_bool("") {
+            return None;
+        }
+        
+        println!("\n--コンピュータの種類を選択--");
+        for (i, kind) in BotKind::all().iter().enumerate() {
+            println!("{}. {}", i + 1, kind.name());
+        }
+        
+        let choice = match InputHandler::get_menu_choice(BotKind::all().len()) {
+            Ok(choice) => choice,
+            Err(_) => {
+                println!("無効な選択です。デフォルトで{}を選択します。", BotKind::Random.name());
+                1
+            }
+        };
+        
+        Some(BotKind::all()[choice - 1])
+    }
+    
+    pub fn prompt_for_seed() -> Option<u64> {
+        let input = InputHandler::get_string("シード値（空欄でランダム）");
+        if input.is_empty() {
+            return None;
+        }
+        
+        match input.parse::<u64>() {
+            Ok(seed) => Some(seed),
+            Err(_) => {
+                println!("無効なシード値です。ランダムに配ります。");
+                None
+            }
+        }
+    }
+    
     pub fn prompt_for_blinds() -> (u32, u32) {
         let small_blind = match InputHandler::get_u32("スモールブラインド額") {
             Ok(amount) => amount,
@@ -4,38 +4,51 @@ use crate::application::usecase::exchange_cards_usecase::{ExchangeCardsParams, E
 use crate::application::usecase::place_bet_usecase::{PlaceBetParams, PlaceBetUseCase};
 use crate::application::usecase::start_game_usecase::StartGameUseCase;
 use crate::application::usecase::start_game_usecase::StartGameParams;
-use crate::domain::model::game::{GameId, GamePhase};
+use crate::application::usecase::showdown_usecase::{ShowdownParams, ShowdownUseCase};
+use std::collections::HashMap;
+
+use crate::domain::model::bet::BetAction;
+use crate::domain::model::card::WildCards;
+use crate::domain::model::game::{Game, GameId, GamePhase, GameVariant, OddChipRule, ShortDeckTripsRule, TableRules};
+use crate::domain::model::player::{BotKind, PlayerId};
+use crate::domain::repository::game_history_repository::GameHistoryRepository;
 use crate::domain::repository::game_repository::GameRepository;
 use crate::domain::repository::player_repository::PlayerRepository;
-use crate::domain::service::game_rule::GameRuleService;
+use crate::domain::service::player_strategy::{create_strategy, PlayerStrategy, PlayerView};
 use crate::presentation::cli::game_view::GameView;
 use crate::presentation::cli::input_handler::InputHandler;
 use crate::domain::model::event::{EventPublisher, EventSubscriber};
 
-pub struct MenuController<G, P, E>
+pub struct MenuController<G, P, E, H>
 where 
     G: GameRepository + Clone,
     P: PlayerRepository + Clone,
     E: EventPublisher + EventSubscriber + Clone,
+    H: GameHistoryRepository + Clone,
 {
     game_repository: G,
     player_repository: P,
     event_publisher: E,
+    history_repository: H,
     current_game_id: Option<GameId>,
+    bots: HashMap<PlayerId, Box<dyn PlayerStrategy>>, // コンピュータが操作する席の戦略
 }
 
-impl<G, P, E> MenuController<G, P, E>
+impl<G, P, E, H> MenuController<G, P, E, H>
 where 
     G: GameRepository + Clone,
     P: PlayerRepository + Clone,
     E: EventPublisher + EventSubscriber + Clone,
+    H: GameHistoryRepository + Clone,
 {
-    pub fn new(game_repository: G, player_repository: P, event_publisher: E) -> Self {
+    pub fn new(game_repository: G, player_repository: P, event_publisher: E, history_repository: H) -> Self {
         Self {
             game_repository,
             player_repository,
             event_publisher,
+            history_repository,
             current_game_id: None,
+            bots: HashMap::new(),
         }
     }
     
@@ -87,10 +100,41 @@ where
         // ブラインド額の設定
         let (small_blind, big_blind) = GameView::prompt_for_blinds();
         
+        // ベットの決まりの選択
+        let betting_structure = GameView::prompt_for_betting_structure(big_blind);
+        
+        // ボタンの動かし方の選択
+        let button_rule = GameView::prompt_for_button_rule();
+        
+        // ショートデッキではスリーカードとストレートの順番を選ぶ
+        let short_deck_trips_rule = match variant {
+            GameVariant::ShortDeckHoldem => GameView::prompt_for_short_deck_trips_rule(),
+            _ => ShortDeckTripsRule::default(),
+        };
+        
+        // ローのゲーム以外ではワイルドカードを選べる
+        let wild_cards = if variant.is_low_only() || variant.is_hi_lo() {
+            WildCards::NONE
+        } else {
+            GameView::prompt_for_wild_cards()
+        };
+        
+        // シードの設定（ハンドを再現したい場合のみ）
+        let seed = GameView::prompt_for_seed();
+        
         let params = CreateGameParams {
             variant,
             small_blind,
             big_blind,
+            betting_structure,
+            rules: TableRules {
+                button_rule,
+                odd_chip_rule: OddChipRule::default(),
+                short_deck_trips_rule,
+                wild_cards,
+                hand_ranking_table: None,
+            },
+            seed,
         };
         
         let mut usecase = CreateGameUseCase::new(
@@ -134,15 +178,19 @@ where
                 }
             };
             
+            let bot = GameView::prompt_for_bot_kind();
+            
             let params = AddPlayerParams {
                 game_id: game_id.clone(),
                 player_name,
                 initial_chips,
+                bot,
             };
             
             let mut usecase = AddPlayerUseCase::new(
                 self.game_repository.clone(),
-                self.player_repository.clone()
+                self.player_repository.clone(),
+                self.event_publisher.clone()
             );
             
             match usecase.execute(params) {
@@ -154,7 +202,10 @@ where
             if let Some(game) = self.game_repository.find_by_id(&game_id) {
                 println!("\n--現在のプレイヤー--");
                 for (i, player) in game.players().iter().enumerate() {
-                    println!("{}. {} ({}チップ)", i + 1, player.name(), player.chips());
+                    match player.bot() {
+                        Some(kind) => println!("{}. {} ({}チップ) [コンピュータ: {}]", i + 1, player.name(), player.chips(), kind.name()),
+                        None => println!("{}. {} ({}チップ)", i + 1, player.name(), player.chips()),
+                    }
                 }
                 
                 if game.players().len() >= 2 {
@@ -175,7 +226,7 @@ where
         };
         
         // ゲームを開始
-        let mut start_usecase = StartGameUseCase::new(self.game_repository.clone());
+        let mut start_usecase = StartGameUseCase::new(self.game_repository.clone(), self.event_publisher.clone());
         let params = StartGameParams {
             game_id: game_id.clone(),
         };
@@ -307,7 +358,7 @@ where
         
         println!("\n新しいゲームを始めますか？ (y/n)");
         if InputHandler::get_bool("") {
-            let mut restart_usecase = StartGameUseCase::new(self.game_repository.clone());
+            let mut restart_usecase = StartGameUseCase::new(self.game_repository.clone(), self.event_publisher.clone());
             
             // ゲームをリセット
             if let Some(mut game) = self.game_repository.find_by_id(&game_id) {
@@ -316,10 +367,17 @@ where
                     return;
                 }
                 
+                let events = game.take_events();
                 if let Err(e) = self.game_repository.save(&game) {
                     GameView::display_error(&e);
                     return;
                 }
+                for event in events {
+                    if let Err(e) = self.event_publisher.publish(event) {
+                        GameView::display_error(&e);
+                        return;
+                    }
+                }
                 
                 // ゲームを再開始
                 let params = StartGameParams {
@@ -351,15 +409,25 @@ where
         let current_player = &game.players()[current_index];
         println!("\n現在のプレイヤー: {}", current_player.name());
         
-        // プレイヤーが自分の手札を確認
-        GameView::display_player_hand(current_player);
-        
-        // アクションの選択
-        let (action, bet_amount) = match GameView::get_player_action(&game, current_index) {
-            Ok(result) => result,
-            Err(e) => {
-                GameView::display_error(&e);
-                return;
+        // アクションの選択（コンピュータの席は戦略に任せる）
+        let (action, bet_amount) = if let Some(kind) = current_player.bot() {
+            match self.bot_action(&game, current_index, kind) {
+                Ok(result) => result,
+                Err(e) => {
+                    GameView::display_error(&e);
+                    return;
+                }
+            }
+        } else {
+            // プレイヤーが自分の手札を確認
+            GameView::display_player_hand(&game, current_player);
+            
+            match GameView::get_player_action(&game, current_index) {
+                Ok(result) => result,
+                Err(e) => {
+                    GameView::display_error(&e);
+                    return;
+                }
             }
         };
         
@@ -371,7 +439,7 @@ where
             bet_amount,
         };
         
-        let mut usecase = PlaceBetUseCase::new(self.game_repository.clone());
+        let mut usecase = PlaceBetUseCase::new(self.game_repository.clone(), self.event_publisher.clone());
         if let Err(e) = usecase.execute(params) {
             GameView::display_error(&e);
         }
@@ -393,12 +461,22 @@ where
         let current_player = &game.players()[current_index];
         println!("\n現在のプレイヤー: {}", current_player.name());
         
-        // カード交換の選択
-        let card_indices = match GameView::get_card_exchange(current_player) {
-            Ok(indices) => indices,
-            Err(e) => {
-                GameView::display_error(&e);
-                return;
+        // カード交換の選択（コンピュータの席は戦略に任せる）
+        let card_indices = if let Some(kind) = current_player.bot() {
+            match self.bot_discards(&game, current_index, kind) {
+                Ok(indices) => indices,
+                Err(e) => {
+                    GameView::display_error(&e);
+                    return;
+                }
+            }
+        } else {
+            match GameView::get_card_exchange(&game, current_player) {
+                Ok(indices) => indices,
+                Err(e) => {
+                    GameView::display_error(&e);
+                    return;
+                }
             }
         };
         
@@ -409,7 +487,7 @@ where
             card_indices,
         };
         
-        let mut usecase = ExchangeCardsUseCase::new(self.game_repository.clone());
+        let mut usecase = ExchangeCardsUseCase::new(self.game_repository.clone(), self.event_publisher.clone());
         if let Err(e) = usecase.execute(params) {
             GameView::display_error(&e);
         }
@@ -426,22 +504,44 @@ where
         // 全プレイヤーの手札を表示
         for player in game.players() {
             if !player.is_folded() {
-                GameView::display_player_hand(player);
+                GameView::display_player_hand(&game, player);
             }
         }
         
-        // 勝者の決定と表示
-        let winners = GameRuleService::determine_winners(&game);
-        GameView::display_winners(&game, &winners);
-        
-        // ポットの分配
-        let mut updated_game = game.clone();
-        if let Err(e) = GameRuleService::distribute_pot(&mut updated_game) {
-            GameView::display_error(&e);
-        } else if let Err(e) = self.game_repository.save(&updated_game) {
-            GameView::display_error(&e);
+        // ポットの分配と勝者の表示（メインポット・サイドポットごと）、ハンドの記録を履歴に保存
+        let mut usecase = ShowdownUseCase::new(
+            self.game_repository.clone(),
+            self.history_repository.clone(),
+            self.event_publisher.clone()
+        );
+        match usecase.execute(ShowdownParams { game_id: game_id.clone() }) {
+            Ok(result) => GameView::display_pot_distributions(&result.game, &result.distributions),
+            Err(e) => GameView::display_error(&e),
         }
         
         InputHandler::wait_for_enter();
     }
-} 
\ No newline at end of file
+    
+    // コンピュータの席の戦略（初めて手番が来たときに作成する）
+    fn strategy_for(&mut self, game: &Game, seat: usize, kind: BotKind) -> &mut Box<dyn PlayerStrategy> {
+        let player_id = game.players()[seat].id().clone();
+        self.bots.entry(player_id).or_insert_with(|| create_strategy(kind, None))
+    }
+    
+    fn bot_action(&mut self, game: &Game, seat: usize, kind: BotKind) -> Result<(BetAction, Option<u32>), String> {
+        let view = PlayerView::new(game, seat)?;
+        let (action, bet_amount) = self.strategy_for(game, seat, kind).decide_action(&view);
+        match bet_amount {
+            Some(amount) => println!("{} は {}（{}チップ）を選択しました", game.players()[seat].name(), action, amount),
+            None => println!("{} は {} を選択しました", game.players()[seat].name(), action),
+        }
+        Ok((action, bet_amount))
+    }
+    
+    fn bot_discards(&mut self, game: &Game, seat: usize, kind: BotKind) -> Result<Vec<usize>, String> {
+        let view = PlayerView::new(game, seat)?;
+        let card_indices = self.strategy_for(game, seat, kind).choose_discards(&view);
+        println!("{} は {}枚のカードを交換しました", game.players()[seat].name(), card_indices.len());
+        Ok(card_indices)
+    }
+}
@@ -1,3 +1,7 @@
 pub mod menu;
 pub mod game_view;
 pub mod input_handler;
+pub mod equity_command;
+
+#[cfg(test)]
+mod equity_command_test;